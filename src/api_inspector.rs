@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use reqwest::{Method, Request, StatusCode};
+
+use crate::http::{HttpTransport, ResponseFuture};
+
+/// Recent exchanges of all clients, oldest first.
+static EXCHANGES: Lazy<Mutex<VecDeque<ApiExchange>>> = Lazy::new(Default::default);
+
+/// A request to GitLab and its response, as recorded by [InspectingTransport].
+#[derive(Debug, Clone)]
+pub struct ApiExchange {
+    pub timestamp: DateTime<Local>,
+    pub method: Method,
+    pub url: String,
+    /// none if no response was received
+    pub status: Option<StatusCode>,
+    pub duration: Duration,
+    /// the response body, or the error if no response was received
+    pub body: String,
+}
+
+impl ApiExchange {
+    /// The body, pretty-printed if it is JSON.
+    pub fn pretty_body(&self) -> String {
+        serde_json::from_str::<serde_json::Value>(&self.body)
+            .and_then(|json| serde_json::to_string_pretty(&json))
+            .unwrap_or_else(|_| self.body.clone())
+    }
+}
+
+/// Number of exchanges kept for the API inspector.
+const CAPACITY: usize = 100;
+
+/// The recorded exchanges, oldest first.
+pub fn recent_exchanges() -> Vec<ApiExchange> {
+    EXCHANGES.lock().expect("api inspector lock poisoned")
+        .iter()
+        .cloned()
+        .collect()
+}
+
+fn record(exchange: ApiExchange) {
+    let mut exchanges = EXCHANGES.lock().expect("api inspector lock poisoned");
+    if exchanges.len() == CAPACITY {
+        exchanges.pop_front();
+    }
+    exchanges.push_back(exchange);
+}
+
+/// [HttpTransport] recording the requests and responses of the wrapped
+/// transport for the API inspector; used when `GLIM_DEBUG` is set.
+pub struct InspectingTransport {
+    inner: Box<dyn HttpTransport>,
+}
+
+impl InspectingTransport {
+    pub fn new(inner: impl HttpTransport + 'static) -> Self {
+        Self { inner: Box::new(inner) }
+    }
+}
+
+impl HttpTransport for InspectingTransport {
+    fn send(&self, request: Request) -> ResponseFuture<'_> {
+        let method = request.method().clone();
+        let url = request.url().to_string();
+
+        Box::pin(async move {
+            let timestamp = Local::now();
+            let started = Instant::now();
+            let result = self.inner.send(request).await;
+
+            let (status, body) = match &result {
+                Ok(response) => (Some(response.status), response.body.clone()),
+                Err(e)       => (None, e.to_string()),
+            };
+            record(ApiExchange { timestamp, method, url, status, duration: started.elapsed(), body });
+
+            result
+        })
+    }
+}
@@ -0,0 +1,129 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::event::GlimEvent;
+use crate::id::{JobId, ProjectId};
+use crate::result::{GlimError, Result};
+
+/// Persistent record of write actions performed through glim. Each entry
+/// is appended as a single JSON line to the audit log file.
+pub struct AuditLog {
+    path: PathBuf,
+    entries: Vec<AuditEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Local>,
+    #[serde(flatten)]
+    pub action: AuditAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AuditAction {
+    ConfigUpdated { gitlab_url: String },
+    IssueCreated { project_id: ProjectId, url: String },
+    JobRetried { project_id: ProjectId, job_id: JobId },
+    PipelineTriggered { project_id: ProjectId, branch: String },
+    SchedulePlayed { project_id: ProjectId, schedule_id: u32 },
+}
+
+impl AuditLog {
+    /// number of entries kept in memory for the audit log popup
+    const MAX_ENTRIES: usize = 500;
+
+    pub fn new(path: PathBuf) -> Self {
+        let entries = Self::read_entries(&path);
+        Self { path, entries }
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent) -> Result<()> {
+        let action = match event {
+            GlimEvent::UpdateConfig(config) => Some(AuditAction::ConfigUpdated {
+                gitlab_url: config.gitlab_url.clone(),
+            }),
+            GlimEvent::IssueCreated(project_id, url) => Some(AuditAction::IssueCreated {
+                project_id: *project_id,
+                url: url.clone(),
+            }),
+            GlimEvent::JobRetried(project_id, _, job_id) => Some(AuditAction::JobRetried {
+                project_id: *project_id,
+                job_id: *job_id,
+            }),
+            GlimEvent::PipelineTriggered(project_id, branch) => Some(AuditAction::PipelineTriggered {
+                project_id: *project_id,
+                branch: branch.clone(),
+            }),
+            GlimEvent::SchedulePlayed(project_id, schedule_id) => Some(AuditAction::SchedulePlayed {
+                project_id: *project_id,
+                schedule_id: *schedule_id,
+            }),
+            _ => None,
+        };
+
+        match action {
+            Some(action) => self.record(action),
+            None         => Ok(()),
+        }
+    }
+
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    fn record(&mut self, action: AuditAction) -> Result<()> {
+        let entry = AuditEntry { timestamp: Local::now(), action };
+        let line = serde_json::to_string(&entry)
+            .map_err(|e| GlimError::GeneralError(format!("failed to serialize audit entry: {e}")))?;
+
+        self.entries.push(entry);
+        if self.entries.len() > Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| GlimError::GeneralError(format!("failed to create audit log dir: {e}")))?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut f| writeln!(f, "{line}"))
+            .map_err(|e| GlimError::GeneralError(format!("failed to write audit log: {e}")))
+    }
+
+    fn read_entries(path: &Path) -> Vec<AuditEntry> {
+        let content = std::fs::read_to_string(path).unwrap_or_default();
+        let entries = content.lines()
+            .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+            .collect_vec();
+
+        let skip = entries.len().saturating_sub(Self::MAX_ENTRIES);
+        entries.into_iter().skip(skip).collect()
+    }
+}
+
+impl AuditAction {
+    pub fn description(&self) -> String {
+        match self {
+            AuditAction::ConfigUpdated { gitlab_url } =>
+                format!("configuration updated for {gitlab_url}"),
+            AuditAction::IssueCreated { project_id, url } =>
+                format!("issue created for project_id={project_id}: {url}"),
+            AuditAction::JobRetried { project_id, job_id } =>
+                format!("job_id={job_id} retried for project_id={project_id}"),
+            AuditAction::PipelineTriggered { project_id, branch } =>
+                format!("pipeline created on {branch} for project_id={project_id}"),
+            AuditAction::SchedulePlayed { project_id, schedule_id } =>
+                format!("pipeline schedule {schedule_id} run for project_id={project_id}"),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::PipelineStatus;
+
+/// Color depth used for rendering, see [GlimConfig::colors](crate::glim_app::GlimConfig::colors).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorSupport {
+    /// truecolor if the terminal advertises it, else 256 colors
+    #[default]
+    Auto,
+    Truecolor,
+    /// colors are downsampled to the 256-color palette
+    Ansi256,
+}
+
+/// Icons of pipeline and runner statuses, see [GlimConfig::icons](crate::glim_app::GlimConfig::icons).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IconSupport {
+    /// emoji unless the terminal is unlikely to render them
+    #[default]
+    Auto,
+    Emoji,
+    Ascii,
+}
+
+static ASCII_ICONS: AtomicBool = AtomicBool::new(false);
+
+/// Glyphs replacing the icons of pipeline statuses, see [set_glyphs].
+static GLYPHS: Lazy<RwLock<HashMap<PipelineStatus, String>>> = Lazy::new(Default::default);
+
+/// Accessibility mode, see [set_accessible].
+static ACCESSIBLE: AtomicBool = AtomicBool::new(false);
+
+/// Whether accessibility mode was requested with `--accessible`, overriding the configuration.
+static ACCESSIBLE_FORCED: AtomicBool = AtomicBool::new(false);
+
+impl ColorSupport {
+    pub fn use_256_colors(self) -> bool {
+        match self {
+            ColorSupport::Auto      => !detect_truecolor(),
+            ColorSupport::Truecolor => false,
+            ColorSupport::Ansi256   => true,
+        }
+    }
+}
+
+impl IconSupport {
+    pub fn use_ascii(self) -> bool {
+        match self {
+            IconSupport::Auto  => !detect_emoji(),
+            IconSupport::Emoji => false,
+            IconSupport::Ascii => true,
+        }
+    }
+}
+
+/// Sets the icons used by [IconRepresentable::icon](crate::domain::IconRepresentable::icon).
+pub fn set_icon_support(icons: IconSupport) {
+    ASCII_ICONS.store(icons.use_ascii(), Ordering::Relaxed);
+}
+
+/// Whether icons are rendered as ASCII in place of emoji.
+pub fn ascii_icons() -> bool {
+    ASCII_ICONS.load(Ordering::Relaxed)
+}
+
+/// Sets the glyphs of pipeline statuses, see [GlimConfig::glyphs](crate::glim_app::GlimConfig::glyphs);
+/// statuses without a glyph keep their emoji or ASCII icon.
+pub fn set_glyphs(glyphs: &HashMap<PipelineStatus, String>) {
+    *GLYPHS.write().expect("glyphs lock poisoned") = glyphs.iter()
+        .filter(|(_, glyph)| !glyph.is_empty())
+        .map(|(status, glyph)| (status.clone(), glyph.clone()))
+        .collect();
+}
+
+/// The configured glyph of the status, if any.
+pub fn glyph(status: &PipelineStatus) -> Option<String> {
+    GLYPHS.read().expect("glyphs lock poisoned").get(status).cloned()
+}
+
+/// Enables accessibility mode regardless of the configuration, for `--accessible`.
+pub fn force_accessible() {
+    ACCESSIBLE_FORCED.store(true, Ordering::Relaxed);
+    ACCESSIBLE.store(true, Ordering::Relaxed);
+}
+
+/// Sets accessibility mode, see [GlimConfig::accessible](crate::glim_app::GlimConfig::accessible);
+/// it stays enabled if forced by [force_accessible].
+pub fn set_accessible(enabled: bool) {
+    let enabled = enabled || ACCESSIBLE_FORCED.load(Ordering::Relaxed);
+    ACCESSIBLE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether accessibility mode is enabled: no animations, statuses in words
+/// next to their icons, and the selection described in plain text.
+pub fn accessible() -> bool {
+    ACCESSIBLE.load(Ordering::Relaxed)
+}
+
+/// Truecolor support, as advertised by `COLORTERM` or known terminals.
+fn detect_truecolor() -> bool {
+    let env = |key: &str| std::env::var(key).unwrap_or_default().to_lowercase();
+
+    matches!(env("COLORTERM").as_str(), "truecolor" | "24bit")
+        || env("TERM").ends_with("-direct")
+        || matches!(env("TERM_PROGRAM").as_str(), "iterm.app" | "wezterm" | "vscode" | "ghostty")
+        || std::env::var("WT_SESSION").is_ok()
+}
+
+/// Emoji support, ruled out for the Linux console, dumb terminals and
+/// non-UTF-8 locales.
+fn detect_emoji() -> bool {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if matches!(term.as_str(), "linux" | "dumb" | "vt100" | "vt220") {
+        return false;
+    }
+    if cfg!(windows) {
+        return std::env::var("WT_SESSION").is_ok();
+    }
+
+    // the first locale variable set takes precedence, as for setlocale(3)
+    ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+        .find_map(|key| std::env::var(key).ok().filter(|v| !v.is_empty()))
+        .is_some_and(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
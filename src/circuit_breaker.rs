@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::id::ProjectId;
+
+/// Endpoints polled in the background, each guarded per project by the
+/// [CircuitBreaker].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    Pipelines,
+    Jobs,
+    /// trigger jobs of a pipeline
+    Bridges,
+    Approvals,
+}
+
+/// Stops requesting an endpoint of a project after repeated failures, until
+/// a cool-down period has passed. After the cool-down, a single request is
+/// let through; the circuit opens again if it fails.
+///
+/// Clones share their state, so that the breaker can be moved into the tasks
+/// performing the requests.
+#[derive(Clone, Default)]
+pub struct CircuitBreaker {
+    circuits: Arc<Mutex<HashMap<(Endpoint, ProjectId), Circuit>>>,
+}
+
+#[derive(Default)]
+struct Circuit {
+    /// consecutive failures
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// consecutive failures before the circuit opens
+    const FAILURE_THRESHOLD: u32 = 3;
+    pub const COOL_DOWN: Duration = Duration::from_secs(5 * 60);
+
+    /// Returns true if requests to the endpoint are currently skipped.
+    pub fn is_open(&self, endpoint: Endpoint, project_id: ProjectId) -> bool {
+        self.circuits.lock().unwrap()
+            .get(&(endpoint, project_id))
+            .and_then(|c| c.open_until)
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    pub fn record_success(&self, endpoint: Endpoint, project_id: ProjectId) {
+        self.circuits.lock().unwrap()
+            .remove(&(endpoint, project_id));
+    }
+
+    /// Records a failed request. Returns true if the circuit opened for the
+    /// first time since the last successful request.
+    pub fn record_failure(&self, endpoint: Endpoint, project_id: ProjectId) -> bool {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits.entry((endpoint, project_id)).or_default();
+
+        circuit.failures += 1;
+        if circuit.failures >= Self::FAILURE_THRESHOLD {
+            circuit.open_until = Some(Instant::now() + Self::COOL_DOWN);
+        }
+
+        circuit.failures == Self::FAILURE_THRESHOLD
+    }
+}
+
+impl std::fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            Endpoint::Pipelines => "pipelines",
+            Endpoint::Jobs      => "jobs",
+            Endpoint::Bridges   => "bridges",
+            Endpoint::Approvals => "approvals",
+        };
+        write!(f, "{name}")
+    }
+}
@@ -1,63 +1,248 @@
-use std::path::Path;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::sync::mpsc::Sender;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use itertools::Itertools;
-use reqwest::{Client, RequestBuilder};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Client, Request, RequestBuilder, StatusCode};
 use serde::Deserialize;
 use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 
+use crate::circuit_breaker::{CircuitBreaker, Endpoint};
+use crate::connectivity::{Connectivity, ConnectivityTransport};
 use crate::dispatcher::Dispatcher;
-use crate::domain::{JobDto, PipelineDto, ProjectDto};
+use crate::etag_cache::ETagCache;
+use crate::domain::{ApprovalsDto, BranchDto, CommitDetailsDto, PersonalAccessTokenDto, RunnerDto, ScheduleDto, JobDto, PipelineDto, ProjectDto};
 use crate::event::{GlimEvent, GlitchState, IntoGlimEvent};
 use crate::event::GlimEvent::GlitchOverride;
-use crate::glim_app::GlimConfig;
+use crate::glim_app::{GlimConfig, InstanceConfig};
+use crate::gitlab_version::{ApiFeature, ApiFeatures, GitlabVersion, VersionDto};
+use crate::api_inspector::InspectingTransport;
+use crate::http::{HttpConfig, HttpResponse, HttpTransport, ReqwestTransport};
 use crate::id::{JobId, PipelineId, ProjectId};
+use crate::issue::{FailureReport, IssueConfig};
+use crate::negative_cache::{GoneResource, NegativeCache};
+use crate::page_size::{PageSizeBounds, PageSizeTuner};
+use crate::token_store;
 use crate::result::*;
 use crate::result::GlimError::{GeneralError, JsonDeserializeError};
 
+/// Interval between job polls for a followed pipeline.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Maximum number of job polls for a followed pipeline, ~30 minutes.
+const FOLLOW_POLL_BUDGET: u32 = 600;
+/// Progress of artifact downloads is reported every time this many bytes
+/// have been received.
+const ARTIFACTS_PROGRESS_STEP: u64 = 256 * 1024;
+/// Pipelines per request for older pipelines, paged through in the project details.
+const OLDER_PIPELINES_PAGE_SIZE: usize = 40;
+
 pub struct GitlabClient {
     sender: Sender<GlimEvent>,
+    /// index of the GitLab instance, used for namespacing project ids
+    instance: u8,
     base_url: String,
     private_token: String,
+    /// used for building requests, which are sent through [Self::transport]
     client: Client,
+    transport: Arc<dyn HttpTransport>,
+    /// tracks whether GitLab is reachable, see [ConnectivityTransport]
+    connectivity: Connectivity,
     search_filter: Option<String>,
-    log_response: bool,
+    request_config: RequestConfig,
+    /// requests are recorded for the API inspector, see [InspectingTransport]
+    debug: bool,
+    /// skips background requests to endpoints failing repeatedly for a project
+    circuit_breaker: CircuitBreaker,
+    /// skips requests to resources which no longer exist
+    negative_cache: NegativeCache,
+    /// page size of pipeline requests
+    page_size: PageSizeTuner,
+    /// ETags of polled resources, for conditional requests
+    etags: ETagCache,
+    /// features of the API supported by the GitLab version, see [Self::dispatch_get_version]
+    features: ApiFeatures,
+    /// interval of the regular polling, see [GlimConfig::poll_interval]
+    poll_interval: Duration,
+    /// regular polling; only set for the primary instance
+    poller: Option<JoinHandle<()>>,
+    follow_poller: Option<JoinHandle<()>>,
     rt: Runtime
 }
 
+/// Dispatches the events of a [GitlabClient], attributing them to its GitLab
+/// instance, see [GlimEvent::with_instance].
+#[derive(Clone)]
+struct InstanceSender {
+    sender: Sender<GlimEvent>,
+    instance: u8,
+}
+
+impl Dispatcher for InstanceSender {
+    fn dispatch(&self, event: GlimEvent) {
+        self.sender.dispatch(event.with_instance(self.instance));
+    }
+}
+
+/// Retry policy applied to all requests against the Gitlab API.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestConfig {
+    /// Maximum number of retries for transient failures.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each subsequent retry.
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two retries.
+    pub max_delay: Duration,
+    /// Bounds of the page size of pipeline requests, see [PageSizeTuner].
+    pub page_size: PageSizeBounds,
+}
+
+/// Options of the client of the primary GitLab instance, see [GitlabClient::new].
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    pub search_filter: Option<String>,
+    pub request_config: RequestConfig,
+    pub http_config: HttpConfig,
+    /// interval of the regular polling, see [GlimConfig::poll_interval]
+    pub poll_interval: Duration,
+    /// requests are recorded for the API inspector, see [InspectingTransport]
+    pub debug: bool,
+}
+
+impl ClientOptions {
+    pub fn from_config(config: &GlimConfig, debug: bool) -> Self {
+        Self {
+            search_filter: config.search_filter.clone(),
+            request_config: RequestConfig::from(config),
+            http_config: HttpConfig::from(config),
+            poll_interval: config.poll_interval(),
+            debug,
+        }
+    }
+}
+
 
 impl GitlabClient {
     pub fn new(
         sender: Sender<GlimEvent>,
         host: String,
         private_token: String,
-        search_filter: Option<String>,
-        debug: bool
+        options: ClientOptions,
     ) -> Self {
-        let client = Self {
+        let http_client = Self::http_client(&sender, &options.http_config);
+        let connectivity = Connectivity::new(sender.clone());
+        let transport = Self::transport(http_client.clone(), options.debug, &connectivity);
+        let mut client = Self {
+            client: http_client,
+            connectivity,
+            search_filter: options.search_filter,
+            debug: options.debug,
+            poll_interval: options.poll_interval,
+            ..Self::with_transport(sender, 0, host, private_token, options.request_config, transport)
+        };
+        client.register_polling();
+        client
+    }
+
+    /// Creates a client sending all requests through `transport`, without
+    /// polling. [Self::new] and [Self::for_instance] create the transport
+    /// from the configuration instead.
+    pub fn with_transport(
+        sender: Sender<GlimEvent>,
+        instance: u8,
+        base_url: String,
+        private_token: String,
+        request_config: RequestConfig,
+        transport: Arc<dyn HttpTransport>,
+    ) -> Self {
+        Self {
+            connectivity: Connectivity::new(sender.clone()),
             sender,
-            base_url: host,
+            instance,
+            base_url,
             private_token,
             client: Client::new(),
-            search_filter,
+            transport,
+            search_filter: None,
+            request_config,
             rt: Runtime::new().unwrap(),
-            log_response: debug
-        };
-        client.register_polling();
-        client
+            debug: false,
+            circuit_breaker: CircuitBreaker::default(),
+            negative_cache: NegativeCache::default(),
+            page_size: PageSizeTuner::new(request_config.page_size),
+            etags: ETagCache::default(),
+            features: ApiFeatures::default(),
+            // unused without polling, see Self::register_polling
+            poll_interval: Duration::from_secs(GlimConfig::DEFAULT_POLL_INTERVAL),
+            poller: None,
+            follow_poller: None,
+        }
+    }
+
+    /// Creates a client for an additional GitLab instance. Polling is driven
+    /// by the client of the primary instance.
+    pub fn for_instance(
+        sender: Sender<GlimEvent>,
+        instance: u8,
+        config: &InstanceConfig,
+        request_config: RequestConfig,
+        http_config: &HttpConfig,
+        debug: bool
+    ) -> Self {
+        let http_client = Self::http_client(&sender, http_config);
+        let private_token = token_store::instance_token(config).unwrap_or_else(|e| {
+            sender.dispatch(GlimEvent::Error(e));
+            config.gitlab_token.clone()
+        });
+        let connectivity = Connectivity::new(sender.clone());
+        let transport = Self::transport(http_client.clone(), debug, &connectivity);
+
+        // polling is driven by the primary instance
+        Self {
+            client: http_client,
+            connectivity,
+            search_filter: config.search_filter.clone(),
+            debug,
+            ..Self::with_transport(sender, instance, config.gitlab_url.clone(), private_token, request_config, transport)
+        }
     }
     
     pub fn update_config(&mut self, config: GlimConfig) {
+        let private_token = token_store::primary_token(&config).unwrap_or_else(|e| {
+            self.sender.dispatch(GlimEvent::Error(e));
+            config.gitlab_token.clone()
+        });
+
+        let http_client = Self::http_client(&self.sender, &HttpConfig::from(&config));
+        self.client = http_client.clone();
+        self.transport = Self::transport(http_client, self.debug, &self.connectivity);
+        // the version is probed again, as the url may point to another instance
+        self.features = ApiFeatures::default();
+        self.request_config = RequestConfig::from(&config);
+        let poll_interval = config.poll_interval();
         self.base_url = config.gitlab_url;
-        self.private_token = config.gitlab_token;
+        self.private_token = private_token;
+        self.page_size = PageSizeTuner::new(self.request_config.page_size);
         self.search_filter = config.search_filter;
+
+        if poll_interval != self.poll_interval {
+            self.poll_interval = poll_interval;
+            self.resync_polling();
+        }
     }
 
     pub fn debug(&self) -> bool {
-        self.log_response
+        self.debug
+    }
+
+    /// Effective page size of pipeline requests.
+    pub fn page_size(&self) -> u16 {
+        self.page_size.current()
     }
     
     pub fn new_from_config(
@@ -65,39 +250,521 @@ impl GitlabClient {
         config: GlimConfig,
         debug: bool
     ) -> Self {
-        Self::new(
-            sender,
-            config.gitlab_url,
-            config.gitlab_token,
-            config.search_filter,
-            debug
-        )
+        let options = ClientOptions::from_config(&config, debug);
+        let private_token = token_store::primary_token(&config).unwrap_or_else(|e| {
+            sender.dispatch(GlimEvent::Error(e));
+            config.gitlab_token.clone()
+        });
+
+        Self::new(sender, config.gitlab_url, private_token, options)
+    }
+
+    /// Transport of the requests, tracking connectivity and recording them
+    /// for the API inspector in debug mode.
+    fn transport(client: Client, debug: bool, connectivity: &Connectivity) -> Arc<dyn HttpTransport> {
+        let transport = ConnectivityTransport::new(ReqwestTransport::new(client), connectivity.clone());
+        if debug {
+            Arc::new(InspectingTransport::new(transport))
+        } else {
+            Arc::new(transport)
+        }
+    }
+
+    /// Creates the HTTP client, falling back to a client without the
+    /// configured headers if they are invalid.
+    fn http_client(sender: &Sender<GlimEvent>, http_config: &HttpConfig) -> Client {
+        if !http_config.headers.is_empty() {
+            let headers = http_config.headers.iter().join(", ");
+            sender.dispatch(GlimEvent::Log(format!("sending extra HTTP headers: {headers}")));
+        }
+        if http_config.insecure_skip_verify {
+            sender.dispatch(GlimEvent::Log("TLS certificate verification is disabled".to_string()));
+        }
+
+        http_config.build_client().unwrap_or_else(|e| {
+            sender.dispatch(GlimEvent::Error(e));
+            Client::new()
+        })
     }
     
     pub fn dispatch_download_job_log(
         &self,
         project_id: ProjectId,
         job_id: JobId,
+    ) {
+        self.dispatch_job_trace(project_id, job_id, GlimEvent::JobLogDownloaded, |_, _, e| GlimEvent::Error(e));
+    }
+
+    pub fn dispatch_get_job_trace(
+        &self,
+        project_id: ProjectId,
+        job_id: JobId,
+    ) {
+        self.dispatch_job_trace(project_id, job_id, GlimEvent::ReceivedJobTrace, GlimEvent::JobTraceUnavailable);
+    }
+
+    fn dispatch_job_trace(
+        &self,
+        project_id: ProjectId,
+        job_id: JobId,
+        to_event: fn(ProjectId, JobId, String) -> GlimEvent,
+        to_error: fn(ProjectId, JobId, GlimError) -> GlimEvent,
     ) {
         let get_trace_request = self.client
             .get(format!("{}/projects/{project_id}/jobs/{job_id}/trace", self.base_url))
             .header("PRIVATE-TOKEN", &self.private_token);
         
-        let sender = self.sender.clone();
+        let sender = self.instance_sender();
+        let transport = self.transport.clone();
         self.rt.spawn(async move {
-            let event = Self::http_request(get_trace_request).await
-                .map(|trace| GlimEvent::JobLogDownloaded(project_id, job_id, trace))
+            let event = Self::http_request(transport.as_ref(), get_trace_request).await
+                .map(|trace| to_event(project_id, job_id, trace))
+                .unwrap_or_else(|e| to_error(project_id, job_id, e));
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Downloads the artifacts archive of a job into `dir`, reporting the
+    /// progress with [GlimEvent::ArtifactsDownloadProgress].
+    pub fn dispatch_download_artifacts(
+        &self,
+        project_id: ProjectId,
+        job_id: JobId,
+        dir: PathBuf,
+    ) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/jobs/{job_id}/artifacts", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        self.rt.spawn(async move {
+            let path = dir.join(format!("artifacts-{job_id}.zip"));
+            let on_progress = |downloaded, total| sender.dispatch(GlimEvent::ArtifactsDownloadProgress(job_id, downloaded, total));
+
+            let event = Self::download_to_file(request, path, on_progress).await
+                .map(|path| GlimEvent::ArtifactsDownloaded(job_id, path))
                 .unwrap_or_else(GlimEvent::Error);
 
             sender.dispatch(event)
         });
     }
 
+    /// Fetches the `needs` relations of a pipeline's jobs, keyed by job name.
+    /// Needs are only exposed by the GraphQL API.
+    pub fn dispatch_get_job_needs(
+        &self,
+        project_id: ProjectId,
+        project_path: &str,
+        pipeline_id: PipelineId,
+    ) {
+        let graphql_url = format!("{}/graphql", self.base_url.trim_end_matches('/').trim_end_matches("/v4"));
+        let query = serde_json::json!({
+            "query": JOB_NEEDS_QUERY,
+            "variables": {
+                "path": project_path,
+                "id": format!("gid://gitlab/Ci::Pipeline/{pipeline_id}"),
+            },
+        });
+
+        let request = self.client
+            .post(graphql_url)
+            .header("PRIVATE-TOKEN", &self.private_token)
+            .header(CONTENT_TYPE, "application/json")
+            .body(query.to_string());
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<JobNeedsResponseDto>(transport.as_ref(), request, request_config).await
+                .map(|response| response.data.project
+                    .and_then(|p| p.pipeline)
+                    .map(|p| p.jobs.nodes)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|job| (job.name, job.needs.nodes.into_iter().map(|n| n.name).collect()))
+                    .collect())
+                .map(|needs| GlimEvent::ReceivedJobNeeds(project_id, pipeline_id, needs))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Creates an issue for a failed pipeline, with the tail of the failed
+    /// job's log included in the description.
+    pub fn dispatch_create_issue(
+        &self,
+        project_id: ProjectId,
+        failed_job: Option<JobId>,
+        report: FailureReport,
+        issue_config: IssueConfig,
+    ) {
+        let get_trace_request = failed_job.map(|job_id| self.client
+            .get(format!("{}/projects/{project_id}/jobs/{job_id}/trace", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token));
+        let create_issue_request = self.client
+            .post(format!("{}/projects/{project_id}/issues", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        // not retried, as a retry could create duplicate issues
+        let request_config = RequestConfig { max_retries: 0, ..self.request_config };
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let report = match get_trace_request {
+                Some(request) => match Self::http_request(transport.as_ref(), request).await {
+                    Ok(trace) => report.with_log(&trace),
+                    Err(e)    => return sender.dispatch(GlimEvent::Error(e)),
+                },
+                None => report,
+            };
+
+            let issue = issue_config.render(&report);
+            let mut form = vec![("title", issue.title), ("description", issue.description)];
+            if let Some(labels) = issue.labels {
+                form.push(("labels", labels));
+            }
+
+            let request = create_issue_request.form(&form);
+            let event = Self::http_json_request::<IssueDto>(transport.as_ref(), request, request_config).await
+                .map(|issue| GlimEvent::IssueCreated(project_id, issue.web_url))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Retries a job; the retried job is created as a new job in the same pipeline.
+    pub fn dispatch_retry_job(
+        &self,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        job_id: JobId,
+    ) {
+        let request = self.client
+            .post(format!("{}/projects/{project_id}/jobs/{job_id}/retry", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        // not retried, as a retry could start the job more than once
+        let request_config = RequestConfig { max_retries: 0, ..self.request_config };
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<JobDto>(transport.as_ref(), request, request_config).await
+                .map(|_| GlimEvent::JobRetried(project_id, pipeline_id, job_id))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    pub fn dispatch_list_branches(&self, project_id: ProjectId) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/repository/branches?per_page=100", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<Vec<BranchDto>>(transport.as_ref(), request, request_config).await
+                .map(|branches| GlimEvent::ReceivedBranches(project_id, branches))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Creates a new pipeline for the head of the branch.
+    pub fn dispatch_trigger_pipeline(
+        &self,
+        project_id: ProjectId,
+        branch: String,
+    ) {
+        let request = self.client
+            .post(format!("{}/projects/{project_id}/pipeline", self.base_url))
+            .query(&[("ref", branch.as_str())])
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        // not retried, as a retry could create more than one pipeline
+        let request_config = RequestConfig { max_retries: 0, ..self.request_config };
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<PipelineDto>(transport.as_ref(), request, request_config).await
+                .map(|_| GlimEvent::PipelineTriggered(project_id, branch))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    pub fn dispatch_list_schedules(&self, project_id: ProjectId) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/pipeline_schedules?per_page=100", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<Vec<ScheduleDto>>(transport.as_ref(), request, request_config).await
+                .map(|schedules| GlimEvent::ReceivedSchedules(project_id, schedules))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Lists the runners available to a project, including shared runners.
+    /// Tags and last contact are fetched per runner, as they are missing
+    /// from the listing; runners without accessible details, e.g. shared
+    /// runners for non-admins, are reported as listed.
+    pub fn dispatch_list_runners(&self, project_id: ProjectId) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/runners?per_page=100", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        let client = self.client.clone();
+        let base_url = self.base_url.clone();
+        let private_token = self.private_token.clone();
+        self.rt.spawn(async move {
+            let runners = match Self::http_json_request::<Vec<RunnerDto>>(transport.as_ref(), request, request_config).await {
+                Ok(runners) => runners,
+                Err(e) => return sender.dispatch(GlimEvent::Error(e)),
+            };
+
+            let mut detailed = Vec::with_capacity(runners.len());
+            for runner in runners {
+                let request = client.get(format!("{base_url}/runners/{}", runner.id))
+                    .header("PRIVATE-TOKEN", &private_token);
+
+                let details = Self::http_json_request::<RunnerDto>(transport.as_ref(), request, request_config).await;
+                detailed.push(details.unwrap_or(runner));
+            }
+
+            sender.dispatch(GlimEvent::ReceivedRunners(project_id, detailed))
+        });
+    }
+
+    /// Fetches the version of GitLab, blocking until done.
+    pub fn gitlab_version(&self) -> Result<GitlabVersion> {
+        let request = self.client
+            .get(format!("{}/version", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let version = self.rt.block_on(Self::http_json_request::<VersionDto>(self.transport.as_ref(), request, self.request_config))?;
+        let version = GitlabVersion::parse(&version.version)
+            .ok_or_else(|| GeneralError(format!("unrecognized GitLab version: {}", version.version)))?;
+
+        self.features.set_version(version);
+        Ok(version)
+    }
+
+    /// Fetches the version of GitLab, and skips requests to features of the
+    /// API it does not support. All features remain enabled if the version
+    /// is unknown; failures are only logged.
+    pub fn dispatch_get_version(&self) {
+        let request = self.client
+            .get(format!("{}/version", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        let features = self.features.clone();
+        let instance = self.instance;
+        self.rt.spawn(async move {
+            let version = Self::http_json_request::<VersionDto>(transport.as_ref(), request, request_config).await
+                .and_then(|dto| GitlabVersion::parse(&dto.version)
+                    .ok_or_else(|| GeneralError(format!("unrecognized GitLab version: {}", dto.version))));
+
+            match &version {
+                Ok(version) => features.set_version(*version),
+                Err(e)      => sender.dispatch(GlimEvent::Log(format!("unable to fetch GitLab version: {e}"))),
+            }
+            sender.dispatch(GlimEvent::ReceivedGitlabVersion(instance, version.ok()))
+        });
+    }
+
+    /// Fetches the scopes and expiry of the token, blocking until done.
+    pub fn token_info(&self) -> Result<PersonalAccessTokenDto> {
+        if !self.features.supports(ApiFeature::TokenDetails) {
+            return Err(Self::unsupported(ApiFeature::TokenDetails));
+        }
+
+        let request = self.client
+            .get(format!("{}/personal_access_tokens/self", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        self.rt.block_on(Self::http_json_request::<PersonalAccessTokenDto>(self.transport.as_ref(), request, self.request_config))
+    }
+
+    /// Checks the url and token of the configuration with a single request for
+    /// the token details, without a polling client; see the onboarding wizard.
+    /// Returns the scopes of the token; GitLab versions before
+    /// [ApiFeature::TokenDetails] lack the endpoint, and return no scopes.
+    pub fn check_token(config: &GlimConfig) -> Result<Vec<String>> {
+        let client = HttpConfig::from(config).build_client()?;
+        let request = client
+            .get(format!("{}/personal_access_tokens/self", config.gitlab_url))
+            .header("PRIVATE-TOKEN", &config.gitlab_token);
+
+        let transport = ReqwestTransport::new(client);
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| GeneralError(format!("failed to create runtime: {e}")))?;
+
+        match rt.block_on(Self::http_json_request::<PersonalAccessTokenDto>(&transport, request, RequestConfig::from(config))) {
+            Ok(token)                  => Ok(token.scopes),
+            Err(e) if e.is_not_found() => Ok(vec![]),
+            Err(e)                     => Err(e),
+        }
+    }
+
+    /// Fetches the expiry and scopes of the token. Older GitLab versions lack
+    /// the endpoint, so failures are only logged.
+    pub fn dispatch_get_token_expiry(&self) {
+        // reported once the version is known
+        if !self.features.supports(ApiFeature::TokenDetails) {
+            return;
+        }
+
+        let request = self.client
+            .get(format!("{}/personal_access_tokens/self", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            match Self::http_json_request::<PersonalAccessTokenDto>(transport.as_ref(), request, request_config).await {
+                Ok(token) => {
+                    sender.dispatch(GlimEvent::ReceivedTokenExpiry(token.expires_at));
+                    sender.dispatch(GlimEvent::ReceivedTokenScopes(token.scopes));
+                },
+                Err(e) => sender.dispatch(GlimEvent::Log(format!("unable to fetch token details: {e}"))),
+            }
+        });
+    }
+
+    /// Runs a pipeline schedule immediately, without affecting its next run.
+    pub fn dispatch_play_schedule(
+        &self,
+        project_id: ProjectId,
+        schedule_id: u32,
+    ) {
+        let request = self.client
+            .post(format!("{}/projects/{project_id}/pipeline_schedules/{schedule_id}/play", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        // not retried, as a retry could create more than one pipeline
+        let request_config = RequestConfig { max_retries: 0, ..self.request_config };
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<serde_json::Value>(transport.as_ref(), request, request_config).await
+                .map(|_| GlimEvent::SchedulePlayed(project_id, schedule_id))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    pub fn dispatch_get_approvals(
+        &self,
+        project_id: ProjectId,
+        merge_request_iid: u32,
+    ) {
+        let path = format!("projects/{project_id}/merge_requests/{merge_request_iid}/approvals");
+        if self.circuit_breaker.is_open(Endpoint::Approvals, project_id) || self.negative_cache.is_gone(&path) {
+            return;
+        }
+
+        let request = self.client
+            .get(format!("{}/{path}", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
+        let negative_cache = self.negative_cache.clone();
+        self.rt.spawn(async move {
+            let approvals = Self::http_json_request::<ApprovalsDto>(transport.as_ref(), request, request_config).await;
+            Self::record_outcome(&circuit_breaker, &sender, Endpoint::Approvals, project_id, &approvals);
+            if Self::is_gone(&negative_cache, &path, &approvals) {
+                return sender.dispatch(GlimEvent::ResourceGone(GoneResource::MergeRequest(project_id, merge_request_iid)));
+            }
+
+            let event = approvals
+                .map(|approvals| GlimEvent::ReceivedApprovals(project_id, merge_request_iid, approvals))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    pub fn dispatch_get_commit(
+        &self,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        sha: &str,
+    ) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/repository/commits/{sha}", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<CommitDetailsDto>(transport.as_ref(), request, request_config).await
+                .map(|commit| GlimEvent::ReceivedCommitDetails(project_id, pipeline_id, commit))
+                .unwrap_or_else(GlimEvent::Error);
+
+            sender.dispatch(event)
+        });
+    }
+
+    /// Fetches a single pipeline, e.g. the pipeline of `glim wait`.
+    pub fn dispatch_get_pipeline(
+        &self,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+    ) {
+        let request = self.client
+            .get(format!("{}/projects/{project_id}/pipelines/{pipeline_id}", self.base_url))
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<PipelineDto>(transport.as_ref(), request, request_config).await
+                .map(|pipeline| GlimEvent::ReceivedPipeline(project_id, Box::new(pipeline)))
+                .unwrap_or_else(|e| GlimEvent::PipelineUnavailable(project_id, pipeline_id, e));
+
+            sender.dispatch(event)
+        });
+    }
+
     pub fn dispatch_get_jobs(
         &self,
         project_id: ProjectId,
         pipeline_id: PipelineId
     ) {
+        let jobs_path = format!("projects/{project_id}/pipelines/{pipeline_id}/jobs");
+        if self.circuit_breaker.is_open(Endpoint::Jobs, project_id) || self.negative_cache.is_gone(&jobs_path) {
+            return;
+        }
+
         let base_url = format!("{}/projects/{project_id}/pipelines/{pipeline_id}", self.base_url);
 
         let get_jobs_request = self.client
@@ -107,30 +774,50 @@ impl GitlabClient {
             .get(format!("{base_url}/bridges"))
             .header("PRIVATE-TOKEN", &self.private_token);
 
-        let sender = self.sender.clone();
+        let sender = self.instance_sender();
+        let trigger_jobs_supported = self.features.supports(ApiFeature::TriggerJobs);
 
-        let debug = self.log_response;
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
+        let negative_cache = self.negative_cache.clone();
         self.rt.spawn(async move {
-            let jobs = match Self::http_json_request::<Vec<JobDto>>(get_jobs_request, debug).await {
+            let jobs = Self::http_json_request::<Vec<JobDto>>(transport.as_ref(), get_jobs_request, request_config).await;
+            Self::record_outcome(&circuit_breaker, &sender, Endpoint::Jobs, project_id, &jobs);
+            if Self::is_gone(&negative_cache, &jobs_path, &jobs) {
+                return sender.dispatch(GlimEvent::ResourceGone(GoneResource::Pipeline(project_id, pipeline_id)));
+            }
+            let jobs = match jobs {
                 Ok(t) => t,
                 Err(e) => {
-                    GlimError::GitlabGetJobsError(project_id, pipeline_id, e.to_string());
+                    let e = e.attributed(|s| GlimError::GitlabGetJobsError(project_id, pipeline_id, s));
                     return sender.dispatch(GlimEvent::Error(e))
                 },
             };
 
-            let triggered_jobs = match Self::http_json_request::<Vec<JobDto>>(get_trigger_jobs_request, debug).await {
-                Ok(t) => t,
-                Err(e) => return sender.dispatch(GlimEvent::Error(e)),
+            // jobs are still reported without trigger jobs while bridges are failing
+            let triggered_jobs = if !trigger_jobs_supported || circuit_breaker.is_open(Endpoint::Bridges, project_id) {
+                None
+            } else {
+                let triggered_jobs = Self::http_json_request::<Vec<JobDto>>(transport.as_ref(), get_trigger_jobs_request, request_config).await;
+                Self::record_outcome(&circuit_breaker, &sender, Endpoint::Bridges, project_id, &triggered_jobs);
+                triggered_jobs
+                    .inspect_err(|e| sender.dispatch(GlimEvent::Error(e.clone()
+                        .attributed(|s| GlimError::GitlabGetTriggerJobsError(project_id, pipeline_id, s)))))
+                    .ok()
             };
+            let partial = triggered_jobs.is_none() && trigger_jobs_supported;
 
             // combine jobs, sorted by id
             let jobs = jobs.into_iter()
-                .chain(triggered_jobs.into_iter())
+                .chain(triggered_jobs.into_iter().flatten())
                 .sorted_by_key(|j| j.id)
                 .collect::<Vec<JobDto>>();
 
-            sender.dispatch((project_id, pipeline_id, jobs).into_glim_event())
+            sender.dispatch((project_id, pipeline_id, jobs).into_glim_event());
+            if partial {
+                sender.dispatch(GlimEvent::JobsPartiallyLoaded(project_id, pipeline_id));
+            }
         });
     }
 
@@ -138,27 +825,59 @@ impl GitlabClient {
         &self, id: ProjectId,
         updated_after: Option<DateTime<Utc>>
     ) {
-        let mut url = format!("{}/projects/{id}/pipelines?per_page=60", self.base_url);
+        if self.circuit_breaker.is_open(Endpoint::Pipelines, id) {
+            return;
+        }
+
+        let mut url = format!("{}/projects/{id}/pipelines?per_page={}", self.base_url, self.page_size.current());
         if let Some(date) = updated_after {
             url.push_str(&format!("?last_activity_after={}", date.to_rfc3339()));
         }
 
-        self.dispatch::<Vec<PipelineDto>>(&url);
+        self.dispatch::<Vec<PipelineDto>>(
+            &url,
+            (Endpoint::Pipelines, id),
+            move |pipelines| GlimEvent::ReceivedPipelines(id, pipelines),
+            GlimEvent::PipelinesNotModified(id),
+        );
+    }
+
+    /// Fetches a page of the pipelines last updated before `before`, i.e.
+    /// preceding the oldest retained pipeline.
+    pub fn dispatch_get_older_pipelines(&self, project_id: ProjectId, before: DateTime<Utc>) {
+        let url = format!(
+            "{}/projects/{project_id}/pipelines?per_page={}&order_by=updated_at&sort=desc&updated_before={}",
+            self.base_url,
+            OLDER_PIPELINES_PAGE_SIZE,
+            before.to_rfc3339_opts(SecondsFormat::Secs, true),
+        );
+        let request = self.client.get(url)
+            .header("PRIVATE-TOKEN", &self.private_token);
+
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        self.rt.spawn(async move {
+            let event = Self::http_json_request::<Vec<PipelineDto>>(transport.as_ref(), request, request_config).await
+                .map(|pipelines| GlimEvent::ReceivedOlderPipelines(project_id, pipelines))
+                .unwrap_or_else(|e| GlimEvent::Error(e.attributed(|s| GlimError::GitlabGetPipelinesError(project_id, s))));
+
+            sender.dispatch(event)
+        });
     }
 
     pub fn dispatch_list_projects(
         &self,
         updated_after: Option<DateTime<Utc>>
     ) {
-        self.dispatch_glitchy::<Vec<ProjectDto>>(&self.list_projects_url(updated_after, 100))
+        self.dispatch_glitchy::<Vec<ProjectDto>>(&self.list_projects_url(updated_after, 100), GlimEvent::ProjectsNotModified(self.instance))
     }
     
     pub fn validate_configuration(&self) -> Result<()> {
         let request = self.client.get(self.list_projects_url(None, 1))
             .header("PRIVATE-TOKEN", &self.private_token);
 
-        let debug = self.log_response;
-        let response = self.rt.block_on(Self::http_json_request::<serde_json::Value>(request, debug))?;
+        let response = self.rt.block_on(Self::http_json_request::<serde_json::Value>(self.transport.as_ref(), request, self.request_config))?;
         if response.is_array() {
             Ok(())
         } else {
@@ -179,115 +898,441 @@ impl GitlabClient {
         )
     }
 
-    fn register_polling(&self) {
-        let sender = self.sender.clone();
-        self.rt.spawn(async move {
+    /// Polls the active jobs and the projects once per [Self::poll_interval],
+    /// half an interval apart.
+    fn register_polling(&mut self) {
+        if let Some(poller) = self.poller.take() {
+            poller.abort();
+        }
+
+        let sender = self.instance_sender();
+        let half_interval = self.poll_interval / 2;
+        let poller = self.rt.spawn(async move {
             loop {
-                sleep(std::time::Duration::from_secs(30)).await;
+                sleep(half_interval).await;
                 sender.dispatch(GlimEvent::RequestActiveJobs);
-                sleep(std::time::Duration::from_secs(30)).await;
-                sender.dispatch(GlimEvent::RequestProjects);
+                sleep(half_interval).await;
+                sender.dispatch(GlimEvent::PollProjects);
             }
         });
+
+        self.poller = Some(poller);
+    }
+
+    /// Restarts the polling schedule, e.g. after the system woke from sleep,
+    /// when the timers of the polling task no longer line up with the clock.
+    /// Does nothing for clients of additional instances.
+    pub fn resync_polling(&mut self) {
+        if self.poller.is_some() {
+            self.register_polling();
+        }
     }
 
-    /// Performs requests against the Gitlab API. Results are sent
-    /// as [GlimEvent]s using [self.sender].
+    /// Polls the jobs of a single pipeline at a higher frequency than the
+    /// regular polling. Stops after [FOLLOW_POLL_BUDGET] polls, or when
+    /// [Self::unfollow_pipeline] is called.
+    pub fn follow_pipeline(&mut self, project_id: ProjectId, pipeline_id: PipelineId) {
+        self.unfollow_pipeline();
+
+        let sender = self.instance_sender();
+        let poller = self.rt.spawn(async move {
+            for _ in 0..FOLLOW_POLL_BUDGET {
+                sender.dispatch(GlimEvent::RequestJobs(project_id, pipeline_id));
+                sleep(FOLLOW_POLL_INTERVAL).await;
+            }
+            sender.dispatch(GlimEvent::UnfollowPipeline);
+        });
+
+        self.follow_poller = Some(poller);
+    }
+
+    pub fn unfollow_pipeline(&mut self) {
+        if let Some(poller) = self.follow_poller.take() {
+            poller.abort();
+        }
+    }
+
+    /// Performs conditional requests against the Gitlab API. Results are sent
+    /// as [GlimEvent]s using [self.sender], or `not_modified` if the response
+    /// is unchanged. The outcome is recorded by the circuit breaker of the endpoint.
     fn dispatch<T>(
         &self,
         url: &str,
-    ) where T: for<'de> Deserialize<'de> + IntoGlimEvent {
+        (endpoint, project_id): (Endpoint, ProjectId),
+        into_event: impl FnOnce(T) -> GlimEvent + Send + 'static,
+        not_modified: GlimEvent,
+    ) where T: for<'de> Deserialize<'de> + Send + 'static {
         let request = self.client.get(url)
             .header("PRIVATE-TOKEN", &self.private_token);
 
-        let sender = self.sender.clone();
+        let sender = self.instance_sender();
 
-        let debug = self.log_response;
+        let request_config = self.request_config;
+        let transport = self.transport.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
+        let etags = self.etags.clone();
+        // only pipelines are requested with a tuned page size
+        let page_size = (endpoint == Endpoint::Pipelines).then(|| self.page_size.clone());
         self.rt.spawn(async move {
-            let event = match Self::http_json_request::<T>(request, debug).await {
-                Ok(t) => t.into_glim_event(),
-                Err(e) => GlimEvent::Error(e),
+            let started = Instant::now();
+            let result = Self::http_conditional_json_request::<T>(transport.as_ref(), request, request_config, &etags).await;
+            Self::record_outcome(&circuit_breaker, &sender, endpoint, project_id, &result);
+            match (page_size, &result) {
+                (Some(page_size), Ok(_))                    => page_size.record_response(started.elapsed()),
+                (Some(page_size), Err(e)) if e.is_timeout() => page_size.record_timeout(),
+                _ => (),
+            }
+
+            let event = match result {
+                Ok(Some(t)) => into_event(t),
+                Ok(None)    => not_modified,
+                Err(e) if endpoint == Endpoint::Pipelines =>
+                    GlimEvent::Error(e.attributed(|s| GlimError::GitlabGetPipelinesError(project_id, s))),
+                Err(e)      => GlimEvent::Error(e),
             };
             sender.dispatch(event)
         });
     }
 
-    /// Performs requests against the Gitlab API. Results are sent
-    /// as [GlimEvent]s using [self.sender].
+    /// Performs conditional requests against the Gitlab API. Results are sent
+    /// as [GlimEvent]s using [self.sender], or `not_modified` if the response
+    /// is unchanged.
     fn dispatch_glitchy<T>(
         &self,
         url: &str,
-    ) where T: for<'de> Deserialize<'de> + IntoGlimEvent {
+        not_modified: GlimEvent,
+    ) where T: for<'de> Deserialize<'de> + IntoGlimEvent + Send + 'static {
         let request = self.client.get(url)
             .header("PRIVATE-TOKEN", &self.private_token);
 
-        let sender = self.sender.clone();
-        let debug = self.log_response;
+        let sender = self.instance_sender();
+        let request_config = self.request_config;
 
+        let transport = self.transport.clone();
+        let etags = self.etags.clone();
         self.rt.spawn(async move {
             sender.dispatch(GlitchOverride(GlitchState::Active));
             sleep(Duration::from_millis(400)).await;
 
-            let event = match Self::http_json_request::<T>(request, debug).await {
-                Ok(t) => t.into_glim_event(),
-                Err(e) => GlimEvent::Error(e),
+            let event = match Self::http_conditional_json_request::<T>(transport.as_ref(), request, request_config, &etags).await {
+                Ok(Some(t)) => t.into_glim_event(),
+                Ok(None)    => not_modified,
+                Err(e)      => GlimEvent::Error(e),
             };
             sender.dispatch(GlimEvent::GlitchOverride(GlitchState::Inactive));
             sender.dispatch(event)
         });
     }
 
-    async fn http_json_request<T>(request: RequestBuilder, debug: bool) -> Result<T>
+    fn instance_sender(&self) -> InstanceSender {
+        InstanceSender { sender: self.sender.clone(), instance: self.instance }
+    }
+
+    fn unsupported(feature: ApiFeature) -> GlimError {
+        GeneralError(format!("requires GitLab {} or later", feature.min_version()))
+    }
+
+    /// Records the outcome of a request to a guarded endpoint. Outages of the
+    /// whole instance are handled separately, and do not count as failures.
+    fn record_outcome<T>(
+        circuit_breaker: &CircuitBreaker,
+        sender: &impl Dispatcher,
+        endpoint: Endpoint,
+        project_id: ProjectId,
+        result: &Result<T>,
+    ) {
+        match result {
+            Ok(_)                         => circuit_breaker.record_success(endpoint, project_id),
+            Err(e) if e.is_unavailable()  => (),
+            Err(_) => if circuit_breaker.record_failure(endpoint, project_id) {
+                sender.dispatch(GlimEvent::CircuitOpened(endpoint, project_id));
+            },
+        }
+    }
+
+    /// Records the response to a resource in the negative cache. Returns true
+    /// if the resource is now considered gone.
+    fn is_gone<T>(
+        negative_cache: &NegativeCache,
+        path: &str,
+        result: &Result<T>,
+    ) -> bool {
+        match result {
+            Err(e) if e.is_not_found() => negative_cache.record_not_found(path),
+            Err(_)                     => false,
+            Ok(_)                      => {
+                negative_cache.record_found(path);
+                false
+            },
+        }
+    }
+
+    /// Performs the request, retrying transient failures with exponential
+    /// backoff as configured by [RequestConfig].
+    async fn http_json_request<T>(
+        transport: &dyn HttpTransport,
+        request: RequestBuilder,
+        request_config: RequestConfig,
+    ) -> Result<T>
+        where T: for<'de> Deserialize<'de>
+    {
+        Self::with_retries(request.build()?, request_config, move |attempt| {
+            Self::http_json_request_once::<T>(transport, attempt)
+        }).await
+    }
+
+    /// Like [Self::http_json_request], but sends the ETag of the previous
+    /// response to the same URL. Returns `None` if GitLab responds with
+    /// 304 Not Modified; unchanged responses are not parsed again.
+    async fn http_conditional_json_request<T>(
+        transport: &dyn HttpTransport,
+        request: RequestBuilder,
+        request_config: RequestConfig,
+        etags: &ETagCache,
+    ) -> Result<Option<T>>
         where T: for<'de> Deserialize<'de>
     {
-        let response = request.send().await?;
-        let path = response.url().path().to_string();
+        let mut request = request.build()?;
+        let url = request.url().to_string();
+        if let Some(etag) = etags.get(&url).and_then(|etag| HeaderValue::from_str(&etag).ok()) {
+            request.headers_mut().insert(IF_NONE_MATCH, etag);
+        }
 
+        let url = url.as_str();
+        Self::with_retries(request, request_config, move |attempt| {
+            Self::http_conditional_json_request_once::<T>(transport, attempt, etags, url)
+        }).await
+    }
 
-        let status = response.status();
-        let body = response.text().await?;
+    /// Sends the request, retrying transient failures with exponential
+    /// backoff as configured by [RequestConfig].
+    async fn with_retries<R, F>(
+        request: Request,
+        request_config: RequestConfig,
+        send: impl Fn(Request) -> F,
+    ) -> Result<R>
+        where F: std::future::Future<Output = Result<R>>
+    {
+        let mut retries = 0;
+        loop {
+            let attempt = request.try_clone()
+                .ok_or_else(|| GeneralError("unable to clone request".to_string()))?;
 
-        if debug {
-            Self::log_response_to_file(path, &body);
+            match send(attempt).await {
+                Err(e) if e.is_retryable() && retries < request_config.max_retries => {
+                    let delay = e.retry_after()
+                        .unwrap_or_else(|| request_config.backoff(retries));
+
+                    retries += 1;
+                    sleep(delay).await;
+                },
+                // kept distinct, as polling slows down while GitLab is unavailable
+                Err(e) if e.is_retryable() && retries > 0 && !e.is_unavailable() =>
+                    return Err(GlimError::RetriesExhausted(retries, e.to_string())),
+                result => return result,
+            }
+        }
+    }
+
+    async fn http_json_request_once<T>(
+        transport: &dyn HttpTransport,
+        request: Request,
+    ) -> Result<T>
+        where T: for<'de> Deserialize<'de>
+    {
+        let response = transport.send(request).await?;
+        Self::parse_json_response(response)
+    }
+
+    async fn http_conditional_json_request_once<T>(
+        transport: &dyn HttpTransport,
+        request: Request,
+        etags: &ETagCache,
+        url: &str,
+    ) -> Result<Option<T>>
+        where T: for<'de> Deserialize<'de>
+    {
+        let response = transport.send(request).await?;
+        if response.status == StatusCode::NOT_MODIFIED {
+            return Ok(None);
         }
 
-        if status.is_success() {
+        let etag = response.headers.get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+
+        let result = Self::parse_json_response::<T>(response);
+        if let (Ok(_), Some(etag)) = (&result, etag) {
+            etags.insert(url, etag);
+        }
+
+        result.map(Some)
+    }
+
+    fn parse_json_response<T>(
+        response: HttpResponse,
+    ) -> Result<T>
+        where T: for<'de> Deserialize<'de>
+    {
+        let body = response.body;
+
+        if response.status.is_success() {
             serde_json::from_str(&body)
                 .map_err(|e| JsonDeserializeError(e.classify(), body))
+        } else if matches!(response.status, StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE) {
+            // the body is usually an html maintenance page; attributed to the
+            // instance when the error is dispatched, see [InstanceSender]
+            Err(GlimError::GitlabUnavailable(response.status, parse_retry_after(&response.headers), 0))
         } else {
+            let status = response.status;
+            let retry_after = parse_retry_after(&response.headers);
             let api = serde_json::from_str::<GitlabApiError>(&body);
-            if let Ok(api) = api {
-                Err(GeneralError(format!("HTTP {}\n {}", api.error, api.description())))
+            let message = if let Ok(api) = api {
+                format!("HTTP {}\n {}", api.error, api.description())
             } else if let Ok(api2) = serde_json::from_str::<GitlabApiError2>(&body) {
-                Err(GeneralError(format!("HTTP {}", api2.message)))
+                format!("HTTP {}", api2.message)
             } else {
-                Err(GeneralError(format!("{}: {}", status, body)))
+                format!("{}: {}", status, body)
+            };
+
+            Err(GlimError::GitlabHttpError(status, message, retry_after))
+        }
+    }
+
+    /// Streams the response body into a file. Bypasses the [HttpTransport],
+    /// which only handles text responses, and is not retried.
+    async fn download_to_file(
+        request: RequestBuilder,
+        path: PathBuf,
+        on_progress: impl Fn(u64, Option<u64>),
+    ) -> Result<PathBuf> {
+        let mut response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(GlimError::GitlabHttpError(status, format!("{status}: failed to download {}", path.display()), None));
+        }
+
+        let io_error = |e: std::io::Error| GeneralError(format!("failed to write {}: {e}", path.display()));
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = std::fs::File::create(&path).map_err(io_error)?;
+
+        let total = response.content_length();
+        let mut downloaded = 0;
+        let mut reported = 0;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).map_err(io_error)?;
+            downloaded += chunk.len() as u64;
+
+            if downloaded - reported >= ARTIFACTS_PROGRESS_STEP {
+                reported = downloaded;
+                on_progress(downloaded, total);
             }
         }
+
+        Ok(path)
     }
 
-    fn log_response_to_file(path: String, body: &String) {
-        if !Path::new("glim-logs").exists() {
-            std::fs::create_dir("glim-logs")
-                .expect("Unable to create directory");
+    async fn http_request(transport: &dyn HttpTransport, request: RequestBuilder) -> Result<String> {
+        let response = transport.send(request.build()?).await?;
+        Ok(response.body)
+    }
+}
+
+impl RequestConfig {
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// Exponential backoff with up to 50% jitter for the given retry.
+    fn backoff(&self, retry: u32) -> Duration {
+        let delay = self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+
+        let jitter_ms = delay.as_millis() as u64 / 2;
+        let jitter = match jitter_ms {
+            0 => 0,
+            n => SystemTime::now().duration_since(UNIX_EPOCH)
+                .map(|t| t.subsec_nanos() as u64 % n)
+                .unwrap_or(0),
+        };
+
+        delay + Duration::from_millis(jitter)
+    }
+}
+
+impl From<&GlimConfig> for RequestConfig {
+    fn from(config: &GlimConfig) -> Self {
+        Self {
+            max_retries: config.max_retries.unwrap_or(Self::DEFAULT_MAX_RETRIES),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            page_size: config.page_size.unwrap_or_default(),
         }
-        
-        let filename = format!("glim-logs/{}_{}.json",
-            Local::now().format("%Y-%m-%d_%H-%M-%S"),
-            path.replace('/', "_"),
-        );
+    }
+}
+
+/// Parses the `Retry-After` header, given either as seconds or as an HTTP date.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
 
-        std::fs::write(filename, body)
-            .expect("Unable to write to file");
+    if let Ok(seconds) = value.parse::<u64>() {
+        Some(Duration::from_secs(seconds))
+    } else {
+        DateTime::parse_from_rfc2822(value).ok()
+            .map(|date| date.with_timezone(&Utc) - Utc::now())
+            .and_then(|delay| delay.to_std().ok())
     }
+}
 
-    async fn http_request(request: RequestBuilder) -> Result<String> {
-        let body = request
-            .send().await?
-            .text().await?;
-    
-        Ok(body)
+#[derive(Debug, Deserialize)]
+struct IssueDto {
+    web_url: String,
+}
+
+const JOB_NEEDS_QUERY: &str = "query($path: ID!, $id: CiPipelineID!) {
+  project(fullPath: $path) {
+    pipeline(id: $id) {
+      jobs { nodes { name needs { nodes { name } } } }
     }
+  }
+}";
+
+#[derive(Debug, Deserialize)]
+struct JobNeedsResponseDto {
+    data: JobNeedsDataDto,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobNeedsDataDto {
+    project: Option<JobNeedsProjectDto>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobNeedsProjectDto {
+    pipeline: Option<JobNeedsPipelineDto>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobNeedsPipelineDto {
+    jobs: NodesDto<JobNeedsJobDto>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobNeedsJobDto {
+    name: String,
+    needs: NodesDto<NamedDto>,
+}
+
+/// GraphQL connection, as `{ nodes: [...] }`
+#[derive(Debug, Deserialize)]
+struct NodesDto<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedDto {
+    name: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -305,4 +1350,100 @@ impl GitlabApiError {
     pub fn description(&self) -> String {
         self.error_description.clone().unwrap_or("".to_string())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use crate::http::FakeTransport;
+    use super::*;
+
+    const VERSION: &str = r#"{"version": "16.5.1-ee"}"#;
+
+    fn client(transport: &Arc<FakeTransport>, base_delay: Duration) -> GitlabClient {
+        let request_config = RequestConfig {
+            max_retries: 2,
+            base_delay,
+            max_delay: base_delay,
+            page_size: PageSizeBounds::default(),
+        };
+
+        let (sender, _) = mpsc::channel();
+        GitlabClient::with_transport(sender, 0, "https://gitlab.test/api/v4".to_string(), "token".to_string(), request_config, transport.clone())
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(500, &[], "oops"),
+            FakeTransport::response(504, &[], "timeout"),
+            FakeTransport::response(200, &[], VERSION),
+        ]));
+
+        let version = client(&transport, Duration::ZERO).gitlab_version();
+        assert_eq!(version.ok(), Some(GitlabVersion::new(16, 5)));
+        assert_eq!(transport.requests(), 3);
+    }
+
+    #[test]
+    fn retries_are_bounded_by_max_retries() {
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(500, &[], "oops"),
+            FakeTransport::response(500, &[], "oops"),
+            FakeTransport::response(500, &[], "oops"),
+        ]));
+
+        let result = client(&transport, Duration::ZERO).gitlab_version();
+        assert!(matches!(result, Err(GlimError::RetriesExhausted(2, _))), "{result:?}");
+        assert_eq!(transport.requests(), 3);
+    }
+
+    #[test]
+    fn retry_after_replaces_the_backoff() {
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(429, &[("retry-after", "0")], "slow down"),
+            FakeTransport::response(200, &[], VERSION),
+        ]));
+
+        // the backoff alone would delay the retry by a minute
+        let started = Instant::now();
+        let version = client(&transport, Duration::from_secs(60)).gitlab_version();
+        assert!(version.is_ok(), "{version:?}");
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(transport.requests(), 2);
+    }
+
+    #[test]
+    fn statuses_are_mapped_to_errors() {
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(404, &[], r#"{"message": "404 Not found"}"#),
+        ]));
+        let result = client(&transport, Duration::ZERO).gitlab_version();
+        assert!(matches!(&result, Err(e @ GlimError::GitlabHttpError(StatusCode::NOT_FOUND, _, None)) if e.is_not_found()), "{result:?}");
+        assert_eq!(transport.requests(), 1);
+
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(503, &[("retry-after", "0")], "<html>maintenance</html>"),
+            FakeTransport::response(503, &[("retry-after", "0")], "<html>maintenance</html>"),
+            FakeTransport::response(503, &[("retry-after", "0")], "<html>maintenance</html>"),
+        ]));
+        let result = client(&transport, Duration::ZERO).gitlab_version();
+        assert!(matches!(result, Err(GlimError::GitlabUnavailable(StatusCode::SERVICE_UNAVAILABLE, Some(_), _))), "{result:?}");
+
+        let transport = Arc::new(FakeTransport::new(vec![
+            FakeTransport::response(200, &[], "not json"),
+        ]));
+        let result = client(&transport, Duration::ZERO).gitlab_version();
+        assert!(matches!(result, Err(JsonDeserializeError(_, _))), "{result:?}");
+    }
+
+    #[test]
+    fn retry_after_is_parsed_from_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+}
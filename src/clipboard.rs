@@ -0,0 +1,69 @@
+use std::io::Write;
+
+use crate::result::{GlimError, Result};
+
+/// Copies text to the system clipboard. Falls back to the OSC 52 escape
+/// sequence without a system clipboard, e.g. over ssh, asking the terminal
+/// to set its clipboard instead.
+pub struct ClipboardService {
+    /// none without a system clipboard
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl ClipboardService {
+    pub fn new() -> Self {
+        Self { clipboard: arboard::Clipboard::new().ok() }
+    }
+
+    pub fn copy(&mut self, text: String) -> Result<()> {
+        let copied = self.clipboard.as_mut()
+            .is_some_and(|clipboard| clipboard.set_text(text.as_str()).is_ok());
+
+        match copied {
+            true  => Ok(()),
+            false => copy_osc52(&text),
+        }
+    }
+}
+
+/// Asks the terminal to set the clipboard; silently ignored by terminals
+/// without OSC 52 support.
+fn copy_osc52(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))
+        .and_then(|_| stdout.flush())
+        .map_err(|e| GlimError::GeneralError(format!("unable to copy to the clipboard: {e}")))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true  => encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"https://gitlab.com"), "aHR0cHM6Ly9naXRsYWIuY29t");
+    }
+}
@@ -0,0 +1,232 @@
+use crossterm::event::KeyCode;
+
+use crate::event::GlimEvent;
+use crate::fuzzy::fuzzy_match;
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::input::keymap::KeyBinding;
+use crate::stores::QuickFilter;
+
+/// Actions of the projects table, bound to keys in normal mode and listed
+/// by the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    OpenDetails,
+    BrowseToProject,
+    Branches,
+    Schedules,
+    Runners,
+    TogglePin,
+    ToggleWatch,
+    HideProject,
+    ShowHidden,
+    Search,
+    FilterProjects,
+    CycleSort,
+    OnlyFailed,
+    OnlyActive,
+    ToggleGroupedView,
+    LastNotification,
+    AuditLog,
+    ApiInspector,
+    ProjectErrors,
+    InternalLogs,
+    Refresh,
+    RefreshPipelines,
+    Config,
+    ProfileMemory,
+    ToggleColorDepth,
+    ToggleRelativeTimes,
+    ToggleRecording,
+    Screenshot,
+    Help,
+    Quit,
+}
+
+impl Command {
+    pub const ALL: [Command; 30] = [
+        Command::OpenDetails,
+        Command::BrowseToProject,
+        Command::Branches,
+        Command::Schedules,
+        Command::Runners,
+        Command::TogglePin,
+        Command::ToggleWatch,
+        Command::HideProject,
+        Command::ShowHidden,
+        Command::Search,
+        Command::FilterProjects,
+        Command::CycleSort,
+        Command::OnlyFailed,
+        Command::OnlyActive,
+        Command::ToggleGroupedView,
+        Command::LastNotification,
+        Command::AuditLog,
+        Command::ApiInspector,
+        Command::ProjectErrors,
+        Command::InternalLogs,
+        Command::Refresh,
+        Command::RefreshPipelines,
+        Command::Config,
+        Command::ProfileMemory,
+        Command::ToggleColorDepth,
+        Command::ToggleRelativeTimes,
+        Command::ToggleRecording,
+        Command::Screenshot,
+        Command::Help,
+        Command::Quit,
+    ];
+
+    /// The key bound to the command in normal mode.
+    pub fn key_binding(&self) -> KeyBinding<Command> {
+        let (code, ctrl) = self.key_code();
+        KeyBinding { code, ctrl, action: *self, description: self.description() }
+    }
+
+    /// The key bound to the command, and whether it is pressed with ctrl.
+    fn key_code(&self) -> (KeyCode, bool) {
+        match self {
+            Command::OpenDetails      => (KeyCode::Enter, false),
+            Command::BrowseToProject  => (KeyCode::Char('w'), false),
+            Command::Branches         => (KeyCode::Char('b'), false),
+            Command::Schedules        => (KeyCode::Char('s'), false),
+            Command::Runners          => (KeyCode::Char('u'), false),
+            Command::TogglePin        => (KeyCode::Char('f'), false),
+            Command::ToggleWatch      => (KeyCode::Char('n'), false),
+            Command::HideProject      => (KeyCode::Char('x'), false),
+            Command::ShowHidden       => (KeyCode::Char('X'), false),
+            Command::Search           => (KeyCode::Char('f'), true),
+            Command::FilterProjects   => (KeyCode::Char('/'), false),
+            Command::CycleSort        => (KeyCode::Char('o'), false),
+            Command::OnlyFailed       => (KeyCode::Char('F'), false),
+            Command::OnlyActive       => (KeyCode::Char('A'), false),
+            Command::ToggleGroupedView => (KeyCode::Char('g'), false),
+            Command::LastNotification => (KeyCode::Char('a'), false),
+            Command::AuditLog         => (KeyCode::Char('h'), false),
+            Command::ApiInspector     => (KeyCode::Char('i'), false),
+            Command::ProjectErrors    => (KeyCode::Char('E'), false),
+            Command::InternalLogs     => (KeyCode::Char('l'), false),
+            Command::Refresh          => (KeyCode::Char('r'), false),
+            Command::RefreshPipelines => (KeyCode::Char('p'), false),
+            Command::Config           => (KeyCode::Char('c'), false),
+            Command::ProfileMemory    => (KeyCode::F(11), false),
+            Command::ToggleColorDepth => (KeyCode::F(12), false),
+            Command::ToggleRelativeTimes => (KeyCode::Char('t'), false),
+            Command::ToggleRecording  => (KeyCode::Char('R'), false),
+            Command::Screenshot       => (KeyCode::Char('S'), false),
+            Command::Help             => (KeyCode::Char('?'), false),
+            Command::Quit             => (KeyCode::Char('q'), false),
+        }
+    }
+
+    /// The key bound to the command, as shown in the command palette.
+    pub fn key(&self) -> String {
+        self.key_binding().key()
+    }
+
+    pub fn label(&self) -> &'static str {
+        t(self.description())
+    }
+
+    fn description(&self) -> Msg {
+        match self {
+            Command::OpenDetails      => Msg::CommandOpenDetails,
+            Command::BrowseToProject  => Msg::CommandBrowseToProject,
+            Command::Branches         => Msg::CommandBranches,
+            Command::Schedules        => Msg::CommandSchedules,
+            Command::Runners          => Msg::CommandRunners,
+            Command::TogglePin        => Msg::CommandTogglePin,
+            Command::ToggleWatch      => Msg::CommandToggleWatch,
+            Command::HideProject      => Msg::CommandHideProject,
+            Command::ShowHidden       => Msg::CommandShowHidden,
+            Command::Search           => Msg::CommandSearch,
+            Command::FilterProjects   => Msg::CommandFilterProjects,
+            Command::CycleSort        => Msg::CommandCycleSort,
+            Command::OnlyFailed       => Msg::CommandOnlyFailed,
+            Command::OnlyActive       => Msg::CommandOnlyActive,
+            Command::ToggleGroupedView => Msg::CommandToggleGroupedView,
+            Command::LastNotification => Msg::CommandLastNotification,
+            Command::AuditLog         => Msg::CommandAuditLog,
+            Command::ApiInspector     => Msg::CommandApiInspector,
+            Command::ProjectErrors    => Msg::CommandProjectErrors,
+            Command::InternalLogs     => Msg::CommandInternalLogs,
+            Command::Refresh          => Msg::CommandRefresh,
+            Command::RefreshPipelines => Msg::CommandRefreshPipelines,
+            Command::Config           => Msg::CommandConfig,
+            Command::ProfileMemory    => Msg::CommandProfileMemory,
+            Command::ToggleColorDepth => Msg::CommandToggleColorDepth,
+            Command::ToggleRelativeTimes => Msg::CommandToggleRelativeTimes,
+            Command::ToggleRecording  => Msg::CommandToggleRecording,
+            Command::Screenshot       => Msg::CommandScreenshot,
+            Command::Help             => Msg::CommandHelp,
+            Command::Quit             => Msg::CommandQuit,
+        }
+    }
+
+    /// Events performing the command; commands acting on a project do
+    /// nothing without a selected project.
+    pub fn events(&self, selected: Option<ProjectId>) -> Vec<GlimEvent> {
+        let project_event = |f: fn(ProjectId) -> GlimEvent| -> Vec<GlimEvent> {
+            selected.map(f).into_iter().collect()
+        };
+
+        match self {
+            Command::OpenDetails      => project_event(GlimEvent::OpenProjectDetails),
+            Command::BrowseToProject  => project_event(GlimEvent::BrowseToProject),
+            Command::Branches         => project_event(GlimEvent::OpenBranches),
+            Command::Schedules        => project_event(GlimEvent::OpenSchedules),
+            Command::Runners          => project_event(GlimEvent::OpenRunners),
+            Command::TogglePin        => project_event(GlimEvent::TogglePinProject),
+            Command::ToggleWatch      => project_event(|id| GlimEvent::ToggleWatch(id, None)),
+            Command::HideProject      => project_event(GlimEvent::ToggleIgnoreProject),
+            Command::ShowHidden       => vec![GlimEvent::ToggleShowIgnored],
+            Command::Search           => vec![GlimEvent::DisplaySearch],
+            Command::FilterProjects   => vec![GlimEvent::DisplayProjectFilter],
+            Command::CycleSort        => vec![GlimEvent::CycleProjectSort],
+            Command::OnlyFailed       => vec![GlimEvent::ToggleQuickFilter(QuickFilter::Failed)],
+            Command::OnlyActive       => vec![GlimEvent::ToggleQuickFilter(QuickFilter::Active)],
+            Command::ToggleGroupedView => vec![GlimEvent::ToggleProjectTree],
+            Command::LastNotification => vec![GlimEvent::ShowLastNotification],
+            Command::AuditLog         => vec![GlimEvent::DisplayAuditLog],
+            Command::ApiInspector     => vec![GlimEvent::DisplayApiInspector],
+            Command::ProjectErrors    => vec![GlimEvent::DisplayProjectErrors],
+            Command::InternalLogs     => vec![GlimEvent::ToggleInternalLogs],
+            // refreshes all projects and the pipelines of the selected project,
+            // without waiting for the next poll
+            Command::Refresh          => [GlimEvent::ManualRefresh, GlimEvent::RequestProjects].into_iter()
+                .chain(selected.map(GlimEvent::RequestPipelines))
+                .collect(),
+            Command::RefreshPipelines => project_event(GlimEvent::RequestPipelines),
+            Command::Config           => vec![GlimEvent::DisplayConfig],
+            Command::ProfileMemory    => vec![GlimEvent::ProfileMemory],
+            Command::ToggleColorDepth => vec![GlimEvent::ToggleColorDepth],
+            Command::ToggleRelativeTimes => vec![GlimEvent::ToggleRelativeTimes],
+            Command::ToggleRecording  => vec![GlimEvent::ToggleRecording],
+            Command::Screenshot       => vec![GlimEvent::Screenshot],
+            Command::Help             => vec![GlimEvent::DisplayHelp],
+            Command::Quit             => vec![GlimEvent::Shutdown],
+        }
+    }
+}
+
+/// A command matching the query of the command palette.
+#[derive(Debug, Clone)]
+pub struct CommandMatch {
+    pub command: Command,
+    /// byte offsets of the matched characters in the label
+    pub matched: Vec<usize>,
+    score: i32,
+}
+
+/// Returns the commands matching `query`, best matches first. Commands match
+/// when the characters of the query appear in order in their label.
+pub fn filter_commands(query: &str) -> Vec<CommandMatch> {
+    let mut matches: Vec<CommandMatch> = Command::ALL.into_iter()
+        .filter_map(|command| fuzzy_match(command.label(), query)
+            .map(|(score, matched)| CommandMatch { command, matched, score }))
+        .collect();
+
+    // stable, so that equally good matches keep the order of [Command::ALL]
+    matches.sort_by_key(|m| -m.score);
+    matches
+}
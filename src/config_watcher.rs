@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::load_config;
+use crate::result::{GlimError, Result};
+
+/// Changes to the configuration file are applied once no further changes
+/// were seen for this long; editors often write a file in several steps.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Contents of the configuration file as last loaded or written by glim;
+/// writes of glim itself are not reloaded.
+static KNOWN_CONTENTS: Lazy<Mutex<Option<String>>> = Lazy::new(Default::default);
+
+/// Watches the configuration file, dispatching [GlimEvent::UpdateConfig] when
+/// it is changed by another program, e.g. an editor or dotfiles tooling.
+/// Invalid configurations are reported as errors, and not applied.
+pub struct ConfigWatcher {
+    /// stops watching when dropped
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    pub fn watch(config_file: &Path, sender: Sender<GlimEvent>) -> Result<Self> {
+        let config_file = config_file.canonicalize()
+            .map_err(|e| GlimError::ConfigError(format!("unable to watch the configuration: {e}")))?;
+        record_contents(&config_file);
+
+        let (changes, changed) = mpsc::channel();
+        let file_name = config_file.file_name().map(|name| name.to_os_string());
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else { return };
+            if event.paths.iter().any(|path| path.file_name() == file_name.as_deref()) {
+                let _ = changes.send(());
+            }
+        }).map_err(|e| GlimError::GeneralError(format!("unable to watch the configuration: {e}")))?;
+
+        // the directory is watched, as editors often replace the file instead of writing to it
+        let dir = config_file.parent().unwrap_or(Path::new("."));
+        watcher.watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|e| GlimError::GeneralError(format!("unable to watch the configuration: {e}")))?;
+
+        std::thread::spawn(move || {
+            while changed.recv().is_ok() {
+                loop {
+                    match changed.recv_timeout(DEBOUNCE) {
+                        Ok(())                              => continue,
+                        Err(RecvTimeoutError::Timeout)      => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                reload(&config_file, &sender);
+            }
+        });
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Records the current contents of the configuration file, e.g. after glim
+/// wrote it, so that the write is not picked up as a change.
+pub fn record_contents(config_file: &Path) {
+    let contents = std::fs::read_to_string(config_file).ok();
+    *KNOWN_CONTENTS.lock().expect("config watcher lock poisoned") = contents;
+}
+
+fn reload(config_file: &PathBuf, sender: &Sender<GlimEvent>) {
+    // e.g. removed, or in the middle of being replaced
+    let Ok(contents) = std::fs::read_to_string(config_file) else { return };
+    {
+        let mut known = KNOWN_CONTENTS.lock().expect("config watcher lock poisoned");
+        if known.as_ref() == Some(&contents) {
+            return;
+        }
+        *known = Some(contents);
+    }
+
+    let config = load_config(config_file)
+        .and_then(|config| config.validate().map(|_| config).map_err(GlimError::ConfigError));
+
+    match config {
+        Ok(config) => {
+            sender.dispatch(GlimEvent::Log("configuration changed on disk, reloading".to_string()));
+            sender.dispatch(GlimEvent::UpdateConfig(Box::new(config)));
+        },
+        Err(e) => sender.dispatch(GlimEvent::Error(
+            GlimError::ConfigError(format!("changed configuration not applied: {e}"))
+        )),
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+use reqwest::Request;
+
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::http::{HttpTransport, ResponseFuture};
+use crate::result::GlimError;
+
+/// Consecutive network failures after which glim considers itself offline.
+const OFFLINE_THRESHOLD: u32 = 3;
+
+/// Connectivity to a GitLab instance, derived from the outcome of its requests.
+/// Dispatches [GlimEvent::ConnectionLost] once [OFFLINE_THRESHOLD] requests in
+/// a row failed with network errors, and [GlimEvent::ConnectionRestored] as
+/// soon as the server responds again.
+#[derive(Clone)]
+pub struct Connectivity {
+    sender: Sender<GlimEvent>,
+    /// consecutive requests failing with network errors
+    failures: Arc<AtomicU32>,
+}
+
+impl Connectivity {
+    pub fn new(sender: Sender<GlimEvent>) -> Self {
+        Self { sender, failures: Arc::new(AtomicU32::new(0)) }
+    }
+
+    /// Records the outcome of a request. Any response from the server, error
+    /// statuses included, counts as connected.
+    fn record(&self, error: Option<&GlimError>) {
+        match error {
+            Some(e) if e.is_network() => {
+                if self.failures.fetch_add(1, Ordering::Relaxed) + 1 == OFFLINE_THRESHOLD {
+                    self.sender.dispatch(GlimEvent::ConnectionLost);
+                }
+            },
+            _ => {
+                if self.failures.swap(0, Ordering::Relaxed) >= OFFLINE_THRESHOLD {
+                    self.sender.dispatch(GlimEvent::ConnectionRestored);
+                }
+            },
+        }
+    }
+}
+
+/// [HttpTransport] tracking the [Connectivity] of the wrapped transport.
+pub struct ConnectivityTransport {
+    inner: Box<dyn HttpTransport>,
+    connectivity: Connectivity,
+}
+
+impl ConnectivityTransport {
+    pub fn new(inner: impl HttpTransport + 'static, connectivity: Connectivity) -> Self {
+        Self { inner: Box::new(inner), connectivity }
+    }
+}
+
+impl HttpTransport for ConnectivityTransport {
+    fn send(&self, request: Request) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            let result = self.inner.send(request).await;
+            self.connectivity.record(result.as_ref().err());
+            result
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use ratatui::style::Color;
+
+/// Minimum contrast ratio for normal text, as per WCAG 2.1 level AA.
+pub const WCAG_AA: f64 = 4.5;
+
+/// Contrast ratio between two colors, from 1.0 (no contrast) to 21.0 (black
+/// on white). Only defined for RGB colors.
+pub fn contrast_ratio(a: Color, b: Color) -> Option<f64> {
+    let (la, lb) = (relative_luminance(a)?, relative_luminance(b)?);
+    Some((la.max(lb) + 0.05) / (la.min(lb) + 0.05))
+}
+
+/// Adjusts the lightness of `fg` until it reaches `min_ratio` against `bg`,
+/// moving it towards white on dark backgrounds and towards black on light ones.
+/// Non-RGB colors are returned as is.
+pub fn ensure_contrast(fg: Color, bg: Color, min_ratio: f64) -> Color {
+    const STEPS: u32 = 20;
+
+    let (Color::Rgb(r, g, b), Some(bg_luminance)) = (fg, relative_luminance(bg)) else {
+        return fg;
+    };
+
+    // white reaches a higher ratio than black on backgrounds darker than this
+    let target = if bg_luminance < 0.179 { 255.0 } else { 0.0 };
+    let mix = |c: u8, amount: f64| (c as f64 + (target - c as f64) * amount).round() as u8;
+
+    (0..=STEPS)
+        .map(|step| step as f64 / STEPS as f64)
+        .map(|amount| Color::Rgb(mix(r, amount), mix(g, amount), mix(b, amount)))
+        .find(|c| contrast_ratio(*c, bg).is_some_and(|ratio| ratio >= min_ratio))
+        .unwrap_or(Color::Rgb(target as u8, target as u8, target as u8))
+}
+
+/// Relative luminance of an sRGB color, see https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+fn relative_luminance(color: Color) -> Option<f64> {
+    let Color::Rgb(r, g, b) = color else { return None };
+
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
@@ -1,14 +1,23 @@
-use chrono::{DateTime, Duration, Local, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
 use itertools::Itertools;
+use ratatui::style::Style;
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::Row;
-use serde::{Deserialize};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use crate::capabilities::{accessible, ascii_icons, glyph};
+use crate::fetch_state::{FetchState, ProjectFetch};
+use crate::project_health::{Health, Trend};
+use crate::i18n::{t, Msg};
 use crate::id::{JobId, PipelineId, ProjectId};
 use crate::theme::theme;
-use crate::ui::format_duration;
-use crate::ui::widget::text_from;
+use crate::ui::{branch_lines, format_duration, shows_default_branch, timestamp_spans};
+use crate::ui::widget::{project_row_height, text_from};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Project {
     pub id: ProjectId,
     pub path: String,
@@ -18,32 +27,111 @@ pub struct Project {
     pub url: String,
     pub last_activity_at: DateTime<Utc>,
     pub pipelines: Option<Vec<Pipeline>>,
+    /// approvals of merge requests with pipelines, by merge request iid
+    pub approvals: HashMap<u32, Approvals>,
     pub commit_count: u32,
     pub repo_size_kb: u64,
     pub artifacts_size_kb: u64,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// set once older pipelines were requested, and none were left
+    #[serde(default)]
+    pub all_pipelines_fetched: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Pipeline {
     pub id: PipelineId,
     pub project_id: ProjectId,
     pub status: PipelineStatus,
     pub source: PipelineSource,
     pub branch: String,
+    pub sha: String,
     pub url: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub jobs: Option<Vec<Job>>,
+    /// trigger jobs are missing from [Self::jobs], as they could not be fetched
+    pub partial_jobs: bool,
+    /// jobs respond with 404, as the pipeline was deleted
+    pub gone: bool,
     pub commit: Option<Commit>,
+    /// fetched on demand, see [GlimEvent::RequestCommitDetails](crate::event::GlimEvent::RequestCommitDetails)
+    pub commit_details: Option<CommitDetails>,
 }
 
-#[derive(Clone, Debug)]
+/// Approval state of a merge request.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Approvals {
+    pub approved: u32,
+    pub required: u32,
+    pub approvals_left: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Commit {
     pub title: String,
     pub author_name: String
 }
 
 #[derive(Clone, Debug)]
+pub struct Branch {
+    pub name: String,
+    pub default: bool,
+    pub protected: bool,
+    pub commit_title: String,
+    pub committed_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    pub id: u32,
+    pub description: String,
+    pub branch: String,
+    pub cron: String,
+    pub cron_timezone: String,
+    /// none for inactive schedules
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Runner {
+    pub id: u32,
+    pub description: String,
+    pub status: RunnerStatus,
+    pub paused: bool,
+    /// shared runners are available to all projects of the instance
+    pub shared: bool,
+    pub contacted_at: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunnerStatus {
+    Online,
+    /// not contacted within the last 2 hours
+    Offline,
+    /// not contacted within the last 3 months
+    Stale,
+    NeverContacted,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommitDetails {
+    pub short_sha: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub authored_at: DateTime<Utc>,
+    pub message: String,
+    pub additions: u32,
+    pub deletions: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Job {
     pub id: JobId,
     pub name: String,
@@ -53,6 +141,53 @@ pub struct Job {
     pub started_at: Option<DateTime<Utc>>,
     pub finished_at: Option<DateTime<Utc>>,
     pub url: String,
+    pub artifacts: Vec<Artifact>,
+    /// pipeline started by a trigger job, see [pipeline_trees](crate::pipeline_tree::pipeline_trees)
+    #[serde(default)]
+    pub downstream: Option<(ProjectId, PipelineId)>,
+}
+
+/// Jobs of a pipeline sharing a stage, see [Pipeline::stages].
+#[derive(Clone, Debug)]
+pub struct Stage<'a> {
+    pub name: &'a str,
+    pub jobs: Vec<&'a Job>,
+}
+
+impl Stage<'_> {
+    /// Status of the stage: that of its most severe job, preferring running
+    /// over waiting jobs, and successful over skipped jobs.
+    pub fn status(&self) -> PipelineStatus {
+        self.jobs.iter()
+            .map(|j| &j.status)
+            .max_by_key(|s| (s.severity(), matches!(s, PipelineStatus::Running | PipelineStatus::Success)))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Time from the first job starting to the last job finishing; measured
+    /// to the current time while any job is still active.
+    pub fn duration(&self) -> Duration {
+        let Some(begin) = self.jobs.iter().filter_map(|j| j.started_at).min() else {
+            return Duration::zero();
+        };
+
+        let end = match self.jobs.iter().any(|j| j.status.is_active()) {
+            true  => Utc::now(),
+            false => self.jobs.iter().filter_map(|j| j.finished_at).max().unwrap_or(begin),
+        };
+
+        end.signed_duration_since(begin)
+    }
+}
+
+/// A file kept by a job, e.g. the artifacts archive or a test report.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Artifact {
+    pub file_type: String,
+    pub filename: String,
+    /// size in bytes
+    pub size: u64,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -64,7 +199,9 @@ pub struct ProjectDto {
     pub ssh_url_to_repo: String,
     pub web_url: String,
     pub last_activity_at: DateTime<Utc>,
-    pub statistics: StatisticsDto
+    pub statistics: StatisticsDto,
+    #[serde(default)]
+    pub topics: Vec<String>,
 }
 
 #[allow(unused)]
@@ -83,6 +220,76 @@ pub struct CommitDto {
     author_name: String,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BranchDto {
+    name: String,
+    default: bool,
+    protected: bool,
+    commit: BranchCommitDto,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BranchCommitDto {
+    title: String,
+    committed_date: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ScheduleDto {
+    id: u32,
+    description: String,
+    #[serde(rename = "ref")]
+    branch: String,
+    cron: String,
+    cron_timezone: String,
+    next_run_at: Option<DateTime<Utc>>,
+    active: bool,
+}
+
+/// The token used to authenticate, as of `/personal_access_tokens/self`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonalAccessTokenDto {
+    pub expires_at: Option<NaiveDate>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// Runners as listed per project; tags and last contact are only part of
+/// the runner details.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RunnerDto {
+    pub id: u32,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    status: RunnerStatus,
+    #[serde(default)]
+    paused: bool,
+    #[serde(default)]
+    is_shared: bool,
+    #[serde(default)]
+    contacted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    tag_list: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitDetailsDto {
+    short_id: String,
+    message: String,
+    author_name: String,
+    author_email: String,
+    authored_date: DateTime<Utc>,
+    #[serde(default)]
+    stats: CommitStatsDto,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitStatsDto {
+    additions: u32,
+    deletions: u32,
+}
+
 #[allow(unused)]
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct JobDto {
@@ -96,6 +303,25 @@ pub struct JobDto {
     finished_at: Option<DateTime<Utc>>,
     pub web_url: String,
     duration: Option<f32>, // seconds
+    #[serde(default)]
+    artifacts: Vec<ArtifactDto>,
+    /// only set for trigger jobs, as returned by the bridges endpoint
+    #[serde(default)]
+    pub downstream_pipeline: Option<DownstreamPipelineDto>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DownstreamPipelineDto {
+    pub id: PipelineId,
+    pub project_id: ProjectId,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ArtifactDto {
+    file_type: String,
+    filename: String,
+    #[serde(default)]
+    size: u64,
 }
 
 #[allow(unused)]
@@ -108,12 +334,20 @@ pub struct PipelineDto {
     pub source: PipelineSource,
     #[serde(rename = "ref")]
     pub branch: String,
+    pub sha: String,
     web_url: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ApprovalsDto {
+    approvals_required: u32,
+    approvals_left: u32,
+    approved_by: Vec<IgnoredAny>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PipelineStatus {
     #[default]
@@ -133,7 +367,7 @@ pub enum PipelineStatus {
     Unknown,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum PipelineSource {
     #[default]
@@ -154,9 +388,9 @@ pub enum PipelineSource {
     Webide,
 }
 
-impl PipelineSource {
-    pub fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for PipelineSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let source = match self {
             PipelineSource::Api                         => "api",
             PipelineSource::Chat                        => "chat",
             PipelineSource::External                    => "external",
@@ -172,7 +406,45 @@ impl PipelineSource {
             PipelineSource::Trigger                     => "trigger",
             PipelineSource::Web                         => "web",
             PipelineSource::Webide                      => "web ide",
-        }.to_string()
+        };
+
+        write!(f, "{source}")
+    }
+}
+
+impl std::fmt::Display for PipelineStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let status = match self {
+            PipelineStatus::Created            => "created",
+            PipelineStatus::WaitingForResource => "waiting",
+            PipelineStatus::Preparing          => "preparing",
+            PipelineStatus::Pending            => "pending",
+            PipelineStatus::Running            => "running",
+            PipelineStatus::Success            => "success",
+            PipelineStatus::Failed             => "failed",
+            PipelineStatus::Canceling          => "canceling",
+            PipelineStatus::Canceled           => "canceled",
+            PipelineStatus::Skipped            => "skipped",
+            PipelineStatus::Manual             => "manual",
+            PipelineStatus::Scheduled          => "scheduled",
+            PipelineStatus::Unknown            => "unknown",
+        };
+
+        write!(f, "{status}")
+    }
+}
+
+impl std::fmt::Display for RunnerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let status = match self {
+            RunnerStatus::Online         => "online",
+            RunnerStatus::Offline        => "offline",
+            RunnerStatus::Stale          => "stale",
+            RunnerStatus::NeverContacted => "never contacted",
+            RunnerStatus::Unknown        => "unknown",
+        };
+
+        write!(f, "{status}")
     }
 }
 
@@ -180,11 +452,24 @@ impl PipelineStatus {
     pub(crate) fn is_active(&self) -> bool {
         self < &PipelineStatus::Success
     }
+
+    /// How much the status calls for attention, failed pipelines being the most severe.
+    pub(crate) fn severity(&self) -> u8 {
+        match self {
+            PipelineStatus::Failed                  => 4,
+            PipelineStatus::Canceling
+            | PipelineStatus::Canceled              => 3,
+            status if status.is_active()            => 2,
+            PipelineStatus::Manual
+            | PipelineStatus::Scheduled             => 1,
+            _                                       => 0,
+        }
+    }
 }
 
 impl PipelineSource {
     pub(crate) fn is_interesting(&self) -> bool {
-        match self {
+        matches!(self,
             PipelineSource::Api
             | PipelineSource::Chat
             | PipelineSource::ParentPipeline
@@ -192,9 +477,8 @@ impl PipelineSource {
             | PipelineSource::Schedule
             | PipelineSource::Trigger
             | PipelineSource::Web
-            | PipelineSource::Webide => true,
-            _ => false,
-        }
+            | PipelineSource::Webide
+        )
     }
 
 }
@@ -235,20 +519,71 @@ impl Project {
         }
     }
 
-    pub fn recent_pipelines(&self) -> Vec<&Pipeline> {
-        if let Some(pipelines) = self.pipelines.as_ref() {
-            pipelines.iter()
-                .filter(|p| p.source.is_interesting())
-                .take(8)
-                .collect()
-        } else {
-            Vec::new()
+    /// The latest pipeline of each branch shown in the projects table, see
+    /// [set_branch_lines](crate::ui::set_branch_lines). The default branch
+    /// replaces the least recent branch if it is to be shown, but not recent
+    /// enough to be among them.
+    pub fn branch_pipelines(&self) -> Vec<&Pipeline> {
+        let count = branch_lines();
+        if !shows_default_branch() {
+            return self.first_pipeline_per_branch(count, |p| p.status.is_active());
         }
+
+        let all = self.first_pipeline_per_branch(usize::MAX, |p| p.status.is_active());
+        let mut shown: Vec<&Pipeline> = all.iter().take(count).copied().collect();
+        if !shown.iter().any(|p| p.branch == self.default_branch) {
+            if let Some(default) = all.iter().find(|p| p.branch == self.default_branch) {
+                shown.truncate(count - 1);
+                shown.push(default);
+            }
+        }
+
+        shown
+    }
+
+    /// The most severe status of the pipelines shown in the projects table,
+    /// see [PipelineStatus::severity].
+    pub fn status_severity(&self) -> u8 {
+        self.branch_pipelines()
+            .iter()
+            .map(|p| p.status.severity())
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn recent_pipelines(&self) -> Vec<&Pipeline> {
+        self.pipeline_page(0)
+    }
+
+    /// Returns a page of [Self::PIPELINES_PER_PAGE] pipelines, the most
+    /// recent pipelines being on the first page. Older pages are only
+    /// available once fetched, see [Self::append_older_pipelines].
+    pub fn pipeline_page(&self, page: usize) -> Vec<&Pipeline> {
+        self.matching_pipeline_page(page, "")
+    }
+
+    /// Returns a page of the pipelines matching the query, see [Pipeline::matches].
+    pub fn matching_pipeline_page(&self, page: usize, query: &str) -> Vec<&Pipeline> {
+        self.pipelines.iter()
+            .flatten()
+            .filter(|p| p.source.is_interesting())
+            .filter(|p| p.matches(query))
+            .skip(page * Self::PIPELINES_PER_PAGE)
+            .take(Self::PIPELINES_PER_PAGE)
+            .collect()
+    }
+
+    /// Returns the last update of the oldest retained pipeline; older
+    /// pipelines are fetched from before it.
+    pub fn oldest_pipeline_update(&self) -> Option<DateTime<Utc>> {
+        self.pipelines.as_ref()
+            .and_then(|ps| ps.last())
+            .map(|p| p.updated_at)
     }
 
     pub fn has_active_pipelines(&self) -> bool {
         self.pipelines.as_ref()
-            .map_or(false, |ps| ps.iter().any(|p| p.status.is_active() || p.has_active_jobs()))
+            .is_some_and(|ps| ps.iter().any(|p| p.status.is_active() || p.has_active_jobs()))
     }
 
     pub fn path_and_name(&self) -> (&str, &str) {
@@ -262,6 +597,12 @@ impl Project {
         self.pipelines.as_ref()
             .and_then(|ps| ps.iter().find(|p| p.id == id))
     }
+
+    /// Returns the most recent fetched pipeline of the branch, if any.
+    pub fn latest_pipeline_on(&self, branch: &str) -> Option<&Pipeline> {
+        self.pipelines.as_ref()
+            .and_then(|ps| ps.iter().find(|p| p.branch == branch))
+    }
 }
 
 impl From<ProjectDto> for Project {
@@ -275,13 +616,32 @@ impl From<ProjectDto> for Project {
             url: p.web_url,
             last_activity_at: p.last_activity_at,
             pipelines: None,
+            approvals: HashMap::new(),
             commit_count: p.statistics.commit_count,
             repo_size_kb: p.statistics.repository_size / 1024,
             artifacts_size_kb: p.statistics.job_artifacts_size / 1024,
+            topics: p.topics,
+            all_pipelines_fetched: false,
+        }
+    }
+}
+
+impl From<ApprovalsDto> for Approvals {
+    fn from(a: ApprovalsDto) -> Self {
+        Self {
+            approved: a.approved_by.len() as u32,
+            required: a.approvals_required,
+            approvals_left: a.approvals_left,
         }
     }
 }
 
+impl Approvals {
+    pub fn is_approved(&self) -> bool {
+        self.approvals_left == 0
+    }
+}
+
 impl Job {
     pub fn duration(&self) -> Duration {
         match (&self.started_at, &self.finished_at) {
@@ -290,26 +650,64 @@ impl Job {
             _                        => Duration::zero(),
         }
     }
+
+    /// Returns true if the job kept an artifacts archive, which can be downloaded.
+    pub fn has_artifacts_archive(&self) -> bool {
+        self.artifacts.iter().any(|a| a.file_type == "archive")
+    }
 }
 
 impl Project {
+    pub const PIPELINES_PER_PAGE: usize = 8;
+    /// Upper bound of the pipelines retained per project.
+    pub const MAX_RETAINED_PIPELINES: usize = 200;
+
+    /// Replaces the pipelines with the latest page of pipelines. Older
+    /// pipelines, fetched on demand, are retained; pipelines missing from
+    /// the period covered by the page were deleted, and are dropped.
     pub fn update_pipelines(&mut self, pipelines: Vec<Pipeline>) {
+        let oldest = pipelines.iter().map(|p| p.updated_at).min();
+        let retained: Vec<Pipeline> = self.pipelines.iter()
+            .flatten()
+            .filter(|p| oldest.is_some_and(|oldest| p.updated_at < oldest))
+            .filter(|p| !pipelines.iter().any(|new| new.id == p.id))
+            .cloned()
+            .collect();
+
         self.pipelines = Some(
             pipelines.iter().map(|p| {
                 if let Some(existing) = self.pipelines.as_ref().and_then(|ps| ps.iter().find(|ep| ep.id == p.id)) {
                     let mut new = p.clone();
                     new.jobs.clone_from(&existing.jobs);
                     new.commit.clone_from(&existing.commit);
+                    new.commit_details.clone_from(&existing.commit_details);
+                    new.partial_jobs = existing.partial_jobs;
+                    new.gone = existing.gone;
                     new
                 } else {
                     p.clone()
                 }
             })
+            .chain(retained)
             .sorted_by(|a, b| b.updated_at.cmp(&a.updated_at))
+            .take(Self::MAX_RETAINED_PIPELINES)
             .collect()
         );
     }
 
+    /// Adds pipelines older than the retained pipelines, e.g. when paging
+    /// through the project details.
+    pub fn append_older_pipelines(&mut self, pipelines: Vec<Pipeline>) {
+        let existing = self.pipelines.get_or_insert_with(Vec::new);
+        let new: Vec<Pipeline> = pipelines.into_iter()
+            .filter(|p| !existing.iter().any(|e| e.id == p.id))
+            .collect();
+
+        existing.extend(new);
+        existing.sort_by_key(|p| Reverse(p.updated_at));
+        existing.truncate(Self::MAX_RETAINED_PIPELINES);
+    }
+
     pub fn update_project(&mut self, project: Project) {
         self.id = project.id;
         self.path = project.path;
@@ -317,12 +715,47 @@ impl Project {
         self.ssh_git_url = project.ssh_git_url;
         self.url = project.url;
         self.last_activity_at = project.last_activity_at;
+        self.topics = project.topics;
+    }
+
+    /// Merge requests of the recent pipelines, by iid.
+    pub fn recent_merge_requests(&self) -> Vec<u32> {
+        self.recent_pipelines()
+            .into_iter()
+            .filter_map(Pipeline::merge_request_iid)
+            .unique()
+            .collect()
     }
 
     pub fn update_jobs(&mut self, pipeline_id: PipelineId, jobs: Vec<Job>) {
         if let Some(pipelines) = self.pipelines.as_mut() {
             if let Some(pipeline) = pipelines.iter_mut().find(|p| p.id == pipeline_id) {
                 pipeline.jobs = Some(jobs);
+                pipeline.partial_jobs = false;
+            }
+        }
+    }
+
+    pub fn mark_gone(&mut self, pipeline_id: PipelineId) {
+        if let Some(pipelines) = self.pipelines.as_mut() {
+            if let Some(pipeline) = pipelines.iter_mut().find(|p| p.id == pipeline_id) {
+                pipeline.gone = true;
+            }
+        }
+    }
+
+    pub fn mark_partial_jobs(&mut self, pipeline_id: PipelineId) {
+        if let Some(pipelines) = self.pipelines.as_mut() {
+            if let Some(pipeline) = pipelines.iter_mut().find(|p| p.id == pipeline_id) {
+                pipeline.partial_jobs = true;
+            }
+        }
+    }
+
+    pub fn update_commit_details(&mut self, pipeline_id: PipelineId, details: CommitDetails) {
+        if let Some(pipelines) = self.pipelines.as_mut() {
+            if let Some(pipeline) = pipelines.iter_mut().find(|p| p.id == pipeline_id) {
+                pipeline.commit_details = Some(details);
             }
         }
     }
@@ -344,11 +777,15 @@ impl From<PipelineDto> for Pipeline {
             status: p.status,
             source: p.source,
             branch: p.branch,
+            sha: p.sha,
             url: p.web_url,
             created_at: p.created_at,
             updated_at: p.updated_at,
             jobs: None,
+            partial_jobs: false,
+            gone: false,
             commit: None,
+            commit_details: None,
         }
     }
 }
@@ -364,6 +801,73 @@ impl From<JobDto> for Job {
             started_at: j.started_at,
             finished_at: j.finished_at,
             url: j.web_url,
+            artifacts: j.artifacts.into_iter().map(Artifact::from).collect(),
+            downstream: j.downstream_pipeline.map(|p| (p.project_id, p.id)),
+        }
+    }
+}
+
+impl From<ArtifactDto> for Artifact {
+    fn from(a: ArtifactDto) -> Self {
+        Self {
+            file_type: a.file_type,
+            filename: a.filename,
+            size: a.size,
+        }
+    }
+}
+
+impl From<BranchDto> for Branch {
+    fn from(b: BranchDto) -> Self {
+        Self {
+            name: b.name,
+            default: b.default,
+            protected: b.protected,
+            commit_title: b.commit.title,
+            committed_at: b.commit.committed_date,
+        }
+    }
+}
+
+impl From<ScheduleDto> for Schedule {
+    fn from(s: ScheduleDto) -> Self {
+        Self {
+            id: s.id,
+            description: s.description,
+            // refs of schedules may be qualified, e.g. refs/heads/main
+            branch: s.branch.trim_start_matches("refs/heads/").to_string(),
+            cron: s.cron,
+            cron_timezone: s.cron_timezone,
+            next_run_at: s.next_run_at.filter(|_| s.active),
+        }
+    }
+}
+
+impl From<RunnerDto> for Runner {
+    fn from(r: RunnerDto) -> Self {
+        Self {
+            id: r.id,
+            description: r.description.filter(|d| !d.is_empty())
+                .unwrap_or_else(|| format!("#{}", r.id)),
+            status: r.status,
+            paused: r.paused,
+            shared: r.is_shared,
+            contacted_at: r.contacted_at,
+            tags: r.tag_list,
+        }
+    }
+}
+
+impl From<CommitDetailsDto> for CommitDetails {
+    fn from(c: CommitDetailsDto) -> Self {
+        Self {
+            short_sha: c.short_id,
+            author_name: c.author_name,
+            author_email: c.author_email,
+            authored_at: c.authored_date,
+            message: c.message,
+            additions: c.stats.additions,
+            deletions: c.stats.deletions,
         }
     }
 }
@@ -378,9 +882,26 @@ impl From<CommitDto> for Commit {
 }
 
 impl Pipeline {
+    /// Returns true if the branch contains the query, or the status starts
+    /// with it, ignoring case; an empty query matches all pipelines.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.branch.to_lowercase().contains(&query)
+            || self.status.to_string().starts_with(&query)
+    }
+
+    /// Iid of the merge request, for merge request pipelines; their ref is
+    /// `refs/merge-requests/{iid}/head` or `refs/merge-requests/{iid}/merge`.
+    pub fn merge_request_iid(&self) -> Option<u32> {
+        self.branch.strip_prefix("refs/merge-requests/")
+            .and_then(|r| r.split('/').next())
+            .and_then(|iid| iid.parse().ok())
+    }
+
     pub fn has_active_jobs(&self) -> bool {
         self.jobs.as_ref()
-            .map_or(false, |jobs| jobs.iter().any(|j| j.status.is_active()))
+            .is_some_and(|jobs| jobs.iter().any(|j| j.status.is_active()))
     }
 
     pub fn active_job(&self) -> Option<&Job> {
@@ -411,6 +932,20 @@ impl Pipeline {
         self.jobs.as_ref()
             .and_then(|jobs| jobs.iter().find(|j| j.id == id))
     }
+
+    /// Returns the jobs grouped by stage, with the stages in the order they
+    /// first appear among the jobs.
+    pub fn stages(&self) -> Vec<Stage<'_>> {
+        let mut stages: Vec<Stage> = Vec::new();
+        for job in self.jobs.iter().flatten() {
+            match stages.iter_mut().find(|s| s.name == job.stage) {
+                Some(stage) => stage.jobs.push(job),
+                None        => stages.push(Stage { name: &job.stage, jobs: vec![job] }),
+            }
+        }
+
+        stages
+    }
     
     /// Returns the duration of the pipeline, measured from the time it was started
     /// to the time it was finished. If the pipeline is still running, the duration
@@ -431,77 +966,149 @@ impl Pipeline {
     }
 }
 
+/// State of a project shown alongside it in the projects table, see [parse_row].
+#[derive(Default)]
+pub struct ProjectRowState<'a> {
+    pub pinned: bool,
+    pub watched: bool,
+    /// ignored projects are dimmed, when listed at all
+    pub ignored: bool,
+    /// name of the GitLab instance, if more than one is configured
+    pub instance: Option<&'a str>,
+    pub label: Option<(&'a str, Style)>,
+    pub fetch: Option<&'a ProjectFetch>,
+    /// byte offsets in the path matching the project filter
+    pub filter_matches: Option<&'a [usize]>,
+    pub health: Option<Health>,
+}
+
 pub fn parse_row<'a>(
     project: &'a Project,
+    state: ProjectRowState<'a>,
 ) -> Row<'a> {
-    let distinct_by_branch = project.first_pipeline_per_branch(3, |p| p.status.is_active());
+    let ProjectRowState { pinned, watched, ignored, instance, label, fetch, filter_matches, health } = state;
+    let distinct_by_branch = project.branch_pipelines();
 
     let pipeline_to_span = |p: &'a Pipeline| -> Line<'a> {
-        let icon = p.status.icon();
+        let icon = p.status.labeled_icon();
         let branch = p.branch.as_str();
 
-        let updated_at = p.updated_at.with_timezone(&Local);
-        match () {
-            _ if p.has_active_jobs() => Line::from(vec![
-                Span::from(updated_at.format("%a, %d %b").to_string()).style(theme().date),
-                Span::from(" "),
-                Span::from(updated_at.format("%H:%M:%S").to_string()).style(theme().time),
+        let mut spans: Vec<Span<'a>> = timestamp_spans(p.updated_at.with_timezone(&Local));
+        spans.extend(match () {
+            _ if p.has_active_jobs() => vec![
                 Span::from(" "),
-                Span::from(p.jobs.as_ref().unwrap().icon()),
+                Span::from(p.jobs.as_ref().unwrap().labeled_icon()),
                 Span::from(" "),
                 Span::from(branch).style(theme().pipeline_branch),
                 Span::from(" "),
                 Span::from(p.active_job_name()).style(theme().pipeline_job),
                 Span::from(" "),
                 Span::from(format_duration(p.duration())).style(theme().time),
-            ]),
-            _ if p.status.is_active() => Line::from(vec![
-                Span::from(updated_at.format("%a, %d %b").to_string()).style(theme().date),
-                Span::from(" "),
-                Span::from(updated_at.format("%H:%M:%S").to_string()).style(theme().time),
+            ],
+            _ if p.status.is_active() => vec![
                 Span::from(" "),
                 Span::from(icon),
                 Span::from(" "),
                 Span::from(branch).style(theme().pipeline_branch),
                 Span::from(" "),
                 Span::from(format_duration(p.duration())).style(theme().time),
-            ]),
-            _ => Line::from(vec![
-                Span::from(updated_at.format("%a, %d %b").to_string()).style(theme().date),
-                Span::from(" "),
-                Span::from(updated_at.format("%H:%M:%S").to_string()).style(theme().time),
+            ],
+            _ => vec![
                 Span::from(" "),
                 Span::from(icon),
                 Span::from(" "),
                 Span::from(branch).style(theme().pipeline_branch),
-            ])
-        }
+            ],
+        });
+
+        Line::from(spans)
     };
 
-    let pipeline_spans: Vec<Line<'a>> = distinct_by_branch.iter()
+    let mut pipeline_spans: Vec<Line<'a>> = distinct_by_branch.iter()
         .map(|p| pipeline_to_span(p))
         .collect();
 
+    let fetch_state = fetch.map(ProjectFetch::state).unwrap_or_default();
+    if fetch_state == FetchState::FetchingPipelines && pipeline_spans.is_empty() {
+        pipeline_spans.push(Line::from(t(Msg::LoadingPipelines)).style(theme().log_message));
+    }
+
     let last_activity = project.last_activity_at.with_timezone(&Local);
 
-    let project_path = match project.path.rfind('/') {
+    let pin_icon = match (pinned, watched) {
+        (true, true)   => format!("{}{} ", pinned_icon(), watched_icon()),
+        (true, false)  => format!("{} ", pinned_icon()),
+        (false, true)  => format!("{} ", watched_icon()),
+        (false, false) => String::new(),
+    };
+    // characters matching the project filter are highlighted
+    let matched = filter_matches.unwrap_or_default();
+    let path_spans = |range: std::ops::Range<usize>| highlight_matches(&project.path, range, matched);
+    let name_style = if ignored { theme().project_parents } else { theme().project_name };
+
+    let mut project_path = match project.path.rfind('/') {
         Some(i) => {
             Text::from(vec![
-                Line::from(&project.path[i + 1..])
-                    .style(theme().project_name),
-                Line::from(&project.path[0..=i])
+                Line::from_iter(std::iter::once(Span::from(pin_icon)).chain(path_spans(i + 1..project.path.len())))
+                    .style(name_style),
+                Line::from(path_spans(0..i + 1))
                     .style(theme().project_parents),
             ])
         }
-        None => Text::from(Span::from(&project.path))
-            .style(theme().project_name),
+        None => Text::from(Line::from_iter(std::iter::once(Span::from(pin_icon)).chain(path_spans(0..project.path.len()))))
+            .style(name_style),
     };
 
-    Row::new(vec![
-        text_from(last_activity),
-        project_path,
-        Text::from(pipeline_spans),
-    ]).height(3)
+    // label chip, trailing the namespace
+    if let Some((name, style)) = label {
+        let chip = Span::from(format!(" {name} ")).style(style);
+        match project_path.lines.get_mut(1) {
+            Some(parents) => parents.spans.extend([Span::from(" "), chip]),
+            None => project_path.lines.push(Line::from(chip)),
+        }
+    }
+
+    // pipelines shown are from the last successful fetch
+    if fetch_state == FetchState::Stale {
+        let since = fetch.and_then(ProjectFetch::hydrated_at)
+            .map(|at| at.with_timezone(&Local).format("%H:%M").to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        project_path.lines.push(Line::from(format!("⚠ {}{since}", t(Msg::FetchStaleSince)))
+            .style(theme().log_message));
+    }
+
+    let instance_badge = instance.map(|name| Text::from(
+        Span::from(format!(" {name} ")).style(theme().instance_badge)
+    ));
+
+    let cells = std::iter::once(text_from(last_activity))
+        .chain(instance_badge)
+        .chain([project_path, health_text(health), Text::from(pipeline_spans)]);
+
+    Row::new(cells).height(project_row_height())
+}
+
+/// Success rate and trend of the project, colored by the success rate.
+fn health_text(health: Option<Health>) -> Text<'static> {
+    let Some(health) = health else { return Text::default() };
+
+    let style = match health.percent() {
+        p if p >= 80 => theme().job_status_success,
+        p if p >= 50 => theme().job_status_active,
+        _            => theme().job_status_failed,
+    };
+
+    let trend = match (health.trend, ascii_icons()) {
+        (Trend::Improving, false) => "↗",
+        (Trend::Steady, false)    => "→",
+        (Trend::Declining, false) => "↘",
+        (Trend::Improving, true)  => "+",
+        (Trend::Steady, true)     => "=",
+        (Trend::Declining, true)  => "-",
+    };
+
+    Text::from(Line::from(format!("{}% {trend}", health.percent())).style(style))
 }
 
 /// Represents types that can be associated with an icon.
@@ -509,11 +1116,48 @@ pub fn parse_row<'a>(
 /// The icon returned is expected to be a string that may contain
 /// special characters or emojis
 pub trait IconRepresentable {
-    fn icon(&self) -> String;
+    fn emoji(&self) -> String;
+
+    /// Alternative to [Self::emoji] for terminals without emoji glyphs.
+    fn ascii(&self) -> String;
+
+    /// Status in words, paired with the icon in accessibility mode.
+    fn word(&self) -> String;
+
+    /// Glyph configured in place of the emoji, see [GlimConfig::glyphs](crate::glim_app::GlimConfig::glyphs).
+    fn glyph(&self) -> Option<String> {
+        None
+    }
+
+    /// Icon for rendering: the configured glyph, else ASCII if configured,
+    /// see [ascii_icons], else the emoji.
+    fn icon(&self) -> String {
+        self.glyph().unwrap_or_else(|| if ascii_icons() { self.ascii() } else { self.emoji() })
+    }
+
+    /// [Self::icon] followed by [Self::word] in accessibility mode, for
+    /// icons not otherwise accompanied by the status.
+    fn labeled_icon(&self) -> String {
+        if accessible() {
+            format!("{} {}", self.icon(), self.word())
+        } else {
+            self.icon()
+        }
+    }
+}
+
+/// Marker of pinned projects.
+pub fn pinned_icon() -> &'static str {
+    if ascii_icons() { "^" } else { "📌" }
+}
+
+/// Marker of watched projects.
+pub fn watched_icon() -> &'static str {
+    if ascii_icons() { "w" } else { "👁" }
 }
 
 impl IconRepresentable for PipelineStatus {
-    fn icon(&self) -> String {
+    fn emoji(&self) -> String {
         match self {
             PipelineStatus::Created            => "⚪",
             PipelineStatus::WaitingForResource => "⏳",
@@ -530,18 +1174,151 @@ impl IconRepresentable for PipelineStatus {
             PipelineStatus::Unknown            => "❓",
         }.to_string()
     }
+
+    fn ascii(&self) -> String {
+        match self {
+            PipelineStatus::Created            => "o",
+            PipelineStatus::WaitingForResource => "~",
+            PipelineStatus::Preparing          => ".",
+            PipelineStatus::Pending            => "-",
+            PipelineStatus::Running            => ">",
+            PipelineStatus::Success            => "+",
+            PipelineStatus::Failed             => "x",
+            PipelineStatus::Canceled           => "/",
+            PipelineStatus::Canceling          => "/",
+            PipelineStatus::Skipped            => "_",
+            PipelineStatus::Manual             => "m",
+            PipelineStatus::Scheduled          => "@",
+            PipelineStatus::Unknown            => "?",
+        }.to_string()
+    }
+
+    fn word(&self) -> String {
+        self.to_string()
+    }
+
+    fn glyph(&self) -> Option<String> {
+        glyph(self)
+    }
+}
+
+impl IconRepresentable for RunnerStatus {
+    fn emoji(&self) -> String {
+        match self {
+            RunnerStatus::Online         => "🟢",
+            RunnerStatus::Offline        => "🔴",
+            RunnerStatus::Stale          => "⚫",
+            RunnerStatus::NeverContacted => "⚪",
+            RunnerStatus::Unknown        => "❓",
+        }.to_string()
+    }
+
+    fn ascii(&self) -> String {
+        match self {
+            RunnerStatus::Online         => "+",
+            RunnerStatus::Offline        => "x",
+            RunnerStatus::Stale          => "_",
+            RunnerStatus::NeverContacted => "o",
+            RunnerStatus::Unknown        => "?",
+        }.to_string()
+    }
+
+    fn word(&self) -> String {
+        self.to_string()
+    }
 }
 
 impl IconRepresentable for &Vec<Job> {
+    fn emoji(&self) -> String {
+        self.iter().map(|j| j.status.emoji()).collect()
+    }
+
+    fn ascii(&self) -> String {
+        self.iter().map(|j| j.status.ascii()).collect()
+    }
+
+    /// Icons of the jobs, each resolved as by [PipelineStatus::icon].
     fn icon(&self) -> String {
         self.iter().map(|j| j.status.icon()).collect()
     }
+
+    /// Status of the first job yet to succeed, or of the last job.
+    fn word(&self) -> String {
+        self.iter()
+            .find(|j| j.status != PipelineStatus::Success)
+            .or(self.last())
+            .map(|j| j.status.word())
+            .unwrap_or_default()
+    }
+}
+
+impl IconRepresentable for Vec<Stage<'_>> {
+    fn emoji(&self) -> String {
+        self.iter().map(|s| s.status().emoji()).collect()
+    }
+
+    fn ascii(&self) -> String {
+        self.iter().map(|s| s.status().ascii()).collect()
+    }
+
+    /// Icons of the stages, each resolved as by [PipelineStatus::icon].
+    fn icon(&self) -> String {
+        self.iter().map(|s| s.status().icon()).collect()
+    }
+
+    /// Status of the first stage yet to succeed, or of the last stage.
+    fn word(&self) -> String {
+        self.iter()
+            .map(Stage::status)
+            .find(|status| *status != PipelineStatus::Success)
+            .or(self.last().map(Stage::status))
+            .map(|status| status.word())
+            .unwrap_or_default()
+    }
 }
 
 impl IconRepresentable for Pipeline {
+    fn emoji(&self) -> String {
+        self.jobs.as_ref()
+            .map(|jobs| jobs.emoji())
+            .unwrap_or(self.status.emoji())
+    }
+
+    fn ascii(&self) -> String {
+        self.jobs.as_ref()
+            .map(|jobs| jobs.ascii())
+            .unwrap_or(self.status.ascii())
+    }
+
+    fn word(&self) -> String {
+        self.status.word()
+    }
+
     fn icon(&self) -> String {
         self.jobs.as_ref()
             .map(|jobs| jobs.icon())
             .unwrap_or(self.status.icon())
     }
 }
+
+/// Splits `text[range]` into spans, highlighting the characters at the byte
+/// offsets in `matched`.
+fn highlight_matches<'a>(
+    text: &'a str,
+    range: std::ops::Range<usize>,
+    matched: &[usize],
+) -> Vec<Span<'a>> {
+    let offset = range.start;
+    text[range].char_indices()
+        .chunk_by(|(idx, _)| matched.contains(&(offset + idx)))
+        .into_iter()
+        .map(|(is_match, chars)| {
+            let chars = chars.collect_vec();
+            let (first, _) = chars[0];
+            let (last, c) = chars[chars.len() - 1];
+
+            let span = Span::from(&text[offset + first..offset + last + c.len_utf8()]);
+            if is_match { span.style(theme().search_match) } else { span }
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// ETags of the last successful responses, by request URL. Sent as
+/// `If-None-Match` with the next request to the same URL, so that GitLab can
+/// respond with 304 Not Modified instead of repeating an unchanged response.
+///
+/// Clones share their state, so that the cache can be moved into the tasks
+/// performing the requests.
+#[derive(Clone, Default)]
+pub struct ETagCache {
+    etags: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl ETagCache {
+    /// Upper bound of remembered URLs; URLs with a timestamp, e.g. the
+    /// projects list, are rarely requested twice in a row.
+    const MAX_ENTRIES: usize = 2048;
+
+    pub fn get(&self, url: &str) -> Option<String> {
+        self.etags.lock().unwrap()
+            .get(url)
+            .cloned()
+    }
+
+    pub fn insert(&self, url: &str, etag: String) {
+        let mut etags = self.etags.lock().unwrap();
+        if etags.len() >= Self::MAX_ENTRIES && !etags.contains_key(url) {
+            etags.clear();
+        }
+
+        etags.insert(url.to_string(), etag);
+    }
+}
@@ -1,51 +1,215 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
-use std::sync::mpsc;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
 
+use chrono::NaiveDate;
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, KeyEventKind};
+use crate::circuit_breaker::Endpoint;
 use crate::dispatcher::Dispatcher;
-use crate::domain::{JobDto, PipelineDto, Project, ProjectDto};
+use crate::domain::{ApprovalsDto, BranchDto, CommitDetailsDto, RunnerDto, ScheduleDto, JobDto, PipelineDto, PipelineStatus, Project, ProjectDto};
+use crate::gitlab_version::GitlabVersion;
 use crate::glim_app::GlimConfig;
 use crate::id::{JobId, PipelineId, ProjectId};
+use crate::memory_usage::MemoryUsage;
+use crate::negative_cache::GoneResource;
 use crate::result;
+use crate::stores::{LogLevel, ProjectSort, QuickFilter};
+use crate::ui::widget::Tab;
+use crate::watch::WatchMatch;
 
 #[derive(Debug, Clone)]
 pub enum GlimEvent {
     Tick,
+    /// the terminal was resized, and must be redrawn
+    Resized,
     Shutdown,
     Key(KeyEvent),
     ToggleInternalLogs,
     Log(String),
+    /// the level of the internal logs changed, see [GlimConfig::log_level](crate::glim_app::GlimConfig::log_level)
+    LogLevelChanged(LogLevel),
     GlitchOverride(GlitchState),
     CloseProjectDetails,
     OpenProjectDetails(ProjectId),
     OpenPipelineActions(ProjectId, PipelineId),
+    OpenJobActions(ProjectId, PipelineId, JobId),
+    FollowPipeline(ProjectId, PipelineId),
+    UnfollowPipeline,
+    /// follows the pipeline until it completes, then exits glim
+    WaitForPipeline(ProjectId, PipelineId),
     ClosePipelineActions,
     RequestProject(ProjectId),
     RequestProjects,
+    ManualRefresh,
     RequestJobs(ProjectId, PipelineId),
+    RequestApprovals(ProjectId, u32),
+    RequestCommitDetails(ProjectId, PipelineId),
     RequestActiveJobs,
+    PollProjects,
     RequestPipelines(ProjectId),
+    /// requests the pipelines preceding the retained pipelines of the project
+    RequestOlderPipelines(ProjectId),
+    /// requests a single pipeline by id, regardless of whether its project is loaded
+    RequestPipeline(ProjectId, PipelineId),
     ReceivedProjects(Vec<ProjectDto>),
-    ReceivedPipelines(Vec<PipelineDto>),
+    ReceivedPipelines(ProjectId, Vec<PipelineDto>),
+    /// pipelines preceding the retained pipelines; empty once all are fetched
+    ReceivedOlderPipelines(ProjectId, Vec<PipelineDto>),
+    ReceivedPipeline(ProjectId, Box<PipelineDto>),
+    /// the pipeline requested by id could not be fetched
+    PipelineUnavailable(ProjectId, PipelineId, result::GlimError),
     ReceivedJobs(ProjectId, PipelineId, Vec<JobDto>),
+    ReceivedApprovals(ProjectId, u32, ApprovalsDto),
+    /// trigger jobs of the pipeline could not be fetched
+    JobsPartiallyLoaded(ProjectId, PipelineId),
+    /// requests failed repeatedly with network errors, see [Connectivity](crate::connectivity::Connectivity)
+    ConnectionLost,
+    /// GitLab responded again after the connection was lost
+    ConnectionRestored,
+    /// requests to the endpoint are paused after repeated failures
+    CircuitOpened(Endpoint, ProjectId),
+    /// the resource responded with 404 repeatedly, and is no longer requested
+    ResourceGone(GoneResource),
+    ReceivedCommitDetails(ProjectId, PipelineId, CommitDetailsDto),
+    /// status of a known pipeline changed, from the old to the new status
+    PipelineStatusChanged(ProjectId, PipelineId, PipelineStatus, PipelineStatus),
     SelectedProject(ProjectId),
+    /// indices of the rows in view of the projects table
+    ProjectsViewport(Range<usize>),
     SelectedPipeline(PipelineId),
     Error(result::GlimError),
     SelectNextProject,
     SelectPreviousProject,
     ApplyConfiguration,
-    UpdateConfig(GlimConfig),
+    /// the onboarding wizard validates its current step, and advances or completes
+    AdvanceOnboarding,
+    UpdateConfig(Box<GlimConfig>),
     DisplayConfig,
     CloseConfig,
+    TestWebhook,
+    DisplayAuditLog,
+    CloseAuditLog,
+    DisplayApiInspector,
+    CloseApiInspector,
+    DisplayProjectErrors,
+    CloseProjectErrors,
     BrowseToJob(ProjectId, PipelineId, JobId),
     BrowseToPipeline(ProjectId, PipelineId),
+    /// opens the diff of the commit the pipeline ran for
+    BrowseToCommit(ProjectId, PipelineId),
     BrowseToProject(ProjectId),
     DownloadErrorLog(ProjectId, PipelineId),
+    RetryJob(ProjectId, PipelineId, JobId),
+    JobRetried(ProjectId, PipelineId, JobId),
+    CreateIssue(ProjectId, PipelineId),
+    IssueCreated(ProjectId, String),
     JobLogDownloaded(ProjectId, JobId, String),
+    CopyToClipboard(String),
+    /// copies the web URL of the pipeline
+    CopyPipelineUrl(ProjectId, PipelineId),
+    /// copies the web URL of the job
+    CopyJobUrl(ProjectId, PipelineId, JobId),
+    /// copies the branch, or ref, the pipeline ran for
+    CopyBranchName(ProjectId, PipelineId),
+    /// copies the ssh clone URL of the project
+    CopyCloneUrl(ProjectId),
+    /// copies the `git clone` command of the project, see [LocalClones](crate::local_clone::LocalClones)
+    CopyCloneCommand(ProjectId),
+    /// runs the configured command opening the local clone of the project
+    OpenLocalClone(ProjectId),
+    OpenJobLog(ProjectId, PipelineId, JobId),
+    CloseJobLog,
+    ReceivedJobTrace(ProjectId, JobId, String),
+    /// the trace requested for the job log popup could not be fetched
+    JobTraceUnavailable(ProjectId, JobId, result::GlimError),
+    OpenPipelineGraph(ProjectId, PipelineId),
+    ClosePipelineGraph,
+    OpenBranches(ProjectId),
+    CloseBranches,
+    ReceivedBranches(ProjectId, Vec<BranchDto>),
+    BrowseToBranch(ProjectId, String),
+    /// runs a new pipeline on the branch
+    TriggerPipeline(ProjectId, String),
+    PipelineTriggered(ProjectId, String),
+    OpenSchedules(ProjectId),
+    CloseSchedules,
+    ReceivedSchedules(ProjectId, Vec<ScheduleDto>),
+    /// runs the pipeline schedule now
+    PlaySchedule(ProjectId, u32),
+    SchedulePlayed(ProjectId, u32),
+    OpenArtifacts(ProjectId, PipelineId, JobId),
+    CloseArtifacts,
+    DownloadArtifacts(ProjectId, JobId),
+    /// bytes received, and total size if known
+    ArtifactsDownloadProgress(JobId, u64, Option<u64>),
+    ArtifactsDownloaded(JobId, PathBuf),
+    OpenRunners(ProjectId),
+    CloseRunners,
+    ReceivedRunners(ProjectId, Vec<RunnerDto>),
+    /// fetches the versions of the GitLab instances
+    RequestGitlabVersion,
+    /// version of the instance, or none if unknown
+    ReceivedGitlabVersion(u8, Option<GitlabVersion>),
+    /// fetches the expiry and scopes of the token of the primary instance
+    RequestTokenExpiry,
+    /// expiry of the token, or none if it never expires
+    ReceivedTokenExpiry(Option<NaiveDate>),
+    /// scopes of the token, see [scope_warnings](crate::token_scopes::scope_warnings)
+    ReceivedTokenScopes(Vec<String>),
+    /// the projects of the instance are unchanged since the last poll
+    ProjectsNotModified(u8),
+    /// the pipelines of the project are unchanged since the last request
+    PipelinesNotModified(ProjectId),
+    ReceivedJobNeeds(ProjectId, PipelineId, HashMap<String, Vec<String>>),
     ProjectUpdated(Box<Project>),
+    TogglePinProject(ProjectId),
+    /// watches the project, or only the given branch
+    ToggleWatch(ProjectId, Option<String>),
+    WatchMatched(WatchMatch),
+    ToggleIgnoreProject(ProjectId),
+    /// lists the ignored projects last in the projects table, where they can be un-ignored
+    ToggleShowIgnored,
+    DisplaySearch,
+    CloseSearch,
+    SearchQuery(String),
+    DisplayCommandPalette,
+    CloseCommandPalette,
+    DisplayHelp,
+    CloseHelp,
+    DisplayProjectFilter,
+    CloseProjectFilter,
+    /// filters the projects table by fuzzy matching the query; empty clears the filter
+    FilterProjects(String),
+    CycleProjectSort,
+    SortProjects(ProjectSort),
+    ToggleQuickFilter(QuickFilter),
+    /// switches the projects table between the flat and the grouped view
+    ToggleProjectTree,
+    CollapseProjectGroup,
+    ExpandProjectGroup,
+    /// shows the view of the tab in the main area
+    TabSelect(Tab),
     ShowLastNotification,
     ToggleColorDepth,
+    /// switches between relative and absolute timestamps, see [relative_times](crate::ui::relative_times)
+    ToggleRelativeTimes,
+    /// starts or stops recording the rendered frames, see [ScreenRecorder](crate::recording::ScreenRecorder)
+    ToggleRecording,
+    RecordingStarted(PathBuf),
+    RecordingSaved(PathBuf),
+    /// writes the screen to a file, see [save_screenshot](crate::screen_capture::save_screenshot)
+    Screenshot,
+    ScreenshotSaved(PathBuf),
+    /// widens the projects table by the given percentage points, or narrows
+    /// it if negative, while the internal logs are shown
+    ResizeSplit(i16),
+    /// measures the memory used by loaded projects and cached job logs
+    ProfileMemory,
+    MemoryProfiled(MemoryUsage),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -58,6 +222,8 @@ pub enum GlitchState {
 pub struct EventHandler {
     sender: mpsc::Sender<GlimEvent>,
     receiver: mpsc::Receiver<GlimEvent>,
+    /// milliseconds between ticks, see [Self::set_tick_rate]
+    tick_rate_ms: Arc<AtomicU64>,
     _handler: thread::JoinHandle<()>
 }
 
@@ -66,17 +232,24 @@ pub trait IntoGlimEvent {
 }
 
 impl EventHandler {
+    const MAX_POLL: std::time::Duration = std::time::Duration::from_millis(50);
+
     pub fn new(tick_rate: std::time::Duration) -> Self {
         let (sender, receiver) = mpsc::channel();
+        let tick_rate_ms = Arc::new(AtomicU64::new(tick_rate.as_millis() as u64));
 
         let handler = {
             let sender = sender.clone();
+            let tick_rate_ms = tick_rate_ms.clone();
             thread::spawn(move || {
                 let mut last_tick = std::time::Instant::now();
                 loop {
+                    let tick_rate = std::time::Duration::from_millis(tick_rate_ms.load(Ordering::Relaxed));
+                    // bounded, so that a changed tick rate applies promptly
                     let timeout = tick_rate
                         .checked_sub(last_tick.elapsed())
-                        .unwrap_or(tick_rate);
+                        .unwrap_or(tick_rate)
+                        .min(Self::MAX_POLL);
 
                     if event::poll(timeout).expect("unable to poll for events") {
                         Self::apply_event(&sender);
@@ -90,7 +263,12 @@ impl EventHandler {
             })
         };
 
-        Self { sender, receiver, _handler: handler }
+        Self { sender, receiver, tick_rate_ms, _handler: handler }
+    }
+
+    /// Changes the time between ticks, e.g. after the configuration is loaded.
+    pub fn set_tick_rate(&self, tick_rate: std::time::Duration) {
+        self.tick_rate_ms.store(tick_rate.as_millis().max(1) as u64, Ordering::Relaxed);
     }
 
     pub fn sender(&self) -> mpsc::Sender<GlimEvent> {
@@ -102,31 +280,67 @@ impl EventHandler {
     }
 
     pub fn try_next(&self) -> Option<GlimEvent> {
-        match self.receiver.try_recv() {
-            Ok(e) => Some(e),
-            Err(_) => None
-        }
+        self.receiver.try_recv().ok()
     }
 
     fn apply_event(sender: &mpsc::Sender<GlimEvent>) {
         match event::read().expect("unable to read event") {
             CrosstermEvent::Key(e) if e.kind == KeyEventKind::Press =>
                 sender.send(GlimEvent::Key(e)),
+            CrosstermEvent::Resize(_, _) =>
+                sender.send(GlimEvent::Resized),
 
             _ => Ok(()),
         }.expect("failed to send event")
     }
 }
 
-impl From<Vec<ProjectDto>> for GlimEvent {
-    fn from(projects: Vec<ProjectDto>) -> Self {
-        GlimEvent::ReceivedProjects(projects)
+impl GlimEvent {
+    /// Namespaces the ids of received entities, and attributes GitLab being
+    /// unavailable, by the index of the GitLab instance they were fetched from.
+    pub fn with_instance(self, instance: u8) -> Self {
+        let pipeline_with_instance = |mut p: PipelineDto| {
+            p.id = p.id.with_instance(instance);
+            p.project_id = p.project_id.with_instance(instance);
+            p
+        };
+
+        match self {
+            GlimEvent::ReceivedProjects(projects) => GlimEvent::ReceivedProjects(projects.into_iter()
+                .map(|mut p| { p.id = p.id.with_instance(instance); p })
+                .collect()),
+            GlimEvent::ReceivedPipelines(project_id, pipelines) => GlimEvent::ReceivedPipelines(project_id, pipelines.into_iter()
+                .map(pipeline_with_instance)
+                .collect()),
+            GlimEvent::ReceivedOlderPipelines(project_id, pipelines) => GlimEvent::ReceivedOlderPipelines(project_id, pipelines.into_iter()
+                .map(pipeline_with_instance)
+                .collect()),
+            GlimEvent::ReceivedPipeline(project_id, pipeline) =>
+                GlimEvent::ReceivedPipeline(project_id, Box::new(pipeline_with_instance(*pipeline))),
+            // downstream pipelines of trigger jobs belong to the same instance
+            GlimEvent::ReceivedJobs(project_id, pipeline_id, jobs) => GlimEvent::ReceivedJobs(project_id, pipeline_id, jobs.into_iter()
+                .map(|mut job| {
+                    job.id = job.id.with_instance(instance);
+                    if let Some(downstream) = job.downstream_pipeline.as_mut() {
+                        downstream.id = downstream.id.with_instance(instance);
+                        downstream.project_id = downstream.project_id.with_instance(instance);
+                    }
+                    job
+                })
+                .collect()),
+            GlimEvent::Error(e) => GlimEvent::Error(e.with_instance(instance)),
+            GlimEvent::PipelineUnavailable(project_id, pipeline_id, e) =>
+                GlimEvent::PipelineUnavailable(project_id, pipeline_id, e.with_instance(instance)),
+            GlimEvent::JobTraceUnavailable(project_id, job_id, e) =>
+                GlimEvent::JobTraceUnavailable(project_id, job_id, e.with_instance(instance)),
+            event => event,
+        }
     }
 }
 
-impl From<Vec<PipelineDto>> for GlimEvent {
-    fn from(pipelines: Vec<PipelineDto>) -> Self {
-        GlimEvent::ReceivedPipelines(pipelines)
+impl From<Vec<ProjectDto>> for GlimEvent {
+    fn from(projects: Vec<ProjectDto>) -> Self {
+        GlimEvent::ReceivedProjects(projects)
     }
 }
 
@@ -143,12 +357,6 @@ impl IntoGlimEvent for Vec<ProjectDto> {
     }
 }
 
-impl IntoGlimEvent for Vec<PipelineDto> {
-    fn into_glim_event(self) -> GlimEvent {
-        GlimEvent::ReceivedPipelines(self)
-    }
-}
-
 impl IntoGlimEvent for (ProjectId, PipelineId, Vec<JobDto>) {
     fn into_glim_event(self) -> GlimEvent {
         let (project_id, pipeline_id, jobs) = self;
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::id::PipelineId;
+
+/// Progress of fetching the pipelines and jobs of a project.
+/// ```
+/// Idle ─▸ FetchingPipelines ─▸ FetchingJobs ─▸ Hydrated
+///                │                  │
+///                ╰──────────────────┴─▸ Stale (failed or timed out)
+/// ```
+/// Hydrated and stale projects return to fetching on the next request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FetchState {
+    /// pipelines not requested, e.g. for inactive projects
+    #[default]
+    Idle,
+    FetchingPipelines,
+    /// pipelines received; waiting for the jobs of active pipelines
+    FetchingJobs,
+    Hydrated,
+    /// the last fetch failed or got no response; retried on the next poll
+    Stale,
+}
+
+/// [FetchState] of a project, with the requests it is waiting on.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectFetch {
+    state: FetchState,
+    /// time of the last transition
+    since: Option<DateTime<Utc>>,
+    /// time the project was last hydrated
+    hydrated_at: Option<DateTime<Utc>>,
+    pending_jobs: HashSet<PipelineId>,
+}
+
+impl ProjectFetch {
+    /// Fetches without a response within this duration are considered failed.
+    const TIMEOUT: Duration = Duration::seconds(90);
+
+    /// Fetch state of a project restored from the cache, hydrated at the time
+    /// the cache was written.
+    pub fn restored(hydrated_at: DateTime<Utc>) -> Self {
+        Self {
+            state: FetchState::Stale,
+            since: Some(Utc::now()),
+            hydrated_at: Some(hydrated_at),
+            pending_jobs: HashSet::new(),
+        }
+    }
+
+    pub fn state(&self) -> FetchState {
+        self.state
+    }
+
+    pub fn hydrated_at(&self) -> Option<DateTime<Utc>> {
+        self.hydrated_at
+    }
+
+    /// Returns true once the pipelines have been requested.
+    pub fn is_requested(&self) -> bool {
+        self.state != FetchState::Idle
+    }
+
+    pub fn request_pipelines(&mut self) {
+        self.transition(FetchState::FetchingPipelines);
+    }
+
+    /// Pipelines received; `active` are the pipelines with jobs being requested.
+    pub fn pipelines_received(&mut self, active: impl IntoIterator<Item = PipelineId>) {
+        self.pending_jobs = active.into_iter().collect();
+        self.transition_to_jobs_or_hydrated();
+    }
+
+    pub fn request_jobs(&mut self, pipeline_id: PipelineId) {
+        // projects without requested pipelines have no jobs to wait on
+        if self.state == FetchState::Idle {
+            return;
+        }
+
+        self.pending_jobs.insert(pipeline_id);
+        if self.state != FetchState::FetchingPipelines {
+            self.transition(FetchState::FetchingJobs);
+        }
+    }
+
+    pub fn jobs_received(&mut self, pipeline_id: PipelineId) {
+        self.pending_jobs.remove(&pipeline_id);
+        if self.state == FetchState::FetchingJobs {
+            self.transition_to_jobs_or_hydrated();
+        }
+    }
+
+    pub fn failed(&mut self) {
+        self.pending_jobs.clear();
+        self.transition(FetchState::Stale);
+    }
+
+    /// Marks fetches without a response within [Self::TIMEOUT] as failed.
+    pub fn expire(&mut self, now: DateTime<Utc>) {
+        let fetching = matches!(self.state, FetchState::FetchingPipelines | FetchState::FetchingJobs);
+        let timed_out = self.since.is_some_and(|since| now - since > Self::TIMEOUT);
+
+        if fetching && timed_out {
+            self.failed();
+        }
+    }
+
+    fn transition_to_jobs_or_hydrated(&mut self) {
+        if self.pending_jobs.is_empty() {
+            self.hydrated_at = Some(Utc::now());
+            self.transition(FetchState::Hydrated);
+        } else {
+            self.transition(FetchState::FetchingJobs);
+        }
+    }
+
+    fn transition(&mut self, state: FetchState) {
+        if self.state != state {
+            self.state = state;
+            self.since = Some(Utc::now());
+        }
+    }
+}
+
+impl std::fmt::Display for FetchState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let state = match self {
+            FetchState::Idle              => "idle",
+            FetchState::FetchingPipelines => "fetching pipelines",
+            FetchState::FetchingJobs      => "fetching jobs",
+            FetchState::Hydrated          => "hydrated",
+            FetchState::Stale             => "stale",
+        };
+        write!(f, "{state}")
+    }
+}
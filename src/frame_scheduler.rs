@@ -0,0 +1,61 @@
+use std::time::{Duration, Instant};
+
+/// Decides when the UI is redrawn. Frames are drawn at up to the maximum
+/// frame rate while effects are running, and otherwise only when events
+/// changed the state; an idle UI is redrawn once per [Self::IDLE_INTERVAL],
+/// to keep clocks and pipeline durations current.
+pub struct FrameScheduler {
+    max_fps: Option<u16>,
+    last_draw: Option<Instant>,
+    popup_opened: Option<Instant>,
+}
+
+impl FrameScheduler {
+    /// Frame rate unless configured by [GlimConfig::max_fps](crate::glim_app::GlimConfig::max_fps).
+    pub const DEFAULT_MAX_FPS: u16 = 30;
+    const IDLE_INTERVAL: Duration = Duration::from_secs(1);
+    /// Duration of the transition played when a popup opens.
+    const POPUP_TRANSITION: Duration = Duration::from_millis(1000);
+
+    pub fn new(max_fps: Option<u16>) -> Self {
+        Self { max_fps, last_draw: None, popup_opened: None }
+    }
+
+    /// Applies a changed [GlimConfig::max_fps](crate::glim_app::GlimConfig::max_fps).
+    pub fn configure(&mut self, max_fps: Option<u16>) {
+        self.max_fps = max_fps;
+    }
+
+    /// Time between ticks; ticks only arrive at the maximum frame rate,
+    /// within 1 and 120 fps, while animating.
+    pub fn tick_rate(&self, animating: bool) -> Duration {
+        if !animating {
+            return Self::IDLE_INTERVAL;
+        }
+
+        let fps = self.max_fps.unwrap_or(Self::DEFAULT_MAX_FPS).clamp(1, 120);
+        Duration::from_millis(1000 / fps as u64)
+    }
+
+    /// Returns true while effects are running, or a popup is still playing
+    /// its opening transition.
+    pub fn is_animating(&mut self, effects_running: bool, popup_open: bool) -> bool {
+        match (popup_open, self.popup_opened) {
+            (true, None)     => self.popup_opened = Some(Instant::now()),
+            (false, Some(_)) => self.popup_opened = None,
+            _                => (),
+        }
+
+        effects_running || self.popup_opened
+            .is_some_and(|at| at.elapsed() < Self::POPUP_TRANSITION)
+    }
+
+    pub fn should_draw(&self, changed: bool, animating: bool) -> bool {
+        changed || animating || self.last_draw
+            .is_none_or(|at| at.elapsed() >= Self::IDLE_INTERVAL)
+    }
+
+    pub fn drawn(&mut self) {
+        self.last_draw = Some(Instant::now());
+    }
+}
@@ -0,0 +1,30 @@
+/// Matches the characters of `query` in order, ignoring case, returning the
+/// score and the byte offsets of the matched characters. Consecutive
+/// characters and characters at the start of words score higher.
+pub fn fuzzy_match(text: &str, query: &str) -> Option<(i32, Vec<usize>)> {
+    let lowercase = |c: char| c.to_lowercase().next().unwrap_or(c);
+
+    let mut matched = Vec::new();
+    let mut score = 0;
+    let mut chars = text.char_indices();
+    let mut prev: Option<(usize, char)> = None;
+
+    for q in query.chars().filter(|c| !c.is_whitespace()).map(lowercase) {
+        let (idx, c) = loop {
+            let (idx, c) = chars.next()?;
+            if lowercase(c) == q {
+                break (idx, c);
+            }
+            prev = Some((idx, c));
+        };
+
+        let word_start = prev.is_none_or(|(_, p)| !p.is_alphanumeric());
+        let consecutive = matched.last().is_some_and(|&last| prev.is_some_and(|(p, _)| p == last));
+        score += 1 + 2 * (word_start as i32) + 3 * (consecutive as i32);
+
+        matched.push(idx);
+        prev = Some((idx, c));
+    }
+
+    Some((score, matched))
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use serde::Deserialize;
+
+use crate::i18n::{t, Msg};
+
+/// Version of a GitLab instance, as reported by `GET /version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GitlabVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+/// Response of `GET /version`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VersionDto {
+    /// e.g. `16.5.1-ee`
+    pub version: String,
+}
+
+/// Features of the API not available in all supported GitLab versions.
+/// Requests for unsupported features are skipped, instead of failing with
+/// a 404 response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiFeature {
+    /// scopes and expiry of the token, `GET /personal_access_tokens/self`
+    TokenDetails,
+    /// trigger jobs of a pipeline, `GET /projects/:id/pipelines/:id/bridges`
+    TriggerJobs,
+}
+
+/// Features supported by a GitLab instance, from its version. All features
+/// are assumed to be supported until the version is known.
+///
+/// Clones share their state, so that the version can be set by the task
+/// probing it.
+#[derive(Debug, Clone, Default)]
+pub struct ApiFeatures {
+    version: Arc<RwLock<Option<GitlabVersion>>>,
+}
+
+impl GitlabVersion {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parses the major and minor version, e.g. of `16.5.1-ee`.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.split(['.', '-']);
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+
+        Some(Self::new(major, minor))
+    }
+
+    pub fn supports(&self, feature: ApiFeature) -> bool {
+        *self >= feature.min_version()
+    }
+
+    /// Features not supported by this version.
+    pub fn unsupported(&self) -> Vec<ApiFeature> {
+        ApiFeature::ALL.into_iter()
+            .filter(|feature| !self.supports(*feature))
+            .collect()
+    }
+}
+
+impl fmt::Display for GitlabVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl ApiFeature {
+    pub const ALL: [ApiFeature; 2] = [ApiFeature::TokenDetails, ApiFeature::TriggerJobs];
+
+    /// Oldest GitLab version supporting the feature.
+    pub fn min_version(&self) -> GitlabVersion {
+        match self {
+            ApiFeature::TokenDetails => GitlabVersion::new(15, 5),
+            ApiFeature::TriggerJobs  => GitlabVersion::new(12, 2),
+        }
+    }
+
+    /// What is missing without the feature, as shown in notices.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ApiFeature::TokenDetails => t(Msg::FeatureTokenDetails),
+            ApiFeature::TriggerJobs  => t(Msg::FeatureTriggerJobs),
+        }
+    }
+}
+
+impl ApiFeatures {
+    pub fn version(&self) -> Option<GitlabVersion> {
+        *self.version.read().expect("api features lock poisoned")
+    }
+
+    pub fn set_version(&self, version: GitlabVersion) {
+        *self.version.write().expect("api features lock poisoned") = Some(version);
+    }
+
+    pub fn supports(&self, feature: ApiFeature) -> bool {
+        self.version().is_none_or(|version| version.supports(feature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_major_and_minor_version() {
+        assert_eq!(GitlabVersion::parse("16.5.1-ee"), Some(GitlabVersion::new(16, 5)));
+        assert_eq!(GitlabVersion::parse("15.11.0-pre"), Some(GitlabVersion::new(15, 11)));
+        assert_eq!(GitlabVersion::parse("17.0-ee"), Some(GitlabVersion::new(17, 0)));
+        assert_eq!(GitlabVersion::parse("latest"), None);
+    }
+
+    #[test]
+    fn features_are_supported_until_the_version_is_known() {
+        let features = ApiFeatures::default();
+        assert!(features.supports(ApiFeature::TokenDetails));
+
+        features.set_version(GitlabVersion::new(15, 4));
+        assert!(!features.supports(ApiFeature::TokenDetails));
+        assert!(features.supports(ApiFeature::TriggerJobs));
+
+        features.set_version(GitlabVersion::new(15, 10));
+        assert!(features.supports(ApiFeature::TokenDetails));
+    }
+}
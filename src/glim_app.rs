@@ -1,35 +1,117 @@
-use std::path::PathBuf;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::mpsc::Sender;
 
 use chrono::{DateTime, Local};
+use directories::UserDirs;
+use itertools::Itertools;
+use ratatui::style::Style;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use tachyonfx::Duration;
 
-use crate::client::GitlabClient;
+use crate::audit_log::{AuditEntry, AuditLog};
+use crate::capabilities::{set_accessible, set_glyphs, set_icon_support, ColorSupport, IconSupport};
+use crate::client::{GitlabClient, RequestConfig};
+use crate::gitlab_version::GitlabVersion;
+use crate::clipboard::ClipboardService;
 use crate::dispatcher::Dispatcher;
-use crate::domain::Project;
+use crate::domain::{Job, Pipeline, PipelineStatus, Project, ProjectDto, ProjectRowState};
 use crate::event::GlimEvent;
-use crate::id::ProjectId;
+use crate::fetch_state::{FetchState, ProjectFetch};
+use crate::http::{HttpConfig, HttpHeader};
+use crate::page_size::PageSizeBounds;
+use crate::id::{JobId, PipelineId, ProjectId};
 use crate::input::processor::NormalModeProcessor;
+use crate::issue::{FailureReport, FailureRule, IssueConfig};
 use crate::input::InputMultiplexer;
-use crate::notice_service::{Notice, NoticeLevel, NoticeService};
+use crate::maintenance::{in_maintenance, MaintenanceWindow};
+use crate::memory_usage::MemoryUsage;
+use crate::metrics::MetricsCollector;
+use crate::notice_service::{Notice, NoticeLevel, NoticeMessage, NoticeService, ProjectError};
+use crate::notifier::{spawn_detached, AlertSound, NotificationConfig, Notifiers};
+use crate::pipeline_history::PipelineRun;
+use crate::pipeline_tree::{pipeline_trees, PipelineTreeRow};
+use crate::project_cache::ProjectCache;
+use crate::project_labels::{ProjectLabel, ProjectLabels};
+use crate::pipeline_wait::PipelineWait;
+use crate::read_only::{self, read_only, set_read_only};
 use crate::result::GlimError;
-use crate::save_config;
-use crate::stores::{InternalLogsStore, ProjectStore};
-use crate::ui::widget::NotificationState;
-use crate::ui::StatefulWidgets;
+use crate::search::{SearchIndex, SearchResult};
+use crate::{load_config, paths, save_config};
+use crate::stores::{InternalLogsStore, LogLevel, ProjectStore, QuickFilter, StatusStore};
+use crate::ui::widget::{NotificationState, Tab};
+use crate::ui::{relative_times, set_branch_lines, set_relative_times, StatefulWidgets};
+use crate::ui::fx::{configure_animations, AnimationConfig};
+use crate::local_clone::{shell_quote, LocalClones};
+use crate::hooks::{HookConfig, Hooks};
+use crate::token_store::{self, TokenStorage};
+use crate::theme::configure_theme;
+use crate::watch::{Watch, WatchList};
+use crate::i18n::{set_locale, t, Locale, Msg};
 
 pub struct GlimApp {
     running: bool,
     config_path: PathBuf,
     gitlab: GitlabClient,
+    /// clients of additional GitLab instances, see [GlimConfig::instances]
+    instances: Vec<GitlabClient>,
+    instance_names: Vec<String>,
+    /// see [GlimConfig::project_labels]
+    project_labels: ProjectLabels,
     last_tick: std::time::Instant,
+    /// wall clock time of the last tick; unlike [Self::last_tick], it advances
+    /// while the system is asleep
+    last_wall_tick: DateTime<Local>,
+    /// set when the system woke from sleep; errors are only logged during
+    /// [Self::WAKE_GRACE_PERIOD]
+    resumed_at: Option<std::time::Instant>,
     pub sender: Sender<GlimEvent>,
     project_store: ProjectStore,
+    /// projects of the previous session, see [ProjectCache]
+    project_cache: ProjectCache,
+    watch_list: WatchList,
     notices: NoticeService,
+    notifiers: Notifiers,
+    hooks: Hooks,
     logs_store: InternalLogsStore,
+    status_store: StatusStore,
+    /// see [GlimConfig::metrics_addr]
+    metrics: MetricsCollector,
+    audit_log: AuditLog,
+    search_index: SearchIndex,
     input: InputMultiplexer,
-    clipboard: arboard::Clipboard,
+    clipboard: ClipboardService,
+    followed_pipeline: Option<(ProjectId, PipelineId)>,
+    /// exits glim once the pipeline completes
+    pipeline_wait: Option<PipelineWait>,
+    issue_config: IssueConfig,
+    /// see [GlimConfig::artifacts_dir]
+    artifacts_dir: PathBuf,
+    local_clones: LocalClones,
+    /// see [GlimConfig::screenshot_dir]
+    screenshot_dir: PathBuf,
+    failure_rule: FailureRule,
+    maintenance_windows: Vec<MaintenanceWindow>,
+    /// whether polling was paused by a maintenance window at the last poll
+    polling_paused: bool,
+    sound_on_failure: Option<AlertSound>,
+    /// see [GlimConfig::open_details_on_failure]
+    open_details_on_failure: bool,
+    /// see [GlimConfig::browser_command]
+    browser_command: Option<String>,
+    /// set per GitLab instance when it responds with 502/503; cleared by its
+    /// next successful response
+    unavailable_since: HashMap<u8, DateTime<Local>>,
+    /// polls skipped per instance since it became unavailable
+    skipped_polls: HashMap<u8, u32>,
+    /// next request while GitLab is unreachable; cleared once it responds again
+    offline_retry_at: Option<std::time::Instant>,
+    /// delay before [Self::offline_retry_at], doubled with each retry
+    offline_retry_delay: std::time::Duration,
     pub ui: UiState,
 }
 
@@ -37,15 +119,185 @@ pub struct GlimApp {
 pub struct GlimConfig {
     /// The URL of the GitLab instance
     pub gitlab_url: String,
-    /// The Personal Access Token to authenticate with GitLab
+    /// The Personal Access Token to authenticate with GitLab, or a reference
+    /// to the token in the keyring, see [Self::token_storage]
     pub gitlab_token: String,
+    /// Where the tokens are kept; plaintext tokens are migrated to the keyring
+    /// when it is selected
+    #[serde(default)]
+    pub token_storage: TokenStorage,
+    /// Command printing the token, e.g. `pass show gitlab/token`, run instead
+    /// of reading [Self::gitlab_token]; `GLIM_GITLAB_TOKEN` overrides both
+    #[serde(default)]
+    pub gitlab_token_cmd: Option<String>,
     /// Filter applied to the projects list
-    pub search_filter: Option<String>
+    pub search_filter: Option<String>,
+    /// Maximum number of retries for transient API failures
+    pub max_retries: Option<u32>,
+    /// Notifications delivered outside of glim
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    /// Language of the user interface
+    #[serde(default)]
+    pub locale: Locale,
+    /// Audible alert on failed pipelines of watched projects
+    #[serde(default)]
+    pub sound_on_failure: Option<AlertSound>,
+    /// Opens the project details on pipelines of watched projects as they
+    /// fail, unless another popup is open
+    #[serde(default)]
+    pub open_details_on_failure: bool,
+    /// Minimum contrast ratio between text and background, e.g. 4.5 for
+    /// WCAG AA; theme colors below it are lightened or darkened
+    #[serde(default)]
+    pub min_contrast: Option<f64>,
+    /// `dark`, `light` or the path to a TOML theme file; dark if unset
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Color depth; detected from the terminal if unset
+    #[serde(default)]
+    pub colors: ColorSupport,
+    /// Emoji or ASCII status icons; detected from the terminal if unset
+    #[serde(default)]
+    pub icons: IconSupport,
+    /// Animations enabled per category
+    #[serde(default)]
+    pub animations: AnimationConfig,
+    /// Accessibility mode, also enabled by `--accessible`: no animations,
+    /// statuses in words next to their icons, and the selection in plain text
+    #[serde(default)]
+    pub accessible: bool,
+    /// Read-only mode, also enabled by `--read-only`, for tokens with the
+    /// `read_api` scope: retrying jobs, running pipelines and creating issues
+    /// are hidden and refused
+    #[serde(default)]
+    pub read_only: bool,
+    /// Glyphs replacing the icons of pipeline statuses, e.g. nerd font glyphs
+    #[serde(default)]
+    pub glyphs: HashMap<PipelineStatus, String>,
+    /// Timestamps of the projects and pipelines tables as ages, e.g. "3m ago"
+    #[serde(default)]
+    pub relative_times: bool,
+    /// Colored labels of projects, by topic or namespace prefix
+    #[serde(default)]
+    pub project_labels: Vec<ProjectLabel>,
+    /// Projects always sorted to the top of the projects table
+    #[serde(default)]
+    pub pinned_projects: Vec<ProjectId>,
+    /// Projects and branches with notifications for completed pipelines
+    #[serde(default)]
+    pub watches: Vec<Watch>,
+    /// Number of most recently active projects with pipelines fetched at
+    /// startup; the pipelines of other projects are fetched when scrolled into view
+    #[serde(default)]
+    pub initial_fetch_limit: Option<usize>,
+    /// Projects hidden from the projects table
+    #[serde(default)]
+    pub ignored_projects: Vec<ProjectId>,
+    /// Issues created for failed pipelines
+    #[serde(default)]
+    pub issues: IssueConfig,
+    /// Additional GitLab instances polled alongside the primary instance
+    #[serde(default)]
+    pub instances: Vec<InstanceConfig>,
+    /// Periods during which polling pauses and API failures are only logged
+    #[serde(default)]
+    pub maintenance_windows: Vec<MaintenanceWindow>,
+    /// Directory for downloaded job artifacts; the user's download directory if unset
+    #[serde(default)]
+    pub artifacts_dir: Option<PathBuf>,
+    /// Directory for screenshots and session recordings; the artifacts directory if unset
+    #[serde(default)]
+    pub screenshot_dir: Option<PathBuf>,
+    /// Directory of local clones, each at the path of its project, e.g. `~/src/group/project`
+    #[serde(default)]
+    pub clone_dir: Option<PathBuf>,
+    /// Command opening the local clone of a project, with `{path}` and `{url}`
+    /// replaced by the clone path and the ssh clone URL, e.g. `code {path}`
+    #[serde(default)]
+    pub open_command: Option<String>,
+    /// Command opening URLs instead of the default browser, with `{url}`
+    /// replaced by the URL, e.g. `firefox -P work {url}`
+    #[serde(default)]
+    pub browser_command: Option<String>,
+    /// Level of the internal logs: `error`, `info` or `debug`
+    #[serde(default)]
+    pub log_level: LogLevel,
+    /// Shell commands run on pipeline and job events
+    #[serde(default)]
+    pub hooks: Vec<HookConfig>,
+    /// Bounds of the page size of pipeline requests, which is tuned to the
+    /// response times of GitLab
+    #[serde(default)]
+    pub page_size: Option<PageSizeBounds>,
+    /// User-Agent of all requests to GitLab
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// Extra headers sent with all requests to GitLab, e.g. for proxies
+    #[serde(default)]
+    pub http_headers: Vec<HttpHeader>,
+    /// PEM bundle of CA certificates, e.g. of an internal CA, trusted in
+    /// addition to the system roots
+    #[serde(default)]
+    pub ca_cert: Option<PathBuf>,
+    /// Accepts invalid TLS certificates; only as a last resort for self-signed certificates
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+    /// HTTP or SOCKS proxy of all requests, e.g. `socks5://proxy:1080`;
+    /// the proxy environment variables apply if unset
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Hosts and domains reached without [Self::proxy]
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+    /// Width of the projects table in percent, while the internal logs are shown
+    #[serde(default)]
+    pub split_ratio: Option<u16>,
+    /// Upper bound of the frame rate; frames are only drawn when something changed
+    #[serde(default)]
+    pub max_fps: Option<u16>,
+    /// Address of the Prometheus metrics endpoint, e.g. `127.0.0.1:9184`;
+    /// no metrics are served if unset
+    #[serde(default)]
+    pub metrics_addr: Option<SocketAddr>,
+    /// Days before the token expires at which a warning is shown; 14 if unset
+    #[serde(default)]
+    pub token_warning_days: Option<u32>,
+    /// Seconds between two polls of the projects, and of the active jobs;
+    /// 60 if unset
+    #[serde(default)]
+    pub poll_interval: Option<u64>,
+    /// Number of branches with their latest pipeline shown per project row; 3 if unset
+    #[serde(default)]
+    pub branch_lines: Option<usize>,
+    /// Shows the latest pipeline of the default branch in each project row,
+    /// even if other branches were more recently active
+    #[serde(default)]
+    pub show_default_branch: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InstanceConfig {
+    /// Name shown in the instance column of the projects table
+    pub name: String,
+    pub gitlab_url: String,
+    #[serde(default)]
+    pub gitlab_token: String,
+    /// Command printing the token, run instead of reading [Self::gitlab_token]
+    #[serde(default)]
+    pub gitlab_token_cmd: Option<String>,
+    pub search_filter: Option<String>,
 }
 
 pub struct UiState {
     pub show_internal_logs: bool,
     pub use_256_colors: bool,
+    /// view shown in the main area
+    pub tab: Tab,
+    /// width of the projects table in percent, see [GlimConfig::split_ratio]
+    pub split_ratio: u16,
+    /// whether the rendered frames are recorded
+    pub recording: bool,
 }
 
 
@@ -54,129 +306,564 @@ impl GlimConfig {
         if self.gitlab_url.trim().is_empty() {
             return Err("gitlab_url is required".to_string());
         }
-        if self.gitlab_token.trim().is_empty() {
+        if self.gitlab_token.trim().is_empty() && !token_store::has_external_token(self) {
             return Err("gitlab_token is required".to_string());
         }
+        ProjectLabels::validate(&self.project_labels)?;
+        HttpConfig::from(self).build_client().map_err(|e| e.to_string())?;
         Ok(())
     }
+
+    pub fn token_warning_days(&self) -> i64 {
+        self.token_warning_days.unwrap_or(14) as i64
+    }
+
+    pub const DEFAULT_POLL_INTERVAL: u64 = 60;
+    pub const MIN_POLL_INTERVAL: u64 = 10;
+
+    /// Interval between two polls, see [Self::poll_interval].
+    pub fn poll_interval(&self) -> std::time::Duration {
+        let secs = self.poll_interval.unwrap_or(Self::DEFAULT_POLL_INTERVAL);
+        std::time::Duration::from_secs(secs.max(Self::MIN_POLL_INTERVAL))
+    }
+
+    pub const DEFAULT_BRANCH_LINES: usize = 3;
+
+    /// Branches shown per project row, see [Self::branch_lines].
+    pub fn branch_lines(&self) -> usize {
+        self.branch_lines.unwrap_or(Self::DEFAULT_BRANCH_LINES).max(1)
+    }
+
+    /// Directory for downloaded job artifacts, falling back to the download
+    /// directory of the user, or the data directory.
+    pub fn artifacts_dir(&self) -> PathBuf {
+        self.artifacts_dir.clone()
+            .or_else(|| UserDirs::new().and_then(|dirs| dirs.download_dir().map(PathBuf::from)))
+            .unwrap_or_else(paths::data_dir)
+    }
+
+    pub fn screenshot_dir(&self) -> PathBuf {
+        self.screenshot_dir.clone()
+            .unwrap_or_else(|| self.artifacts_dir())
+    }
+
+    /// URLs of the primary and additional GitLab instances.
+    pub fn instance_urls(&self) -> Vec<String> {
+        std::iter::once(self.gitlab_url.clone())
+            .chain(self.instances.iter().map(|i| i.gitlab_url.clone()))
+            .collect()
+    }
+
+    /// Returns the names of all GitLab instances, starting with the primary
+    /// instance, which is named after its host.
+    pub fn instance_names(&self) -> Vec<String> {
+        let host = self.gitlab_url
+            .split("://")
+            .last()
+            .and_then(|url| url.split('/').next())
+            .unwrap_or_default()
+            .to_string();
+
+        std::iter::once(host)
+            .chain(self.instances.iter().map(|i| i.name.clone()))
+            .collect()
+    }
 }
 
 
 impl GlimApp {
+    /// Slows polling down by this factor while GitLab is unavailable.
+    const UNAVAILABLE_POLL_FACTOR: u32 = 4;
+    /// Delay of the first request after GitLab became unreachable.
+    const OFFLINE_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+    /// Upper bound of the delay between two requests while offline.
+    const OFFLINE_MAX_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+    /// Gaps between two ticks longer than this are treated as system sleep.
+    const SLEEP_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(30);
+    /// Requests failing this soon after waking up are expected, as the
+    /// network is often not yet reconnected.
+    const WAKE_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(20);
+
     pub fn new(
         sender: Sender<GlimEvent>,
         config_path: PathBuf,
+        notifiers: Notifiers,
+        config: &GlimConfig,
         gitlab: GitlabClient
     ) -> Self {
         let mut input = InputMultiplexer::new(sender.clone());
         input.push(Box::new(NormalModeProcessor::new(sender.clone())));
 
-        Self {
+        let metrics = MetricsCollector::default();
+        if let Some(addr) = config.metrics_addr {
+            metrics.serve(addr, sender.clone());
+        }
+
+        let instances = Self::instance_clients(&sender, config, gitlab.debug());
+
+        let mut project_store = ProjectStore::new(sender.clone(), config);
+        let project_cache = ProjectCache::new(paths::project_cache(), config.instance_urls());
+        if let Some(cached) = project_cache.load() {
+            project_store.restore(cached);
+        }
+
+        let hooks = Hooks::new(config, sender.clone());
+
+        let app = Self {
             running: true,
             config_path,
             gitlab,
+            instances,
+            instance_names: config.instance_names(),
+            project_labels: ProjectLabels::new(&config.project_labels),
             last_tick: std::time::Instant::now(),
+            last_wall_tick: Local::now(),
+            resumed_at: None,
             sender: sender.clone(),
-            project_store: ProjectStore::new(sender),
-            logs_store: InternalLogsStore::new(),
+            project_store,
+            project_cache,
+            watch_list: WatchList::new(sender, config),
+            logs_store: InternalLogsStore::new(config.log_level),
+            status_store: StatusStore::new(config),
+            metrics,
+            audit_log: AuditLog::new(paths::audit_log()),
+            search_index: SearchIndex::new(),
             notices: NoticeService::new(),
+            notifiers,
+            hooks,
             input,
-            clipboard: arboard::Clipboard::new().expect("failed to create clipboard"),
-            ui: UiState::new(),
-        }
+            clipboard: ClipboardService::new(),
+            followed_pipeline: None,
+            pipeline_wait: None,
+            issue_config: config.issues.clone(),
+            artifacts_dir: config.artifacts_dir(),
+            local_clones: LocalClones::new(config),
+            screenshot_dir: config.screenshot_dir(),
+            failure_rule: FailureRule::new(&config.issues),
+            maintenance_windows: config.maintenance_windows.clone(),
+            polling_paused: false,
+            sound_on_failure: config.sound_on_failure.clone(),
+            open_details_on_failure: config.open_details_on_failure,
+            browser_command: Self::browser_command(config),
+            unavailable_since: HashMap::new(),
+            skipped_polls: HashMap::new(),
+            offline_retry_at: None,
+            offline_retry_delay: Self::OFFLINE_RETRY_DELAY,
+            ui: UiState::new(config.split_ratio, config.colors.use_256_colors()),
+        };
+        app.configure_theme(config);
+        app
     }
 
     pub fn apply(&mut self, event: GlimEvent, ui: &mut StatefulWidgets) {
+        // counted before expected failures are turned into logs below
+        self.metrics.apply(&event);
+
+        // failures during maintenance are expected, and only logged
+        let event = match event {
+            GlimEvent::Error(e) if self.is_resuming() =>
+                GlimEvent::Log(format!("after wake from sleep: {e}")),
+            GlimEvent::Error(e) if self.is_in_maintenance() =>
+                GlimEvent::Log(format!("during maintenance: {e}")),
+            // shown as a banner instead of repeated error notices
+            GlimEvent::Error(e @ GlimError::GitlabUnavailable(_, _, instance)) => {
+                self.unavailable_since.entry(instance).or_insert_with(Local::now);
+                GlimEvent::Log(e.to_string())
+            },
+            // shown as a banner instead of one notice per failed request
+            GlimEvent::Error(e) if self.is_offline() && e.is_network() =>
+                GlimEvent::Log(format!("while offline: {e}")),
+            event => event,
+        };
+
+        // refused before reaching any handler, e.g. the audit log or the hooks
+        let event = match read_only::mutation(&event) {
+            Some(action) if read_only() => GlimEvent::Error(GlimError::GeneralError(
+                t(Msg::NoticeReadOnly).replace("{action}", t(action))
+            )),
+            _ => event,
+        };
+
+        if let Some(instance) = Self::responding_instance(&event).filter(|i| self.is_unavailable(*i)) {
+            self.unavailable_since.remove(&instance);
+            self.skipped_polls.remove(&instance);
+            let host = self.instance_names.get(instance as usize).cloned().unwrap_or_default();
+            self.dispatch(GlimEvent::Log(format!("GitLab at {host} available again, polling resumed")));
+        }
+
         self.input.apply(&event, ui);
         self.ui.apply(&event);
         self.logs_store.apply(&event);
+        self.status_store.apply(&event);
         self.notices.apply(&event);
         self.project_store.apply(&event);
+        if self.pipeline_wait.is_some() && Self::responding_instance(&event).is_some() {
+            self.update_pipeline_wait();
+        }
+        self.watch_list.apply(&event, &self.project_store);
+        self.search_index.apply(&event);
+        if let Err(e) = self.audit_log.apply(&event) {
+            self.dispatch(GlimEvent::Error(e));
+        }
+        if let Err(e) = self.notifiers.apply(&event, &self.project_store) {
+            // not dispatched as an error, as it would trigger another notification
+            self.dispatch(GlimEvent::Log(e.to_string()));
+        }
+        self.hooks.apply(&event);
 
         match event {
             GlimEvent::Shutdown                 => self.running = false,
             
             // www
-            GlimEvent::BrowseToProject(id) => open::that(&self.project(id).url)
-                .expect("unable to open browser"),
+            GlimEvent::BrowseToProject(id) => {
+                let result = self.project(id)
+                    .and_then(|project| self.browse(&project.url));
+                self.dispatch_error(result);
+            },
             GlimEvent::BrowseToPipeline(project_id, pipeline_id) => {
-                let project = self.project(project_id);
-                let pipeline = project.pipeline(pipeline_id)
-                    .expect("pipeline not found");
-
-                open::that(&pipeline.url)
-                    .expect("unable to open browser");
+                let result = self.pipeline(project_id, pipeline_id)
+                    .and_then(|(_, pipeline)| self.browse(&pipeline.url));
+                self.dispatch_error(result);
+            },
+            GlimEvent::BrowseToCommit(project_id, pipeline_id) => {
+                let result = self.pipeline(project_id, pipeline_id)
+                    .and_then(|(project, pipeline)| self.browse(&format!("{}/-/commit/{}", project.url, pipeline.sha)));
+                self.dispatch_error(result);
+            },
+            GlimEvent::BrowseToBranch(project_id, branch) => {
+                let result = self.project(project_id)
+                    .and_then(|project| self.browse(&format!("{}/-/tree/{branch}", project.url)));
+                self.dispatch_error(result);
             },
             GlimEvent::BrowseToJob(project_id, pipeline_id, job_id) => {
-                let project = self.project(project_id);
-                let job_url = project.pipeline(pipeline_id)
-                    .and_then(|p| p.job(job_id))
-                    .map(|job| &job.url)
-                    .expect("job not found");
-
-                open::that(job_url)
-                    .expect("unable to open browser");
+                let result = self.job(project_id, pipeline_id, job_id)
+                    .and_then(|job| self.browse(&job.url));
+                self.dispatch_error(result);
             },
 
             GlimEvent::DownloadErrorLog(project_id, pipeline_id) => {
-                let project = self.project(project_id);
-                let pipeline = project.pipeline(pipeline_id)
-                    .expect("pipeline not found");
-
-                let job = pipeline.failed_job()
-                    .expect("no failed job found");
+                let failed_job = self.pipeline(project_id, pipeline_id)
+                    .and_then(|(_, pipeline)| pipeline.failed_job()
+                        .map(|job| job.id)
+                        .ok_or_else(|| GlimError::GeneralError(format!("no failed job in pipeline_id={pipeline_id}"))));
 
-                self.gitlab.dispatch_download_job_log(project_id, job.id);
+                match failed_job {
+                    Ok(job_id) => self.request(project_id, |gitlab| gitlab.dispatch_download_job_log(project_id, job_id)),
+                    Err(e)     => self.dispatch(GlimEvent::Error(e)),
+                }
+            },
+            GlimEvent::OpenPipelineGraph(project_id, pipeline_id) => match self.project(project_id) {
+                Ok(project) => {
+                    if project.pipeline(pipeline_id).is_some_and(|p| p.jobs.is_none()) {
+                        self.request(project_id, |gitlab| gitlab.dispatch_get_jobs(project_id, pipeline_id));
+                    }
+                    self.request(project_id, |gitlab| gitlab.dispatch_get_job_needs(project_id, &project.path, pipeline_id));
+                },
+                Err(e) => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::OpenBranches(project_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_list_branches(project_id)),
+            GlimEvent::TriggerPipeline(project_id, branch) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_trigger_pipeline(project_id, branch)),
+            GlimEvent::PipelineTriggered(project_id, _) =>
+                self.dispatch(GlimEvent::RequestPipelines(project_id)),
+            GlimEvent::OpenSchedules(project_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_list_schedules(project_id)),
+            GlimEvent::PlaySchedule(project_id, schedule_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_play_schedule(project_id, schedule_id)),
+            GlimEvent::SchedulePlayed(project_id, _) =>
+                self.dispatch(GlimEvent::RequestPipelines(project_id)),
+            GlimEvent::DownloadArtifacts(project_id, job_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_download_artifacts(project_id, job_id, self.artifacts_dir.clone())),
+            GlimEvent::OpenRunners(project_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_list_runners(project_id)),
+            GlimEvent::RequestGitlabVersion => {
+                self.gitlab.dispatch_get_version();
+                self.instances.iter().for_each(GitlabClient::dispatch_get_version);
             },
+            GlimEvent::ReceivedGitlabVersion(instance, version) => {
+                let host = self.instance_names.get(instance as usize).cloned().unwrap_or_default();
+                for feature in version.iter().flat_map(GitlabVersion::unsupported) {
+                    let message = t(Msg::NoticeUnsupportedFeature)
+                        .replace("{host}", &host)
+                        .replace("{version}", &version.map(|v| v.to_string()).unwrap_or_default())
+                        .replace("{feature}", feature.description())
+                        .replace("{required}", &feature.min_version().to_string());
+                    self.notices.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(message));
+                }
+                if instance == 0 {
+                    self.dispatch(GlimEvent::RequestTokenExpiry);
+                }
+            },
+            GlimEvent::RequestTokenExpiry => self.gitlab.dispatch_get_token_expiry(),
+            GlimEvent::ReceivedTokenExpiry(Some(date)) if self.status_store.is_token_expiring() => {
+                let days = self.status_store.token_days_left().unwrap_or_default();
+                let message = t(Msg::NoticeTokenExpiring).replace("{days}", &days.to_string());
+                self.notices.push_notice(NoticeLevel::Warning, NoticeMessage::GeneralMessage(format!("{message}{date}")));
+            },
+            GlimEvent::ReceivedTokenScopes(_) => {
+                for warning in self.status_store.token_scope_warnings() {
+                    self.notices.push_notice(NoticeLevel::Warning, NoticeMessage::GeneralMessage(warning.message()));
+                }
+            },
+            GlimEvent::RetryJob(project_id, pipeline_id, job_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_retry_job(project_id, pipeline_id, job_id)),
+            GlimEvent::JobRetried(project_id, pipeline_id, _) => {
+                self.request(project_id, |gitlab| gitlab.dispatch_get_jobs(project_id, pipeline_id));
+                self.dispatch(GlimEvent::RequestPipelines(project_id));
+            },
+            GlimEvent::OpenJobLog(project_id, _, job_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_get_job_trace(project_id, job_id)),
+            // also shown by the job log popup
+            GlimEvent::JobTraceUnavailable(_, _, e) =>
+                self.dispatch(GlimEvent::Error(e)),
+            GlimEvent::CreateIssue(project_id, pipeline_id) => match self.pipeline(project_id, pipeline_id) {
+                Ok((project, pipeline)) => {
+                    let report = FailureReport::new(project, pipeline);
+                    let failed_job = pipeline.failed_job().map(|j| j.id);
+                    self.request(project_id, |gitlab| gitlab.dispatch_create_issue(project_id, failed_job, report, self.issue_config.clone()));
+                },
+                Err(e) => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::ProjectUpdated(ref project) if !self.is_in_maintenance() && !read_only() => {
+                if let Some(pipeline_id) = self.failure_rule.check(project) {
+                    self.dispatch(GlimEvent::CreateIssue(project.id, pipeline_id));
+                }
+            },
+            GlimEvent::PipelineStatusChanged(project_id, _, _, PipelineStatus::Failed) =>
+                self.play_failure_sound(project_id),
             GlimEvent::JobLogDownloaded(_, _, trace) => {
-                self.clipboard.set_text(trace).unwrap();
+                let result = self.clipboard.copy(trace);
+                self.dispatch_error(result);
+            },
+            GlimEvent::CopyToClipboard(text) => {
+                let result = self.clipboard.copy(text);
+                self.dispatch_error(result);
+            },
+            GlimEvent::CopyPipelineUrl(project_id, pipeline_id) => match self.pipeline(project_id, pipeline_id) {
+                Ok((_, pipeline)) => self.dispatch(GlimEvent::CopyToClipboard(pipeline.url.clone())),
+                Err(e)            => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::CopyJobUrl(project_id, pipeline_id, job_id) => match self.job(project_id, pipeline_id, job_id) {
+                Ok(job) => self.dispatch(GlimEvent::CopyToClipboard(job.url.clone())),
+                Err(e)  => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::CopyBranchName(project_id, pipeline_id) => match self.pipeline(project_id, pipeline_id) {
+                Ok((_, pipeline)) => self.dispatch(GlimEvent::CopyToClipboard(pipeline.branch.clone())),
+                Err(e)            => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::CopyCloneUrl(id) => match self.project(id) {
+                Ok(project) => self.dispatch(GlimEvent::CopyToClipboard(project.ssh_git_url.clone())),
+                Err(e)      => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::CopyCloneCommand(id) => match self.project(id) {
+                Ok(project) => self.dispatch(GlimEvent::CopyToClipboard(self.local_clones.clone_command(project))),
+                Err(e)      => self.dispatch(GlimEvent::Error(e)),
+            },
+            GlimEvent::OpenLocalClone(id) => match self.project(id).and_then(|project| self.local_clones.open(project)) {
+                Ok(command) => self.dispatch(GlimEvent::Log(format!("running {command}"))),
+                Err(e)      => self.dispatch(GlimEvent::Error(e)),
             },
 
+            GlimEvent::PollProjects => self.poll_projects(),
+            GlimEvent::ConnectionLost => {
+                self.offline_retry_delay = Self::OFFLINE_RETRY_DELAY;
+                self.offline_retry_at = Some(std::time::Instant::now() + self.offline_retry_delay);
+            },
+            GlimEvent::ConnectionRestored if self.is_offline() => {
+                self.offline_retry_at = None;
+                self.dispatch(GlimEvent::Log("back online, polling resumed".to_string()));
+                self.dispatch(GlimEvent::RequestActiveJobs);
+            },
+            GlimEvent::RequestActiveJobs if self.is_in_maintenance() || self.is_offline() => (),
             GlimEvent::RequestActiveJobs => {
                 self.projects().iter()
+                    .filter(|p| !self.is_unavailable(p.id.instance()))
                     .flat_map(|p| p.pipelines.iter())
                     .flatten()
-                    .filter(|p| p.status.is_active() || p.has_active_jobs())
-                    .for_each(|p| self.gitlab.dispatch_get_jobs(p.project_id, p.id));
+                    .filter(|p| !p.gone && (p.status.is_active() || p.has_active_jobs()))
+                    .for_each(|p| self.request(p.project_id, |gitlab| gitlab.dispatch_get_jobs(p.project_id, p.id)));
+
+                // merge requests still awaiting approval
+                self.projects().iter()
+                    .filter(|p| !self.is_unavailable(p.id.instance()))
+                    .flat_map(|p| p.recent_merge_requests().into_iter().map(move |iid| (p, iid)))
+                    .filter(|(p, iid)| p.approvals.get(iid).is_some_and(|a| !a.is_approved()))
+                    .for_each(|(p, iid)| self.request(p.id, |gitlab| gitlab.dispatch_get_approvals(p.id, iid)));
             }
+            // retried with the next poll once the instance is available again
+            GlimEvent::RequestPipelines(id) if self.is_unavailable(id.instance()) => (),
             GlimEvent::RequestPipelines(id)     =>
-                self.gitlab.dispatch_get_pipelines(id, None),
+                self.request(id, |gitlab| gitlab.dispatch_get_pipelines(id, None)),
+            GlimEvent::RequestOlderPipelines(id) => match self.project(id) {
+                Ok(project) => match project.oldest_pipeline_update() {
+                    Some(before) => self.request(id, |gitlab| gitlab.dispatch_get_older_pipelines(id, before)),
+                    None         => self.dispatch(GlimEvent::RequestPipelines(id)),
+                },
+                Err(e) => self.dispatch(GlimEvent::Error(e)),
+            },
             GlimEvent::RequestProjects          => {
-                let latest_activity = self.projects().iter()
-                    .max_by_key(|p| p.last_activity_at)
-                    .map(|p| p.last_activity_at);
+                let skipped: Vec<u8> = (0..=self.instances.len() as u8)
+                    .filter(|instance| self.skip_poll(*instance))
+                    .collect();
 
-                let updated_after = self.projects().iter()
-                    .filter(|p| p.has_active_pipelines())
-                    .min_by_key(|p| p.last_activity_at)
-                    .map(|p| p.last_activity_at)
-                    .map_or_else(|| latest_activity, Some);
+                let clients = std::iter::once(&self.gitlab).chain(self.instances.iter());
+                for (instance, client) in clients.enumerate() {
+                    if skipped.contains(&(instance as u8)) {
+                        continue;
+                    }
 
-                self.gitlab.dispatch_list_projects(updated_after)
+                    let projects = || self.projects().iter()
+                        .filter(move |p| p.id.instance() as usize == instance);
+
+                    let latest_activity = projects()
+                        .max_by_key(|p| p.last_activity_at)
+                        .map(|p| p.last_activity_at);
+
+                    let updated_after = projects()
+                        .filter(|p| p.has_active_pipelines())
+                        .min_by_key(|p| p.last_activity_at)
+                        .map(|p| p.last_activity_at)
+                        .map_or_else(|| latest_activity, Some);
+
+                    client.dispatch_list_projects(updated_after)
+                }
             },
+            GlimEvent::RequestApprovals(project_id, iid) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_get_approvals(project_id, iid)),
             GlimEvent::RequestJobs(project_id, pipeline_id) =>
-                self.gitlab.dispatch_get_jobs(project_id, pipeline_id),
+                self.request(project_id, |gitlab| gitlab.dispatch_get_jobs(project_id, pipeline_id)),
+            GlimEvent::RequestCommitDetails(project_id, pipeline_id) => {
+                let sha = self.pipeline(project_id, pipeline_id)
+                    .map(|(_, p)| p.sha.clone());
+
+                // the pipeline may be gone by the time its commit is requested
+                if let Ok(sha) = sha {
+                    self.request(project_id, |gitlab| gitlab.dispatch_get_commit(project_id, pipeline_id, &sha));
+                }
+            },
+
+            // soft real-time polling of a single pipeline
+            GlimEvent::FollowPipeline(project_id, pipeline_id) => {
+                self.followed_pipeline = Some((project_id, pipeline_id));
+                self.gitlab.follow_pipeline(project_id, pipeline_id);
+            },
+            GlimEvent::UnfollowPipeline => {
+                self.followed_pipeline = None;
+                self.gitlab.unfollow_pipeline();
+            },
+            GlimEvent::WaitForPipeline(project_id, pipeline_id) =>
+                self.wait_for_pipeline(PipelineWait::new(project_id, pipeline_id, None, None)),
+            GlimEvent::RequestPipeline(project_id, pipeline_id) =>
+                self.request(project_id, |gitlab| gitlab.dispatch_get_pipeline(project_id, pipeline_id)),
+            GlimEvent::ReceivedPipeline(project_id, pipeline) => {
+                let pipeline = Pipeline::from(*pipeline);
+                let Some(wait) = self.pipeline_wait.as_mut()
+                    .filter(|w| w.project_id == project_id && w.pipeline_id == pipeline.id) else { return };
+
+                if wait.update(&pipeline) {
+                    self.dispatch(GlimEvent::Log(format!("pipeline_id={} completed, exiting", pipeline.id)));
+                    self.dispatch(GlimEvent::Shutdown);
+                }
+            },
+            // the wait fails if the pipeline is not accessible; other errors
+            // are retried with the next poll
+            GlimEvent::PipelineUnavailable(project_id, pipeline_id, e) => {
+                let waiting = self.pipeline_wait.as_ref()
+                    .is_some_and(|w| w.project_id == project_id && w.pipeline_id == pipeline_id);
+
+                match e {
+                    GlimError::GitlabHttpError(StatusCode::NOT_FOUND | StatusCode::FORBIDDEN, _, _) if waiting =>
+                        self.fail_pipeline_wait(format!("unable to wait for pipeline_id={pipeline_id}: {e}")),
+                    e => self.dispatch(GlimEvent::Error(e)),
+                }
+            },
+            GlimEvent::ReceivedJobs(project_id, pipeline_id, _)
+                if self.followed_pipeline == Some((project_id, pipeline_id)) =>
+            {
+                let completed = self.followed_pipeline()
+                    .is_none_or(|(_, p)| p.jobs.is_some() && !p.has_active_jobs());
+
+                if completed {
+                    // refresh the pipeline status before dropping back to regular polling
+                    self.dispatch(GlimEvent::RequestPipelines(project_id));
+                    self.dispatch(GlimEvent::UnfollowPipeline);
+                }
+            },
             
+            GlimEvent::TogglePinProject(_) | GlimEvent::ToggleIgnoreProject(_) | GlimEvent::ToggleWatch(_, _) => {
+                if let Err(e) = self.save_project_preferences() {
+                    self.dispatch(GlimEvent::Error(e));
+                }
+            },
+
+            GlimEvent::ResizeSplit(_) if self.ui.show_internal_logs => {
+                if let Err(e) = self.save_split_ratio() {
+                    self.dispatch(GlimEvent::Error(e));
+                }
+            },
+
             // configuration 
-            GlimEvent::UpdateConfig(config) => self.gitlab.update_config(config),
+            GlimEvent::UpdateConfig(config) => {
+                set_locale(config.locale);
+                set_icon_support(config.icons);
+                set_accessible(config.accessible);
+                set_read_only(config.read_only);
+                set_glyphs(&config.glyphs);
+                set_relative_times(config.relative_times);
+                set_branch_lines(config.branch_lines(), config.show_default_branch);
+                configure_animations(config.animations);
+                self.ui.use_256_colors = config.colors.use_256_colors();
+                self.configure_theme(&config);
+                if config.log_level != self.logs_store.level() {
+                    self.dispatch(GlimEvent::LogLevelChanged(config.log_level));
+                }
+                self.failure_rule.update_config(&config.issues);
+                self.issue_config = config.issues.clone();
+                self.artifacts_dir = config.artifacts_dir();
+                self.local_clones = LocalClones::new(&config);
+                self.screenshot_dir = config.screenshot_dir();
+                self.instances = Self::instance_clients(&self.sender, &config, self.gitlab.debug());
+                self.instance_names = config.instance_names();
+                self.project_labels = ProjectLabels::new(&config.project_labels);
+                self.maintenance_windows.clone_from(&config.maintenance_windows);
+                self.sound_on_failure.clone_from(&config.sound_on_failure);
+                self.open_details_on_failure = config.open_details_on_failure;
+                self.browser_command = Self::browser_command(&config);
+                self.gitlab.update_config(*config);
+                // the token expiry is requested once the version is known
+                self.dispatch(GlimEvent::RequestGitlabVersion);
+            },
             GlimEvent::ApplyConfiguration => {
-                if let Some(config_popup) = ui.config_popup_state.as_ref() {
+                if let Some(config_popup) = ui.config_popup_state.as_mut() {
                     let config = config_popup.to_config();
-                    let client = GitlabClient::new_from_config(self.sender.clone(), config.clone(), self.gitlab.debug());
-                    match client.validate_configuration() {
-                        Ok(_) => {
-                            save_config(&self.config_path, config.clone())
-                                .expect("failed to save config");
-                            self.dispatch(GlimEvent::UpdateConfig(config));
-                            self.dispatch(GlimEvent::CloseConfig);
-                        }
-                        Err(e) => {
-                            self.dispatch(GlimEvent::Error(e));
+                    // e.g. an unreadable CA bundle, shown in the popup
+                    if let Err(e) = config.validate() {
+                        config_popup.error_message = Some(e);
+                    } else {
+                        let client = GitlabClient::new_from_config(self.sender.clone(), config.clone(), self.gitlab.debug());
+                        match client.validate_configuration() {
+                            Ok(_) => {
+                                // applied to this session, even if it couldn't be saved
+                                if let Err(e) = save_config(&self.config_path, config.clone()) {
+                                    self.dispatch(GlimEvent::Error(e));
+                                }
+                                self.dispatch(GlimEvent::UpdateConfig(Box::new(config)));
+                                self.dispatch(GlimEvent::CloseConfig);
+                            }
+                            Err(e) => {
+                                self.dispatch(GlimEvent::Error(e));
+                            }
                         }
                     }
                 }
             },
 
+            GlimEvent::ProfileMemory                 => {
+                let usage = MemoryUsage::measure(self.project_store.all_projects(), self.search_index.log_bytes());
+                self.dispatch(GlimEvent::MemoryProfiled(usage));
+            },
+
             GlimEvent::ShowLastNotification          => {
                 if let Some(notice) = self.notices.last_notification() {
                     ui.notice = Some(NotificationState::new(notice.clone(), &self.project_store));
@@ -202,38 +889,491 @@ impl GlimApp {
     pub fn load_config(&self) -> Result<GlimConfig, GlimError> {
         let config_file = &self.config_path;
         if config_file.exists() {
-            let config: GlimConfig = confy::load_path(config_file)
-                .map_err(|e| GlimError::ConfigError(e.to_string()))?;
-
-            Ok(config)
+            load_config(config_file)
         } else {
             Err(GlimError::ConfigError(format!("Unable to find configuration file at {:?}", config_file)))
         }
     }
 
+    /// Returns the client of the GitLab instance the project belongs to.
+    /// Client of the GitLab instance the project belongs to.
+    fn gitlab(&self, id: ProjectId) -> Result<&GitlabClient, GlimError> {
+        match id.instance() {
+            0 => Ok(&self.gitlab),
+            n => self.instances.get(n as usize - 1).ok_or(GlimError::InstanceNotFound(n)),
+        }
+    }
+
+    /// Sends a request with the client of the project's GitLab instance,
+    /// reporting an instance which is no longer configured as an error.
+    fn request(&self, id: ProjectId, request: impl FnOnce(&GitlabClient)) {
+        self.dispatch_error(self.gitlab(id).map(request));
+    }
+
+    fn instance_clients(
+        sender: &Sender<GlimEvent>,
+        config: &GlimConfig,
+        debug: bool,
+    ) -> Vec<GitlabClient> {
+        config.instances.iter()
+            .enumerate()
+            .map(|(idx, instance)| GitlabClient::for_instance(
+                sender.clone(),
+                idx as u8 + 1,
+                instance,
+                RequestConfig::from(config),
+                &HttpConfig::from(config),
+                debug,
+            ))
+            .collect()
+    }
+
+    /// Returns the name and style of the project's label, see [GlimConfig::project_labels].
+    pub fn project_label(&self, id: ProjectId) -> Option<(&str, Style)> {
+        self.project_store.find(id)
+            .and_then(|project| self.project_labels.label(project))
+    }
+
+    /// Returns the name of the project's GitLab instance, if more
+    /// than one instance is configured.
+    pub fn instance_name(&self, id: ProjectId) -> Option<&str> {
+        if self.instances.is_empty() {
+            return None;
+        }
+
+        self.instance_names.get(id.instance() as usize)
+            .map(String::as_str)
+    }
+
+    /// Persists the pinned, ignored and watched projects to the configuration file.
+    fn save_project_preferences(&self) -> Result<(), GlimError> {
+        let config = GlimConfig {
+            pinned_projects: self.project_store.pinned_projects(),
+            ignored_projects: self.project_store.ignored_projects(),
+            watches: self.watch_list.watches().to_vec(),
+            ..self.load_config()?
+        };
+
+        save_config(&self.config_path, config)
+    }
+
+    /// Writes the projects to the cache, for the next session to start from.
+    pub fn save_project_cache(&self) -> Result<(), GlimError> {
+        self.project_cache.save(self.project_store.all_projects())
+    }
+
+    /// Persists the width of the projects table to the configuration file.
+    fn save_split_ratio(&self) -> Result<(), GlimError> {
+        let config = GlimConfig {
+            split_ratio: Some(self.ui.split_ratio),
+            ..self.load_config()?
+        };
+
+        save_config(&self.config_path, config)
+    }
+
+    /// Plays the failure sound for watched projects, unless silenced by quiet hours.
+    fn play_failure_sound(&self, project_id: ProjectId) {
+        let Some(sound) = self.sound_on_failure.as_ref() else { return };
+
+        if self.watch_list.is_watched(project_id) && !self.notifiers.is_quiet() {
+            if let Err(e) = sound.play() {
+                self.dispatch(GlimEvent::Log(e.to_string()));
+            }
+        }
+    }
+
+    fn configure_theme(&self, config: &GlimConfig) {
+        let action = if config.min_contrast.is_some() { "adjusted" } else { "low contrast in" };
+        match configure_theme(config.theme.as_deref(), config.min_contrast) {
+            Ok(low_contrast) => for (style, ratio) in low_contrast {
+                self.dispatch(GlimEvent::Log(format!("{action} theme style {style}, contrast ratio {ratio:.1}:1")));
+            },
+            Err(e) => self.dispatch(GlimEvent::Error(e)),
+        }
+    }
+
+    pub fn is_in_maintenance(&self) -> bool {
+        in_maintenance(&self.maintenance_windows, Local::now())
+    }
+
+    /// Time since which a GitLab instance has been responding with 502/503,
+    /// if any still is.
+    pub fn unavailable_since(&self) -> Option<DateTime<Local>> {
+        self.unavailable_since.values().min().copied()
+    }
+
+    fn is_unavailable(&self, instance: u8) -> bool {
+        self.unavailable_since.contains_key(&instance)
+    }
+
+    /// Returns true if the projects of an unavailable instance are not requested
+    /// this time; only every [Self::UNAVAILABLE_POLL_FACTOR]th request is made.
+    fn skip_poll(&mut self, instance: u8) -> bool {
+        if !self.is_unavailable(instance) {
+            return false;
+        }
+
+        let skipped = self.skipped_polls.entry(instance).or_default();
+        *skipped += 1;
+        if *skipped < Self::UNAVAILABLE_POLL_FACTOR {
+            return true;
+        }
+
+        *skipped = 0;
+        false
+    }
+
+    /// Time until the next request while GitLab is unreachable, if it is.
+    pub fn offline_retry_in(&self) -> Option<std::time::Duration> {
+        self.offline_retry_at.map(|at| at.saturating_duration_since(std::time::Instant::now()))
+    }
+
+    fn is_offline(&self) -> bool {
+        self.offline_retry_at.is_some()
+    }
+
+    /// Requests projects to detect when GitLab is reachable again, backing
+    /// off up to [Self::OFFLINE_MAX_RETRY_DELAY] between attempts.
+    fn retry_offline(&mut self, now: std::time::Instant) {
+        self.offline_retry_delay = (self.offline_retry_delay * 2).min(Self::OFFLINE_MAX_RETRY_DELAY);
+        self.offline_retry_at = Some(now + self.offline_retry_delay);
+        self.dispatch(GlimEvent::RequestProjects);
+    }
+
+    /// The GitLab instance which responded to polling, if the event is such a response.
+    fn responding_instance(event: &GlimEvent) -> Option<u8> {
+        match event {
+            GlimEvent::ReceivedProjects(projects)     => projects.first().map(|p| p.id.instance()),
+            GlimEvent::ReceivedPipelines(id, _)
+                | GlimEvent::ReceivedJobs(id, _, _)
+                | GlimEvent::PipelinesNotModified(id) => Some(id.instance()),
+            GlimEvent::ProjectsNotModified(instance)  => Some(*instance),
+            _                                         => None,
+        }
+    }
+
+    /// Requests projects from all instances, unless inside a maintenance window.
+    /// Unavailable instances are polled less often, see [Self::skip_poll]; while
+    /// offline, requests are made by [Self::retry_offline].
+    fn poll_projects(&mut self) {
+        let paused = self.is_in_maintenance();
+        if paused != self.polling_paused {
+            self.polling_paused = paused;
+            let message = if paused {
+                "maintenance window started, polling paused"
+            } else {
+                "maintenance window ended, polling resumed"
+            };
+            self.dispatch(GlimEvent::Log(message.to_string()));
+        }
+
+        if !paused && !self.is_offline() {
+            self.dispatch(GlimEvent::RequestProjects);
+        }
+    }
+
     pub fn process_timers(&mut self) -> Duration {
         let now = std::time::Instant::now();
         let elapsed = now - self.last_tick;
         self.last_tick = now;
 
-        // do nothing with elapsed time for now;
-        // and consider moving to UiState
+        // the monotonic clock may or may not advance during sleep, depending
+        // on the platform; the wall clock always does
+        let wall_now = Local::now();
+        let wall_elapsed = (wall_now - self.last_wall_tick).to_std().unwrap_or_default();
+        self.last_wall_tick = wall_now;
+
+        let slept = elapsed.max(wall_elapsed);
+        if slept > Self::SLEEP_THRESHOLD {
+            self.resume_after_sleep(slept);
+            // don't fast-forward animations and countdowns by the time asleep
+            return Duration::from_millis(0);
+        }
+
+        if self.offline_retry_at.is_some_and(|at| now >= at) {
+            self.retry_offline(now);
+        }
+
+        self.poll_pipeline_wait(now);
 
         Duration::from_millis(elapsed.as_millis() as u32)
     }
 
-    pub fn project(&self, id: ProjectId) -> &Project {
-        self.project_store.find(id).expect("project not found")
+    /// Refreshes projects and active jobs right away after waking up from
+    /// sleep, and restarts the polling schedule. Errors of the first requests
+    /// are logged instead of shown as notices.
+    fn resume_after_sleep(&mut self, slept: std::time::Duration) {
+        self.resumed_at = Some(self.last_tick);
+        self.dispatch(GlimEvent::Log(format!("resumed after {}s of sleep, refreshing", slept.as_secs())));
+
+        self.gitlab.resync_polling();
+        if self.is_offline() {
+            self.offline_retry_delay = Self::OFFLINE_RETRY_DELAY;
+            self.offline_retry_at = Some(self.last_tick);
+        }
+        self.dispatch(GlimEvent::PollProjects);
+        self.dispatch(GlimEvent::RequestActiveJobs);
+    }
+
+    fn is_resuming(&self) -> bool {
+        self.resumed_at.is_some_and(|at| at.elapsed() < Self::WAKE_GRACE_PERIOD)
+    }
+
+    /// Returns the project, or an error if it is no longer in the store,
+    /// e.g. hidden by the time an action on it is handled.
+    pub fn project(&self, id: ProjectId) -> Result<&Project, GlimError> {
+        self.project_store.project(id)
+    }
+
+    pub fn pipeline(&self, project_id: ProjectId, pipeline_id: PipelineId) -> Result<(&Project, &Pipeline), GlimError> {
+        self.project_store.pipeline(project_id, pipeline_id)
+    }
+
+    pub fn job(&self, project_id: ProjectId, pipeline_id: PipelineId, job_id: JobId) -> Result<&Job, GlimError> {
+        self.project_store.job(project_id, pipeline_id, job_id)
+    }
+
+    /// Dispatches the error of a failed action, to be shown as a notice.
+    fn browser_command(config: &GlimConfig) -> Option<String> {
+        config.browser_command.clone().filter(|cmd| !cmd.trim().is_empty())
+    }
+
+    /// Opens the URL with the browser command, if configured, or the default
+    /// browser. The URL is appended to browser commands without `{url}`.
+    fn browse(&self, url: &str) -> Result<(), GlimError> {
+        let Some(template) = self.browser_command.as_ref() else {
+            return open::that(url)
+                .map_err(|e| GlimError::GeneralError(format!("unable to open browser: {e}")));
+        };
+
+        let command = match template.contains("{url}") {
+            true  => template.replace("{url}", &shell_quote(url)),
+            false => format!("{template} {}", shell_quote(url)),
+        };
+
+        spawn_detached(Command::new("sh").arg("-c").arg(&command))
+            .map_err(|e| GlimError::GeneralError(format!("failed to run browser_command: {e}")))
+    }
+
+    fn dispatch_error(&self, result: Result<(), GlimError>) {
+        if let Err(e) = result {
+            self.dispatch(GlimEvent::Error(e));
+        }
+    }
+
+    /// Returns the project and pipeline currently followed, if any.
+    pub fn followed_pipeline(&self) -> Option<(&Project, &Pipeline)> {
+        let (project_id, pipeline_id) = self.followed_pipeline?;
+        let project = self.project_store.find(project_id)?;
+        project.pipeline(pipeline_id).map(|pipeline| (project, pipeline))
+    }
+
+    /// Follows the pipeline until it completes, after which glim exits.
+    pub fn wait_for_pipeline(&mut self, wait: PipelineWait) {
+        self.pipeline_wait = Some(wait);
+        self.update_pipeline_wait();
+    }
+
+    /// The pipeline glim exits for once it completes, if any.
+    pub fn pipeline_wait(&self) -> Option<&PipelineWait> {
+        self.pipeline_wait.as_ref()
+    }
+
+    /// Requests the awaited pipeline by id, as its project may not be among
+    /// the loaded projects, and ends the wait once it has timed out.
+    fn poll_pipeline_wait(&mut self, now: std::time::Instant) {
+        let Some(wait) = self.pipeline_wait.as_mut() else { return };
+
+        if wait.is_timed_out(now) {
+            let error = format!("timed out waiting for pipeline_id={}", wait.pipeline_id);
+            self.fail_pipeline_wait(error);
+        } else if wait.poll(now) {
+            let (project_id, pipeline_id) = (wait.project_id, wait.pipeline_id);
+            self.dispatch(GlimEvent::RequestPipeline(project_id, pipeline_id));
+        }
+    }
+
+    /// Ends the wait without an outcome, and shuts down; glim exits with an error.
+    fn fail_pipeline_wait(&mut self, error: String) {
+        let Some(wait) = self.pipeline_wait.as_mut() else { return };
+
+        wait.fail(error.clone());
+        self.dispatch(GlimEvent::Log(error));
+        self.dispatch(GlimEvent::Shutdown);
+    }
+
+    /// Follows the awaited pipeline once its project is loaded, and shuts
+    /// down when the pipeline has completed.
+    fn update_pipeline_wait(&mut self) {
+        let Some(wait) = self.pipeline_wait.as_mut() else { return };
+        let Some(project) = self.project_store.find(wait.project_id) else { return };
+        let (project_id, pipeline_id) = (wait.project_id, wait.pipeline_id);
+
+        if wait.start_following() {
+            self.sender.dispatch(GlimEvent::RequestPipelines(project_id));
+            self.sender.dispatch(GlimEvent::FollowPipeline(project_id, pipeline_id));
+        }
+
+        if project.pipeline(pipeline_id).is_some_and(|p| wait.update(p)) {
+            self.sender.dispatch(GlimEvent::Log(format!("pipeline_id={pipeline_id} completed, exiting")));
+            self.sender.dispatch(GlimEvent::Shutdown);
+        }
+    }
+
+    pub fn followed_pipeline_id(&self) -> Option<PipelineId> {
+        self.followed_pipeline.map(|(_, pipeline_id)| pipeline_id)
     }
 
     pub fn projects(&self) -> &[Project] {
         self.project_store.projects()
     }
 
+    pub fn is_pinned(&self, id: ProjectId) -> bool {
+        self.project_store.is_pinned(id)
+    }
+
+    pub fn is_watched(&self, id: ProjectId) -> bool {
+        self.watch_list.is_watched(id)
+    }
+
+    /// Returns true if failed pipelines of the project open its details,
+    /// see [GlimConfig::open_details_on_failure].
+    pub fn opens_details_on_failure(&self, id: ProjectId) -> bool {
+        self.open_details_on_failure && self.watch_list.is_watched(id)
+    }
+
+    pub fn quick_filter(&self) -> Option<QuickFilter> {
+        self.project_store.quick_filter()
+    }
+
+    /// Returns the query of the project filter; empty when not filtered.
+    pub fn project_filter(&self) -> &str {
+        self.project_store.filter()
+    }
+
+    /// Returns the byte offsets of the characters in the path of the project
+    /// matching the project filter.
+    pub fn project_filter_matches(&self, id: ProjectId) -> Option<&[usize]> {
+        self.project_store.filter_matches(id)
+    }
+
+    pub fn fetch(&self, id: ProjectId) -> Option<&ProjectFetch> {
+        self.project_store.fetch(id)
+    }
+
+    /// Returns the state shown alongside the project in the projects table.
+    pub fn project_row_state(&self, id: ProjectId) -> ProjectRowState<'_> {
+        ProjectRowState {
+            pinned: self.is_pinned(id),
+            watched: self.is_watched(id),
+            ignored: self.project_store.is_ignored(id),
+            instance: self.instance_name(id),
+            label: self.project_label(id),
+            fetch: self.fetch(id),
+            filter_matches: self.project_filter_matches(id),
+            health: self.project_store.health(id),
+        }
+    }
+
+    /// Returns true if any of the received projects is new or was updated.
+    pub fn has_project_changes(&self, projects: &[ProjectDto]) -> bool {
+        projects.iter().any(|p| self.project_store.is_project_changed(p))
+    }
+
+    /// Returns the durations of the finished pipelines of the branch, oldest first.
+    pub fn pipeline_history(&self, project_id: ProjectId, branch: &str) -> Vec<PipelineRun> {
+        self.project_store.pipeline_history(project_id, branch)
+    }
+
+    pub fn fetch_summary(&self) -> HashMap<FetchState, usize> {
+        self.project_store.fetch_summary()
+    }
+
+    /// Effective page size of pipeline requests to the primary instance.
+    pub fn page_size(&self) -> u16 {
+        self.gitlab.page_size()
+    }
+
+    pub fn search(&self, query: &str) -> Vec<SearchResult> {
+        self.search_index.search(query)
+    }
+
+    pub fn audit_entries(&self) -> &[AuditEntry] {
+        self.audit_log.entries()
+    }
+
+    /// Recent failed fetches of pipelines and jobs, with the title of their project.
+    pub fn project_errors(&self) -> Vec<(ProjectError, String)> {
+        self.notices.project_errors().iter()
+            .map(|error| {
+                let project = self.project_store.find(error.project_id)
+                    .map(|p| p.title())
+                    .unwrap_or_else(|| t(Msg::NoticeUnknownProject).to_string());
+
+                (error.clone(), project)
+            })
+            .collect()
+    }
+
+    /// Pipelines of all projects in the projects table, most recently updated first.
+    pub fn recent_pipelines(&self) -> Vec<(&Project, &Pipeline)> {
+        let mut pipelines: Vec<(&Project, &Pipeline)> = self.projects().iter()
+            .flat_map(|project| project.pipelines.iter().flatten().map(move |p| (project, p)))
+            .collect();
+
+        pipelines.sort_by_key(|(_, p)| Reverse(p.updated_at));
+        pipelines
+    }
+
+    /// Multi-project pipelines of all projects in the projects table, as
+    /// trees of upstream and downstream pipelines.
+    pub fn pipeline_trees(&self) -> Vec<PipelineTreeRow<'_>> {
+        pipeline_trees(self.projects())
+    }
+
+    /// Failed jobs of the latest pipeline of each branch, of all projects in
+    /// the projects table, most recently failed first.
+    pub fn failed_jobs(&self) -> Vec<(&Project, &Pipeline, &Job)> {
+        let mut jobs: Vec<(&Project, &Pipeline, &Job)> = self.projects().iter()
+            .flat_map(|project| project.pipelines.iter().flatten()
+                .unique_by(|p| &p.branch)
+                .map(move |p| (project, p)))
+            .flat_map(|(project, p)| p.jobs.iter().flatten()
+                .filter(|job| job.status == PipelineStatus::Failed)
+                .map(move |job| (project, p, job)))
+            .collect();
+
+        let failed_at = |(_, p, job): &(&Project, &Pipeline, &Job)| job.finished_at.unwrap_or(p.updated_at);
+        jobs.sort_by_key(|job| Reverse(failed_at(job)));
+        jobs
+    }
+
+    /// Directory of screenshots and session recordings, see [GlimConfig::screenshot_dir].
+    pub fn screenshot_dir(&self) -> &Path {
+        &self.screenshot_dir
+    }
+
+    pub fn status(&self) -> &StatusStore {
+        &self.status_store
+    }
+
+    /// Number of running or pending pipelines of the projects in the projects table.
+    pub fn active_pipelines(&self) -> usize {
+        self.projects().iter()
+            .flat_map(|p| p.pipelines.iter().flatten())
+            .filter(|p| p.status.is_active())
+            .count()
+    }
+
     pub fn logs(&self) -> Vec<(DateTime<Local>, &str)> {
         self.logs_store.logs()
     }
 
+    pub fn log_level(&self) -> LogLevel {
+        self.logs_store.level()
+    }
+
     pub fn is_running(&self) -> bool {
         self.running
     }
@@ -248,10 +1388,18 @@ impl GlimApp {
 }
 
 impl UiState {
-    pub fn new() -> Self {
+    const DEFAULT_SPLIT_RATIO: u16 = 65;
+    const MIN_SPLIT_RATIO: u16 = 30;
+    const MAX_SPLIT_RATIO: u16 = 85;
+
+    pub fn new(split_ratio: Option<u16>, use_256_colors: bool) -> Self {
         Self {
             show_internal_logs: false,
-            use_256_colors: false,
+            use_256_colors,
+            tab: Tab::default(),
+            recording: false,
+            split_ratio: split_ratio.unwrap_or(Self::DEFAULT_SPLIT_RATIO)
+                .clamp(Self::MIN_SPLIT_RATIO, Self::MAX_SPLIT_RATIO),
         }
     }
 
@@ -259,6 +1407,14 @@ impl UiState {
         match event {
             GlimEvent::ToggleInternalLogs => self.show_internal_logs = !self.show_internal_logs,
             GlimEvent::ToggleColorDepth   => self.use_256_colors = !self.use_256_colors,
+            GlimEvent::ToggleRelativeTimes => set_relative_times(!relative_times()),
+            GlimEvent::TabSelect(tab)     => self.tab = *tab,
+            GlimEvent::RecordingStarted(_) => self.recording = true,
+            GlimEvent::RecordingSaved(_)  => self.recording = false,
+            GlimEvent::ResizeSplit(delta) if self.show_internal_logs => {
+                self.split_ratio = self.split_ratio.saturating_add_signed(*delta)
+                    .clamp(Self::MIN_SPLIT_RATIO, Self::MAX_SPLIT_RATIO);
+            },
             _ => ()
         }
     }
@@ -270,7 +1426,7 @@ impl Dispatcher for GlimApp {
     }
 }
 
-
+/// Opens the URL in the browser.
 #[allow(unused)]
 pub fn modulo(a: u32, b: u32) -> u32 {
     if b == 0 { return 0; }
@@ -314,4 +1470,58 @@ impl Modulo for usize {
 
         (self as isize).modulo(b as isize) as usize
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::sync::{mpsc, Arc};
+
+    use super::*;
+    use crate::http::FakeTransport;
+    use crate::stores::tests::pipeline_dto;
+
+    #[test]
+    fn actions_on_stale_ids_are_reported_as_errors() {
+        let dir = std::env::temp_dir().join("glim-tests");
+        paths::set_overrides(Some(dir.clone()), Some(dir));
+
+        let (sender, receiver) = mpsc::channel();
+        let config = GlimConfig::default();
+        let gitlab = GitlabClient::with_transport(sender.clone(), 0, String::new(), String::new(),
+            RequestConfig::from(&config), Arc::new(FakeTransport::new(vec![])));
+        let notifiers = Notifiers::new(&config, sender.clone());
+        let mut app = GlimApp::new(sender.clone(), PathBuf::from("glim.toml"), notifiers, &config, gitlab);
+        let mut ui = StatefulWidgets::new(sender);
+
+        let project_id = ProjectId::new(1);
+        let (pipeline_id, stale_pipeline_id) = (PipelineId::new(4), PipelineId::new(5));
+        app.apply(GlimEvent::ReceivedProjects(vec![ProjectDto { id: project_id, ..Default::default() }]), &mut ui);
+        app.apply(GlimEvent::ReceivedPipelines(project_id, vec![pipeline_dto(1, 4)]), &mut ui);
+        receiver.try_iter().for_each(drop);
+
+        let stale_project_id = ProjectId::new(2);
+        for event in [
+            GlimEvent::BrowseToCommit(stale_project_id, pipeline_id),
+            GlimEvent::BrowseToCommit(project_id, stale_pipeline_id),
+            GlimEvent::CreateIssue(stale_project_id, pipeline_id),
+            GlimEvent::CreateIssue(project_id, stale_pipeline_id),
+            GlimEvent::DownloadErrorLog(stale_project_id, pipeline_id),
+            GlimEvent::DownloadErrorLog(project_id, stale_pipeline_id),
+        ] {
+            let description = format!("{event:?}");
+            app.apply(event, &mut ui);
+
+            let errors: Vec<GlimError> = receiver.try_iter()
+                .filter_map(|e| match e {
+                    GlimEvent::Error(e) => Some(e),
+                    _                   => None,
+                })
+                .collect();
+
+            assert!(matches!(errors.as_slice(),
+                [GlimError::ProjectNotFound(id)] if *id == stale_project_id) ||
+                matches!(errors.as_slice(),
+                [GlimError::PipelineNotFound(_, id)] if *id == stale_pipeline_id),
+                "{description}: {errors:?}");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+use crate::client::GitlabClient;
+use crate::gitlab_version::ApiFeature;
+use crate::load_config;
+use crate::read_only::read_only;
+use crate::result::GlimError;
+use crate::token_scopes::scope_warnings;
+
+/// Scopes of which the token needs at least one.
+pub const REQUIRED_SCOPES: [&str; 2] = ["read_api", "api"];
+
+/// Checks the configuration, connectivity and token without starting the
+/// TUI, printing the outcome of each step; see `glim check`. Returns the
+/// exit code: 0 if all checks passed, 1 otherwise.
+pub fn run(config_path: &PathBuf, debug: bool) -> i32 {
+    println!("configuration: {}", config_path.display());
+    if !config_path.exists() {
+        return fail("configuration file not found; run glim to create it");
+    }
+
+    let config = match load_config(config_path) {
+        Ok(config) => config,
+        Err(e) => return fail(&format!("unable to load configuration: {e}")),
+    };
+    if let Err(e) = config.validate() {
+        return fail(&format!("invalid configuration: {e}"));
+    }
+    pass("configuration is valid");
+
+    // the receiver is kept alive for the events dispatched by the client
+    let (sender, _receiver) = mpsc::channel();
+    let gitlab_url = config.gitlab_url.clone();
+    let config_read_only = config.read_only;
+    let client = GitlabClient::new_from_config(sender, config, debug);
+
+    match client.validate_configuration() {
+        Ok(_) => pass(&format!("connected to {gitlab_url}")),
+        Err(GlimError::InvalidGitlabToken) => return fail("token is invalid"),
+        Err(GlimError::ExpiredGitlabToken) => return fail("token has expired"),
+        Err(e) => return fail(&format!("unable to reach {gitlab_url}: {e}")),
+    }
+
+    let version = match client.gitlab_version() {
+        Ok(version) => {
+            pass(&format!("GitLab {version}"));
+            Some(version)
+        },
+        Err(e) => {
+            println!("? unable to check the GitLab version: {e}");
+            None
+        },
+    };
+
+    // the token scopes are only checked if the version supports it, or is unknown
+    if version.is_some_and(|v| !v.supports(ApiFeature::TokenDetails)) {
+        println!("? token scopes not checked; requires GitLab {}", ApiFeature::TokenDetails.min_version());
+        return 0;
+    }
+
+    match client.token_info() {
+        Ok(token) if !token.scopes.iter().any(|s| REQUIRED_SCOPES.contains(&s.as_str())) =>
+            return fail(&format!("token lacks the read_api scope; scopes: {}", token.scopes.join(", "))),
+        Ok(token) => {
+            pass(&format!("token scopes: {}", token.scopes.join(", ")));
+            for warning in scope_warnings(&token.scopes, config_read_only || read_only()) {
+                warn(&warning.message());
+            }
+            match token.expires_at {
+                Some(date) => pass(&format!("token expires on {date}")),
+                None       => pass("token never expires"),
+            }
+        },
+        // e.g. older GitLab versions, or tokens other than personal access tokens
+        Err(e) => println!("? unable to check token scopes: {e}"),
+    }
+
+    0
+}
+
+fn pass(message: &str) {
+    println!("✓ {message}");
+}
+
+fn warn(message: &str) {
+    println!("! {message}");
+}
+
+fn fail(message: &str) -> i32 {
+    println!("✗ {message}");
+    1
+}
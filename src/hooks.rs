@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::Sender;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dispatcher::Dispatcher;
+use crate::domain::{PipelineStatus, Project};
+use crate::event::GlimEvent;
+use crate::glim_app::GlimConfig;
+use crate::id::ProjectId;
+use crate::local_clone::shell_quote;
+
+/// Shell command run on an event, e.g.
+/// `{ event = "pipeline_failed", command = "notify.sh {project} {branch} {url}" }`.
+///
+/// `{project}`, `{branch}`, `{url}`, `{status}`, `{pipeline_id}` and `{job_id}`
+/// are replaced by their shell-quoted values; variables not applying to the
+/// event are empty.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HookConfig {
+    pub event: HookEvent,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// A pipeline failed
+    PipelineFailed,
+    /// A pipeline succeeded on a branch whose previous pipeline failed
+    PipelineFixed,
+    /// A job log was downloaded; the log is passed on stdin
+    JobLogDownloaded,
+}
+
+/// Runs the configured [HookConfig]s on a thread each, logging their output.
+pub struct Hooks {
+    sender: Sender<GlimEvent>,
+    hooks: Vec<HookConfig>,
+    /// latest state of each project, for the template variables
+    projects: HashMap<ProjectId, Box<Project>>,
+    /// status of the last completed pipeline per project and branch
+    last_completed: HashMap<(ProjectId, String), PipelineStatus>,
+}
+
+/// Values of the template variables of a hook.
+#[derive(Default)]
+struct HookContext {
+    project: String,
+    branch: String,
+    url: String,
+    status: String,
+    pipeline_id: String,
+    job_id: String,
+    stdin: Option<String>,
+}
+
+impl Hooks {
+    pub fn new(config: &GlimConfig, sender: Sender<GlimEvent>) -> Self {
+        Self {
+            sender,
+            hooks: config.hooks.clone(),
+            projects: HashMap::new(),
+            last_completed: HashMap::new(),
+        }
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent) {
+        match event {
+            GlimEvent::UpdateConfig(config) => self.hooks = config.hooks.clone(),
+            GlimEvent::ProjectUpdated(project) => {
+                // pipelines completed when first seen are only recorded; pipelines are ordered by recency
+                for p in project.pipelines.iter().flatten().filter(|p| !p.status.is_active()) {
+                    self.last_completed.entry((project.id, p.branch.clone()))
+                        .or_insert_with(|| p.status.clone());
+                }
+                self.projects.insert(project.id, project.clone());
+            },
+            GlimEvent::PipelineStatusChanged(project_id, pipeline_id, _, status @ (PipelineStatus::Failed | PipelineStatus::Success)) => {
+                let Some(project) = self.projects.get(project_id) else { return };
+                let Some(pipeline) = project.pipeline(*pipeline_id) else { return };
+
+                let previous = self.last_completed
+                    .insert((*project_id, pipeline.branch.clone()), status.clone());
+
+                let hook_event = match status {
+                    PipelineStatus::Failed                                  => HookEvent::PipelineFailed,
+                    _ if previous == Some(PipelineStatus::Failed)           => HookEvent::PipelineFixed,
+                    _                                                       => return,
+                };
+
+                let context = HookContext {
+                    project: project.path.clone(),
+                    branch: pipeline.branch.clone(),
+                    url: pipeline.url.clone(),
+                    status: status.to_string(),
+                    pipeline_id: pipeline_id.to_string(),
+                    ..HookContext::default()
+                };
+                self.run(hook_event, context);
+            },
+            GlimEvent::JobLogDownloaded(project_id, job_id, trace) => {
+                let context = HookContext {
+                    project: self.projects.get(project_id).map(|p| p.path.clone()).unwrap_or_default(),
+                    job_id: job_id.to_string(),
+                    stdin: Some(trace.clone()),
+                    ..HookContext::default()
+                };
+                self.run(HookEvent::JobLogDownloaded, context);
+            },
+            _ => (),
+        }
+    }
+
+    fn run(&self, event: HookEvent, context: HookContext) {
+        for hook in self.hooks.iter().filter(|h| h.event == event) {
+            let command = context.expand(&hook.command);
+            let stdin = context.stdin.clone();
+            let sender = self.sender.clone();
+
+            std::thread::spawn(move || {
+                sender.dispatch(GlimEvent::Log(format!("running hook: {command}")));
+                match run_command(&command, stdin) {
+                    Ok((status, output)) => {
+                        for line in output.lines().filter(|l| !l.trim().is_empty()) {
+                            sender.dispatch(GlimEvent::Log(format!("hook: {line}")));
+                        }
+                        if !status.success() {
+                            sender.dispatch(GlimEvent::Log(format!("hook {command} failed with {status}")));
+                        }
+                    },
+                    Err(e) => sender.dispatch(GlimEvent::Log(format!("failed to run hook {command}: {e}"))),
+                }
+            });
+        }
+    }
+}
+
+impl HookContext {
+    fn expand(&self, template: &str) -> String {
+        template
+            .replace("{project}", &shell_quote(&self.project))
+            .replace("{branch}", &shell_quote(&self.branch))
+            .replace("{url}", &shell_quote(&self.url))
+            .replace("{status}", &shell_quote(&self.status))
+            .replace("{pipeline_id}", &shell_quote(&self.pipeline_id))
+            .replace("{job_id}", &shell_quote(&self.job_id))
+    }
+}
+
+/// Runs the command with `sh -c`, returning its exit status and combined output.
+fn run_command(
+    command: &str,
+    stdin: Option<String>,
+) -> std::io::Result<(std::process::ExitStatus, String)> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        // written on a thread of its own, as the hook may not read all of it
+        std::thread::spawn(move || pipe.write_all(input.as_bytes()));
+    }
+
+    let output = child.wait_with_output()?;
+    let text = String::from_utf8_lossy(&output.stdout).into_owned()
+        + &String::from_utf8_lossy(&output.stderr);
+
+    Ok((output.status, text))
+}
@@ -0,0 +1,205 @@
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Client, NoProxy, Proxy, Request, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::glim_app::GlimConfig;
+use crate::result::{GlimError, Result};
+
+/// Future returned by [HttpTransport::send].
+pub type ResponseFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// Sends HTTP requests on behalf of the [GitlabClient](crate::client::GitlabClient).
+/// Retries and the mapping of responses to errors are handled by the client,
+/// independently of how requests reach the server.
+pub trait HttpTransport: Send + Sync {
+    fn send(&self, request: Request) -> ResponseFuture<'_>;
+}
+
+/// A fully received HTTP response.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// [HttpTransport] backed by a [reqwest::Client].
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: Request) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            let response = self.client.execute(request).await?;
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.text().await?;
+
+            Ok(HttpResponse { status, headers, body })
+        })
+    }
+}
+
+/// Extra header sent with every request, e.g. as required by corporate proxies.
+/// The value is redacted when formatted, as it may contain credentials.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// User agent, extra headers and TLS settings applied to all requests.
+#[derive(Debug, Clone, Default)]
+pub struct HttpConfig {
+    pub user_agent: Option<String>,
+    pub headers: Vec<HttpHeader>,
+    /// PEM bundle of certificates trusted in addition to the system roots
+    pub ca_cert: Option<PathBuf>,
+    /// accepts invalid certificates, e.g. self-signed ones
+    pub insecure_skip_verify: bool,
+    /// URL of the HTTP or SOCKS proxy of all requests
+    pub proxy: Option<String>,
+    /// hosts and domains reached without the proxy
+    pub no_proxy: Vec<String>,
+}
+
+impl HttpConfig {
+    /// Builds a client sending the configured user agent and headers. Header
+    /// values are marked as sensitive, keeping them out of debug output.
+    /// Certificates of the CA bundle are trusted along with the system roots.
+    pub fn build_client(&self) -> Result<Client> {
+        let mut headers = HeaderMap::new();
+        for header in &self.headers {
+            let name = HeaderName::from_bytes(header.name.as_bytes())
+                .map_err(|_| GlimError::ConfigError(format!("invalid HTTP header name: {}", header.name)))?;
+            let mut value = HeaderValue::from_str(&header.value)
+                .map_err(|_| GlimError::ConfigError(format!("invalid value of HTTP header {}", header.name)))?;
+
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        let mut builder = Client::builder().default_headers(headers);
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        for certificate in self.ca_certificates()? {
+            builder = builder.add_root_certificate(certificate);
+        }
+        if self.insecure_skip_verify {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        if let Some(proxy) = self.proxy()? {
+            builder = builder.proxy(proxy);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    fn proxy(&self) -> Result<Option<Proxy>> {
+        let Some(url) = self.proxy.as_ref() else { return Ok(None) };
+
+        let proxy = Proxy::all(url)
+            .map_err(|e| GlimError::ConfigError(format!("invalid proxy {url}: {e}")))?
+            .no_proxy(NoProxy::from_string(&self.no_proxy.join(",")));
+
+        Ok(Some(proxy))
+    }
+
+    fn ca_certificates(&self) -> Result<Vec<Certificate>> {
+        let Some(path) = self.ca_cert.as_ref() else { return Ok(Vec::new()) };
+
+        let pem = std::fs::read(path)
+            .map_err(|e| GlimError::ConfigError(format!("unable to read CA bundle {}: {e}", path.display())))?;
+        let certificates = Certificate::from_pem_bundle(&pem)
+            .map_err(|e| GlimError::ConfigError(format!("invalid CA bundle {}: {e}", path.display())))?;
+
+        if certificates.is_empty() {
+            return Err(GlimError::ConfigError(format!("no certificates in CA bundle {}", path.display())));
+        }
+
+        Ok(certificates)
+    }
+}
+
+impl From<&GlimConfig> for HttpConfig {
+    fn from(config: &GlimConfig) -> Self {
+        Self {
+            user_agent: config.user_agent.clone(),
+            headers: config.http_headers.clone(),
+            ca_cert: config.ca_cert.clone(),
+            insecure_skip_verify: config.insecure_skip_verify,
+            proxy: config.proxy.clone(),
+            no_proxy: config.no_proxy.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for HttpHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HttpHeader")
+            .field("name", &self.name)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+impl std::fmt::Display for HttpHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: <redacted>", self.name)
+    }
+}
+
+/// [HttpTransport] replaying scripted responses, in order, for tests.
+#[cfg(test)]
+pub struct FakeTransport {
+    responses: std::sync::Mutex<std::collections::VecDeque<Result<HttpResponse>>>,
+    requests: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(test)]
+impl FakeTransport {
+    pub fn new(responses: Vec<Result<HttpResponse>>) -> Self {
+        Self {
+            responses: std::sync::Mutex::new(responses.into()),
+            requests: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// A response with the given status, headers and body.
+    pub fn response(status: u16, headers: &[(&'static str, &str)], body: &str) -> Result<HttpResponse> {
+        let headers = headers.iter()
+            .map(|(name, value)| (HeaderName::from_static(name), HeaderValue::from_str(value).unwrap()))
+            .collect();
+
+        Ok(HttpResponse { status: StatusCode::from_u16(status).unwrap(), headers, body: body.to_string() })
+    }
+
+    /// Number of requests sent so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+impl HttpTransport for FakeTransport {
+    fn send(&self, _request: Request) -> ResponseFuture<'_> {
+        self.requests.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let response = self.responses.lock().unwrap().pop_front()
+            .expect("no more scripted responses");
+
+        Box::pin(async move { response })
+    }
+}
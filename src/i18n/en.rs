@@ -0,0 +1,286 @@
+use crate::i18n::Msg;
+
+pub(super) fn message(msg: Msg) -> &'static str {
+    match msg {
+        Msg::TitleGitlabPipelines       => "gitlab pipelines",
+        Msg::TabProjects                => "projects",
+        Msg::TabPipelines               => "pipelines",
+        Msg::TabFailedJobs              => "failed jobs",
+        Msg::TabMultiProject            => "multi-project",
+        Msg::TabMergeRequests           => "merge requests",
+        Msg::TabRunners                 => "runners",
+        Msg::TabLogs                    => "logs",
+        Msg::TabNotAvailable            => "not available yet",
+        Msg::TabRunnersHint             => "press u on a project in the projects tab to list its runners",
+        Msg::TitleRecentPipelines       => "pipelines · {active} active",
+        Msg::TitleFailedJobs            => "failed jobs · {count}",
+        Msg::TitleMultiProject          => "multi-project pipelines · {count}",
+        Msg::NoMultiProjectPipelines    => "no pipelines with downstream pipelines in other projects",
+        Msg::TitleInternalLogs          => "internal logs",
+        Msg::TitleFollowingPipeline     => "following pipeline",
+        Msg::BannerGitlabUnavailable    => "GitLab unavailable since {time}, polling slowed down",
+        Msg::BannerOffline              => "offline — retrying in {seconds}s",
+        Msg::SelectionAnnouncement      => "selected: {item}",
+        Msg::SelectionNoPipelines       => "no pipelines",
+        Msg::TimeAgo                    => "{age} ago",
+        Msg::StatusPolledAgo            => "polled {time} ago",
+        Msg::StatusNotPolled            => "not polled yet",
+        Msg::StatusActivePipelines      => "{count} active pipelines",
+        Msg::StatusFilter               => "filter",
+        Msg::StatusRecording            => "● rec",
+        Msg::StatusTokenExpires         => "token expires in {days}d",
+        Msg::StatusTokenScopes          => "⚠ token scopes",
+        Msg::TitleConfiguration         => "configuration",
+        Msg::TitleOnboarding            => "welcome to glim",
+        Msg::TitleProjectDetails        => "project details",
+        Msg::TitlePipelineActions       => "pipeline actions",
+        Msg::TitleAuditLog              => "audit log",
+        Msg::TitleApiInspector          => "api inspector",
+        Msg::TitleProjectErrors         => "project errors",
+        Msg::TitleJobLog                => "job log",
+        Msg::TitleJobActions            => "job actions",
+        Msg::TitlePipelineGraph         => "stage graph",
+        Msg::TitleSearch                => "search",
+        Msg::TitleCommands              => "commands",
+        Msg::TitleHelp                  => "keybindings",
+        Msg::HelpModeProjects           => "projects",
+        Msg::HelpModeProjectDetails     => "project details",
+        Msg::HelpModePipelineActions    => "pipeline actions",
+        Msg::HelpModeConfig             => "configuration",
+        Msg::HelpModeSearch             => "search",
+        Msg::HelpModeProjectFilter      => "project filter",
+        Msg::HelpModeCommandPalette     => "command palette",
+        Msg::TitleBranches              => "branches",
+        Msg::TitleSchedules             => "pipeline schedules",
+        Msg::TitleRunners               => "runners",
+        Msg::TitleArtifacts             => "job artifacts",
+
+        Msg::ShortcutQuit               => "quit",
+        Msg::ShortcutOpenWeb            => "open web",
+        Msg::ShortcutConfig             => "config",
+        Msg::ShortcutPin                => "pin",
+        Msg::ShortcutWatch              => "watch",
+        Msg::ShortcutWatchBranch        => "watch branch",
+        Msg::ShortcutHide               => "hide",
+        Msg::ShortcutShowHidden         => "hidden",
+        Msg::ShortcutSearch             => "search",
+        Msg::ShortcutFilter             => "filter",
+        Msg::ShortcutClearFilter        => "clear filter",
+        Msg::ShortcutSort               => "sort",
+        Msg::ShortcutGroupedView        => "grouped",
+        Msg::ShortcutFlatView           => "flat",
+        Msg::ShortcutCollapse           => "collapse group",
+        Msg::ShortcutExpand             => "expand group",
+        Msg::ShortcutCollapseExpand     => "collapse/expand",
+        Msg::ShortcutTab                => "tab",
+        Msg::SortLastActivity           => "last activity",
+        Msg::SortName                   => "name",
+        Msg::SortStatus                 => "pipeline status",
+        Msg::QuickFilterFailed          => "failed",
+        Msg::QuickFilterActive          => "running",
+        Msg::ShortcutCommands           => "commands",
+        Msg::ShortcutHelp               => "help",
+        Msg::ShortcutResize             => "resize",
+        Msg::ShortcutLastNotification   => "last notification",
+        Msg::ShortcutHistory            => "history",
+        Msg::ShortcutLogs               => "logs",
+        Msg::ShortcutRefresh            => "refresh",
+        Msg::ShortcutPipelineRefresh    => "pipeline refresh",
+        Msg::ShortcutSelection          => "selection",
+        Msg::ShortcutDetails            => "details",
+        Msg::ShortcutClose              => "close",
+        Msg::ShortcutApply              => "apply",
+        Msg::ShortcutNext               => "next",
+        Msg::ShortcutBack               => "back",
+        Msg::ShortcutActions            => "actions...",
+        Msg::ShortcutToggleStage        => "collapse stage",
+        Msg::ShortcutOlderPipelines     => "older pipelines",
+        Msg::ShortcutNewerPipelines     => "newer pipelines",
+        Msg::ShortcutFocus              => "focus",
+        Msg::ShortcutCommitDetails      => "commit",
+        Msg::ShortcutJobDurations       => "timings",
+        Msg::ShortcutDurationHistory    => "history",
+        Msg::ShortcutCopySha            => "copy sha",
+        Msg::ShortcutCopyCloneUrl       => "copy clone url",
+        Msg::ShortcutCopyCloneCommand   => "copy git clone",
+        Msg::ShortcutCopyPipelineUrl    => "copy URL",
+        Msg::ShortcutCopyJobUrl         => "copy job URL",
+        Msg::ShortcutCopyBranchName     => "copy branch",
+        Msg::ShortcutOpenClone          => "open clone",
+        Msg::ShortcutScroll             => "scroll",
+        Msg::ShortcutScrollBody         => "scroll body",
+        Msg::ShortcutFold               => "fold",
+        Msg::ShortcutFoldAll            => "fold all",
+        Msg::ShortcutZoom               => "zoom",
+        Msg::ShortcutJump               => "jump to",
+        Msg::ShortcutTestWebhook        => "test webhook",
+        Msg::ShortcutBranches           => "branches",
+        Msg::ShortcutTriggerPipeline    => "run pipeline",
+        Msg::ShortcutSchedules          => "schedules",
+        Msg::ShortcutRunners            => "runners",
+        Msg::ShortcutDownload           => "download",
+        Msg::ShortcutRunSchedule        => "run now",
+
+        Msg::ActionViewJobLog           => "view failed job log",
+        Msg::ActionViewLog              => "view job log",
+        Msg::ActionCreateIssue          => "create issue for failure",
+        Msg::ActionViewStageGraph       => "show stage graph",
+        Msg::ActionBrowseToFailedJob    => "browse to failed job",
+        Msg::ActionBrowseToJob          => "browse to job",
+        Msg::ActionRetryJob             => "retry job",
+        Msg::ActionRunSchedule          => "run pipeline schedule",
+        Msg::ActionBrowseToPipeline     => "browse to pipeline",
+        Msg::ActionBrowseToCommit       => "browse to commit diff",
+        Msg::ActionBrowseToProject      => "browse to project",
+        Msg::ActionDownloadErrorLog     => "download failed job log to clipboard",
+        Msg::ActionFollowPipeline       => "follow pipeline until done",
+        Msg::ActionUnfollowPipeline     => "stop following pipeline",
+        Msg::ActionWaitForPipeline      => "wait until done, then exit",
+        Msg::ActionBrowseArtifacts      => "browse artifacts",
+        Msg::ActionCopyPipelineUrl      => "copy pipeline URL",
+        Msg::ActionCopyJobUrl           => "copy job URL",
+        Msg::ActionCopyFailedJobUrl     => "copy failed job URL",
+        Msg::ActionCopyBranchName       => "copy branch name",
+
+        Msg::NoticeUnknownProject       => "<unknown project>",
+        Msg::NoticeJobLogDownloaded     => "Job log downloaded",
+        Msg::NoticeConfigError          => "Config error: ",
+        Msg::NoticeRetriesExhausted     => "Gave up after {retries} retries: ",
+        Msg::NoticeJsonParseFailed      => "Failed to parse JSON",
+        Msg::NoticeGetJobsFailed        => "Failed to get jobs for ",
+        Msg::NoticeGetTriggerJobsFailed => "Failed to get trigger jobs for ",
+        Msg::NoticeGetPipelinesFailed   => "Failed to get pipelines for ",
+        Msg::NoticeJobLogDownloadedFor  => "Finished downloading job log for ",
+        Msg::NoticeIssueCreated         => "Issue created: ",
+        Msg::NoticeJobRetried           => "Retrying job ",
+        Msg::NoticePipelineTriggered    => "Pipeline started on ",
+        Msg::NoticeSchedulePlayed       => "Running pipeline schedule ",
+        Msg::NoticeArtifactsDownloaded  => "Artifacts saved to ",
+        Msg::NoticeRecordingSaved       => "Recording saved to ",
+        Msg::NoticeScreenshotSaved      => "Screenshot saved to ",
+        Msg::NoticeMemoryUsage          => "Memory usage ",
+        Msg::FetchStaleSince            => "stale since ",
+        Msg::NoticeCopiedToClipboard    => "Copied to clipboard: ",
+        Msg::NoticeTokenExpiring        => "GitLab token expires in {days} days, on ",
+        Msg::NoticeUnsupportedFeature   => "{host} runs GitLab {version}: {feature} require GitLab {required}",
+        Msg::NoticeReadOnly             => "Read-only mode: {action} requires a token with the api scope",
+        Msg::NoticeTokenMissingApiScope => "The token lacks the api scope needed to retry jobs and run pipelines; set read_only = true to hide these actions",
+        Msg::NoticeTokenBroadScopes     => "The token has scopes glim does not need: {scopes}",
+        Msg::FeatureTokenDetails        => "token expiry warnings",
+        Msg::FeatureTriggerJobs         => "trigger jobs",
+        Msg::NoticeWatchFailed          => "pipeline failed",
+        Msg::NoticeWatchSucceeded       => "pipeline succeeded",
+        Msg::NoticeWatchRecovered       => "back to green",
+        Msg::PipelineApprovals          => "approvals",
+
+        Msg::NoActionsRecorded          => "no actions recorded",
+        Msg::NoRequestsRecorded         => "no requests recorded; set GLIM_DEBUG to record requests",
+        Msg::NoProjectErrors            => "no failed fetches",
+        Msg::LoadingJobLog              => "loading job log...",
+        Msg::JobLogUnavailable          => "unable to load job log: ",
+        Msg::LoadingJobs                => "loading jobs...",
+        Msg::LoadingCommit              => "loading commit...",
+        Msg::PipelinePage               => "page {page}",
+        Msg::LoadingOlderPipelines      => "loading older pipelines...",
+        Msg::LoadingBranches            => "loading branches...",
+        Msg::LoadingPipelines           => "loading pipelines...",
+        Msg::PipelineGone               => "gone",
+        Msg::LoadingSchedules           => "loading schedules...",
+        Msg::NoSchedules                => "no pipeline schedules",
+        Msg::ScheduleInactive           => "inactive",
+        Msg::LoadingRunners             => "loading runners...",
+        Msg::NoRunners                  => "no runners available",
+        Msg::RunnersOnline              => "online",
+        Msg::RunnerPaused               => "paused",
+        Msg::RunnerShared               => "(shared)",
+        Msg::ArtifactsDownloadHint      => "press d to download the artifacts archive",
+        Msg::ArtifactsNoArchive         => "no downloadable archive",
+        Msg::NoSearchResults            => "no matches",
+        Msg::NoMatchingCommands         => "no matching commands",
+        Msg::CommandOpenDetails         => "show project details",
+        Msg::CommandBrowseToProject     => "open project in browser",
+        Msg::CommandBranches            => "list branches",
+        Msg::CommandSchedules           => "list pipeline schedules",
+        Msg::CommandRunners             => "list runners",
+        Msg::CommandTogglePin           => "pin or unpin project",
+        Msg::CommandToggleWatch         => "watch or unwatch project",
+        Msg::CommandHideProject         => "hide project",
+        Msg::CommandShowHidden          => "show hidden projects",
+        Msg::CommandSearch              => "search projects, branches and jobs",
+        Msg::CommandFilterProjects      => "filter projects",
+        Msg::CommandCycleSort           => "cycle sort order of projects",
+        Msg::CommandOnlyFailed          => "show only projects with failed pipelines",
+        Msg::CommandOnlyActive          => "show only projects with running pipelines",
+        Msg::CommandToggleGroupedView   => "toggle projects grouped by namespace",
+        Msg::CommandLastNotification    => "show last notification",
+        Msg::CommandAuditLog            => "show audit log",
+        Msg::CommandApiInspector        => "show recent api requests",
+        Msg::CommandProjectErrors       => "show recent failed fetches per project",
+        Msg::CommandInternalLogs        => "toggle internal logs",
+        Msg::CommandRefresh             => "refresh projects",
+        Msg::CommandRefreshPipelines    => "refresh pipelines of project",
+        Msg::CommandConfig              => "edit configuration",
+        Msg::CommandProfileMemory       => "profile memory usage",
+        Msg::CommandToggleColorDepth    => "toggle 256 colors",
+        Msg::CommandToggleRelativeTimes => "toggle relative times",
+        Msg::CommandToggleRecording     => "record session",
+        Msg::CommandScreenshot          => "save screenshot",
+        Msg::CommandHelp                => "show keybindings",
+        Msg::CommandQuit                => "quit",
+        Msg::JobsPaneTitle              => "jobs",
+        Msg::CommitPaneTitle            => "commit",
+        Msg::JobDurationsPaneTitle      => "job durations",
+        Msg::DurationHistoryPaneTitle   => "duration history of",
+        Msg::DurationAverage            => "avg ",
+        Msg::DurationSlowest            => "slowest ",
+        Msg::DurationRuns               => "runs",
+        Msg::NoDurationHistory          => "no finished pipelines on this branch yet",
+
+        Msg::OnboardingStepInstance     => "gitlab instance",
+        Msg::OnboardingStepToken        => "access token",
+        Msg::OnboardingStepFilter       => "project filter",
+        Msg::OnboardingStepPolling      => "polling",
+        Msg::OnboardingStepHeading      => "step {step} of {steps} · {label}",
+        Msg::OnboardingInstanceHelp     => "base url of the gitlab api, e.g.\n`https://gitlab.example.com/api/v4`",
+        Msg::OnboardingTokenHelp        => "personal access token, created under `preferences › access tokens`\n`read_api` is enough to monitor pipelines; `api` is required to\nretry jobs and start pipelines.\nenter validates the token against the instance, enter again continues.",
+        Msg::OnboardingFilterHelp       => "optional project filter, applied to the project namespace;\nleave empty to list all projects you are a member of.",
+        Msg::OnboardingPollingHelp      => "seconds between polls of the projects and of the active jobs; at least `{min}`\nthe configuration is written once this step is completed.",
+        Msg::OnboardingTokenValid       => "✓ token valid",
+        Msg::OnboardingTokenValidScopes => "✓ token valid; scopes: {scopes}",
+        Msg::OnboardingUrlRequired      => "gitlab url is required",
+        Msg::OnboardingTokenLacksScope  => "token lacks the read_api scope; scopes: {scopes}",
+        Msg::OnboardingInvalidInterval  => "poll interval must be a number of seconds, at least {min}",
+
+        Msg::ConfigGitlabUrl            => "gitlab url",
+        Msg::ConfigGitlabToken          => "gitlab token",
+        Msg::ConfigSearchFilter         => "search filter",
+        Msg::ConfigProxy                => "proxy",
+        Msg::ConfigNoProxy              => "no proxy",
+        Msg::ConfigAnimations           => "animations",
+        Msg::ConfigLogLevel             => "log level",
+        Msg::ConfigUrlHelp              => "base url of the gitlab instance, e.g. `https://mygitlab.com/api/v4`",
+        Msg::ConfigTokenHelp            => "`personal access token` for the gitlab api; scoped to `read_api`",
+        Msg::ConfigTokenExpiresIn       => " · expires in {days} days",
+        Msg::ConfigProxyHelp            => "optional http or socks5 proxy, e.g. `http://proxy.example.com:3128`",
+        Msg::ConfigNoProxyHelp          => "comma-separated hosts and domains reached without the proxy",
+        Msg::ConfigAnimationsHelp       => "comma-separated enabled animations, e.g. `popup_transitions, glitch`",
+        Msg::ConfigLogLevelHelp         => "level of the internal logs, applied immediately: `error, info or debug`",
+        Msg::ConfigFilterHelp           => "optional project filter, applied to project namespace",
+        Msg::ProjectCommits             => "commits",
+        Msg::ProjectSizeInRepository    => "in repository",
+        Msg::ProjectSizeInArtifacts     => "in artifacts",
+
+        Msg::NotifyPipelineFailed       => "{project}: pipeline #{pipeline} on {branch} failed",
+        Msg::NotifyDesktopFailed        => "pipeline #{pipeline} on {branch} failed",
+        Msg::NotifyDesktopSucceeded     => "pipeline #{pipeline} on {branch} succeeded",
+        Msg::NotifyWebhookFailed        => "{icon} {project}: pipeline on `{branch}` failed <{url}>",
+        Msg::NotifyWebhookRecovered     => "{icon} {project}: pipeline on `{branch}` recovered <{url}>",
+        Msg::NotifyWebhookTest          => "glim webhook test message",
+
+        Msg::SearchKindProject          => "project",
+        Msg::SearchKindBranch           => "branch",
+        Msg::SearchKindCommit           => "commit",
+        Msg::SearchKindJob              => "job",
+        Msg::SearchKindLog              => "log",
+    }
+}
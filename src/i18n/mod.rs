@@ -0,0 +1,335 @@
+//! Message catalog for user-facing strings.
+//!
+//! Strings are looked up with [t] for the locale set via [set_locale]. Adding
+//! a locale amounts to adding a [Locale] variant and a catalog module with an
+//! entry for every [Msg].
+
+mod en;
+mod sv;
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum Locale {
+    #[default]
+    En,
+    Sv,
+}
+
+/// Keys of all translatable messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // window titles
+    TitleGitlabPipelines,
+    TabProjects,
+    TabPipelines,
+    TabFailedJobs,
+    TabMultiProject,
+    TabMergeRequests,
+    TabRunners,
+    TabLogs,
+    TabNotAvailable,
+    TabRunnersHint,
+    TitleRecentPipelines,
+    TitleFailedJobs,
+    TitleMultiProject,
+    NoMultiProjectPipelines,
+    TitleInternalLogs,
+    TitleFollowingPipeline,
+    BannerGitlabUnavailable,
+    BannerOffline,
+    SelectionAnnouncement,
+    SelectionNoPipelines,
+    TimeAgo,
+    StatusPolledAgo,
+    StatusNotPolled,
+    StatusActivePipelines,
+    StatusFilter,
+    StatusRecording,
+    StatusTokenExpires,
+    StatusTokenScopes,
+    TitleConfiguration,
+    TitleOnboarding,
+    TitleProjectDetails,
+    TitlePipelineActions,
+    TitleAuditLog,
+    TitleApiInspector,
+    TitleProjectErrors,
+    TitleJobLog,
+    TitleJobActions,
+    TitlePipelineGraph,
+    TitleSearch,
+    TitleCommands,
+    TitleHelp,
+    HelpModeProjects,
+    HelpModeProjectDetails,
+    HelpModePipelineActions,
+    HelpModeConfig,
+    HelpModeSearch,
+    HelpModeProjectFilter,
+    HelpModeCommandPalette,
+    TitleBranches,
+    TitleSchedules,
+    TitleRunners,
+    TitleArtifacts,
+
+    // shortcut labels
+    ShortcutQuit,
+    ShortcutOpenWeb,
+    ShortcutConfig,
+    ShortcutPin,
+    ShortcutWatch,
+    ShortcutWatchBranch,
+    ShortcutHide,
+    ShortcutShowHidden,
+    ShortcutSearch,
+    ShortcutFilter,
+    ShortcutClearFilter,
+    ShortcutSort,
+    ShortcutGroupedView,
+    ShortcutFlatView,
+    ShortcutCollapse,
+    ShortcutExpand,
+    ShortcutCollapseExpand,
+    ShortcutTab,
+    SortLastActivity,
+    SortName,
+    SortStatus,
+    QuickFilterFailed,
+    QuickFilterActive,
+    ShortcutCommands,
+    ShortcutHelp,
+    ShortcutResize,
+    ShortcutLastNotification,
+    ShortcutHistory,
+    ShortcutLogs,
+    ShortcutRefresh,
+    ShortcutPipelineRefresh,
+    ShortcutSelection,
+    ShortcutDetails,
+    ShortcutClose,
+    ShortcutApply,
+    ShortcutNext,
+    ShortcutBack,
+    ShortcutActions,
+    ShortcutToggleStage,
+    ShortcutOlderPipelines,
+    ShortcutNewerPipelines,
+    ShortcutFocus,
+    ShortcutCommitDetails,
+    ShortcutJobDurations,
+    ShortcutDurationHistory,
+    ShortcutCopySha,
+    ShortcutCopyCloneUrl,
+    ShortcutCopyCloneCommand,
+    ShortcutCopyPipelineUrl,
+    ShortcutCopyJobUrl,
+    ShortcutCopyBranchName,
+    ShortcutOpenClone,
+    ShortcutScroll,
+    ShortcutScrollBody,
+    ShortcutFold,
+    ShortcutFoldAll,
+    ShortcutZoom,
+    ShortcutJump,
+    ShortcutTestWebhook,
+    ShortcutBranches,
+    ShortcutTriggerPipeline,
+    ShortcutSchedules,
+    ShortcutRunners,
+    ShortcutDownload,
+    ShortcutRunSchedule,
+
+    // pipeline actions
+    ActionViewJobLog,
+    ActionViewLog,
+    ActionCreateIssue,
+    ActionViewStageGraph,
+    ActionBrowseToFailedJob,
+    ActionBrowseToJob,
+    ActionRetryJob,
+    ActionRunSchedule,
+    ActionBrowseToPipeline,
+    ActionBrowseToCommit,
+    ActionBrowseToProject,
+    ActionDownloadErrorLog,
+    ActionFollowPipeline,
+    ActionUnfollowPipeline,
+    ActionWaitForPipeline,
+    ActionBrowseArtifacts,
+    ActionCopyPipelineUrl,
+    ActionCopyJobUrl,
+    ActionCopyFailedJobUrl,
+    ActionCopyBranchName,
+
+    // notices
+    NoticeUnknownProject,
+    NoticeJobLogDownloaded,
+    NoticeConfigError,
+    NoticeRetriesExhausted,
+    NoticeJsonParseFailed,
+    NoticeGetJobsFailed,
+    NoticeGetTriggerJobsFailed,
+    NoticeGetPipelinesFailed,
+    NoticeJobLogDownloadedFor,
+    NoticeIssueCreated,
+    NoticeJobRetried,
+    NoticePipelineTriggered,
+    NoticeSchedulePlayed,
+    NoticeArtifactsDownloaded,
+    NoticeRecordingSaved,
+    NoticeScreenshotSaved,
+    NoticeMemoryUsage,
+    FetchStaleSince,
+    NoticeCopiedToClipboard,
+    NoticeTokenExpiring,
+    NoticeUnsupportedFeature,
+    NoticeReadOnly,
+    NoticeTokenMissingApiScope,
+    NoticeTokenBroadScopes,
+    FeatureTokenDetails,
+    FeatureTriggerJobs,
+    NoticeWatchFailed,
+    NoticeWatchSucceeded,
+    NoticeWatchRecovered,
+    PipelineApprovals,
+
+    // misc
+    NoActionsRecorded,
+    NoRequestsRecorded,
+    NoProjectErrors,
+    LoadingJobLog,
+    JobLogUnavailable,
+    LoadingJobs,
+    LoadingCommit,
+    PipelinePage,
+    LoadingOlderPipelines,
+    LoadingBranches,
+    LoadingPipelines,
+    PipelineGone,
+    LoadingSchedules,
+    NoSchedules,
+    ScheduleInactive,
+    LoadingRunners,
+    NoRunners,
+    RunnersOnline,
+    RunnerPaused,
+    RunnerShared,
+    ArtifactsDownloadHint,
+    ArtifactsNoArchive,
+    NoSearchResults,
+    NoMatchingCommands,
+    CommandOpenDetails,
+    CommandBrowseToProject,
+    CommandBranches,
+    CommandSchedules,
+    CommandRunners,
+    CommandTogglePin,
+    CommandToggleWatch,
+    CommandHideProject,
+    CommandShowHidden,
+    CommandSearch,
+    CommandFilterProjects,
+    CommandCycleSort,
+    CommandOnlyFailed,
+    CommandOnlyActive,
+    CommandToggleGroupedView,
+    CommandLastNotification,
+    CommandAuditLog,
+    CommandApiInspector,
+    CommandProjectErrors,
+    CommandInternalLogs,
+    CommandRefresh,
+    CommandRefreshPipelines,
+    CommandConfig,
+    CommandProfileMemory,
+    CommandToggleColorDepth,
+    CommandToggleRelativeTimes,
+    CommandToggleRecording,
+    CommandScreenshot,
+    CommandHelp,
+    CommandQuit,
+    JobsPaneTitle,
+    CommitPaneTitle,
+    JobDurationsPaneTitle,
+    DurationHistoryPaneTitle,
+    DurationAverage,
+    DurationSlowest,
+    DurationRuns,
+    NoDurationHistory,
+
+    // onboarding; text enclosed in backticks is emphasized
+    OnboardingStepInstance,
+    OnboardingStepToken,
+    OnboardingStepFilter,
+    OnboardingStepPolling,
+    OnboardingStepHeading,
+    OnboardingInstanceHelp,
+    OnboardingTokenHelp,
+    OnboardingFilterHelp,
+    OnboardingPollingHelp,
+    OnboardingTokenValid,
+    OnboardingTokenValidScopes,
+    OnboardingUrlRequired,
+    OnboardingTokenLacksScope,
+    OnboardingInvalidInterval,
+
+    // configuration; text enclosed in backticks is emphasized
+    ConfigGitlabUrl,
+    ConfigGitlabToken,
+    ConfigSearchFilter,
+    ConfigProxy,
+    ConfigNoProxy,
+    ConfigAnimations,
+    ConfigLogLevel,
+    ConfigUrlHelp,
+    ConfigTokenHelp,
+    ConfigTokenExpiresIn,
+    ConfigProxyHelp,
+    ConfigNoProxyHelp,
+    ConfigAnimationsHelp,
+    ConfigLogLevelHelp,
+    ConfigFilterHelp,
+    ProjectCommits,
+    ProjectSizeInRepository,
+    ProjectSizeInArtifacts,
+
+    // notifications
+    NotifyPipelineFailed,
+    NotifyDesktopFailed,
+    NotifyDesktopSucceeded,
+    NotifyWebhookFailed,
+    NotifyWebhookRecovered,
+    NotifyWebhookTest,
+
+    // search result kinds
+    SearchKindProject,
+    SearchKindBranch,
+    SearchKindCommit,
+    SearchKindJob,
+    SearchKindLog,
+}
+
+static LOCALE: AtomicU8 = AtomicU8::new(Locale::En as u8);
+
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        l if l == Locale::Sv as u8 => Locale::Sv,
+        _                          => Locale::En,
+    }
+}
+
+/// Returns the message for the current locale.
+pub fn t(msg: Msg) -> &'static str {
+    match locale() {
+        Locale::En => en::message(msg),
+        Locale::Sv => sv::message(msg),
+    }
+}
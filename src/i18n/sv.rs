@@ -0,0 +1,286 @@
+use crate::i18n::Msg;
+
+pub(super) fn message(msg: Msg) -> &'static str {
+    match msg {
+        Msg::TitleGitlabPipelines       => "gitlab-pipelines",
+        Msg::TabProjects                => "projekt",
+        Msg::TabPipelines               => "pipelines",
+        Msg::TabFailedJobs              => "fallerade jobb",
+        Msg::TabMultiProject            => "flerprojekt",
+        Msg::TabMergeRequests           => "merge requests",
+        Msg::TabRunners                 => "runners",
+        Msg::TabLogs                    => "loggar",
+        Msg::TabNotAvailable            => "inte tillgängligt än",
+        Msg::TabRunnersHint             => "tryck u på ett projekt under projektfliken för att lista dess runners",
+        Msg::TitleRecentPipelines       => "pipelines · {active} aktiva",
+        Msg::TitleFailedJobs            => "fallerade jobb · {count}",
+        Msg::TitleMultiProject          => "flerprojektspipelines · {count}",
+        Msg::NoMultiProjectPipelines    => "inga pipelines med nedströmspipelines i andra projekt",
+        Msg::TitleInternalLogs          => "interna loggar",
+        Msg::TitleFollowingPipeline     => "följer pipeline",
+        Msg::BannerGitlabUnavailable    => "GitLab otillgängligt sedan {time}, hämtning sker mer sällan",
+        Msg::BannerOffline              => "offline — försöker igen om {seconds} s",
+        Msg::SelectionAnnouncement      => "markerat: {item}",
+        Msg::SelectionNoPipelines       => "inga pipelines",
+        Msg::TimeAgo                    => "{age} sedan",
+        Msg::StatusPolledAgo            => "hämtat för {time} sedan",
+        Msg::StatusNotPolled            => "ännu inte hämtat",
+        Msg::StatusActivePipelines      => "{count} aktiva pipelines",
+        Msg::StatusFilter               => "filter",
+        Msg::StatusRecording            => "● insp",
+        Msg::StatusTokenExpires         => "token går ut om {days} d",
+        Msg::StatusTokenScopes          => "⚠ token-behörigheter",
+        Msg::TitleConfiguration         => "konfiguration",
+        Msg::TitleOnboarding            => "välkommen till glim",
+        Msg::TitleProjectDetails        => "projektdetaljer",
+        Msg::TitlePipelineActions       => "pipelineåtgärder",
+        Msg::TitleAuditLog              => "granskningslogg",
+        Msg::TitleApiInspector          => "api-inspektör",
+        Msg::TitleProjectErrors         => "projektfel",
+        Msg::TitleJobLog                => "jobblogg",
+        Msg::TitleJobActions            => "jobbåtgärder",
+        Msg::TitlePipelineGraph         => "stegöversikt",
+        Msg::TitleSearch                => "sök",
+        Msg::TitleCommands              => "kommandon",
+        Msg::TitleHelp                  => "kortkommandon",
+        Msg::HelpModeProjects           => "projekt",
+        Msg::HelpModeProjectDetails     => "projektdetaljer",
+        Msg::HelpModePipelineActions    => "pipelineåtgärder",
+        Msg::HelpModeConfig             => "konfiguration",
+        Msg::HelpModeSearch             => "sök",
+        Msg::HelpModeProjectFilter      => "projektfilter",
+        Msg::HelpModeCommandPalette     => "kommandopalett",
+        Msg::TitleBranches              => "grenar",
+        Msg::TitleSchedules             => "schemalagda pipelines",
+        Msg::TitleRunners               => "runners",
+        Msg::TitleArtifacts             => "jobbartefakter",
+
+        Msg::ShortcutQuit               => "avsluta",
+        Msg::ShortcutOpenWeb            => "öppna webb",
+        Msg::ShortcutConfig             => "inställningar",
+        Msg::ShortcutPin                => "fäst",
+        Msg::ShortcutWatch              => "bevaka",
+        Msg::ShortcutWatchBranch        => "bevaka gren",
+        Msg::ShortcutHide               => "dölj",
+        Msg::ShortcutShowHidden         => "dolda",
+        Msg::ShortcutSearch             => "sök",
+        Msg::ShortcutFilter             => "filtrera",
+        Msg::ShortcutClearFilter        => "rensa filter",
+        Msg::ShortcutSort               => "sortera",
+        Msg::ShortcutGroupedView        => "grupperad",
+        Msg::ShortcutFlatView           => "platt",
+        Msg::ShortcutCollapse           => "fäll ihop grupp",
+        Msg::ShortcutExpand             => "fäll ut grupp",
+        Msg::ShortcutCollapseExpand     => "fäll ihop/ut",
+        Msg::ShortcutTab                => "flik",
+        Msg::SortLastActivity           => "senaste aktivitet",
+        Msg::SortName                   => "namn",
+        Msg::SortStatus                 => "pipelinestatus",
+        Msg::QuickFilterFailed          => "misslyckade",
+        Msg::QuickFilterActive          => "pågående",
+        Msg::ShortcutCommands           => "kommandon",
+        Msg::ShortcutHelp               => "hjälp",
+        Msg::ShortcutResize             => "ändra storlek",
+        Msg::ShortcutLastNotification   => "senaste notis",
+        Msg::ShortcutHistory            => "historik",
+        Msg::ShortcutLogs               => "loggar",
+        Msg::ShortcutRefresh            => "uppdatera",
+        Msg::ShortcutPipelineRefresh    => "uppdatera pipelines",
+        Msg::ShortcutSelection          => "markering",
+        Msg::ShortcutDetails            => "detaljer",
+        Msg::ShortcutClose              => "stäng",
+        Msg::ShortcutApply              => "verkställ",
+        Msg::ShortcutNext               => "nästa",
+        Msg::ShortcutBack               => "tillbaka",
+        Msg::ShortcutActions            => "åtgärder...",
+        Msg::ShortcutToggleStage        => "fäll ihop steg",
+        Msg::ShortcutOlderPipelines     => "äldre pipelines",
+        Msg::ShortcutNewerPipelines     => "nyare pipelines",
+        Msg::ShortcutFocus              => "fokus",
+        Msg::ShortcutCommitDetails      => "commit",
+        Msg::ShortcutJobDurations       => "tidsåtgång",
+        Msg::ShortcutDurationHistory    => "historik",
+        Msg::ShortcutCopySha            => "kopiera sha",
+        Msg::ShortcutCopyCloneUrl       => "kopiera klon-url",
+        Msg::ShortcutCopyCloneCommand   => "kopiera git clone",
+        Msg::ShortcutCopyPipelineUrl    => "kopiera URL",
+        Msg::ShortcutCopyJobUrl         => "kopiera jobb-URL",
+        Msg::ShortcutCopyBranchName     => "kopiera gren",
+        Msg::ShortcutOpenClone          => "öppna klon",
+        Msg::ShortcutScroll             => "rulla",
+        Msg::ShortcutScrollBody         => "rulla innehåll",
+        Msg::ShortcutFold               => "fäll",
+        Msg::ShortcutFoldAll            => "fäll alla",
+        Msg::ShortcutZoom               => "zooma",
+        Msg::ShortcutJump               => "gå till",
+        Msg::ShortcutTestWebhook        => "testa webhook",
+        Msg::ShortcutBranches           => "grenar",
+        Msg::ShortcutTriggerPipeline    => "kör pipeline",
+        Msg::ShortcutSchedules          => "scheman",
+        Msg::ShortcutRunners            => "runners",
+        Msg::ShortcutDownload           => "ladda ner",
+        Msg::ShortcutRunSchedule        => "kör nu",
+
+        Msg::ActionViewJobLog           => "visa logg för misslyckat jobb",
+        Msg::ActionViewLog              => "visa jobblogg",
+        Msg::ActionCreateIssue          => "skapa ärende för felet",
+        Msg::ActionViewStageGraph       => "visa stegöversikt",
+        Msg::ActionBrowseToFailedJob    => "öppna misslyckat jobb",
+        Msg::ActionBrowseToJob          => "öppna jobb",
+        Msg::ActionRetryJob             => "kör om jobb",
+        Msg::ActionRunSchedule          => "köra pipelineschema",
+        Msg::ActionBrowseToPipeline     => "öppna pipeline",
+        Msg::ActionBrowseToCommit       => "öppna commitens diff",
+        Msg::ActionBrowseToProject      => "öppna projekt",
+        Msg::ActionDownloadErrorLog     => "kopiera logg för misslyckat jobb",
+        Msg::ActionFollowPipeline       => "följ pipeline tills den är klar",
+        Msg::ActionUnfollowPipeline     => "sluta följa pipeline",
+        Msg::ActionWaitForPipeline      => "vänta tills den är klar, avsluta sedan",
+        Msg::ActionBrowseArtifacts      => "visa artefakter",
+        Msg::ActionCopyPipelineUrl      => "kopiera pipeline-URL",
+        Msg::ActionCopyJobUrl           => "kopiera jobb-URL",
+        Msg::ActionCopyFailedJobUrl     => "kopiera URL till misslyckat jobb",
+        Msg::ActionCopyBranchName       => "kopiera grennamn",
+
+        Msg::NoticeUnknownProject       => "<okänt projekt>",
+        Msg::NoticeJobLogDownloaded     => "Jobbloggen har laddats ner",
+        Msg::NoticeConfigError          => "Konfigurationsfel: ",
+        Msg::NoticeRetriesExhausted     => "Gav upp efter {retries} försök: ",
+        Msg::NoticeJsonParseFailed      => "Kunde inte tolka JSON",
+        Msg::NoticeGetJobsFailed        => "Kunde inte hämta jobb för ",
+        Msg::NoticeGetTriggerJobsFailed => "Kunde inte hämta triggerjobb för ",
+        Msg::NoticeGetPipelinesFailed   => "Kunde inte hämta pipelines för ",
+        Msg::NoticeJobLogDownloadedFor  => "Jobbloggen har laddats ner för ",
+        Msg::NoticeIssueCreated         => "Ärende skapat: ",
+        Msg::NoticeJobRetried           => "Kör om jobb ",
+        Msg::NoticePipelineTriggered    => "Pipeline startad på ",
+        Msg::NoticeSchedulePlayed       => "Kör schemalagd pipeline ",
+        Msg::NoticeArtifactsDownloaded  => "Artefakter sparade i ",
+        Msg::NoticeRecordingSaved       => "Inspelning sparad i ",
+        Msg::NoticeScreenshotSaved      => "Skärmbild sparad i ",
+        Msg::NoticeMemoryUsage          => "Minnesanvändning ",
+        Msg::FetchStaleSince            => "inaktuell sedan ",
+        Msg::NoticeCopiedToClipboard    => "Kopierat till urklipp: ",
+        Msg::NoticeTokenExpiring        => "GitLab-token går ut om {days} dagar, den ",
+        Msg::NoticeUnsupportedFeature   => "{host} kör GitLab {version}: {feature} kräver GitLab {required}",
+        Msg::NoticeReadOnly             => "Skrivskyddat läge: {action} kräver en token med api-behörighet",
+        Msg::NoticeTokenMissingApiScope => "Token saknar api-behörigheten som krävs för att köra om jobb och starta pipelines; sätt read_only = true för att dölja dessa åtgärder",
+        Msg::NoticeTokenBroadScopes     => "Token har behörigheter som glim inte behöver: {scopes}",
+        Msg::FeatureTokenDetails        => "varningar om tokens utgång",
+        Msg::FeatureTriggerJobs         => "triggerjobb",
+        Msg::NoticeWatchFailed          => "pipeline misslyckades",
+        Msg::NoticeWatchSucceeded       => "pipeline lyckades",
+        Msg::NoticeWatchRecovered       => "grön igen",
+        Msg::PipelineApprovals          => "godkännanden",
+
+        Msg::NoActionsRecorded          => "inga åtgärder registrerade",
+        Msg::NoRequestsRecorded         => "inga anrop registrerade; sätt GLIM_DEBUG för att registrera anrop",
+        Msg::NoProjectErrors            => "inga misslyckade hämtningar",
+        Msg::LoadingJobLog              => "laddar jobblogg...",
+        Msg::JobLogUnavailable          => "kunde inte ladda jobbloggen: ",
+        Msg::LoadingJobs                => "laddar jobb...",
+        Msg::LoadingCommit              => "laddar commit...",
+        Msg::PipelinePage               => "sida {page}",
+        Msg::LoadingOlderPipelines      => "laddar äldre pipelines...",
+        Msg::LoadingBranches            => "laddar grenar...",
+        Msg::LoadingPipelines           => "laddar pipelines...",
+        Msg::PipelineGone               => "borttagen",
+        Msg::LoadingSchedules           => "laddar scheman...",
+        Msg::NoSchedules                => "inga schemalagda pipelines",
+        Msg::ScheduleInactive           => "inaktiv",
+        Msg::LoadingRunners             => "laddar runners...",
+        Msg::NoRunners                  => "inga tillgängliga runners",
+        Msg::RunnersOnline              => "online",
+        Msg::RunnerPaused               => "pausad",
+        Msg::RunnerShared               => "(delad)",
+        Msg::ArtifactsDownloadHint      => "tryck d för att ladda ner artefaktarkivet",
+        Msg::ArtifactsNoArchive         => "inget nedladdningsbart arkiv",
+        Msg::NoSearchResults            => "inga träffar",
+        Msg::NoMatchingCommands         => "inga matchande kommandon",
+        Msg::CommandOpenDetails         => "visa projektdetaljer",
+        Msg::CommandBrowseToProject     => "öppna projektet i webbläsaren",
+        Msg::CommandBranches            => "lista grenar",
+        Msg::CommandSchedules           => "lista pipeline-scheman",
+        Msg::CommandRunners             => "lista runners",
+        Msg::CommandTogglePin           => "fäst eller lossa projektet",
+        Msg::CommandToggleWatch         => "bevaka projektet eller sluta bevaka",
+        Msg::CommandHideProject         => "dölj projektet",
+        Msg::CommandShowHidden          => "visa dolda projekt",
+        Msg::CommandSearch              => "sök projekt, grenar och jobb",
+        Msg::CommandFilterProjects      => "filtrera projekt",
+        Msg::CommandCycleSort           => "växla sorteringsordning för projekt",
+        Msg::CommandOnlyFailed          => "visa endast projekt med misslyckade pipelines",
+        Msg::CommandOnlyActive          => "visa endast projekt med pågående pipelines",
+        Msg::CommandToggleGroupedView   => "växla projekt grupperade efter namnrymd",
+        Msg::CommandLastNotification    => "visa senaste notisen",
+        Msg::CommandAuditLog            => "visa granskningsloggen",
+        Msg::CommandApiInspector        => "visa senaste api-anrop",
+        Msg::CommandProjectErrors       => "visa senaste misslyckade hämtningar per projekt",
+        Msg::CommandInternalLogs        => "visa eller dölj interna loggar",
+        Msg::CommandRefresh             => "uppdatera projekt",
+        Msg::CommandRefreshPipelines    => "uppdatera projektets pipelines",
+        Msg::CommandConfig              => "redigera konfigurationen",
+        Msg::CommandProfileMemory       => "mät minnesanvändning",
+        Msg::CommandToggleColorDepth    => "växla 256 färger",
+        Msg::CommandToggleRelativeTimes => "växla relativa tider",
+        Msg::CommandToggleRecording     => "spela in sessionen",
+        Msg::CommandScreenshot          => "spara skärmbild",
+        Msg::CommandHelp                => "visa kortkommandon",
+        Msg::CommandQuit                => "avsluta",
+        Msg::JobsPaneTitle              => "jobb",
+        Msg::CommitPaneTitle            => "commit",
+        Msg::JobDurationsPaneTitle      => "jobbens körtider",
+        Msg::DurationHistoryPaneTitle   => "körtidshistorik för",
+        Msg::DurationAverage            => "snitt ",
+        Msg::DurationSlowest            => "långsammast ",
+        Msg::DurationRuns               => "körningar",
+        Msg::NoDurationHistory          => "inga avslutade pipelines på grenen ännu",
+
+        Msg::OnboardingStepInstance     => "gitlab-instans",
+        Msg::OnboardingStepToken        => "åtkomsttoken",
+        Msg::OnboardingStepFilter       => "projektfilter",
+        Msg::OnboardingStepPolling      => "hämtning",
+        Msg::OnboardingStepHeading      => "steg {step} av {steps} · {label}",
+        Msg::OnboardingInstanceHelp     => "bas-url till gitlabs api, t.ex.\n`https://gitlab.example.com/api/v4`",
+        Msg::OnboardingTokenHelp        => "personlig åtkomsttoken, skapas under `preferences › access tokens`\n`read_api` räcker för att följa pipelines; `api` krävs för att\nköra om jobb och starta pipelines.\nenter validerar token mot instansen, enter igen fortsätter.",
+        Msg::OnboardingFilterHelp       => "valfritt projektfilter, tillämpat på projektets namnrymd;\nlämna tomt för att lista alla projekt du är medlem i.",
+        Msg::OnboardingPollingHelp      => "sekunder mellan hämtningar av projekten och de aktiva jobben; minst `{min}`\nkonfigurationen skrivs när det här steget är klart.",
+        Msg::OnboardingTokenValid       => "✓ token giltig",
+        Msg::OnboardingTokenValidScopes => "✓ token giltig; scopes: {scopes}",
+        Msg::OnboardingUrlRequired      => "gitlab-url krävs",
+        Msg::OnboardingTokenLacksScope  => "token saknar scopet read_api; scopes: {scopes}",
+        Msg::OnboardingInvalidInterval  => "hämtningsintervallet måste vara ett antal sekunder, minst {min}",
+
+        Msg::ConfigGitlabUrl            => "gitlab-url",
+        Msg::ConfigGitlabToken          => "gitlab-token",
+        Msg::ConfigSearchFilter         => "sökfilter",
+        Msg::ConfigProxy                => "proxy",
+        Msg::ConfigNoProxy              => "utan proxy",
+        Msg::ConfigAnimations           => "animationer",
+        Msg::ConfigLogLevel             => "loggnivå",
+        Msg::ConfigUrlHelp              => "bas-url till gitlab-instansen, t.ex. `https://mygitlab.com/api/v4`",
+        Msg::ConfigTokenHelp            => "`personlig åtkomsttoken` för gitlabs api; med scopet `read_api`",
+        Msg::ConfigTokenExpiresIn       => " · går ut om {days} dagar",
+        Msg::ConfigProxyHelp            => "valfri http- eller socks5-proxy, t.ex. `http://proxy.example.com:3128`",
+        Msg::ConfigNoProxyHelp          => "kommaseparerade värdar och domäner som nås utan proxyn",
+        Msg::ConfigAnimationsHelp       => "kommaseparerade aktiverade animationer, t.ex. `popup_transitions, glitch`",
+        Msg::ConfigLogLevelHelp         => "nivå för de interna loggarna, tillämpas direkt: `error, info eller debug`",
+        Msg::ConfigFilterHelp           => "valfritt projektfilter, tillämpat på projektets namnrymd",
+        Msg::ProjectCommits             => "commits",
+        Msg::ProjectSizeInRepository    => "i repositoryt",
+        Msg::ProjectSizeInArtifacts     => "i artefakter",
+
+        Msg::NotifyPipelineFailed       => "{project}: pipeline #{pipeline} på {branch} fallerade",
+        Msg::NotifyDesktopFailed        => "pipeline #{pipeline} på {branch} fallerade",
+        Msg::NotifyDesktopSucceeded     => "pipeline #{pipeline} på {branch} lyckades",
+        Msg::NotifyWebhookFailed        => "{icon} {project}: pipeline på `{branch}` fallerade <{url}>",
+        Msg::NotifyWebhookRecovered     => "{icon} {project}: pipeline på `{branch}` återhämtade sig <{url}>",
+        Msg::NotifyWebhookTest          => "glim webhook-testmeddelande",
+
+        Msg::SearchKindProject          => "projekt",
+        Msg::SearchKindBranch           => "gren",
+        Msg::SearchKindCommit           => "commit",
+        Msg::SearchKindJob              => "jobb",
+        Msg::SearchKindLog              => "logg",
+    }
+}
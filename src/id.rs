@@ -1,38 +1,90 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+/// Namespaced by the GitLab instance, like [ProjectId].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct JobId {
     value: u32,
+    instance: u8,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+/// Ids are namespaced by the index of the GitLab instance the entity
+/// belongs to, as ids are only unique within an instance.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct ProjectId {
     value: u32,
+    instance: u8,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+/// Namespaced by the GitLab instance, like [ProjectId].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct PipelineId {
     value: u32,
+    instance: u8,
 }
 
 impl ProjectId {
-    pub fn new(id: u32) -> Self { Self { value: id } }
+    pub fn new(id: u32) -> Self { Self { value: id, instance: 0 } }
+
+    pub fn with_instance(self, instance: u8) -> Self { Self { instance, ..self } }
+
+    /// Index of the GitLab instance; 0 for the primary instance.
+    pub fn instance(&self) -> u8 { self.instance }
 }
 
 impl PipelineId {
-    pub fn new(id: u32) -> Self { Self { value: id } }
+    pub fn new(id: u32) -> Self { Self { value: id, instance: 0 } }
+
+    pub fn with_instance(self, instance: u8) -> Self { Self { instance, ..self } }
+
+    /// Index of the GitLab instance; 0 for the primary instance.
+    pub fn instance(&self) -> u8 { self.instance }
 }
 
 impl JobId {
-    pub fn new(id: u32) -> Self { Self { value: id } }
+    pub fn new(id: u32) -> Self { Self { value: id, instance: 0 } }
+
+    pub fn with_instance(self, instance: u8) -> Self { Self { instance, ..self } }
+
+    /// Index of the GitLab instance; 0 for the primary instance.
+    pub fn instance(&self) -> u8 { self.instance }
+}
+
+/// Accepts both plain ids, as returned by the GitLab API, and ids
+/// namespaced as `"{instance}:{id}"`; returns the instance and the id.
+fn deserialize_namespaced<'de, D>(deserializer: D, kind: &str) -> Result<(u8, u32), D::Error>
+    where D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Id(u32),
+        Namespaced(String),
+    }
+
+    match Repr::deserialize(deserializer)? {
+        Repr::Id(id) => Ok((0, id)),
+        Repr::Namespaced(s) => s.split_once(':')
+            .and_then(|(instance, id)| Some((instance.parse().ok()?, id.parse().ok()?)))
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid {kind} id: {s}"))),
+    }
+}
+
+/// Ids of the primary instance are serialized as plain ids.
+fn serialize_namespaced<S>(serializer: S, instance: u8, id: u32) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+{
+    match instance {
+        0        => id.serialize(serializer),
+        instance => format!("{instance}:{id}").serialize(serializer),
+    }
 }
 
 impl<'de> Deserialize<'de> for ProjectId {
     fn deserialize<D>(deserializer: D) -> Result<ProjectId, D::Error>
         where D: Deserializer<'de>,
     {
-        let id = u32::deserialize(deserializer)?;
-        Ok(ProjectId::new(id))
+        let (instance, id) = deserialize_namespaced(deserializer, "project")?;
+        Ok(ProjectId::new(id).with_instance(instance))
     }
 }
 
@@ -40,8 +92,8 @@ impl<'de> Deserialize<'de> for PipelineId {
     fn deserialize<D>(deserializer: D) -> Result<PipelineId, D::Error>
         where D: Deserializer<'de>,
     {
-        let id = u32::deserialize(deserializer)?;
-        Ok(PipelineId::new(id))
+        let (instance, id) = deserialize_namespaced(deserializer, "pipeline")?;
+        Ok(PipelineId::new(id).with_instance(instance))
     }
 }
 
@@ -49,8 +101,32 @@ impl<'de> Deserialize<'de> for JobId {
     fn deserialize<D>(deserializer: D) -> Result<JobId, D::Error>
         where D: Deserializer<'de>,
     {
-        let id = u32::deserialize(deserializer)?;
-        Ok(JobId::new(id))
+        let (instance, id) = deserialize_namespaced(deserializer, "job")?;
+        Ok(JobId::new(id).with_instance(instance))
+    }
+}
+
+impl Serialize for JobId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        serialize_namespaced(serializer, self.instance, self.value)
+    }
+}
+
+impl Serialize for PipelineId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        serialize_namespaced(serializer, self.instance, self.value)
+    }
+}
+
+impl Serialize for ProjectId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        serialize_namespaced(serializer, self.instance, self.value)
     }
 }
 
@@ -71,4 +147,4 @@ impl std::fmt::Display for JobId {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.value)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,67 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::i18n::{t, Msg};
+
+/// A key of an input mode, bound to an action of its processor. Processors
+/// resolve keys through their bindings, which are also listed by the help
+/// popup, so that the two cannot drift apart.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyBinding<A> {
+    pub code: KeyCode,
+    pub ctrl: bool,
+    pub action: A,
+    pub description: Msg,
+}
+
+impl<A: Copy> KeyBinding<A> {
+    pub const fn new(code: KeyCode, action: A, description: Msg) -> Self {
+        Self { code, ctrl: false, action, description }
+    }
+
+    pub const fn ctrl(code: KeyCode, action: A, description: Msg) -> Self {
+        Self { code, ctrl: true, action, description }
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        self.code == event.code && self.ctrl == event.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// The key as shown to the user, e.g. "^f".
+    pub fn key(&self) -> String {
+        key_label(self.code, self.ctrl)
+    }
+
+    /// The key and description of the binding, without the action.
+    pub fn describe(&self) -> (String, &'static str) {
+        (self.key(), t(self.description))
+    }
+}
+
+/// Returns the action bound to the key, if any.
+pub fn find_action<A: Copy>(bindings: &[KeyBinding<A>], event: &KeyEvent) -> Option<A> {
+    bindings.iter()
+        .find(|binding| binding.matches(event))
+        .map(|binding| binding.action)
+}
+
+/// Formats a key as shown in shortcuts, e.g. "↵" or "^p".
+fn key_label(code: KeyCode, ctrl: bool) -> String {
+    let key = match code {
+        KeyCode::Enter     => "↵".to_string(),
+        KeyCode::Esc       => "ESC".to_string(),
+        KeyCode::Tab       => "TAB".to_string(),
+        KeyCode::BackTab   => "⇧TAB".to_string(),
+        KeyCode::Up        => "↑".to_string(),
+        KeyCode::Down      => "↓".to_string(),
+        KeyCode::Left      => "←".to_string(),
+        KeyCode::Right     => "→".to_string(),
+        KeyCode::PageUp    => "PgUp".to_string(),
+        KeyCode::PageDown  => "PgDn".to_string(),
+        KeyCode::Char(' ') => "SPACE".to_string(),
+        KeyCode::Char(c)   => c.to_string(),
+        KeyCode::F(n)      => format!("F{n}"),
+        code               => format!("{code:?}"),
+    };
+
+    if ctrl { format!("^{key}") } else { key }
+}
@@ -1,4 +1,5 @@
 pub mod processor;
+pub mod keymap;
 mod input_processor;
 mod multiplexer;
 
@@ -1,7 +1,7 @@
 use std::sync::mpsc::Sender;
 use crate::event::GlimEvent;
 use crate::input::InputProcessor;
-use crate::input::processor::{ConfigProcessor, PipelineActionsProcessor, ProjectDetailsProcessor};
+use crate::input::processor::{ApiInspectorProcessor, ProjectErrorsProcessor, ArtifactsProcessor, AuditLogProcessor, BranchesProcessor, RunnersProcessor, SchedulesProcessor, CommandPaletteProcessor, ConfigProcessor, HelpProcessor, ProjectFilterProcessor, JobLogProcessor, PipelineActionsProcessor, PipelineGraphProcessor, ProjectDetailsProcessor, SearchProcessor};
 use crate::ui::StatefulWidgets;
 
 pub struct InputMultiplexer {
@@ -43,7 +43,7 @@ impl InputMultiplexer {
             GlimEvent::CloseProjectDetails => self.pop_processor(),
 
             // pipeline actions popup
-            GlimEvent::OpenPipelineActions(_, _) => {
+            GlimEvent::OpenPipelineActions(_, _) | GlimEvent::OpenJobActions(_, _, _) => {
                 self.push(Box::new(PipelineActionsProcessor::new(self.sender.clone())));
             },
             GlimEvent::ClosePipelineActions => self.pop_processor(),
@@ -53,6 +53,80 @@ impl InputMultiplexer {
                 self.push(Box::new(ConfigProcessor::new(self.sender.clone())));
             },
             GlimEvent::CloseConfig => self.pop_processor(),
+
+            // audit log
+            GlimEvent::DisplayAuditLog => {
+                self.push(Box::new(AuditLogProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseAuditLog => self.pop_processor(),
+
+            // api inspector
+            GlimEvent::DisplayApiInspector => {
+                self.push(Box::new(ApiInspectorProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseApiInspector => self.pop_processor(),
+
+            // project errors
+            GlimEvent::DisplayProjectErrors => {
+                self.push(Box::new(ProjectErrorsProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseProjectErrors => self.pop_processor(),
+
+            // job log
+            GlimEvent::OpenJobLog(_, _, _) => {
+                self.push(Box::new(JobLogProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseJobLog => self.pop_processor(),
+
+            // pipeline stage graph
+            GlimEvent::OpenPipelineGraph(_, _) => {
+                self.push(Box::new(PipelineGraphProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::ClosePipelineGraph => self.pop_processor(),
+
+            // branch browser
+            GlimEvent::OpenBranches(_) => {
+                self.push(Box::new(BranchesProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseBranches => self.pop_processor(),
+
+            // pipeline schedules
+            GlimEvent::OpenSchedules(_) => {
+                self.push(Box::new(SchedulesProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseSchedules => self.pop_processor(),
+
+            // job artifacts
+            GlimEvent::OpenArtifacts(_, _, _) => {
+                self.push(Box::new(ArtifactsProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseArtifacts => self.pop_processor(),
+
+            // runners
+            GlimEvent::OpenRunners(_) => {
+                self.push(Box::new(RunnersProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseRunners => self.pop_processor(),
+
+            // global search
+            GlimEvent::DisplaySearch => {
+                self.push(Box::new(SearchProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseSearch => self.pop_processor(),
+
+            // command palette
+            GlimEvent::DisplayCommandPalette => {
+                self.push(Box::new(CommandPaletteProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseCommandPalette => self.pop_processor(),
+            GlimEvent::DisplayHelp => {
+                self.push(Box::new(HelpProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseHelp => self.pop_processor(),
+            GlimEvent::DisplayProjectFilter => {
+                self.push(Box::new(ProjectFilterProcessor::new(self.sender.clone())));
+            },
+            GlimEvent::CloseProjectFilter => self.pop_processor(),
             
             _ => ()
         }
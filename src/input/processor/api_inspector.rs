@@ -0,0 +1,45 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct ApiInspectorProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl ApiInspectorProcessor {
+    /// lines scrolled by page up and page down
+    const PAGE: i32 = 10;
+
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        match event.code {
+            KeyCode::Esc      => self.sender.dispatch(GlimEvent::CloseApiInspector),
+            KeyCode::Up       => ui.handle_api_inspector_selection(-1),
+            KeyCode::Down     => ui.handle_api_inspector_selection(1),
+            KeyCode::PageUp   => ui.handle_api_inspector_scroll(-Self::PAGE),
+            KeyCode::PageDown => ui.handle_api_inspector_scroll(Self::PAGE),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for ApiInspectorProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
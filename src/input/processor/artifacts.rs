@@ -0,0 +1,50 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct ArtifactsProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl ArtifactsProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::CloseArtifacts);
+        }
+
+        let artifacts = match ui.artifacts.as_mut() {
+            Some(artifacts) => artifacts,
+            None            => return,
+        };
+
+        match event.code {
+            KeyCode::Up   => artifacts.select(-1),
+            KeyCode::Down => artifacts.select(1),
+            KeyCode::Char('d') if artifacts.has_archive && !artifacts.is_downloading() =>
+                self.sender.dispatch(GlimEvent::DownloadArtifacts(artifacts.project_id, artifacts.job_id)),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for ArtifactsProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
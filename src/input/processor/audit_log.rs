@@ -0,0 +1,40 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct AuditLogProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl AuditLogProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        match event.code {
+            KeyCode::Esc  => self.sender.dispatch(GlimEvent::CloseAuditLog),
+            KeyCode::Up   => ui.handle_audit_log_selection(-1),
+            KeyCode::Down => ui.handle_audit_log_selection(1),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for AuditLogProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
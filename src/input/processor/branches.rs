@@ -0,0 +1,54 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct BranchesProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl BranchesProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::CloseBranches);
+        }
+
+        let branches = match ui.branches.as_mut() {
+            Some(branches) => branches,
+            None           => return,
+        };
+
+        let project_id = branches.project_id;
+        let selected = branches.selected_branch().map(|b| b.name.clone());
+        match (event.code, selected) {
+            (KeyCode::Up, _)   => branches.select(-1),
+            (KeyCode::Down, _) => branches.select(1),
+            (KeyCode::Char('w'), Some(branch)) =>
+                self.sender.dispatch(GlimEvent::BrowseToBranch(project_id, branch)),
+            (KeyCode::Char('r'), Some(branch)) =>
+                self.sender.dispatch(GlimEvent::TriggerPipeline(project_id, branch)),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for BranchesProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
@@ -0,0 +1,80 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+use tui_input::backend::crossterm::EventHandler;
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+/// Actions bound to keys in the command palette popup; other keys edit the query.
+#[derive(Debug, Clone, Copy)]
+pub enum CommandPaletteAction {
+    Close,
+    SelectPrevious,
+    SelectNext,
+    Apply,
+}
+
+pub struct CommandPaletteProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl CommandPaletteProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    pub const KEYBINDINGS: [KeyBinding<CommandPaletteAction>; 4] = [
+        KeyBinding::new(KeyCode::Esc, CommandPaletteAction::Close, Msg::ShortcutClose),
+        KeyBinding::new(KeyCode::Up, CommandPaletteAction::SelectPrevious, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, CommandPaletteAction::SelectNext, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, CommandPaletteAction::Apply, Msg::ShortcutApply),
+    ];
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        let palette = match ui.command_palette.as_mut() {
+            Some(palette) => palette,
+            None          => return,
+        };
+
+        match find_action(&Self::KEYBINDINGS, event) {
+            Some(CommandPaletteAction::Close)          => self.sender.dispatch(GlimEvent::CloseCommandPalette),
+            Some(CommandPaletteAction::SelectPrevious) => ui.handle_command_palette_selection(-1),
+            Some(CommandPaletteAction::SelectNext)     => ui.handle_command_palette_selection(1),
+            Some(CommandPaletteAction::Apply) => {
+                let events = palette.selected_command()
+                    .map(|cmd| cmd.events(palette.project_id))
+                    .unwrap_or_default();
+
+                // closed before executing, as the command may open another popup
+                self.sender.dispatch(GlimEvent::CloseCommandPalette);
+                events.into_iter().for_each(|e| self.sender.dispatch(e));
+            },
+            None => {
+                let changed = palette.input.handle_event(&CrosstermEvent::Key(*event))
+                    .is_some_and(|response| response.value);
+
+                if changed {
+                    palette.update_matches();
+                }
+            },
+        }
+    }
+}
+
+impl InputProcessor for CommandPaletteProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
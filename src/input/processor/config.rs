@@ -4,9 +4,22 @@ use tui_input::backend::crossterm::EventHandler;
 use crossterm::{event::Event as CrosstermEvent};
 use crate::dispatcher::Dispatcher;
 use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
 use crate::input::InputProcessor;
 use crate::ui::StatefulWidgets;
 
+/// Actions bound to keys in the configuration popup; other keys edit the
+/// selected input.
+#[derive(Debug, Clone, Copy)]
+pub enum ConfigAction {
+    Apply,
+    Close,
+    TestWebhook,
+    NextInput,
+    PreviousInput,
+}
+
 pub struct ConfigProcessor {
     sender: Sender<GlimEvent>,
 }
@@ -15,18 +28,27 @@ impl ConfigProcessor {
     pub fn new(sender: Sender<GlimEvent>) -> Self {
         Self { sender }
     }
+
+    pub const KEYBINDINGS: [KeyBinding<ConfigAction>; 5] = [
+        KeyBinding::new(KeyCode::Esc, ConfigAction::Close, Msg::ShortcutClose),
+        KeyBinding::new(KeyCode::Up, ConfigAction::PreviousInput, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, ConfigAction::NextInput, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, ConfigAction::Apply, Msg::ShortcutApply),
+        KeyBinding::ctrl(KeyCode::Char('t'), ConfigAction::TestWebhook, Msg::ShortcutTestWebhook),
+    ];
 }
 
 impl InputProcessor for ConfigProcessor {
     fn apply(&mut self, event: &GlimEvent, widgets: &mut StatefulWidgets) {
         if let GlimEvent::Key(code) = event {
             let popup = widgets.config_popup_state.as_mut().unwrap();
-            match code.code {
-                KeyCode::Enter => self.sender.dispatch(GlimEvent::ApplyConfiguration),
-                KeyCode::Esc   => self.sender.dispatch(GlimEvent::CloseConfig),
-                KeyCode::Down  => popup.select_next_input(),
-                KeyCode::Up    => popup.select_previous_input(),
-                _ => {
+            match find_action(&Self::KEYBINDINGS, code) {
+                Some(ConfigAction::Apply)         => self.sender.dispatch(GlimEvent::ApplyConfiguration),
+                Some(ConfigAction::Close)         => self.sender.dispatch(GlimEvent::CloseConfig),
+                Some(ConfigAction::TestWebhook)   => self.sender.dispatch(GlimEvent::TestWebhook),
+                Some(ConfigAction::NextInput)     => popup.select_next_input(),
+                Some(ConfigAction::PreviousInput) => popup.select_previous_input(),
+                None => {
                     popup.input_mut().handle_event(&CrosstermEvent::Key(*code));
                 },
             }
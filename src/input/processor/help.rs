@@ -0,0 +1,42 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct HelpProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl HelpProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        let Some(help) = ui.help.as_mut() else { return };
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('?') => self.sender.dispatch(GlimEvent::CloseHelp),
+            KeyCode::Up   => help.scroll(-1),
+            KeyCode::Down => help.scroll(1),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for HelpProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
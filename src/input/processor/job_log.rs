@@ -0,0 +1,59 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct JobLogProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl JobLogProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::CloseJobLog);
+        }
+
+        let job_log = match ui.job_log.as_mut() {
+            Some(job_log) => job_log,
+            None          => return,
+        };
+
+        match event.code {
+            KeyCode::Up        => job_log.move_cursor(-1),
+            KeyCode::Down      => job_log.move_cursor(1),
+            KeyCode::PageUp    => job_log.page(-1),
+            KeyCode::PageDown  => job_log.page(1),
+            KeyCode::Home      => job_log.cursor_to_start(),
+            KeyCode::End       => job_log.cursor_to_end(),
+            KeyCode::Left      => job_log.collapse_section(),
+            KeyCode::Right     => job_log.expand_section(),
+            KeyCode::Enter     => job_log.toggle_section(),
+            KeyCode::Char(' ') => job_log.toggle_section(),
+            KeyCode::Char('-') => job_log.collapse_all(),
+            KeyCode::Char('+') => job_log.expand_all(),
+            KeyCode::Char('z') => job_log.toggle_zoom(),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for JobLogProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
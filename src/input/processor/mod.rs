@@ -2,8 +2,36 @@ mod normal;
 mod project_details;
 mod pipeline_actions;
 mod config;
+mod onboarding;
+mod audit_log;
+mod api_inspector;
+mod project_errors;
+mod job_log;
+mod search;
+mod pipeline_graph;
+mod branches;
+mod schedules;
+mod runners;
+mod artifacts;
+mod command_palette;
+mod help;
+mod project_filter;
 
 pub use normal::*;
 pub use project_details::*;
 pub use pipeline_actions::*;
 pub use config::*;
+pub use onboarding::*;
+pub use audit_log::*;
+pub use api_inspector::*;
+pub use project_errors::*;
+pub use job_log::*;
+pub use search::*;
+pub use pipeline_graph::*;
+pub use branches::*;
+pub use schedules::*;
+pub use runners::*;
+pub use artifacts::*;
+pub use command_palette::*;
+pub use help::*;
+pub use project_filter::*;
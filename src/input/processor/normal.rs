@@ -1,14 +1,32 @@
 use std::sync::mpsc::Sender;
 use crossterm::event::{KeyCode, KeyEvent};
+use crate::command::Command;
 use crate::dispatcher::Dispatcher;
 use crate::event::GlimEvent;
+use crate::i18n::Msg;
 use crate::id::ProjectId;
+use crate::input::keymap::{find_action, KeyBinding};
 use crate::input::InputProcessor;
 use crate::ui::StatefulWidgets;
+use crate::ui::widget::Tab;
+
+/// Actions bound to keys in normal mode, see [NormalModeProcessor::keybindings].
+#[derive(Debug, Clone, Copy)]
+pub enum NormalAction {
+    Run(Command),
+    CommandPalette,
+    ResizeSplit(i16),
+    CollapseGroup,
+    SelectTab(Tab),
+    ExpandGroup,
+    SelectPrevious,
+    SelectNext,
+}
 
 pub struct NormalModeProcessor {
     sender: Sender<GlimEvent>,
-    selected: Option<ProjectId>
+    selected: Option<ProjectId>,
+    tab: Tab,
 }
 
 impl NormalModeProcessor {
@@ -16,37 +34,80 @@ impl NormalModeProcessor {
         Self {
             sender,
             selected: None,
+            tab: Tab::default(),
         }
     }
 
+    /// Keys of the projects table; the commands are also listed by the command palette.
+    pub fn keybindings() -> Vec<KeyBinding<NormalAction>> {
+        let selection = [
+            KeyBinding::new(KeyCode::Up, NormalAction::SelectPrevious, Msg::ShortcutSelection),
+            KeyBinding::new(KeyCode::Down, NormalAction::SelectNext, Msg::ShortcutSelection),
+        ];
+        let commands = Command::ALL.iter()
+            .map(|cmd| cmd.key_binding())
+            .map(|b| KeyBinding { action: NormalAction::Run(b.action), code: b.code, ctrl: b.ctrl, description: b.description });
+        let tabs = Tab::ALL.iter()
+            .map(|tab| KeyBinding::new(KeyCode::Char(tab.key()), NormalAction::SelectTab(*tab), Msg::ShortcutTab));
+        let other = [
+            KeyBinding::new(KeyCode::Char(':'), NormalAction::CommandPalette, Msg::ShortcutCommands),
+            KeyBinding::ctrl(KeyCode::Char('p'), NormalAction::CommandPalette, Msg::ShortcutCommands),
+            KeyBinding::ctrl(KeyCode::Left, NormalAction::ResizeSplit(-5), Msg::ShortcutResize),
+            KeyBinding::ctrl(KeyCode::Right, NormalAction::ResizeSplit(5), Msg::ShortcutResize),
+            KeyBinding::new(KeyCode::Left, NormalAction::CollapseGroup, Msg::ShortcutCollapse),
+            KeyBinding::new(KeyCode::Right, NormalAction::ExpandGroup, Msg::ShortcutExpand),
+        ];
+
+        selection.into_iter()
+            .chain(commands)
+            .chain(tabs)
+            .chain(other)
+            .collect()
+    }
+
     fn process(
         &self,
         event: &KeyEvent,
+        ui: &StatefulWidgets,
     ) {
-        if let Some(e) = match event.code {
-            KeyCode::Enter if self.selected.is_some() =>
-                Some(GlimEvent::OpenProjectDetails(self.selected.unwrap())),
-            KeyCode::Char('a') => Some(GlimEvent::ShowLastNotification),
-            KeyCode::Char('c') => Some(GlimEvent::DisplayConfig),
-            KeyCode::Char('l') => Some(GlimEvent::ToggleInternalLogs),
-            KeyCode::Char('p') => self.selected.map(GlimEvent::RequestPipelines),
-            KeyCode::Char('q') => Some(GlimEvent::Shutdown),
-            KeyCode::Char('r') => Some(GlimEvent::RequestProjects),
-            KeyCode::Char('w') => self.selected.map(GlimEvent::BrowseToProject),
-            KeyCode::Up        => Some(GlimEvent::SelectPreviousProject),
-            KeyCode::Down      => Some(GlimEvent::SelectNextProject),
-            KeyCode::F(12)     => Some(GlimEvent::ToggleColorDepth),
-            _ => None
-        } { self.dispatch(e) }
+        let events = match find_action(&Self::keybindings(), event) {
+            // the pipelines tab opens the actions of the selected pipeline
+            Some(NormalAction::Run(Command::OpenDetails)) if self.tab == Tab::Pipelines => ui.selected_recent_pipeline
+                .map(|(project_id, pipeline_id)| GlimEvent::OpenPipelineActions(project_id, pipeline_id))
+                .into_iter()
+                .collect(),
+            // the multi-project tab opens the actions of the selected pipeline
+            Some(NormalAction::Run(Command::OpenDetails)) if self.tab == Tab::MultiProject => ui.selected_tree_pipeline
+                .map(|(project_id, pipeline_id)| GlimEvent::OpenPipelineActions(project_id, pipeline_id))
+                .into_iter()
+                .collect(),
+            // the failed jobs tab opens the actions of the selected job: log, browse and retry
+            Some(NormalAction::Run(Command::OpenDetails)) if self.tab == Tab::FailedJobs => ui.selected_failed_job
+                .map(|(project_id, pipeline_id, job_id)| GlimEvent::OpenJobActions(project_id, pipeline_id, job_id))
+                .into_iter()
+                .collect(),
+            Some(NormalAction::Run(cmd))        => cmd.events(self.selected),
+            Some(NormalAction::CommandPalette)  => vec![GlimEvent::DisplayCommandPalette],
+            Some(NormalAction::ResizeSplit(d))  => vec![GlimEvent::ResizeSplit(d)],
+            Some(NormalAction::CollapseGroup)   => vec![GlimEvent::CollapseProjectGroup],
+            Some(NormalAction::SelectTab(tab))  => vec![GlimEvent::TabSelect(tab)],
+            Some(NormalAction::ExpandGroup)     => vec![GlimEvent::ExpandProjectGroup],
+            Some(NormalAction::SelectPrevious)  => vec![GlimEvent::SelectPreviousProject],
+            Some(NormalAction::SelectNext)      => vec![GlimEvent::SelectNextProject],
+            None                                => vec![],
+        };
+
+        events.into_iter().for_each(|e| self.dispatch(e));
     }
 }
 
 impl InputProcessor for NormalModeProcessor {
 
-    fn apply(&mut self, event: &GlimEvent, _ui: &mut StatefulWidgets) {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
         match event {
             GlimEvent::SelectedProject(id)   => self.selected = Some(*id),
-            GlimEvent::Key(e)                => self.process(e),
+            GlimEvent::TabSelect(tab)        => self.tab = *tab,
+            GlimEvent::Key(e)                => self.process(e, ui),
             _                                => ()
         }
     }
@@ -0,0 +1,54 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::KeyCode;
+use tui_input::backend::crossterm::EventHandler;
+use crossterm::{event::Event as CrosstermEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+/// Actions bound to keys in the onboarding wizard; other keys edit the
+/// input of the current step.
+#[derive(Debug, Clone, Copy)]
+pub enum OnboardingAction {
+    Next,
+    Back,
+}
+
+pub struct OnboardingProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl OnboardingProcessor {
+    pub fn new(sender: Sender<GlimEvent>) -> Self {
+        Self { sender }
+    }
+
+    pub const KEYBINDINGS: [KeyBinding<OnboardingAction>; 2] = [
+        KeyBinding::new(KeyCode::Esc, OnboardingAction::Back, Msg::ShortcutBack),
+        KeyBinding::new(KeyCode::Enter, OnboardingAction::Next, Msg::ShortcutNext),
+    ];
+}
+
+impl InputProcessor for OnboardingProcessor {
+    fn apply(&mut self, event: &GlimEvent, widgets: &mut StatefulWidgets) {
+        if let GlimEvent::Key(code) = event {
+            let Some(wizard) = widgets.onboarding.as_mut() else { return };
+            match find_action(&Self::KEYBINDINGS, code) {
+                // steps are validated before advancing, see run_config_ui_loop
+                Some(OnboardingAction::Next) => self.sender.dispatch(GlimEvent::AdvanceOnboarding),
+                Some(OnboardingAction::Back) => if !wizard.back() {
+                    self.sender.dispatch(GlimEvent::CloseConfig)
+                },
+                None => {
+                    wizard.input_mut().handle_event(&CrosstermEvent::Key(*code));
+                },
+            }
+        }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
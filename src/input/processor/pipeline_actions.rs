@@ -2,9 +2,23 @@ use std::sync::mpsc::Sender;
 use crossterm::event::{KeyCode, KeyEvent};
 use crate::dispatcher::Dispatcher;
 use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
 use crate::input::InputProcessor;
 use crate::ui::StatefulWidgets;
 
+/// Actions bound to keys in the pipeline actions popup.
+#[derive(Debug, Clone, Copy)]
+pub enum PipelineActionsAction {
+    Close,
+    SelectPrevious,
+    SelectNext,
+    Apply,
+    CopyPipelineUrl,
+    CopyJobUrl,
+    CopyBranchName,
+}
+
 pub struct PipelineActionsProcessor {
     sender: Sender<GlimEvent>,
 }
@@ -16,23 +30,56 @@ impl PipelineActionsProcessor {
         Self { sender }
     }
 
+    pub const KEYBINDINGS: [KeyBinding<PipelineActionsAction>; 7] = [
+        KeyBinding::new(KeyCode::Esc, PipelineActionsAction::Close, Msg::ShortcutClose),
+        KeyBinding::new(KeyCode::Up, PipelineActionsAction::SelectPrevious, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, PipelineActionsAction::SelectNext, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, PipelineActionsAction::Apply, Msg::ShortcutApply),
+        KeyBinding::new(KeyCode::Char('u'), PipelineActionsAction::CopyPipelineUrl, Msg::ShortcutCopyPipelineUrl),
+        KeyBinding::new(KeyCode::Char('j'), PipelineActionsAction::CopyJobUrl, Msg::ShortcutCopyJobUrl),
+        KeyBinding::new(KeyCode::Char('b'), PipelineActionsAction::CopyBranchName, Msg::ShortcutCopyBranchName),
+    ];
+
     fn process(
         &self,
         event: &KeyEvent,
         ui: &mut StatefulWidgets,
     ) {
-        match event.code {
-            KeyCode::Esc       => self.sender.dispatch(GlimEvent::ClosePipelineActions),
-            KeyCode::Up        => ui.handle_pipeline_action_selection(-1),
-            KeyCode::Down      => ui.handle_pipeline_action_selection(1),
-            KeyCode::Enter => {
+        match find_action(&Self::KEYBINDINGS, event) {
+            Some(PipelineActionsAction::Close)          => self.sender.dispatch(GlimEvent::ClosePipelineActions),
+            Some(PipelineActionsAction::SelectPrevious) => ui.handle_pipeline_action_selection(-1),
+            Some(PipelineActionsAction::SelectNext)     => ui.handle_pipeline_action_selection(1),
+            Some(PipelineActionsAction::Apply) => {
                 let state = ui.pipeline_actions.as_ref().unwrap();
-                if let Some(action) = state.list_state.selected()
-                    .map(|_| state.copy_action()) { self.sender.dispatch(action) }
+                let action = state.list_state.selected()
+                    .map(|_| state.copy_action());
 
-                self.sender.dispatch(GlimEvent::ClosePipelineActions)
+                // closed before applying the action, as it may open another popup
+                self.sender.dispatch(GlimEvent::ClosePipelineActions);
+                if let Some(action) = action { self.sender.dispatch(action) }
             }
-            _ => ()
+            Some(PipelineActionsAction::CopyPipelineUrl) =>
+                self.apply_matching(ui, |a| matches!(a, GlimEvent::CopyPipelineUrl(..))),
+            Some(PipelineActionsAction::CopyJobUrl) =>
+                self.apply_matching(ui, |a| matches!(a, GlimEvent::CopyJobUrl(..))),
+            Some(PipelineActionsAction::CopyBranchName) =>
+                self.apply_matching(ui, |a| matches!(a, GlimEvent::CopyBranchName(..))),
+            None => ()
+        }
+    }
+
+    /// Applies the first action matching, if listed, and closes the popup.
+    fn apply_matching(
+        &self,
+        ui: &StatefulWidgets,
+        matches: impl Fn(&GlimEvent) -> bool,
+    ) {
+        let action = ui.pipeline_actions.as_ref()
+            .and_then(|state| state.find_action(matches));
+
+        if let Some(action) = action {
+            self.sender.dispatch(GlimEvent::ClosePipelineActions);
+            self.sender.dispatch(action);
         }
     }
 }
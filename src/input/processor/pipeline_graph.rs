@@ -0,0 +1,48 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct PipelineGraphProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl PipelineGraphProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::ClosePipelineGraph);
+        }
+
+        let graph = match ui.pipeline_graph.as_mut() {
+            Some(graph) => graph,
+            None        => return,
+        };
+
+        match event.code {
+            KeyCode::Left  => graph.scroll(-1),
+            KeyCode::Right => graph.scroll(1),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for PipelineGraphProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
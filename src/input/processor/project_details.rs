@@ -1,11 +1,39 @@
 use std::sync::mpsc::Sender;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+use tui_input::backend::crossterm::EventHandler;
 use crate::dispatcher::Dispatcher;
 use crate::event::GlimEvent;
+use crate::i18n::Msg;
 use crate::id::{PipelineId, ProjectId};
+use crate::input::keymap::{find_action, KeyBinding};
 use crate::input::InputProcessor;
+use crate::input::processor::{ProjectFilterAction, ProjectFilterProcessor};
+use crate::ui::popup::DetailsFocus;
 use crate::ui::StatefulWidgets;
 
+/// Actions bound to keys in the project details popup; some depend on
+/// whether the pipelines or the jobs are focused.
+#[derive(Debug, Clone, Copy)]
+pub enum DetailsAction {
+    ToggleFocus,
+    Close,
+    SelectPrevious,
+    SelectNext,
+    WatchBranch,
+    ToggleCommit,
+    ToggleDurations,
+    ToggleHistory,
+    CopySha,
+    CopyCloneUrl,
+    CopyCloneCommand,
+    OpenClone,
+    ToggleStage,
+    OlderPipelines,
+    NewerPipelines,
+    Filter,
+    Open,
+}
+
 pub struct ProjectDetailsProcessor {
     sender: Sender<GlimEvent>,
     project_id: ProjectId,
@@ -24,26 +52,207 @@ impl ProjectDetailsProcessor {
         }
     }
 
+    pub const KEYBINDINGS: [KeyBinding<DetailsAction>; 18] = [
+        KeyBinding::new(KeyCode::Esc, DetailsAction::Close, Msg::ShortcutClose),
+        KeyBinding::new(KeyCode::Tab, DetailsAction::ToggleFocus, Msg::ShortcutFocus),
+        KeyBinding::new(KeyCode::BackTab, DetailsAction::ToggleFocus, Msg::ShortcutFocus),
+        KeyBinding::new(KeyCode::Up, DetailsAction::SelectPrevious, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, DetailsAction::SelectNext, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, DetailsAction::Open, Msg::ShortcutActions),
+        KeyBinding::new(KeyCode::Char('n'), DetailsAction::WatchBranch, Msg::ShortcutWatchBranch),
+        KeyBinding::new(KeyCode::Char('d'), DetailsAction::ToggleCommit, Msg::ShortcutCommitDetails),
+        KeyBinding::new(KeyCode::Char('t'), DetailsAction::ToggleDurations, Msg::ShortcutJobDurations),
+        KeyBinding::new(KeyCode::Char('h'), DetailsAction::ToggleHistory, Msg::ShortcutDurationHistory),
+        KeyBinding::new(KeyCode::Char('y'), DetailsAction::CopySha, Msg::ShortcutCopySha),
+        KeyBinding::new(KeyCode::Char('c'), DetailsAction::CopyCloneUrl, Msg::ShortcutCopyCloneUrl),
+        KeyBinding::new(KeyCode::Char('C'), DetailsAction::CopyCloneCommand, Msg::ShortcutCopyCloneCommand),
+        KeyBinding::new(KeyCode::Char('e'), DetailsAction::OpenClone, Msg::ShortcutOpenClone),
+        KeyBinding::new(KeyCode::Char(' '), DetailsAction::ToggleStage, Msg::ShortcutToggleStage),
+        KeyBinding::new(KeyCode::PageDown, DetailsAction::OlderPipelines, Msg::ShortcutOlderPipelines),
+        KeyBinding::new(KeyCode::PageUp, DetailsAction::NewerPipelines, Msg::ShortcutNewerPipelines),
+        KeyBinding::new(KeyCode::Char('/'), DetailsAction::Filter, Msg::ShortcutFilter),
+    ];
+
     fn process(
         &self,
         event: &KeyEvent,
         ui: &mut StatefulWidgets,
     ) {
-        match event.code {
-            KeyCode::Esc       => self.sender.dispatch(GlimEvent::CloseProjectDetails),
-            KeyCode::Up        => ui.handle_pipeline_selection(-1),
-            KeyCode::Down      => ui.handle_pipeline_selection(1),
-            KeyCode::Enter if self.selected.is_some() =>
+        if ui.project_details.as_ref().is_some_and(|pd| pd.editing_filter) {
+            return self.process_filter(event, ui);
+        }
+
+        let focus = ui.project_details.as_ref()
+            .map(|pd| pd.focus)
+            .unwrap_or(DetailsFocus::Pipelines);
+
+        let Some(action) = find_action(&Self::KEYBINDINGS, event) else { return };
+        match (focus, action) {
+            (_, DetailsAction::ToggleFocus) => {
+                if let Some(pd) = ui.project_details.as_mut() { pd.toggle_focus() }
+            },
+
+            (DetailsFocus::Pipelines, DetailsAction::Close) => {
+                let Some(pd) = ui.project_details.as_mut() else { return };
+                // an applied filter is cleared before closing the popup
+                if pd.pipeline_filter.value().is_empty() {
+                    self.sender.dispatch(GlimEvent::CloseProjectDetails);
+                } else {
+                    pd.clear_pipeline_filter();
+                    self.on_page_changed(ui);
+                }
+            },
+            (DetailsFocus::Pipelines, DetailsAction::SelectPrevious) => ui.handle_pipeline_selection(-1),
+            (DetailsFocus::Pipelines, DetailsAction::SelectNext)     => ui.handle_pipeline_selection(1),
+            (DetailsFocus::Pipelines, DetailsAction::WatchBranch) => {
+                let branch = ui.project_details.as_ref()
+                    .and_then(|pd| pd.selected_pipeline())
+                    .map(|p| p.branch.clone());
+
+                if branch.is_some() {
+                    self.sender.dispatch(GlimEvent::ToggleWatch(self.project_id, branch));
+                }
+            },
+            (DetailsFocus::Pipelines, DetailsAction::ToggleCommit) => {
+                if let Some(pd) = ui.project_details.as_mut() {
+                    pd.show_commit = !pd.show_commit;
+                }
+                self.request_commit_details(ui);
+            },
+            (DetailsFocus::Pipelines, DetailsAction::ToggleDurations) => {
+                if let Some(pd) = ui.project_details.as_mut() {
+                    pd.show_durations = !pd.show_durations;
+                }
+            },
+            (DetailsFocus::Pipelines, DetailsAction::ToggleHistory) => {
+                if let Some(pd) = ui.project_details.as_mut() {
+                    pd.show_history = !pd.show_history;
+                }
+            },
+            (DetailsFocus::Pipelines, DetailsAction::CopySha) => {
+                let sha = ui.project_details.as_ref()
+                    .and_then(|pd| pd.selected_pipeline())
+                    .map(|p| p.sha.clone());
+
+                if let Some(sha) = sha {
+                    self.sender.dispatch(GlimEvent::CopyToClipboard(sha));
+                }
+            },
+            (_, DetailsAction::OlderPipelines) => {
+                let Some(pd) = ui.project_details.as_mut() else { return };
+                let page = pd.pipeline_page;
+                if pd.show_older_pipelines() {
+                    self.sender.dispatch(GlimEvent::RequestOlderPipelines(self.project_id));
+                }
+                if pd.pipeline_page != page {
+                    self.on_page_changed(ui);
+                }
+            },
+            (_, DetailsAction::NewerPipelines) => {
+                let Some(pd) = ui.project_details.as_mut() else { return };
+                if pd.show_newer_pipelines() {
+                    self.on_page_changed(ui);
+                }
+            },
+            (_, DetailsAction::Filter) => {
+                if let Some(pd) = ui.project_details.as_mut() {
+                    pd.editing_filter = true;
+                    pd.focus = DetailsFocus::Pipelines;
+                }
+            },
+            (_, DetailsAction::CopyCloneUrl)     => self.sender.dispatch(GlimEvent::CopyCloneUrl(self.project_id)),
+            (_, DetailsAction::CopyCloneCommand) => self.sender.dispatch(GlimEvent::CopyCloneCommand(self.project_id)),
+            (_, DetailsAction::OpenClone)        => self.sender.dispatch(GlimEvent::OpenLocalClone(self.project_id)),
+            (DetailsFocus::Pipelines, DetailsAction::Open) if self.selected.is_some() =>
                 self.sender.dispatch(GlimEvent::OpenPipelineActions(self.project_id, self.selected.unwrap())),
+
+            (DetailsFocus::Jobs, DetailsAction::Close)  => {
+                if let Some(pd) = ui.project_details.as_mut() { pd.toggle_focus() }
+            },
+            (DetailsFocus::Jobs, DetailsAction::SelectPrevious) => ui.handle_job_selection(-1),
+            (DetailsFocus::Jobs, DetailsAction::SelectNext)     => ui.handle_job_selection(1),
+            (DetailsFocus::Jobs, DetailsAction::ToggleStage) => {
+                if let Some(pd) = ui.project_details.as_mut() { pd.toggle_selected_stage() }
+            },
+            (DetailsFocus::Jobs, DetailsAction::Open) => {
+                let job_id = ui.project_details.as_ref().and_then(|pd| pd.selected_job_id());
+                match (self.selected, job_id) {
+                    (Some(pipeline_id), Some(job_id)) =>
+                        self.sender.dispatch(GlimEvent::OpenJobActions(self.project_id, pipeline_id, job_id)),
+                    // stage headers have no actions; enter collapses them instead
+                    _ => if let Some(pd) = ui.project_details.as_mut() { pd.toggle_selected_stage() },
+                }
+            },
             _ => ()
         }
     }
+
+    /// Edits the pipeline filter, with the keys of the project filter; other
+    /// keys edit the query.
+    fn process_filter(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        let Some(pd) = ui.project_details.as_mut() else { return };
+
+        match find_action(&ProjectFilterProcessor::KEYBINDINGS, event) {
+            Some(ProjectFilterAction::Clear) => {
+                pd.clear_pipeline_filter();
+                self.on_page_changed(ui);
+            },
+            Some(ProjectFilterAction::SelectPrevious) => ui.handle_pipeline_selection(-1),
+            Some(ProjectFilterAction::SelectNext)     => ui.handle_pipeline_selection(1),
+            // the filter stays applied to the pipelines table
+            Some(ProjectFilterAction::Apply)          => pd.editing_filter = false,
+            None => {
+                let changed = pd.pipeline_filter.handle_event(&CrosstermEvent::Key(*event))
+                    .is_some_and(|response| response.value);
+
+                if changed {
+                    pd.apply_pipeline_filter();
+                    self.on_page_changed(ui);
+                }
+            },
+        }
+    }
+
+    /// Selects the first pipeline of the page, and requests the jobs of the
+    /// pipelines fetched without them.
+    fn on_page_changed(&self, ui: &StatefulWidgets) {
+        let Some(pd) = ui.project_details.as_ref() else { return };
+
+        if let Some(pipeline) = pd.selected_pipeline() {
+            self.sender.dispatch(GlimEvent::SelectedPipeline(pipeline.id));
+        }
+
+        pd.pipelines().iter()
+            .filter(|p| p.jobs.is_none())
+            .for_each(|p| self.sender.dispatch(GlimEvent::RequestJobs(self.project_id, p.id)));
+    }
+
+    /// Requests the commit details of the selected pipeline, if expanded and
+    /// not yet fetched.
+    fn request_commit_details(&self, ui: &StatefulWidgets) {
+        let missing = ui.project_details.as_ref()
+            .filter(|pd| pd.show_commit)
+            .and_then(|pd| pd.selected_pipeline())
+            .filter(|p| p.commit_details.is_none())
+            .map(|p| p.id);
+
+        if let Some(pipeline_id) = missing {
+            self.sender.dispatch(GlimEvent::RequestCommitDetails(self.project_id, pipeline_id));
+        }
+    }
 }
 
 impl InputProcessor for ProjectDetailsProcessor {
     fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
         match event {
-            GlimEvent::SelectedPipeline(pipeline) => self.selected = Some(*pipeline),
+            GlimEvent::SelectedPipeline(pipeline) => {
+                self.selected = Some(*pipeline);
+                self.request_commit_details(ui);
+            },
             GlimEvent::Key(e)                     => self.process(e, ui),
             _ => ()
         }
@@ -0,0 +1,77 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+use tui_input::backend::crossterm::EventHandler;
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+/// Actions bound to keys while editing the project filter; other keys edit the query.
+#[derive(Debug, Clone, Copy)]
+pub enum ProjectFilterAction {
+    Clear,
+    SelectPrevious,
+    SelectNext,
+    Apply,
+}
+
+pub struct ProjectFilterProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl ProjectFilterProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    pub const KEYBINDINGS: [KeyBinding<ProjectFilterAction>; 4] = [
+        KeyBinding::new(KeyCode::Esc, ProjectFilterAction::Clear, Msg::ShortcutClearFilter),
+        KeyBinding::new(KeyCode::Up, ProjectFilterAction::SelectPrevious, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, ProjectFilterAction::SelectNext, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, ProjectFilterAction::Apply, Msg::ShortcutApply),
+    ];
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        let filter = match ui.project_filter.as_mut() {
+            Some(filter) => filter,
+            None         => return,
+        };
+
+        match find_action(&Self::KEYBINDINGS, event) {
+            Some(ProjectFilterAction::Clear) => {
+                self.sender.dispatch(GlimEvent::FilterProjects(String::new()));
+                self.sender.dispatch(GlimEvent::CloseProjectFilter);
+            },
+            Some(ProjectFilterAction::SelectPrevious) => self.sender.dispatch(GlimEvent::SelectPreviousProject),
+            Some(ProjectFilterAction::SelectNext)     => self.sender.dispatch(GlimEvent::SelectNextProject),
+            // the filter stays applied to the projects table
+            Some(ProjectFilterAction::Apply)          => self.sender.dispatch(GlimEvent::CloseProjectFilter),
+            None => {
+                let changed = filter.handle_event(&CrosstermEvent::Key(*event))
+                    .is_some_and(|response| response.value);
+
+                if changed {
+                    let query = filter.value().to_string();
+                    self.sender.dispatch(GlimEvent::FilterProjects(query));
+                }
+            },
+        }
+    }
+}
+
+impl InputProcessor for ProjectFilterProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
@@ -0,0 +1,48 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct RunnersProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl RunnersProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::CloseRunners);
+        }
+
+        let runners = match ui.runners.as_mut() {
+            Some(runners) => runners,
+            None          => return,
+        };
+
+        match event.code {
+            KeyCode::Up   => runners.select(-1),
+            KeyCode::Down => runners.select(1),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for RunnersProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
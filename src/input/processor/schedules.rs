@@ -0,0 +1,52 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+pub struct SchedulesProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl SchedulesProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        if event.code == KeyCode::Esc {
+            return self.sender.dispatch(GlimEvent::CloseSchedules);
+        }
+
+        let schedules = match ui.schedules.as_mut() {
+            Some(schedules) => schedules,
+            None            => return,
+        };
+
+        let project_id = schedules.project_id;
+        let selected = schedules.selected_schedule().map(|s| s.id);
+        match (event.code, selected) {
+            (KeyCode::Up, _)   => schedules.select(-1),
+            (KeyCode::Down, _) => schedules.select(1),
+            (KeyCode::Char('r'), Some(schedule_id)) =>
+                self.sender.dispatch(GlimEvent::PlaySchedule(project_id, schedule_id)),
+            _ => ()
+        }
+    }
+}
+
+impl InputProcessor for SchedulesProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
@@ -0,0 +1,82 @@
+use std::sync::mpsc::Sender;
+use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent};
+use tui_input::backend::crossterm::EventHandler;
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::i18n::Msg;
+use crate::input::keymap::{find_action, KeyBinding};
+use crate::input::InputProcessor;
+use crate::ui::StatefulWidgets;
+
+/// Actions bound to keys in the search popup; other keys edit the query.
+#[derive(Debug, Clone, Copy)]
+pub enum SearchAction {
+    Close,
+    SelectPrevious,
+    SelectNext,
+    Apply,
+}
+
+pub struct SearchProcessor {
+    sender: Sender<GlimEvent>,
+}
+
+impl SearchProcessor {
+    pub fn new(
+        sender: Sender<GlimEvent>,
+    ) -> Self {
+        Self { sender }
+    }
+
+    pub const KEYBINDINGS: [KeyBinding<SearchAction>; 4] = [
+        KeyBinding::new(KeyCode::Esc, SearchAction::Close, Msg::ShortcutClose),
+        KeyBinding::new(KeyCode::Up, SearchAction::SelectPrevious, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Down, SearchAction::SelectNext, Msg::ShortcutSelection),
+        KeyBinding::new(KeyCode::Enter, SearchAction::Apply, Msg::ShortcutJump),
+    ];
+
+    fn process(
+        &self,
+        event: &KeyEvent,
+        ui: &mut StatefulWidgets,
+    ) {
+        let search = match ui.search.as_mut() {
+            Some(search) => search,
+            None         => return,
+        };
+
+        match find_action(&Self::KEYBINDINGS, event) {
+            Some(SearchAction::Close)          => self.sender.dispatch(GlimEvent::CloseSearch),
+            Some(SearchAction::SelectPrevious) => ui.handle_search_selection(-1),
+            Some(SearchAction::SelectNext)     => ui.handle_search_selection(1),
+            Some(SearchAction::Apply) => {
+                let target = search.selected_result()
+                    .map(|result| result.entry.target);
+
+                // closed before navigating, as the target may open another popup
+                self.sender.dispatch(GlimEvent::CloseSearch);
+                target.iter()
+                    .flat_map(|t| t.navigation_events())
+                    .for_each(|e| self.sender.dispatch(e));
+            },
+            None => {
+                let changed = search.input.handle_event(&CrosstermEvent::Key(*event))
+                    .is_some_and(|response| response.value);
+
+                if changed {
+                    let query = search.input.value().to_string();
+                    self.sender.dispatch(GlimEvent::SearchQuery(query));
+                }
+            },
+        }
+    }
+}
+
+impl InputProcessor for SearchProcessor {
+    fn apply(&mut self, event: &GlimEvent, ui: &mut StatefulWidgets) {
+        if let GlimEvent::Key(e) = event { self.process(e, ui) }
+    }
+
+    fn on_pop(&self) {}
+    fn on_push(&self) {}
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{Pipeline, PipelineStatus, Project};
+use crate::id::PipelineId;
+use crate::job_log::JobLog;
+
+/// Number of trailing log lines included in the issue description.
+const LOG_SUMMARY_LINES: usize = 30;
+
+/// Issues created in GitLab for failed pipelines. Templates may reference
+/// `{project}`, `{branch}`, `{pipeline_url}`, `{job}`, `{job_url}` and
+/// `{log_summary}`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IssueConfig {
+    pub title: String,
+    pub description: String,
+    /// Comma-separated labels assigned to created issues
+    pub labels: Option<String>,
+    /// Automatically creates an issue when this many consecutive pipelines
+    /// fail on the default branch
+    pub auto_create_after: Option<usize>,
+}
+
+/// Details of a failed pipeline, used for rendering issue templates.
+#[derive(Debug, Clone, Default)]
+pub struct FailureReport {
+    pub project: String,
+    pub branch: String,
+    pub pipeline_url: String,
+    pub job: String,
+    pub job_url: String,
+    pub log_summary: String,
+}
+
+/// A rendered issue, ready to be submitted to the issues API.
+#[derive(Debug, Clone)]
+pub struct IssueRequest {
+    pub title: String,
+    pub description: String,
+    pub labels: Option<String>,
+}
+
+/// Watches pipelines on the default branch for repeated failures, as
+/// configured by [IssueConfig::auto_create_after].
+pub struct FailureRule {
+    threshold: Option<usize>,
+    /// pipelines observed while running; only these may trigger the rule,
+    /// which prevents creating issues for historical failures on startup
+    observed_active: HashSet<PipelineId>,
+    reported: HashSet<PipelineId>,
+}
+
+impl Default for IssueConfig {
+    fn default() -> Self {
+        Self {
+            title: "CI failure: {job} on {branch}".to_string(),
+            description: [
+                "Pipeline {pipeline_url} failed on `{branch}`.",
+                "",
+                "Failed job: [{job}]({job_url})",
+                "",
+                "```",
+                "{log_summary}",
+                "```",
+            ].join("\n"),
+            labels: None,
+            auto_create_after: None,
+        }
+    }
+}
+
+impl IssueConfig {
+    pub fn render(&self, report: &FailureReport) -> IssueRequest {
+        IssueRequest {
+            title: report.render(&self.title),
+            description: report.render(&self.description),
+            labels: self.labels.clone(),
+        }
+    }
+}
+
+impl FailureReport {
+    pub fn new(project: &Project, pipeline: &Pipeline) -> Self {
+        let failed_job = pipeline.failed_job();
+        Self {
+            project: project.path.clone(),
+            branch: pipeline.branch.clone(),
+            pipeline_url: pipeline.url.clone(),
+            job: failed_job.map(|j| j.name.clone()).unwrap_or_default(),
+            job_url: failed_job.map(|j| j.url.clone()).unwrap_or_default(),
+            log_summary: String::new(),
+        }
+    }
+
+    /// Sets the log summary to the tail of the job's trace.
+    pub fn with_log(self, trace: &str) -> Self {
+        let log = JobLog::parse(trace);
+        let skip = log.lines.len().saturating_sub(LOG_SUMMARY_LINES);
+
+        Self {
+            log_summary: log.lines[skip..].join("\n"),
+            ..self
+        }
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{project}", &self.project)
+            .replace("{branch}", &self.branch)
+            .replace("{pipeline_url}", &self.pipeline_url)
+            .replace("{job}", &self.job)
+            .replace("{job_url}", &self.job_url)
+            .replace("{log_summary}", &self.log_summary)
+    }
+}
+
+impl FailureRule {
+    pub fn new(config: &IssueConfig) -> Self {
+        Self {
+            threshold: config.auto_create_after.filter(|&n| n > 0),
+            observed_active: HashSet::new(),
+            reported: HashSet::new(),
+        }
+    }
+
+    pub fn update_config(&mut self, config: &IssueConfig) {
+        self.threshold = config.auto_create_after.filter(|&n| n > 0);
+    }
+
+    /// Returns the most recent pipeline on the default branch if it completes
+    /// a streak of failures reaching the configured threshold.
+    pub fn check(&mut self, project: &Project) -> Option<PipelineId> {
+        let threshold = self.threshold?;
+        let pipelines: Vec<&Pipeline> = project.pipelines.iter()
+            .flatten()
+            .filter(|p| p.branch == project.default_branch)
+            .collect();
+
+        pipelines.iter()
+            .filter(|p| p.status.is_active())
+            .for_each(|p| { self.observed_active.insert(p.id); });
+
+        let latest = pipelines.first()?;
+        let failed_streak = pipelines.iter()
+            .take(threshold)
+            .filter(|p| p.status == PipelineStatus::Failed)
+            .count();
+
+        let triggered = failed_streak == threshold
+            && self.observed_active.contains(&latest.id)
+            && self.reported.insert(latest.id);
+
+        triggered.then_some(latest.id)
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// A parsed GitLab job trace, with collapsible sections as delimited
+/// by `section_start`/`section_end` markers.
+#[derive(Debug, Clone, Default)]
+pub struct JobLog {
+    pub lines: Vec<String>,
+    pub sections: Vec<LogSection>,
+    /// per-line timestamps, present when the runner prefixes lines with timestamps
+    line_times: Vec<Option<DateTime<Utc>>>,
+    /// earliest timestamp or section start in the trace
+    started_at: Option<DateTime<Utc>>,
+    /// section indices, keyed by the line of the section header
+    section_starts: HashMap<usize, Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogSection {
+    pub name: String,
+    /// index of the header line
+    pub start_line: usize,
+    /// index of the first line after the section
+    pub end_line: usize,
+    pub depth: usize,
+    pub started_at: i64,
+    pub ended_at: Option<i64>,
+    /// whether the section is collapsed by default, as hinted by the job
+    pub collapsed: bool,
+}
+
+/// A row in the folded view of a [JobLog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogRow {
+    /// header of the section at the given index
+    Section(usize),
+    Line(usize),
+}
+
+impl JobLog {
+    pub fn parse(trace: &str) -> Self {
+        let mut lines: Vec<String> = Vec::new();
+        let mut line_times: Vec<Option<DateTime<Utc>>> = Vec::new();
+        let mut sections: Vec<LogSection> = Vec::new();
+        let mut open_sections: Vec<usize> = Vec::new();
+
+        for raw_line in trace.lines() {
+            let (time, append, mut rest) = parse_timestamp_prefix(raw_line);
+            let mut has_marker = false;
+
+            // the runner splits long lines, flagging the continuations
+            if append && !lines.is_empty() && next_marker(rest).is_none() {
+                lines.last_mut().unwrap().push_str(&strip_ansi(rest));
+                continue;
+            }
+
+            while let Some((pos, marker)) = next_marker(rest) {
+                has_marker = true;
+
+                let text = strip_ansi(&rest[..pos]);
+                if !text.trim().is_empty() {
+                    lines.push(text);
+                    line_times.push(time);
+                }
+
+                let (section_marker, remainder) = parse_marker(&rest[pos..], marker);
+                rest = remainder;
+
+                match section_marker {
+                    SectionMarker::Start { name, timestamp, collapsed } => {
+                        open_sections.push(sections.len());
+                        sections.push(LogSection {
+                            name,
+                            start_line: lines.len(),
+                            end_line: lines.len(),
+                            depth: open_sections.len() - 1,
+                            started_at: timestamp,
+                            ended_at: None,
+                            collapsed,
+                        });
+                    },
+                    SectionMarker::End { name, timestamp } => {
+                        // close the most recently opened section with a matching name,
+                        // along with any unterminated sections nested inside it
+                        if let Some(idx) = open_sections.iter().rposition(|&s| sections[s].name == name) {
+                            for s in open_sections.drain(idx..) {
+                                sections[s].end_line = lines.len();
+                                sections[s].ended_at = Some(timestamp);
+                            }
+                        }
+                    },
+                }
+            }
+
+            if !has_marker || !rest.is_empty() {
+                lines.push(strip_ansi(rest));
+                line_times.push(time);
+            }
+        }
+
+        // sections still running, or a truncated trace
+        for s in open_sections {
+            sections[s].end_line = lines.len();
+        }
+
+        let mut section_starts: HashMap<usize, Vec<usize>> = HashMap::new();
+        sections.iter()
+            .enumerate()
+            .for_each(|(idx, s)| section_starts.entry(s.start_line).or_default().push(idx));
+
+        let started_at = line_times.iter()
+            .flatten()
+            .copied()
+            .chain(sections.iter().filter_map(|s| s.start_time()))
+            .min();
+
+        Self { lines, sections, line_times, started_at, section_starts }
+    }
+
+    /// Returns true if the trace carries line timestamps or section timings.
+    pub fn has_timings(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Returns the time elapsed since the start of the job, for rows
+    /// with a known timestamp.
+    pub fn elapsed(&self, row: LogRow) -> Option<Duration> {
+        let time = match row {
+            LogRow::Section(s) => self.sections[s].start_time(),
+            LogRow::Line(line) => self.line_times[line],
+        };
+
+        Some(time? - self.started_at?)
+    }
+
+    /// Returns the rows to display, given the collapsed state of each section.
+    pub fn visible_rows(&self, collapsed: &[bool]) -> Vec<LogRow> {
+        let mut rows = Vec::new();
+        let mut line = 0;
+
+        'lines: while line < self.lines.len() {
+            let mut header_consumed = false;
+            if let Some(sections) = self.section_starts.get(&line) {
+                for &s in sections {
+                    let section = &self.sections[s];
+                    rows.push(LogRow::Section(s));
+                    if collapsed[s] {
+                        line = section.end_line.max(line + 1);
+                        continue 'lines;
+                    }
+                    header_consumed |= section.start_line < section.end_line;
+                }
+            }
+
+            if !header_consumed {
+                rows.push(LogRow::Line(line));
+            }
+            line += 1;
+        }
+
+        rows
+    }
+
+    /// Returns the innermost section containing the line.
+    pub fn enclosing_section(&self, line: usize) -> Option<usize> {
+        self.sections.iter()
+            .enumerate()
+            .filter(|(_, s)| s.start_line <= line && line < s.end_line)
+            .max_by_key(|(_, s)| s.depth)
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns the section directly enclosing the given section.
+    pub fn parent_section(&self, section: usize) -> Option<usize> {
+        let s = &self.sections[section];
+        self.sections.iter()
+            .enumerate()
+            .filter(|(_, p)| p.depth + 1 == s.depth)
+            .find(|(_, p)| p.start_line <= s.start_line && s.end_line <= p.end_line)
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns the section's header text, falling back to the section name.
+    pub fn section_title(&self, section: usize) -> &str {
+        let s = &self.sections[section];
+        match self.lines.get(s.start_line) {
+            Some(header) if s.start_line < s.end_line && !header.trim().is_empty() => header,
+            _ => &s.name,
+        }
+    }
+}
+
+impl LogSection {
+    pub fn duration(&self) -> Option<Duration> {
+        self.ended_at.map(|end| Duration::seconds(end - self.started_at))
+    }
+
+    fn start_time(&self) -> Option<DateTime<Utc>> {
+        // a zero timestamp signals a malformed marker
+        Some(self.started_at)
+            .filter(|&ts| ts > 0)
+            .and_then(|ts| DateTime::from_timestamp(ts, 0))
+    }
+}
+
+enum SectionMarker {
+    Start { name: String, timestamp: i64, collapsed: bool },
+    End { name: String, timestamp: i64 },
+}
+
+const SECTION_START: &str = "section_start:";
+const SECTION_END: &str = "section_end:";
+
+/// Finds the position of the next section marker.
+fn next_marker(s: &str) -> Option<(usize, &'static str)> {
+    let start = s.find(SECTION_START).map(|pos| (pos, SECTION_START));
+    let end = s.find(SECTION_END).map(|pos| (pos, SECTION_END));
+
+    match (start, end) {
+        (Some(a), Some(b)) => Some(if a.0 < b.0 { a } else { b }),
+        (a, b)             => a.or(b),
+    }
+}
+
+/// Parses a marker of the form `section_start:1560896352:name[collapsed=true]\r\e[0K`,
+/// returning the marker and the remainder of the line.
+fn parse_marker<'a>(s: &'a str, marker: &str) -> (SectionMarker, &'a str) {
+    let s = &s[marker.len()..];
+    let marker_end = s.find('\r').unwrap_or(s.len());
+    let remainder = s[marker_end..].trim_start_matches('\r');
+    let remainder = remainder.strip_prefix("\x1b[0K").unwrap_or(remainder);
+
+    let (timestamp, name) = s[..marker_end].split_once(':').unwrap_or(("0", &s[..marker_end]));
+    let timestamp = timestamp.parse().unwrap_or(0);
+    let (name, options) = match name.find('[') {
+        Some(idx) => (&name[..idx], &name[idx..]),
+        None      => (name, ""),
+    };
+
+    let section_marker = if marker == SECTION_START {
+        SectionMarker::Start {
+            name: name.to_string(),
+            timestamp,
+            collapsed: options.contains("collapsed=true"),
+        }
+    } else {
+        SectionMarker::End { name: name.to_string(), timestamp }
+    };
+
+    (section_marker, remainder)
+}
+
+/// Parses the timestamp prefix added by runners with `FF_TIMESTAMPS` enabled, e.g.
+/// `2024-03-21T10:15:30.123456Z 00O+ text`. Returns the timestamp, whether the line
+/// continues the previous line, and the remainder of the line.
+fn parse_timestamp_prefix(line: &str) -> (Option<DateTime<Utc>>, bool, &str) {
+    let prefix = line.split_once(' ')
+        .map(|(timestamp, rest)| {
+            let (stream, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+            (timestamp, stream, rest)
+        })
+        .and_then(|(timestamp, stream, rest)| {
+            let time = DateTime::parse_from_rfc3339(timestamp).ok()?;
+            let (stream, append) = match stream.strip_suffix('+') {
+                Some(stream) => (stream, true),
+                None         => (stream, false),
+            };
+
+            // two hex digits for the stream number, followed by the stream type
+            let is_stream = stream.len() == 3
+                && stream.is_ascii()
+                && stream[..2].chars().all(|c| c.is_ascii_hexdigit())
+                && stream.ends_with(['O', 'E']);
+
+            is_stream.then(|| (Some(time.with_timezone(&Utc)), append, rest))
+        });
+
+    prefix.unwrap_or((None, false, line))
+}
+
+/// Removes ANSI escape sequences; text overwritten by carriage returns is discarded.
+fn strip_ansi(s: &str) -> String {
+    let s = s.rsplit('\r').find(|part| !part.is_empty()).unwrap_or("");
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI sequences end with a byte in the range 0x40..=0x7e
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) { break; }
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
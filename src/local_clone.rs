@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::domain::Project;
+use crate::glim_app::GlimConfig;
+use crate::notifier::spawn_detached;
+use crate::result::{GlimError, Result};
+
+/// Local clones of projects: the `git clone` command of a project, and the
+/// command opening its clone, see [GlimConfig::clone_dir] and [GlimConfig::open_command].
+#[derive(Debug, Clone, Default)]
+pub struct LocalClones {
+    clone_dir: Option<PathBuf>,
+    open_command: Option<String>,
+}
+
+impl LocalClones {
+    pub fn new(config: &GlimConfig) -> Self {
+        Self {
+            clone_dir: config.clone_dir.clone(),
+            open_command: config.open_command.clone().filter(|cmd| !cmd.trim().is_empty()),
+        }
+    }
+
+    /// Path of the project's clone, below the clone directory at the path
+    /// of the project, e.g. `~/src/group/project`.
+    pub fn clone_path(&self, project: &Project) -> Option<PathBuf> {
+        self.clone_dir.as_ref().map(|dir| dir.join(&project.path))
+    }
+
+    /// `git clone` command of the project, cloning into [Self::clone_path]
+    /// if the clone directory is configured.
+    pub fn clone_command(&self, project: &Project) -> String {
+        match self.clone_path(project) {
+            Some(path) => format!("git clone {} {}", project.ssh_git_url, shell_quote(&path.to_string_lossy())),
+            None       => format!("git clone {}", project.ssh_git_url),
+        }
+    }
+
+    /// Runs the open command with `{path}` and `{url}` replaced by the clone
+    /// path and the ssh clone URL, returning the command run.
+    pub fn open(&self, project: &Project) -> Result<String> {
+        let Some(template) = self.open_command.as_ref() else {
+            return Err(GlimError::ConfigError("open_command is not configured".to_string()));
+        };
+        let Some(path) = self.clone_path(project) else {
+            return Err(GlimError::ConfigError("clone_dir is not configured".to_string()));
+        };
+
+        let command = template
+            .replace("{path}", &shell_quote(&path.to_string_lossy()))
+            .replace("{url}", &shell_quote(&project.ssh_git_url));
+
+        spawn_detached(Command::new("sh").arg("-c").arg(&command))
+            .map_err(|e| GlimError::GeneralError(format!("failed to run open_command: {e}")))?;
+
+        Ok(command)
+    }
+}
+
+/// Quotes the value for `sh`, unless it consists of safe characters only.
+pub(crate) fn shell_quote(value: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "/._-+:@".contains(c);
+    if !value.is_empty() && value.chars().all(is_safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
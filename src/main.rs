@@ -1,26 +1,36 @@
 use std::path::PathBuf;
 use std::process::exit;
 use std::sync::mpsc::Sender;
-use clap::Parser;
-use directories::BaseDirs;
+use clap::{Parser, Subcommand};
 use ratatui::{Frame, Terminal};
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Layout, Margin, Rect};
 use ratatui::prelude::Direction;
 use tachyonfx::{Duration, EffectRenderer, Shader};
 use tachyonfx::fx::term256_colors;
+use tui_input::Input;
 
+use crate::capabilities::{accessible, ColorSupport};
 use crate::client::GitlabClient;
+use crate::config_watcher::ConfigWatcher;
+use crate::dispatcher::Dispatcher;
+use crate::domain::{Pipeline, Project};
 use crate::event::{EventHandler, GlimEvent};
+use crate::frame_scheduler::FrameScheduler;
 use crate::glim_app::{GlimApp, GlimConfig};
+use crate::health_check::REQUIRED_SCOPES;
+use crate::i18n::{t, Msg};
+use crate::id::{PipelineId, ProjectId};
 use crate::input::InputProcessor;
-use crate::input::processor::ConfigProcessor;
+use crate::input::processor::OnboardingProcessor;
+use crate::notifier::Notifiers;
+use crate::pipeline_wait::PipelineWait;
 use crate::result::{GlimError, Result};
 use crate::theme::theme;
 use crate::tui::Tui;
-use crate::ui::popup::{ConfigPopup, ConfigPopupState, PipelineActionsPopup, ProjectDetailsPopup};
+use crate::ui::popup::{ApiInspectorPopup, ProjectErrorsPopup, ArtifactsPopup, AuditLogPopup, BranchesPopup, RunnersPopup, SchedulesPopup, CommandPalettePopup, ConfigPopup, HelpPopup, ConfigPopupState, OnboardingPopup, OnboardingPopupState, OnboardingStep, JobLogPopup, SearchPopup, PipelineActionsPopup, PipelineGraphPopup, ProjectDetailsPopup};
 use crate::ui::StatefulWidgets;
-use crate::ui::widget::{LogsWidget, Notification, ProjectsTable};
+use crate::ui::widget::{FollowedPipelineHeader, LogsWidget, Notification, EmptyTab, FailedJobsView, PipelinesView, MultiProjectView, ProjectTree, ProjectsTable, SelectionLine, StatusBar, Tab, TabBar, UnavailableBanner, OfflineBanner};
 
 mod tui;
 mod event;
@@ -36,6 +46,49 @@ mod id;
 mod dispatcher;
 mod input;
 mod notice_service;
+mod audit_log;
+mod notifier;
+mod i18n;
+mod job_log;
+mod search;
+mod issue;
+mod http;
+mod maintenance;
+mod contrast;
+mod watch;
+mod circuit_breaker;
+mod fetch_state;
+mod pipeline_wait;
+mod negative_cache;
+mod page_size;
+mod memory_usage;
+mod pipeline_history;
+mod project_health;
+mod project_cache;
+mod etag_cache;
+mod project_labels;
+mod command;
+mod frame_scheduler;
+mod fuzzy;
+mod token_store;
+mod health_check;
+mod metrics;
+mod recording;
+mod screen_capture;
+mod capabilities;
+mod local_clone;
+mod hooks;
+mod api_inspector;
+mod pipeline_tree;
+mod clipboard;
+mod gitlab_version;
+mod read_only;
+mod token_scopes;
+mod paths;
+mod config_watcher;
+mod connectivity;
+#[cfg(all(test, feature = "gitlab-smoke-test"))]
+mod smoke_test;
 
 /// A TUI for monitoring GitLab CI/CD pipelines and projects
 #[derive(Parser, Debug)]
@@ -44,20 +97,69 @@ pub struct Args {
     /// Alternate path to the configuration file.
     #[arg(short, long, value_name = "FILE")]
     config: Option<PathBuf>,
+    /// Directory of persisted state, e.g. the audit log.
+    #[arg(long, value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+    /// Directory of cached data, e.g. the projects shown at startup.
+    #[arg(long, value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
     /// Print the path to the configuration file and exit.
     #[arg(short, long)]
     print_config_path: bool,
+    /// Accessibility mode: no animations, statuses in words and the
+    /// selection described in plain text.
+    #[arg(long)]
+    accessible: bool,
+    /// Read-only mode: actions changing anything in GitLab, e.g. retrying
+    /// jobs, are disabled; for tokens with the read_api scope.
+    #[arg(long)]
+    read_only: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Watch a pipeline until it completes, then exit with 0 if it succeeded,
+    /// 1 if it failed and 2 otherwise.
+    Wait {
+        /// Id of the project
+        project: u32,
+        /// Id of the pipeline
+        pipeline: u32,
+        /// Shell command to run once the pipeline completes. The outcome is
+        /// available as GLIM_PIPELINE_STATUS, GLIM_PIPELINE_URL,
+        /// GLIM_PROJECT_ID and GLIM_PIPELINE_ID.
+        #[arg(long, value_name = "COMMAND")]
+        hook: Option<String>,
+        /// Give up waiting after this many minutes
+        #[arg(long, value_name = "MINUTES", default_value_t = 120)]
+        timeout: u64,
+    },
+    /// Check the configuration, connectivity to GitLab and the token, then
+    /// exit with 0 if all checks passed and 1 otherwise.
+    Check,
 }
 
 
 fn main() -> Result<()> {
     let args = Args::parse();
-    let config_path = args.config.unwrap_or_else(default_config_path);
+    paths::set_overrides(args.data_dir, args.cache_dir);
+    let config_path = args.config.unwrap_or_else(paths::config_file);
     if args.print_config_path {
         println!("{}", config_path.display());
         exit(0);
     }
     let debug = std::env::var("GLIM_DEBUG").is_ok();
+    if args.accessible {
+        capabilities::force_accessible();
+    }
+    if args.read_only {
+        read_only::force_read_only();
+    }
+    if let Some(Command::Check) = args.command {
+        exit(health_check::run(&config_path, debug));
+    }
 
     // event handler
     let event_handler = EventHandler::new(std::time::Duration::from_millis(33));
@@ -71,62 +173,287 @@ fn main() -> Result<()> {
     tui.enter()?;
 
     let mut widget_states = StatefulWidgets::new(sender.clone());
-    let config = run_config_ui_loop(&mut tui, &mut widget_states, sender.clone(), config_path.clone(), debug)?;
+    let config = run_config_ui_loop(&mut tui, &mut widget_states, sender.clone(), config_path.clone())?;
 
     // app state and initial setup
-    let mut app = GlimApp::new(sender.clone(), config_path, gitlab_client(sender.clone(), config, debug));
+    i18n::set_locale(config.locale);
+    capabilities::set_icon_support(config.icons);
+    capabilities::set_accessible(config.accessible);
+    read_only::set_read_only(config.read_only);
+    capabilities::set_glyphs(&config.glyphs);
+    ui::set_relative_times(config.relative_times);
+    ui::set_branch_lines(config.branch_lines(), config.show_default_branch);
+    ui::fx::configure_animations(config.animations);
+    let notifiers = Notifiers::new(&config, sender.clone());
+    let gitlab = gitlab_client(sender.clone(), config.clone(), debug);
+    let mut app = GlimApp::new(sender.clone(), config_path.clone(), notifiers, &config, gitlab);
     app.apply(GlimEvent::RequestProjects, &mut widget_states);
+    // the token expiry is requested once the version is known
+    app.apply(GlimEvent::RequestGitlabVersion, &mut widget_states);
+    // stops watching when dropped, at the end of main
+    let _config_watcher = ConfigWatcher::watch(&config_path, sender.clone())
+        .inspect_err(|e| sender.dispatch(GlimEvent::Log(e.to_string())))
+        .ok();
+    if let Some(Command::Wait { project, pipeline, hook, timeout }) = args.command {
+        let timeout = std::time::Duration::from_secs(timeout * 60);
+        let wait = PipelineWait::new(ProjectId::new(project), PipelineId::new(pipeline), hook, Some(timeout));
+        app.wait_for_pipeline(wait);
+    }
 
-    // main loop
+    // main loop; idle frames are skipped, see [FrameScheduler]
+    let mut frames = FrameScheduler::new(config.max_fps);
     while app.is_running() {
         widget_states.last_frame = app.process_timers();
+        let mut changed = false;
+        let mut toggle_recording = false;
+        let mut screenshot = false;
         tui.receive_events(|event| {
+            if let GlimEvent::UpdateConfig(config) = &event {
+                frames.configure(config.max_fps);
+            }
+            changed |= !matches!(event, GlimEvent::Tick);
+            toggle_recording |= matches!(event, GlimEvent::ToggleRecording);
+            screenshot |= matches!(event, GlimEvent::Screenshot);
             widget_states.apply(&app, &event);
             app.apply(event, &mut widget_states);
         });
-        tui.draw(|f| render_widgets(f, &app, &mut widget_states))?;
+
+        if toggle_recording {
+            let event = tui.toggle_recording(app.screenshot_dir())
+                .unwrap_or_else(GlimEvent::Error);
+            sender.dispatch(event);
+        }
+        if screenshot {
+            let event = tui.screenshot(app.screenshot_dir(), |f| render_widgets(f, &app, &mut widget_states))
+                .unwrap_or_else(GlimEvent::Error);
+            sender.dispatch(event);
+        }
+
+        let animating = frames.is_animating(widget_states.is_animating(), widget_states.is_popup_open());
+        tui.set_tick_rate(frames.tick_rate(animating));
+        if frames.should_draw(changed, animating) {
+            tui.draw(|f| render_widgets(f, &app, &mut widget_states))?;
+            frames.drawn();
+        }
     }
 
     tui.exit().map_err(|_| GlimError::GeneralError("failed to exit TUI".to_string()))?;
+
+    if let Err(e) = app.save_project_cache() {
+        eprintln!("{e}");
+    }
+
+    if let Some(wait) = app.pipeline_wait() {
+        if let Some(error) = wait.error() {
+            eprintln!("{error}");
+        }
+        if let Err(e) = wait.run_hook() {
+            eprintln!("{e}");
+        }
+        exit(wait.exit_code());
+    }
+
     Ok(())
 }
 
+/// Renders the projects table, or its grouped view, with the header of the
+/// followed pipeline.
+fn render_projects_view(
+    f: &mut Frame,
+    app: &GlimApp,
+    widget_states: &mut StatefulWidgets,
+    main_area: Rect,
+) {
+    let last_tick = widget_states.last_frame;
+
+    // followed pipeline progress header
+    let table_area = if let Some((project, pipeline)) = app.followed_pipeline() {
+        let [header_area, table_area] = Layout::vertical([
+            Constraint::Length(FollowedPipelineHeader::HEIGHT),
+            Constraint::Percentage(100),
+        ]).areas(main_area);
+
+        f.render_widget(FollowedPipelineHeader::new(project, pipeline), header_area);
+        table_area
+    } else {
+        main_area
+    };
+
+    // gitlab pipelines, grouped by namespace or flat
+    if let Some(tree) = widget_states.project_tree.as_mut() {
+        let projects = ProjectTree::new(app.projects(), |id| app.is_pinned(id));
+        f.render_stateful_widget(projects, table_area, tree);
+        let viewport = tree.viewport();
+        widget_states.update_projects_viewport(viewport);
+    } else {
+        // the filter is shown while edited, and while applied
+        let filter = widget_states.project_filter.as_ref()
+            .map(Input::value)
+            .or(Some(app.project_filter()).filter(|query| !query.is_empty()));
+        let projects = ProjectsTable::new(
+            app.projects(),
+            filter,
+            widget_states.project_sort,
+            app.quick_filter(),
+            |id| app.project_row_state(id),
+        );
+        f.render_stateful_widget(projects, table_area, &mut widget_states.project_table_state);
+        if let Some(filter) = widget_states.project_filter.as_ref() {
+            f.set_cursor_position(ProjectsTable::filter_cursor_position(table_area, filter.cursor()));
+        }
+        widget_states.update_projects_viewport(ProjectsTable::visible_rows(table_area, &widget_states.project_table_state));
+    }
+
+    // row effects, e.g. flashing rows of projects with failed or succeeded pipelines
+    let projects = app.projects();
+    widget_states.effects.process_row_effects(last_tick, f.buffer_mut(), |id| match widget_states.project_tree.as_ref() {
+        Some(tree) => tree.row_area(id),
+        None       => {
+            let idx = projects.iter().position(|p| p.id == id)?;
+            ProjectsTable::row_area(table_area, &widget_states.project_table_state, idx)
+        },
+    });
+}
+
+fn render_logs(
+    f: &mut Frame,
+    app: &GlimApp,
+    widget_states: &mut StatefulWidgets,
+    area: Rect,
+) {
+    let raw_logs = app.logs();
+    let logs = LogsWidget::from(&raw_logs)
+        .fetch_summary(app.fetch_summary())
+        .page_size(app.page_size())
+        .level(app.log_level());
+    *widget_states.logs_state.selected_mut() = Some(raw_logs.len());
+    f.render_stateful_widget(logs, area, &mut widget_states.logs_state);
+}
+
 fn render_widgets(
     f: &mut Frame,
     app: &GlimApp,
     widget_states: &mut StatefulWidgets
 ) {
     let last_tick = widget_states.last_frame;
-    let layout = if app.ui.show_internal_logs {
+
+    // status bar
+    let [content_area, status_area] = Layout::vertical([
+        Constraint::Percentage(100),
+        Constraint::Length(StatusBar::HEIGHT),
+    ]).areas(f.area());
+    let status = StatusBar::new(app.status(), app.active_pipelines(), app.project_filter(), app.quick_filter(), app.ui.recording);
+    f.render_widget(status, status_area);
+
+    // selection in plain text, for screen readers
+    let selection = if accessible() { describe_selection(app, widget_states) } else { None };
+    let content_area = match selection {
+        Some(selection) => {
+            let [content_area, selection_area] = Layout::vertical([
+                Constraint::Percentage(100),
+                Constraint::Length(SelectionLine::HEIGHT),
+            ]).areas(content_area);
+
+            f.render_widget(SelectionLine::new(selection), selection_area);
+            content_area
+        },
+        None => content_area,
+    };
+
+    // tab bar
+    let [tab_area, content_area] = Layout::vertical([
+        Constraint::Length(TabBar::HEIGHT),
+        Constraint::Percentage(100),
+    ]).areas(content_area);
+    f.render_widget(TabBar::new(app.ui.tab), tab_area);
+
+    // the logs tab shows the internal logs in full
+    let show_logs_panel = app.ui.show_internal_logs && app.ui.tab != Tab::Logs;
+    let layout = if show_logs_panel {
         Layout::new(Direction::Horizontal, [
-            Constraint::Percentage(65),
-            Constraint::Percentage(35),
-        ]).split(f.area())
+            Constraint::Percentage(app.ui.split_ratio),
+            Constraint::Percentage(100 - app.ui.split_ratio),
+        ]).split(content_area)
     } else {
         Layout::new(Direction::Horizontal, [
             Constraint::Percentage(100),
-        ]).split(f.area())
+        ]).split(content_area)
+    };
+
+    // offline or gitlab unavailable banner
+    let main_area = if let Some(retry_in) = app.offline_retry_in() {
+        let [banner_area, main_area] = Layout::vertical([
+            Constraint::Length(OfflineBanner::HEIGHT),
+            Constraint::Percentage(100),
+        ]).areas(layout[0]);
+
+        f.render_widget(OfflineBanner::new(retry_in), banner_area);
+        main_area
+    } else if let Some(since) = app.unavailable_since() {
+        let [banner_area, main_area] = Layout::vertical([
+            Constraint::Length(UnavailableBanner::HEIGHT),
+            Constraint::Percentage(100),
+        ]).areas(layout[0]);
+
+        f.render_widget(UnavailableBanner::new(since), banner_area);
+        main_area
+    } else {
+        layout[0]
     };
 
-    // gitlab pipelines
-    let projects = ProjectsTable::new(app.projects());
-    f.render_stateful_widget(projects, layout[0], &mut widget_states.project_table_state);
+    // main area of the selected tab
+    match app.ui.tab {
+        Tab::Projects      => render_projects_view(f, app, widget_states, main_area),
+        Tab::Pipelines     => {
+            let pipelines = app.recent_pipelines();
+            let state = &mut widget_states.recent_pipelines_state;
+            f.render_stateful_widget(PipelinesView::new(&pipelines), main_area, state);
+
+            widget_states.selected_recent_pipeline = state.selected()
+                .and_then(|idx| pipelines.get(idx))
+                .map(|(project, p)| (project.id, p.id));
+        },
+        Tab::FailedJobs    => {
+            let jobs = app.failed_jobs();
+            let state = &mut widget_states.failed_jobs_state;
+            f.render_stateful_widget(FailedJobsView::new(&jobs), main_area, state);
+
+            widget_states.selected_failed_job = state.selected()
+                .and_then(|idx| jobs.get(idx))
+                .map(|(project, p, job)| (project.id, p.id, job.id));
+        },
+        Tab::MultiProject  => {
+            let rows = app.pipeline_trees();
+            let state = &mut widget_states.pipeline_trees_state;
+            f.render_stateful_widget(MultiProjectView::new(&rows), main_area, state);
+
+            widget_states.selected_tree_pipeline = state.selected()
+                .and_then(|idx| rows.get(idx))
+                .map(|row| row.id);
+        },
+        Tab::Logs          => render_logs(f, app, widget_states, main_area),
+        Tab::MergeRequests
+        | Tab::Runners     => f.render_widget(EmptyTab::new(app.ui.tab), main_area),
+    }
 
-    // internal logs
-    if app.ui.show_internal_logs {
-        let raw_logs = app.logs();
-        let logs = LogsWidget::from(&raw_logs);
-        *widget_states.logs_state.selected_mut() = Some(raw_logs.len());
-        f.render_stateful_widget(logs, layout[1], &mut widget_states.logs_state);
+    // internal logs, next to the main area
+    if show_logs_panel {
+        render_logs(f, app, widget_states, layout[1]);
     }
 
     // project details popup
     if let Some(project_details) = widget_states.project_details.as_mut() {
-        let popup = ProjectDetailsPopup::new(last_tick);
+        let history = project_details.selected_pipeline()
+            .map(|p| app.pipeline_history(p.project_id, &p.branch))
+            .unwrap_or_default();
+        let popup = ProjectDetailsPopup::new(last_tick, history);
         let popup_area = layout[0].inner(Margin::new(6, 2));
 
         // f.render_effect(popup_area, &mut project_details.fade_in, last_frame_ms);
         f.render_stateful_widget(popup, popup_area, project_details);
+        if let Some(cursor) = project_details.cursor_position {
+            f.set_cursor_position(cursor);
+        }
     }
     
     // pipeline actions popup
@@ -137,9 +464,88 @@ fn render_widgets(
         f.render_stateful_widget(popup, layout[0], pipeline_actions);
     }
 
-    let last_tick = last_tick;
+    // audit log popup
+    if let Some(audit_log) = widget_states.audit_log.as_mut() {
+        f.render_stateful_widget(AuditLogPopup::new(last_tick), layout[0], audit_log);
+    }
+
+    // api inspector popup
+    if let Some(api_inspector) = widget_states.api_inspector.as_mut() {
+        f.render_stateful_widget(ApiInspectorPopup::new(last_tick), layout[0], api_inspector);
+    }
+
+    // project errors popup
+    if let Some(project_errors) = widget_states.project_errors.as_mut() {
+        f.render_stateful_widget(ProjectErrorsPopup::new(last_tick), layout[0], project_errors);
+    }
+
+    // job log popup
+    if let Some(job_log) = widget_states.job_log.as_mut() {
+        let area = if job_log.zoomed { f.area() } else { layout[0] };
+        f.render_stateful_widget(JobLogPopup::new(last_tick), area, job_log);
+    }
+
+    // pipeline stage graph popup
+    if let Some(graph) = widget_states.pipeline_graph.as_mut() {
+        let jobs = app.projects().iter()
+            .find(|p| p.id == graph.project_id)
+            .and_then(|p| p.pipeline(graph.pipeline_id))
+            .and_then(|p| p.jobs.as_deref())
+            .unwrap_or_default();
+
+        f.render_stateful_widget(PipelineGraphPopup::new(last_tick, jobs), layout[0], graph);
+    }
+
+    // branch browser popup
+    if let Some(branches) = widget_states.branches.as_mut() {
+        // the project may be hidden while the popup is open
+        if let Ok(project) = app.project(branches.project_id) {
+            f.render_stateful_widget(BranchesPopup::new(last_tick, project), layout[0], branches);
+        }
+    }
+
+    // pipeline schedules popup
+    if let Some(schedules) = widget_states.schedules.as_mut() {
+        f.render_stateful_widget(SchedulesPopup::new(last_tick), layout[0], schedules);
+    }
+
+    // job artifacts popup
+    if let Some(artifacts) = widget_states.artifacts.as_mut() {
+        f.render_stateful_widget(ArtifactsPopup::new(last_tick), layout[0], artifacts);
+    }
+
+    // runners popup
+    if let Some(runners) = widget_states.runners.as_mut() {
+        f.render_stateful_widget(RunnersPopup::new(last_tick), layout[0], runners);
+    }
+
+    // global search popup
+    if let Some(search) = widget_states.search.as_mut() {
+        f.render_stateful_widget(SearchPopup::new(last_tick), layout[0], search);
+        if search.is_open_complete() {
+            let cursor = search.cursor_position;
+            f.set_cursor_position(cursor);
+        }
+    }
+
+    // help popup
+    if let Some(help) = widget_states.help.as_mut() {
+        f.render_stateful_widget(HelpPopup::new(last_tick), layout[0], help);
+    }
+
+    // command palette popup
+    if let Some(palette) = widget_states.command_palette.as_mut() {
+        f.render_stateful_widget(CommandPalettePopup::new(last_tick), layout[0], palette);
+        if palette.is_open_complete() {
+            let cursor = palette.cursor_position;
+            f.set_cursor_position(cursor);
+        }
+    }
+
     // glitch shader
-    f.render_effect(widget_states.glitch(), f.area(), last_tick);
+    if let Some(glitch) = widget_states.glitch() {
+        f.render_effect(glitch, f.area(), last_tick);
+    }
 
     // fade in table
     if let Some(shader) = &mut widget_states.table_fade_in {
@@ -175,6 +581,37 @@ fn render_widgets(
     }
 }
 
+/// Plain-text description of the selected row of the current tab.
+fn describe_selection(app: &GlimApp, widget_states: &StatefulWidgets) -> Option<String> {
+    let describe = |project: &Project, pipeline: Option<&Pipeline>| match pipeline {
+        Some(p) => format!("{}, {}: {}", project.title(), p.branch, p.status),
+        None    => format!("{}, {}", project.title(), t(Msg::SelectionNoPipelines)),
+    };
+
+    match app.ui.tab {
+        Tab::Projects   => widget_states.selected_project
+            .and_then(|id| app.projects().iter().find(|p| p.id == id))
+            .map(|project| describe(project, project.pipelines.iter().flatten().next())),
+        Tab::Pipelines  => widget_states.selected_recent_pipeline
+            .and_then(|(project_id, pipeline_id)| {
+                let project = app.project(project_id).ok()?;
+                Some(describe(project, project.pipeline(pipeline_id)))
+            }),
+        Tab::MultiProject => widget_states.selected_tree_pipeline
+            .and_then(|(project_id, pipeline_id)| {
+                let (project, pipeline) = app.pipeline(project_id, pipeline_id).ok()?;
+                Some(describe(project, Some(pipeline)))
+            }),
+        Tab::FailedJobs => widget_states.selected_failed_job
+            .and_then(|(project_id, pipeline_id, job_id)| {
+                let (project, pipeline) = app.pipeline(project_id, pipeline_id).ok()?;
+                let job = pipeline.job(job_id)?;
+                Some(format!("{}, {}, {}: {}", project.title(), pipeline.branch, job.name, job.status))
+            }),
+        _               => None,
+    }
+}
+
 fn render_config_popup(
     f: &mut Frame,
     config_popup: &mut ConfigPopupState,
@@ -198,113 +635,141 @@ fn gitlab_client(
     config: GlimConfig,
     debug: bool,
 ) -> GitlabClient {
-    GitlabClient::new(
-        sender,
-        config.gitlab_url,
-        config.gitlab_token,
-        config.search_filter,
-        debug,
-    )
+    GitlabClient::new_from_config(sender, config, debug)
 }
 
-fn default_config_path() -> PathBuf {
-    if let Some(dirs) = BaseDirs::new() {
-        dirs.config_dir().join("glim.toml")
-    } else {
-        PathBuf::from("glim.toml")
+/// Loads the configuration file, with the tokens read from the keyring if
+/// configured. Plaintext tokens are first migrated to the keyring.
+pub fn load_config(config_file: &PathBuf) -> Result<GlimConfig> {
+    let mut config: GlimConfig = confy::load_path(config_file)
+        .map_err(|e| GlimError::ConfigError(e.to_string()))?;
+
+    if token_store::needs_migration(&config) {
+        save_config(config_file, config.clone())?;
+        config = confy::load_path(config_file)
+            .map_err(|e| GlimError::ConfigError(e.to_string()))?;
     }
+
+    token_store::resolve_tokens(&mut config)?;
+    Ok(config)
 }
 
-pub fn save_config(config_file: &PathBuf, config: GlimConfig) -> Result<()> {
+/// Saves the configuration file; the tokens are written to the keyring instead,
+/// if configured.
+pub fn save_config(config_file: &PathBuf, mut config: GlimConfig) -> Result<()> {
+    token_store::store_tokens(&mut config)?;
+
     confy::store_path(config_file, &config)
         .map_err(|e| GlimError::ConfigError(e.to_string()))?;
+    config_watcher::record_contents(config_file);
 
     Ok(())
 }
 
 
-/// Run the configuration UI loop to create the configuration file.
+/// Run the onboarding wizard to create the configuration file.
 /// If the configuration file already exists, it is loaded and returned.
 pub fn run_config_ui_loop(
     tui: &mut Tui,
     ui: &mut StatefulWidgets,
     sender: Sender<GlimEvent>,
     config_file: PathBuf,
-    debug: bool,
 ) -> Result<GlimConfig> {
-        if config_file.exists() {
-            let config: GlimConfig = confy::load_path(config_file)
-                .map_err(|e| GlimError::ConfigError(e.to_string()))?;
-
-            Ok(config)
-        } else {
-            ui.config_popup_state = Some(ConfigPopupState::new(GlimConfig::default()));
-            let sender = sender.clone();
-
-            let mut last_tick = std::time::Instant::now();
-            let mut valid_config: Option<GlimConfig> = None;
-            while valid_config.is_none() && ui.config_popup_state.is_some() {
-                let now = std::time::Instant::now();
-                ui.last_frame = Duration::from_millis((now - last_tick).as_millis() as u32 / 2);
-                last_tick = now;
-
-                let mut input_processor = ConfigProcessor::new(sender.clone());
-
-                tui.receive_events(|event| {
-                    input_processor.apply(&event, ui);
-                    match event {
-                        // GlimEvent::CloseAlert => {}
-                        GlimEvent::ApplyConfiguration => {
-                            let config = ui.config_popup_state.as_ref().unwrap().to_config();
-                            match config.validate() {
+    if config_file.exists() {
+        return load_config(&config_file);
+    }
+
+    ui.onboarding = Some(OnboardingPopupState::new());
+    let mut input_processor = OnboardingProcessor::new(sender.clone());
+
+    let mut last_tick = std::time::Instant::now();
+    let mut valid_config: Option<GlimConfig> = None;
+    let use_256_colors = ColorSupport::Auto.use_256_colors();
+    while valid_config.is_none() && ui.onboarding.is_some() {
+        let now = std::time::Instant::now();
+        ui.last_frame = Duration::from_millis((now - last_tick).as_millis() as u32 / 2);
+        last_tick = now;
+
+        tui.receive_events(|event| {
+            input_processor.apply(&event, ui);
+            match event {
+                GlimEvent::AdvanceOnboarding => {
+                    let Some(wizard) = ui.onboarding.as_mut() else { return };
+                    match validate_onboarding_step(wizard) {
+                        Ok(true) if wizard.is_last_step() => {
+                            let config = wizard.to_config();
+                            match save_config(&config_file, config.clone()) {
                                 Ok(_) => {
-                                    let client = GitlabClient::new_from_config(sender.clone(), config, debug);
-                                    match client.validate_configuration() {
-                                        Ok(_) => {
-                                            let state = ui.config_popup_state.as_ref().unwrap();
-                                            save_config(&config_file, state.to_config())
-                                                .expect("failed to save configuration");
-
-                                            valid_config = Some(state.to_config());
-                                            ui.config_popup_state = None;
-                                        }
-                                        Err(error) => {
-                                            ui.config_popup_state.as_mut().unwrap().error_message = Some(error.to_string());
-                                        }
-                                    }
-                                }
-                                Err(error) => {
-                                    ui.config_popup_state.as_mut().unwrap().error_message = Some(error.to_string());
+                                    valid_config = Some(config);
+                                    ui.onboarding = None;
                                 }
+                                Err(error) => wizard.error_message = Some(error.to_string()),
                             }
                         }
-                        GlimEvent::CloseConfig => {
-                            ui.config_popup_state = None;
-                        }
-                        GlimEvent::Error(error) => {
-                            ui.config_popup_state.as_mut().unwrap().error_message = Some(error.to_string());
-                        }
-                        GlimEvent::Shutdown => {}
-                        _ => {}
+                        Ok(true)   => wizard.next(),
+                        Ok(false)  => wizard.error_message = None,
+                        Err(error) => wizard.error_message = Some(error),
                     }
-                });
-
-                if ui.config_popup_state.is_none() {
-                    break;
                 }
-
-                tui.draw(|f| {
-                    if let Some(config_popup) = ui.config_popup_state.as_mut() {
-                        render_config_popup(f, config_popup, ui.last_frame, f.area())
+                GlimEvent::CloseConfig => {
+                    ui.onboarding = None;
+                }
+                GlimEvent::Error(error) => {
+                    if let Some(wizard) = ui.onboarding.as_mut() {
+                        wizard.error_message = Some(error.to_string());
                     }
-                })?;
-            };
-
-            if let Some(config) = valid_config {
-                Ok(config)
-            } else {
-                tui.exit()?;
-                exit(0)
+                }
+                _ => {}
             }
-        }
+        });
+
+        let Some(wizard) = ui.onboarding.as_mut() else { break };
+        let last_frame = ui.last_frame;
+        tui.draw(|f| {
+            f.render_stateful_widget(OnboardingPopup::new(last_frame), f.area(), wizard);
+
+            // render cursor once UI has ~faded in
+            if wizard.is_open_complete() {
+                let cursor = wizard.cursor_position;
+                f.buffer_mut().set_style(Rect::new(cursor.x, cursor.y, 1, 1), theme().input_selected);
+                f.set_cursor_position(cursor);
+            }
+            if use_256_colors {
+                f.render_effect(&mut term256_colors(), f.area(), last_frame);
+            }
+        })?;
+    }
+
+    if let Some(config) = valid_config {
+        Ok(config)
+    } else {
+        tui.exit()?;
+        exit(0)
+    }
+}
+
+/// Validates the current step of the onboarding wizard, returning whether it
+/// is complete. The token step first checks the token against the instance,
+/// showing its scopes, and completes once confirmed.
+fn validate_onboarding_step(
+    wizard: &mut OnboardingPopupState,
+) -> std::result::Result<bool, String> {
+    let config = wizard.to_config();
+    match wizard.step() {
+        OnboardingStep::Instance if config.gitlab_url.is_empty() =>
+            Err(t(Msg::OnboardingUrlRequired).to_string()),
+        OnboardingStep::Token if !wizard.is_token_validated() => {
+            config.validate()?;
+            let scopes = GitlabClient::check_token(&config).map_err(|e| e.to_string())?;
+            // no scopes are reported by older GitLab versions
+            if !scopes.is_empty() && !scopes.iter().any(|s| REQUIRED_SCOPES.contains(&s.as_str())) {
+                return Err(t(Msg::OnboardingTokenLacksScope).replace("{scopes}", &scopes.join(", ")));
+            }
+            wizard.set_token_scopes(scopes);
+            Ok(false)
+        },
+        OnboardingStep::Polling if wizard.poll_interval().is_none() =>
+            Err(t(Msg::OnboardingInvalidInterval).replace("{min}", &GlimConfig::MIN_POLL_INTERVAL.to_string())),
+        _ => Ok(true),
+    }
 }
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// A period during which polling pauses and API failures are logged instead
+/// of raised as error notices, e.g. for planned GitLab upgrades.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MaintenanceWindow {
+    /// Recurring window, starting on the given days; the window may wrap
+    /// around midnight.
+    Weekly {
+        /// Days on which the window starts; every day if empty
+        #[serde(default)]
+        days: Vec<Weekday>,
+        start: NaiveTime,
+        end: NaiveTime,
+    },
+    /// One-off window, in local time
+    Once {
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    },
+}
+
+impl MaintenanceWindow {
+    pub fn contains(&self, now: DateTime<Local>) -> bool {
+        match self {
+            MaintenanceWindow::Weekly { days, start, end } => {
+                let starts_on = |day: Weekday| days.is_empty() || days.contains(&day);
+                let time = now.time();
+
+                if start <= end {
+                    starts_on(now.weekday()) && *start <= time && time < *end
+                } else {
+                    // wraps around midnight; the early hours belong to the previous day's window
+                    (starts_on(now.weekday()) && *start <= time)
+                        || (starts_on(now.weekday().pred()) && time < *end)
+                }
+            },
+            MaintenanceWindow::Once { from, until } => {
+                let now = now.naive_local();
+                *from <= now && now < *until
+            },
+        }
+    }
+}
+
+/// Returns true if `now` falls within any of the maintenance windows.
+pub fn in_maintenance(windows: &[MaintenanceWindow], now: DateTime<Local>) -> bool {
+    windows.iter().any(|w| w.contains(now))
+}
@@ -0,0 +1,101 @@
+use crate::domain::{Job, Pipeline, Project};
+
+/// Approximate heap and inline memory used by the loaded projects, their
+/// pipelines and jobs, and the job logs kept by the search index. Used to
+/// guide eviction settings and to spot leaks in long-running sessions; the
+/// numbers are estimates, ignoring allocator overhead and hash map slack.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryUsage {
+    pub projects: usize,
+    pub pipelines: usize,
+    pub jobs: usize,
+    pub traces: usize,
+    pub project_count: usize,
+    pub pipeline_count: usize,
+    pub job_count: usize,
+}
+
+impl MemoryUsage {
+    /// Measures the given projects; `traces` is the size of the cached job logs.
+    pub fn measure(projects: &[Project], traces: usize) -> Self {
+        let mut usage = MemoryUsage { traces, ..Self::default() };
+
+        for project in projects {
+            usage.project_count += 1;
+            usage.projects += Self::project_size(project);
+
+            for pipeline in project.pipelines.iter().flatten() {
+                usage.pipeline_count += 1;
+                usage.pipelines += Self::pipeline_size(pipeline);
+
+                for job in pipeline.jobs.iter().flatten() {
+                    usage.job_count += 1;
+                    usage.jobs += Self::job_size(job);
+                }
+            }
+        }
+
+        usage
+    }
+
+    pub fn total(&self) -> usize {
+        self.projects + self.pipelines + self.jobs + self.traces
+    }
+
+    fn project_size(project: &Project) -> usize {
+        size_of::<Project>()
+            + project.path.capacity()
+            + project.description.as_ref().map_or(0, String::capacity)
+            + project.default_branch.capacity()
+            + project.ssh_git_url.capacity()
+            + project.url.capacity()
+            + project.topics.iter().map(|t| size_of::<String>() + t.capacity()).sum::<usize>()
+            + project.approvals.capacity() * size_of::<(u32, crate::domain::Approvals)>()
+    }
+
+    /// Size of the pipeline, excluding its jobs.
+    fn pipeline_size(pipeline: &Pipeline) -> usize {
+        let commit = pipeline.commit.as_ref()
+            .map_or(0, |c| c.title.capacity());
+        let commit_details = pipeline.commit_details.as_ref()
+            .map_or(0, |c| c.message.capacity() + c.author_name.capacity() + c.author_email.capacity());
+
+        size_of::<Pipeline>()
+            + pipeline.branch.capacity()
+            + pipeline.sha.capacity()
+            + pipeline.url.capacity()
+            + commit
+            + commit_details
+    }
+
+    fn job_size(job: &Job) -> usize {
+        size_of::<Job>()
+            + job.name.capacity()
+            + job.stage.capacity()
+            + job.url.capacity()
+            + job.artifacts.iter()
+                .map(|a| size_of_val(a) + a.file_type.capacity() + a.filename.capacity())
+                .sum::<usize>()
+    }
+}
+
+/// Formats a size in bytes, e.g. "1.2 MiB".
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b < 1024        => format!("{b} B"),
+        b if b < 1024 * 1024 => format!("{:.1} KiB", b as f64 / 1024.0),
+        b                    => format!("{:.1} MiB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+impl std::fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "~{} total: {} projects {}, {} pipelines {}, {} jobs {}, cached logs {}",
+            format_bytes(self.total()),
+            self.project_count, format_bytes(self.projects),
+            self.pipeline_count, format_bytes(self.pipelines),
+            self.job_count, format_bytes(self.jobs),
+            format_bytes(self.traces),
+        )
+    }
+}
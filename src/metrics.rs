@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::Response;
+use hyper_util::rt::TokioIo;
+use itertools::Itertools;
+use tokio::net::TcpListener;
+
+use crate::dispatcher::Dispatcher;
+use crate::event::GlimEvent;
+use crate::id::ProjectId;
+use crate::result::GlimError;
+
+/// Collects metrics from the events of glim, served in the Prometheus text
+/// format by [MetricsCollector::serve].
+#[derive(Clone, Default)]
+pub struct MetricsCollector {
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+#[derive(Default)]
+struct Metrics {
+    /// statuses of the latest pipeline of each branch, per project
+    pipelines: HashMap<ProjectId, Vec<String>>,
+    /// start of the projects request in flight
+    poll_started: Option<Instant>,
+    last_poll_seconds: Option<f64>,
+    polls: u64,
+    /// API errors, by kind
+    errors: BTreeMap<&'static str, u64>,
+}
+
+impl MetricsCollector {
+    pub fn apply(&self, event: &GlimEvent) {
+        let mut metrics = self.metrics.lock().unwrap();
+        match event {
+            GlimEvent::RequestProjects => {
+                metrics.poll_started.get_or_insert_with(Instant::now);
+            },
+            GlimEvent::ReceivedProjects(_) | GlimEvent::ProjectsNotModified(_) => {
+                if let Some(started) = metrics.poll_started.take() {
+                    metrics.last_poll_seconds = Some(started.elapsed().as_secs_f64());
+                }
+                metrics.polls += 1;
+            },
+            GlimEvent::ProjectUpdated(project) => {
+                let statuses = project.pipelines.iter()
+                    .flatten()
+                    .unique_by(|p| &p.branch)
+                    .map(|p| p.status.to_string())
+                    .collect();
+
+                metrics.pipelines.insert(project.id, statuses);
+            },
+            GlimEvent::Error(e) | GlimEvent::PipelineUnavailable(_, _, e) => {
+                if let Some(kind) = api_error_kind(e) {
+                    *metrics.errors.entry(kind).or_default() += 1;
+                }
+            },
+            _ => (),
+        }
+    }
+
+    /// Serves the metrics at `/metrics` on a thread of its own; failing to
+    /// bind the address is reported as an error.
+    pub fn serve(&self, addr: SocketAddr, sender: Sender<GlimEvent>) {
+        let collector = self.clone();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to create metrics runtime");
+
+            if let Err(e) = rt.block_on(collector.listen(addr, &sender)) {
+                let message = format!("unable to serve metrics on {addr}: {e}");
+                sender.dispatch(GlimEvent::Error(GlimError::GeneralError(message)));
+            }
+        });
+    }
+
+    async fn listen(self, addr: SocketAddr, sender: &Sender<GlimEvent>) -> std::io::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        sender.dispatch(GlimEvent::Log(format!("serving metrics on http://{addr}/metrics")));
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let collector = self.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |_request| {
+                    let body = collector.render();
+                    async move {
+                        Ok::<_, Infallible>(Response::builder()
+                            .header(CONTENT_TYPE, "text/plain; version=0.0.4")
+                            .body(Full::new(Bytes::from(body)))
+                            .unwrap())
+                    }
+                });
+
+                // connections closed by the client are of no interest
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+            });
+        }
+    }
+
+    /// Formats the metrics in the Prometheus text format.
+    fn render(&self) -> String {
+        let metrics = self.metrics.lock().unwrap();
+        let mut out = String::new();
+
+        let pipelines = metrics.pipelines.values()
+            .flatten()
+            .counts();
+        writeln!(out, "# HELP glim_pipelines Latest pipeline of each branch, by status.").unwrap();
+        writeln!(out, "# TYPE glim_pipelines gauge").unwrap();
+        for (status, count) in pipelines.iter().sorted() {
+            writeln!(out, "glim_pipelines{{status=\"{status}\"}} {count}").unwrap();
+        }
+
+        writeln!(out, "# HELP glim_poll_duration_seconds Duration of the last poll of the projects.").unwrap();
+        writeln!(out, "# TYPE glim_poll_duration_seconds gauge").unwrap();
+        if let Some(seconds) = metrics.last_poll_seconds {
+            writeln!(out, "glim_poll_duration_seconds {seconds:.3}").unwrap();
+        }
+
+        writeln!(out, "# HELP glim_polls_total Completed polls of the projects.").unwrap();
+        writeln!(out, "# TYPE glim_polls_total counter").unwrap();
+        writeln!(out, "glim_polls_total {}", metrics.polls).unwrap();
+
+        writeln!(out, "# HELP glim_api_errors_total Failed requests to GitLab, by kind.").unwrap();
+        writeln!(out, "# TYPE glim_api_errors_total counter").unwrap();
+        for (kind, count) in &metrics.errors {
+            writeln!(out, "glim_api_errors_total{{kind=\"{kind}\"}} {count}").unwrap();
+        }
+
+        out
+    }
+}
+
+/// Kind of a failed request to GitLab; none for errors not caused by a
+/// request, e.g. of the configuration or the clipboard.
+fn api_error_kind(error: &GlimError) -> Option<&'static str> {
+    let kind = match error {
+        GlimError::InvalidGitlabToken
+        | GlimError::ExpiredGitlabToken             => "token",
+        GlimError::NetworkError(_)                  => "network",
+        GlimError::GitlabHttpError(_, _, _)         => "http",
+        GlimError::GitlabUnavailable(_, _, _)       => "unavailable",
+        GlimError::RetriesExhausted(_, _)           => "retries_exhausted",
+        GlimError::JsonDeserializeError(_, _)       => "json",
+        GlimError::GitlabGetJobsError(_, _, _)
+        | GlimError::GitlabGetTriggerJobsError(_, _, _)
+        | GlimError::GitlabGetPipelinesError(_, _)    => "pipelines",
+        GlimError::ConfigError(_)
+        | GlimError::GeneralError(_)
+        | GlimError::ProjectNotFound(_)
+        | GlimError::PipelineNotFound(_, _)
+        | GlimError::JobNotFound(_, _, _)
+        | GlimError::InstanceNotFound(_)            => return None,
+    };
+
+    Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::*;
+
+    #[test]
+    fn only_failed_requests_are_counted_as_api_errors() {
+        let collector = MetricsCollector::default();
+        collector.apply(&GlimEvent::Error(GlimError::GitlabUnavailable(StatusCode::SERVICE_UNAVAILABLE, None, 0)));
+        collector.apply(&GlimEvent::Error(GlimError::NetworkError("connection refused".to_string())));
+        collector.apply(&GlimEvent::Error(GlimError::ConfigError("invalid theme".to_string())));
+        collector.apply(&GlimEvent::Error(GlimError::GeneralError("unable to copy".to_string())));
+
+        let rendered = collector.render();
+        assert!(rendered.contains("glim_api_errors_total{kind=\"unavailable\"} 1"));
+        assert!(rendered.contains("glim_api_errors_total{kind=\"network\"} 1"));
+        assert!(!rendered.contains("kind=\"config\""));
+        assert!(!rendered.contains("kind=\"general\""));
+    }
+}
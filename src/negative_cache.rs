@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::id::{PipelineId, ProjectId};
+
+/// Resources which no longer exist on GitLab, as reported by [NegativeCache].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoneResource {
+    /// jobs of a deleted pipeline
+    Pipeline(ProjectId, PipelineId),
+    /// approvals of a deleted merge request, by iid
+    MergeRequest(ProjectId, u32),
+}
+
+/// Remembers resources which GitLab consistently responds to with 404, e.g.
+/// deleted pipelines, so that they are not requested again on every poll.
+/// Entries are keyed by the path of the resource, and expire after
+/// [NegativeCache::TTL] in case the resource becomes accessible again.
+///
+/// Clones share their state, so that the cache can be moved into the tasks
+/// performing the requests.
+#[derive(Clone, Default)]
+pub struct NegativeCache {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+#[derive(Default)]
+struct Entry {
+    /// consecutive 404 responses
+    not_found: u32,
+    gone_until: Option<Instant>,
+}
+
+impl NegativeCache {
+    /// consecutive 404 responses before a resource is considered gone
+    const NOT_FOUND_THRESHOLD: u32 = 2;
+    pub const TTL: Duration = Duration::from_secs(60 * 60);
+
+    /// Returns true if the resource is known to be gone, and should not be requested.
+    pub fn is_gone(&self, path: &str) -> bool {
+        self.entries.lock().unwrap()
+            .get(path)
+            .and_then(|e| e.gone_until)
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    pub fn record_found(&self, path: &str) {
+        self.entries.lock().unwrap()
+            .remove(path);
+    }
+
+    /// Records a 404 response. Returns true if the resource is now considered gone.
+    pub fn record_not_found(&self, path: &str) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(path.to_string()).or_default();
+
+        entry.not_found += 1;
+        if entry.not_found >= Self::NOT_FOUND_THRESHOLD {
+            entry.gone_until = Some(Instant::now() + Self::TTL);
+        }
+
+        entry.gone_until.is_some()
+    }
+}
+
+impl std::fmt::Display for GoneResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GoneResource::Pipeline(project_id, pipeline_id) =>
+                write!(f, "pipeline_id={pipeline_id} of project_id={project_id}"),
+            GoneResource::MergeRequest(project_id, iid) =>
+                write!(f, "merge request !{iid} of project_id={project_id}"),
+        }
+    }
+}
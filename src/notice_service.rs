@@ -1,14 +1,20 @@
 use std::collections::VecDeque;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 use serde_json::error::Category;
 use crate::event::GlimEvent;
 use crate::id::{JobId, PipelineId, ProjectId};
 use crate::result::GlimError;
+use crate::i18n::{t, Msg};
+use crate::watch::WatchMatch;
 
 #[derive(Debug)]
 pub struct NoticeService {
     info_notices: VecDeque<Notice>,
     error_notices: VecDeque<Notice>,
-    most_recent: Option<Notice>
+    most_recent: Option<Notice>,
+    /// recent failed fetches, most recent last
+    project_errors: VecDeque<ProjectError>,
 }
 
 #[derive(Debug, Clone)]
@@ -17,9 +23,11 @@ pub struct Notice {
     pub message: NoticeMessage,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NoticeLevel {
     Info,
+    Warning,
     Error,
 }
 
@@ -30,18 +38,35 @@ pub enum NoticeMessage {
     // InvalidGitlabToken,
     // ExpiredGitlabToken,
     ConfigError(String),
+    RetriesExhausted(u32, String),
     JsonDeserializeError(Category, String),
     GitlabGetJobsError(ProjectId, PipelineId, String),
     GitlabGetTriggerJobsError(ProjectId, PipelineId, String),
-    GitlabGetPipelinesError(ProjectId, PipelineId, String),
+    GitlabGetPipelinesError(ProjectId, String),
+    WatchMatched(WatchMatch),
+}
+
+/// A failed fetch of the pipelines or jobs of a project, listed by the
+/// project errors popup.
+#[derive(Debug, Clone)]
+pub struct ProjectError {
+    pub timestamp: DateTime<Local>,
+    pub project_id: ProjectId,
+    /// what failed, e.g. [Msg::NoticeGetJobsFailed]
+    pub kind: Msg,
+    pub error: String,
 }
 
 impl NoticeService {
+    /// number of project errors kept for the project errors popup
+    const MAX_PROJECT_ERRORS: usize = 50;
+
     pub fn new() -> Self {
         Self {
             info_notices: VecDeque::new(),
             error_notices: VecDeque::new(),
             most_recent: None,
+            project_errors: VecDeque::new(),
         }
     }
 
@@ -52,23 +77,64 @@ impl NoticeService {
                 // GlimError::ExpiredGitlabToken => {}
                 GlimError::ConfigError(s) =>
                     Some(NoticeMessage::ConfigError(s)),
-                GlimError::GeneralError(s) =>
+                GlimError::GeneralError(s) |
+                GlimError::NetworkError(s) |
+                GlimError::GitlabHttpError(_, s, _) =>
                     Some(NoticeMessage::GeneralMessage(s)),
+                GlimError::RetriesExhausted(retries, s) =>
+                    Some(NoticeMessage::RetriesExhausted(retries, s)),
                 GlimError::JsonDeserializeError(cat, json) =>
                     Some(NoticeMessage::JsonDeserializeError(cat, json)),
                 GlimError::GitlabGetJobsError(project_id, pipeline_id, s) =>
                     Some(NoticeMessage::GitlabGetJobsError(project_id, pipeline_id, s)),
                 GlimError::GitlabGetTriggerJobsError(project_id, pipeline_id, s) =>
                     Some(NoticeMessage::GitlabGetTriggerJobsError(project_id, pipeline_id, s)),
-                GlimError::GitlabGetPipelinesError(project_id, pipeline_id, s) =>
-                    Some(NoticeMessage::GitlabGetPipelinesError(project_id, pipeline_id, s)),
+                GlimError::GitlabGetPipelinesError(project_id, s) =>
+                    Some(NoticeMessage::GitlabGetPipelinesError(project_id, s)),
                 _ => None
-            }.map(|m| self.push_notice(NoticeLevel::Error, m)).unwrap_or(()),
-            GlimEvent::JobLogDownloaded(_project_id, _job_id, _) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage("Job log downloaded".to_string())),
+            }.map(|m| {
+                self.record_project_error(&m);
+                self.push_notice(NoticeLevel::Error, m)
+            }).unwrap_or(()),
+            GlimEvent::WatchMatched(m) => self.push_notice(NoticeLevel::Info, NoticeMessage::WatchMatched(m.clone())),
+            GlimEvent::PipelineTriggered(_, branch) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{branch}", t(Msg::NoticePipelineTriggered)))),
+            GlimEvent::SchedulePlayed(_, schedule_id) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{schedule_id}", t(Msg::NoticeSchedulePlayed)))),
+            GlimEvent::ArtifactsDownloaded(_, path) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{}", t(Msg::NoticeArtifactsDownloaded), path.display()))),
+            GlimEvent::RecordingSaved(path) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{}", t(Msg::NoticeRecordingSaved), path.display()))),
+            GlimEvent::ScreenshotSaved(path) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{}", t(Msg::NoticeScreenshotSaved), path.display()))),
+            GlimEvent::MemoryProfiled(usage) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{usage}", t(Msg::NoticeMemoryUsage)))),
+            GlimEvent::JobRetried(_, _, job_id) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{job_id}", t(Msg::NoticeJobRetried)))),
+            GlimEvent::IssueCreated(_, url) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{url}", t(Msg::NoticeIssueCreated)))),
+            GlimEvent::JobLogDownloaded(_project_id, _job_id, _) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(t(Msg::NoticeJobLogDownloaded).to_string())),
+            GlimEvent::CopyToClipboard(text) => self.push_notice(NoticeLevel::Info, NoticeMessage::GeneralMessage(format!("{}{text}", t(Msg::NoticeCopiedToClipboard)))),
             _ => {}
         }
     }
 
+    /// Recent failed fetches of pipelines and jobs, most recent last.
+    pub fn project_errors(&self) -> &VecDeque<ProjectError> {
+        &self.project_errors
+    }
+
+    fn record_project_error(&mut self, message: &NoticeMessage) {
+        let (project_id, kind, error) = match message {
+            NoticeMessage::GitlabGetJobsError(id, _, s)        => (*id, Msg::NoticeGetJobsFailed, s),
+            NoticeMessage::GitlabGetTriggerJobsError(id, _, s) => (*id, Msg::NoticeGetTriggerJobsFailed, s),
+            NoticeMessage::GitlabGetPipelinesError(id, s)      => (*id, Msg::NoticeGetPipelinesFailed, s),
+            _ => return,
+        };
+
+        if self.project_errors.len() == Self::MAX_PROJECT_ERRORS {
+            self.project_errors.pop_front();
+        }
+        self.project_errors.push_back(ProjectError {
+            timestamp: Local::now(),
+            project_id,
+            kind,
+            error: error.clone(),
+        });
+    }
+
     pub fn has_error(&self) -> bool {
         !self.error_notices.is_empty()
     }
@@ -93,7 +159,8 @@ impl NoticeService {
 
         match level {
             NoticeLevel::Info => self.info_notices.push_back(notice),
-            NoticeLevel::Error => self.error_notices.push_back(notice),
+            // warnings are shown ahead of info notices, as errors are
+            NoticeLevel::Warning | NoticeLevel::Error => self.error_notices.push_back(notice),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,571 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+use chrono::{Local, NaiveTime};
+use itertools::Itertools;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Runtime;
+
+use crate::dispatcher::Dispatcher;
+use crate::domain::{IconRepresentable, PipelineStatus, Project};
+use crate::event::GlimEvent;
+use crate::glim_app::GlimConfig;
+use crate::i18n::{t, Msg};
+use crate::id::{PipelineId, ProjectId};
+use crate::notice_service::NoticeLevel;
+use crate::result::{GlimError, Result};
+use crate::stores::ProjectStore;
+use crate::watch::WatchMatch;
+
+/// Backend for notifications delivered outside of the TUI, e.g. to
+/// the terminal emulator or the desktop environment.
+pub trait Notifier {
+    /// Returns true if the notifier should be notified for the given level.
+    fn accepts(&self, level: NoticeLevel) -> bool;
+
+    fn notify(&mut self, level: NoticeLevel, message: &str) -> Result<()>;
+
+    /// Invoked for every event, for notifiers reacting to more than notices;
+    /// `projects` holds the latest state of each project.
+    fn apply(&mut self, _event: &GlimEvent, _projects: &ProjectStore) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Forwards notable events to all configured [Notifier]s, unless
+/// silenced by quiet hours.
+pub struct Notifiers {
+    sender: Sender<GlimEvent>,
+    notifiers: Vec<Box<dyn Notifier>>,
+    quiet_hours: Option<QuietHours>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// Period of the day during which no notifications are emitted
+    pub quiet_hours: Option<QuietHours>,
+    /// Notifications emitted as terminal escape sequences
+    pub terminal: TerminalNotifierConfig,
+    /// Notifications posted to a Slack or Mattermost incoming webhook
+    pub webhook: Option<WebhookNotifierConfig>,
+    /// Desktop notifications for failed and succeeded pipelines
+    pub desktop: DesktopNotifierConfig,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TerminalNotifierConfig {
+    pub enabled: bool,
+    /// Notice levels which trigger a notification
+    pub levels: Vec<NoticeLevel>,
+    /// Escape sequence template; `{message}` is replaced by the notification
+    /// text. Defaults to OSC 9.
+    pub escape: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebhookNotifierConfig {
+    pub url: String,
+    /// Pipeline events which trigger a notification
+    pub events: Vec<WebhookEvent>,
+    /// Branches watched for recovery, in addition to the default branch
+    pub branches: Vec<String>,
+    /// Maximum number of messages posted per hour
+    pub max_per_hour: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DesktopNotifierConfig {
+    pub enabled: bool,
+    /// Only notify for pipelines of pinned projects
+    pub pinned_only: bool,
+}
+
+/// Audible alert, either the terminal bell or a shell command, e.g.
+/// `{ command = "paplay /usr/share/sounds/freedesktop/stereo/dialog-error.oga" }`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSound {
+    Bell,
+    Command(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A pipeline failed on the default branch
+    PipelineFailed,
+    /// A watched branch succeeded after a failed pipeline
+    BranchRecovered,
+}
+
+/// Emits notifications as terminal escape sequences (OSC 9 by default), which
+/// terminals such as kitty or iTerm2 turn into notifications or window badges.
+/// Sequences are wrapped for passthrough when running inside tmux.
+pub struct TerminalNotifier {
+    levels: Vec<NoticeLevel>,
+    escape: String,
+    tmux: bool,
+}
+
+impl Notifiers {
+    pub fn new(glim_config: &GlimConfig, sender: Sender<GlimEvent>) -> Self {
+        let config = &glim_config.notifications;
+        let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+        if config.terminal.enabled {
+            notifiers.push(Box::new(TerminalNotifier::new(&config.terminal)));
+        }
+        if let Some(webhook) = config.webhook.as_ref() {
+            notifiers.push(Box::new(WebhookNotifier::new(webhook, sender.clone())));
+        }
+        if config.desktop.enabled {
+            notifiers.push(Box::new(DesktopNotifier::new(&config.desktop, &glim_config.pinned_projects)));
+        }
+
+        Self {
+            sender,
+            notifiers,
+            quiet_hours: config.quiet_hours,
+        }
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent, projects: &ProjectStore) -> Result<()> {
+        match event {
+            GlimEvent::Error(e)           => self.notify(NoticeLevel::Error, &e.to_string()),
+            GlimEvent::UpdateConfig(config) => {
+                *self = Self::new(config, self.sender.clone());
+                Ok(())
+            },
+            // explicitly requested, so not subject to quiet hours
+            GlimEvent::TestWebhook => self.notifiers.iter_mut()
+                .try_for_each(|n| n.apply(event, projects)),
+            _ if self.is_quiet() => Ok(()),
+            _ => self.notifiers.iter_mut()
+                .try_for_each(|n| n.apply(event, projects)),
+        }
+    }
+
+    pub fn is_quiet(&self) -> bool {
+        self.quiet_hours.is_some_and(|q| q.contains(Local::now().time()))
+    }
+
+    pub fn notify(&mut self, level: NoticeLevel, message: &str) -> Result<()> {
+        if self.is_quiet() {
+            return Ok(());
+        }
+
+        self.notifiers.iter_mut()
+            .filter(|n| n.accepts(level))
+            .try_for_each(|n| n.notify(level, message))
+    }
+}
+
+impl QuietHours {
+    /// Returns true if `time` falls within the quiet hours; the
+    /// period may wrap around midnight.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+}
+
+impl Default for TerminalNotifierConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            levels: vec![NoticeLevel::Error],
+            escape: None,
+        }
+    }
+}
+
+impl TerminalNotifier {
+    const OSC_9: &'static str = "\x1b]9;{message}\x07";
+
+    pub fn new(config: &TerminalNotifierConfig) -> Self {
+        Self {
+            levels: config.levels.clone(),
+            escape: config.escape.clone().unwrap_or_else(|| Self::OSC_9.to_string()),
+            tmux: std::env::var("TMUX").is_ok(),
+        }
+    }
+
+    fn failure_message(projects: &ProjectStore, project_id: ProjectId, pipeline_id: PipelineId) -> Option<String> {
+        let (project, pipeline) = projects.pipeline(project_id, pipeline_id).ok()?;
+
+        Some(t(Msg::NotifyPipelineFailed)
+            .replace("{project}", &project.path)
+            .replace("{pipeline}", &pipeline_id.to_string())
+            .replace("{branch}", &pipeline.branch))
+    }
+
+    fn escape_sequence(&self, message: &str) -> String {
+        // control characters would terminate the sequence prematurely
+        let message: String = message.chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+
+        let sequence = self.escape.replace("{message}", &message);
+        if self.tmux {
+            format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+        } else {
+            sequence
+        }
+    }
+}
+
+impl Notifier for TerminalNotifier {
+    fn accepts(&self, level: NoticeLevel) -> bool {
+        self.levels.contains(&level)
+    }
+
+    fn notify(&mut self, _level: NoticeLevel, message: &str) -> Result<()> {
+        let mut stdout = std::io::stdout();
+        stdout.write_all(self.escape_sequence(message).as_bytes())
+            .and_then(|_| stdout.flush())
+            .map_err(|e| GlimError::GeneralError(format!("failed to emit terminal notification: {e}")))
+    }
+
+    fn apply(&mut self, event: &GlimEvent, projects: &ProjectStore) -> Result<()> {
+        match event {
+            // failed pipelines are notified at the error level
+            GlimEvent::PipelineStatusChanged(project_id, pipeline_id, _, PipelineStatus::Failed)
+                if self.accepts(NoticeLevel::Error) =>
+            {
+                if let Some(message) = Self::failure_message(projects, *project_id, *pipeline_id) {
+                    self.notify(NoticeLevel::Error, &message)?;
+                }
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for WebhookNotifierConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            events: vec![WebhookEvent::PipelineFailed, WebhookEvent::BranchRecovered],
+            branches: Vec::new(),
+            max_per_hour: 20,
+        }
+    }
+}
+
+/// Posts pipeline failures and recoveries to a Slack or Mattermost incoming
+/// webhook. Both accept the same `{"text": ...}` payload.
+pub struct WebhookNotifier {
+    config: WebhookNotifierConfig,
+    sender: Sender<GlimEvent>,
+    client: Client,
+    /// most recent completed pipeline per project and branch
+    last_completed: HashMap<(ProjectId, String), (PipelineId, PipelineStatus)>,
+    sent_at: VecDeque<Instant>,
+    rt: Runtime,
+}
+
+impl WebhookNotifier {
+    const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(3600);
+
+    pub fn new(config: &WebhookNotifierConfig, sender: Sender<GlimEvent>) -> Self {
+        Self {
+            config: config.clone(),
+            sender,
+            client: Client::new(),
+            last_completed: HashMap::new(),
+            sent_at: VecDeque::new(),
+            rt: Runtime::new().expect("failed to create runtime"),
+        }
+    }
+
+    /// Returns notification messages for pipelines completed since the last
+    /// update. Pipelines already completed when first seen are only recorded.
+    fn pipeline_messages(&mut self, project: &Project) -> Vec<String> {
+        let pipelines = match project.pipelines.as_ref() {
+            Some(pipelines) => pipelines,
+            None            => return Vec::new(),
+        };
+
+        let watched = |branch: &str| branch == project.default_branch
+            || self.config.branches.iter().any(|b| b == branch);
+
+        // pipelines are ordered by recency
+        let latest_completed: Vec<_> = pipelines.iter()
+            .filter(|p| watched(&p.branch) && !p.status.is_active())
+            .unique_by(|p| p.branch.clone())
+            .collect();
+
+        let mut messages = Vec::new();
+        for pipeline in latest_completed {
+            let key = (project.id, pipeline.branch.clone());
+            let previous = self.last_completed.insert(key, (pipeline.id, pipeline.status.clone()));
+            let previous_status = match previous {
+                Some((id, _)) if id == pipeline.id => continue,
+                Some((_, status))                  => status,
+                None                               => continue,
+            };
+
+            let is_default_branch = pipeline.branch == project.default_branch;
+            let event = match pipeline.status {
+                PipelineStatus::Failed if is_default_branch =>
+                    Some((WebhookEvent::PipelineFailed, Msg::NotifyWebhookFailed)),
+                PipelineStatus::Success if previous_status == PipelineStatus::Failed =>
+                    Some((WebhookEvent::BranchRecovered, Msg::NotifyWebhookRecovered)),
+                _ => None,
+            };
+
+            if let Some((_, msg)) = event.filter(|(e, _)| self.config.events.contains(e)) {
+                messages.push(t(msg)
+                    .replace("{icon}", &pipeline.status.icon())
+                    .replace("{project}", &project.path)
+                    .replace("{branch}", &pipeline.branch)
+                    .replace("{url}", &pipeline.url));
+            }
+        }
+
+        messages
+    }
+
+    /// Returns false if the hourly message budget is spent.
+    fn acquire_rate_limit(&mut self) -> bool {
+        let now = Instant::now();
+        while self.sent_at.front().is_some_and(|t| now.duration_since(*t) > Self::RATE_LIMIT_WINDOW) {
+            self.sent_at.pop_front();
+        }
+
+        let available = self.sent_at.len() < self.config.max_per_hour;
+        if available {
+            self.sent_at.push_back(now);
+        }
+
+        available
+    }
+
+    fn post(&self, message: String, on_success: Option<String>) {
+        let request = self.client.post(&self.config.url)
+            .header("Content-Type", "application/json")
+            .body(serde_json::json!({ "text": message }).to_string());
+
+        let sender = self.sender.clone();
+        self.rt.spawn(async move {
+            // not dispatched as errors, as they would trigger another notification
+            match request.send().await.and_then(|r| r.error_for_status()) {
+                Ok(_)  => if let Some(log) = on_success { sender.dispatch(GlimEvent::Log(log)) },
+                Err(e) => sender.dispatch(GlimEvent::Log(format!("failed to post webhook notification: {}", e.without_url()))),
+            }
+        });
+    }
+
+    /// The host of the webhook URL; the full URL is a secret and never logged.
+    fn host(&self) -> String {
+        Url::parse(&self.config.url).ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| "<invalid url>".to_string())
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn accepts(&self, _level: NoticeLevel) -> bool {
+        false
+    }
+
+    fn notify(&mut self, _level: NoticeLevel, _message: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn apply(&mut self, event: &GlimEvent, _projects: &ProjectStore) -> Result<()> {
+        match event {
+            GlimEvent::TestWebhook => self.post(
+                t(Msg::NotifyWebhookTest).to_string(),
+                Some(format!("webhook test message posted to {}", self.host())),
+            ),
+            GlimEvent::ProjectUpdated(project) => {
+                for message in self.pipeline_messages(project) {
+                    if self.acquire_rate_limit() {
+                        self.post(message, None);
+                    } else {
+                        self.sender.dispatch(GlimEvent::Log("webhook rate limit reached, dropping notification".to_string()));
+                    }
+                }
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for DesktopNotifierConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pinned_only: true,
+        }
+    }
+}
+
+/// Shows desktop notifications when pipelines fail or succeed, through
+/// the platform's notification service.
+pub struct DesktopNotifier {
+    pinned_only: bool,
+    pinned: HashSet<ProjectId>,
+    /// last pipeline notified per project; pinned projects may also be watched
+    last_notified: HashMap<ProjectId, PipelineId>,
+}
+
+impl DesktopNotifier {
+    pub fn new(config: &DesktopNotifierConfig, pinned: &[ProjectId]) -> Self {
+        Self {
+            pinned_only: config.pinned_only,
+            pinned: pinned.iter().copied().collect(),
+            last_notified: HashMap::new(),
+        }
+    }
+
+    fn accepts_project(&self, project_id: ProjectId) -> bool {
+        !self.pinned_only || self.pinned.contains(&project_id)
+    }
+
+    fn pipeline_message(
+        projects: &ProjectStore,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        status: &PipelineStatus,
+    ) -> Option<(String, String)> {
+        let (project, pipeline) = projects.pipeline(project_id, pipeline_id).ok()?;
+
+        let body = match status {
+            PipelineStatus::Failed => t(Msg::NotifyDesktopFailed),
+            _                      => t(Msg::NotifyDesktopSucceeded),
+        };
+
+        Some((
+            format!("{} {}", status.icon(), project.path),
+            body.replace("{pipeline}", &pipeline_id.to_string())
+                .replace("{branch}", &pipeline.branch),
+        ))
+    }
+
+    fn watch_message(projects: &ProjectStore, watch_match: &WatchMatch) -> Option<(String, String)> {
+        let project = projects.find(watch_match.project_id)?;
+
+        Some((
+            format!("{} {}", watch_match.status.icon(), project.path),
+            format!("{}: {}", watch_match.branch, watch_match.outcome()),
+        ))
+    }
+
+    /// Shows the notification, unless already shown for the pipeline.
+    fn notify_pipeline(
+        &mut self,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        message: Option<(String, String)>,
+    ) -> Result<()> {
+        if self.last_notified.get(&project_id) == Some(&pipeline_id) {
+            return Ok(());
+        }
+
+        match message {
+            Some((title, body)) => {
+                self.last_notified.insert(project_id, pipeline_id);
+                Self::show(&title, &body)
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn show(title: &str, body: &str) -> Result<()> {
+        notify_rust::Notification::new()
+            .appname("glim")
+            .summary(title)
+            .body(body)
+            .show()
+            .map(|_| ())
+            .map_err(|e| GlimError::GeneralError(format!("failed to show desktop notification: {e}")))
+    }
+}
+
+/// Spawns the command without waiting for it to complete; output is discarded.
+pub(crate) fn spawn_detached(command: &mut Command) -> std::io::Result<()> {
+    let mut child = command
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // reaps the process once it exits
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+impl Notifier for DesktopNotifier {
+    fn accepts(&self, _level: NoticeLevel) -> bool {
+        false
+    }
+
+    fn notify(&mut self, _level: NoticeLevel, _message: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn apply(&mut self, event: &GlimEvent, projects: &ProjectStore) -> Result<()> {
+        match event {
+            GlimEvent::TogglePinProject(id) => {
+                let was_pinned = self.pinned.remove(id);
+                if !was_pinned {
+                    self.pinned.insert(*id);
+                }
+            },
+            GlimEvent::PipelineStatusChanged(project_id, pipeline_id, _, status @ (PipelineStatus::Failed | PipelineStatus::Success))
+                if self.accepts_project(*project_id) =>
+            {
+                let message = Self::pipeline_message(projects, *project_id, *pipeline_id, status);
+                self.notify_pipeline(*project_id, *pipeline_id, message)?;
+            },
+            // watches apply regardless of pinned_only
+            GlimEvent::WatchMatched(watch_match) => {
+                let message = Self::watch_message(projects, watch_match);
+                self.notify_pipeline(watch_match.project_id, watch_match.pipeline_id, message)?;
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl AlertSound {
+    pub fn play(&self) -> Result<()> {
+        match self {
+            AlertSound::Bell => {
+                let mut stdout = std::io::stdout();
+                stdout.write_all(b"\x07")
+                    .and_then(|_| stdout.flush())
+                    .map_err(|e| GlimError::GeneralError(format!("failed to ring terminal bell: {e}")))
+            },
+            AlertSound::Command(command) => {
+                let mut shell = Command::new("sh");
+                shell.arg("-c").arg(command);
+                spawn_detached(&mut shell)
+                    .map_err(|e| GlimError::GeneralError(format!("failed to play alert sound: {e}")))
+            },
+        }
+    }
+}
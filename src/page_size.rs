@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Bounds of the page size of pipeline requests, see [PageSizeTuner].
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct PageSizeBounds {
+    pub min: u16,
+    pub max: u16,
+}
+
+/// Tunes the page size of pipeline requests to the responsiveness of GitLab.
+/// The page size is halved after slow responses and timeouts, which are common
+/// with large responses from self-hosted instances, and is increased again
+/// step-wise while responses are fast.
+///
+/// Clones share their state, so that the tuner can be moved into the tasks
+/// performing the requests.
+#[derive(Clone)]
+pub struct PageSizeTuner {
+    bounds: PageSizeBounds,
+    current: Arc<AtomicU16>,
+}
+
+impl PageSizeTuner {
+    /// Responses slower than this decrease the page size.
+    const SLOW_RESPONSE: Duration = Duration::from_secs(5);
+    /// Responses faster than this increase the page size.
+    const FAST_RESPONSE: Duration = Duration::from_secs(1);
+    const STEP: u16 = 10;
+
+    pub fn new(bounds: PageSizeBounds) -> Self {
+        let bounds = PageSizeBounds { min: bounds.min.max(1), max: bounds.max.max(bounds.min) };
+        let initial = PageSizeBounds::DEFAULT_PAGE_SIZE.clamp(bounds.min, bounds.max);
+
+        Self { bounds, current: Arc::new(AtomicU16::new(initial)) }
+    }
+
+    /// The effective page size.
+    pub fn current(&self) -> u16 {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    /// Adjusts the page size to the time it took to receive a response.
+    pub fn record_response(&self, elapsed: Duration) {
+        if elapsed > Self::SLOW_RESPONSE {
+            self.decrease();
+        } else if elapsed < Self::FAST_RESPONSE {
+            self.update(|size| size.saturating_add(Self::STEP));
+        }
+    }
+
+    pub fn record_timeout(&self) {
+        self.decrease();
+    }
+
+    fn decrease(&self) {
+        self.update(|size| size / 2);
+    }
+
+    fn update(&self, f: impl Fn(u16) -> u16) {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        // only fails if the closure returns None
+        let _ = self.current.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |size| Some(f(size).clamp(min, max)));
+    }
+}
+
+impl PageSizeBounds {
+    /// page size before any tuning
+    const DEFAULT_PAGE_SIZE: u16 = 60;
+}
+
+impl Default for PageSizeBounds {
+    fn default() -> Self {
+        Self { min: 20, max: 100 }
+    }
+}
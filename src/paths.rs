@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use directories::BaseDirs;
+use once_cell::sync::Lazy;
+
+/// Directories overriding the defaults, see [set_overrides].
+static OVERRIDES: Lazy<RwLock<Overrides>> = Lazy::new(Default::default);
+
+#[derive(Debug, Default)]
+struct Overrides {
+    data_dir: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+}
+
+/// Overrides the data and cache directories, e.g. from `--data-dir` and `--cache-dir`.
+pub fn set_overrides(data_dir: Option<PathBuf>, cache_dir: Option<PathBuf>) {
+    *OVERRIDES.write().expect("paths lock poisoned") = Overrides { data_dir, cache_dir };
+}
+
+/// Default path of the configuration file, e.g. `~/.config/glim.toml`.
+pub fn config_file() -> PathBuf {
+    BaseDirs::new()
+        .map(|dirs| dirs.config_dir().join("glim.toml"))
+        .unwrap_or_else(|| PathBuf::from("glim.toml"))
+}
+
+/// Directory of persisted state, e.g. the audit log; `$XDG_STATE_HOME/glim`
+/// unless overridden.
+pub fn data_dir() -> PathBuf {
+    let overridden = OVERRIDES.read().expect("paths lock poisoned").data_dir.clone();
+    overridden.unwrap_or_else(|| BaseDirs::new()
+        .map(|dirs| dirs.state_dir().unwrap_or(dirs.data_local_dir()).join("glim"))
+        .unwrap_or_else(fallback_dir))
+}
+
+/// Directory of data which is recreated if missing, e.g. the project cache;
+/// `$XDG_CACHE_HOME/glim` unless overridden.
+pub fn cache_dir() -> PathBuf {
+    let overridden = OVERRIDES.read().expect("paths lock poisoned").cache_dir.clone();
+    overridden.unwrap_or_else(|| BaseDirs::new()
+        .map(|dirs| dirs.cache_dir().join("glim"))
+        .unwrap_or_else(fallback_dir))
+}
+
+pub fn audit_log() -> PathBuf {
+    data_dir().join("audit.jsonl")
+}
+
+pub fn project_cache() -> PathBuf {
+    cache_dir().join("projects.json")
+}
+
+/// Used without a home directory; never the working directory, which
+/// changes between sessions.
+fn fallback_dir() -> PathBuf {
+    std::env::temp_dir().join("glim")
+}
@@ -0,0 +1,76 @@
+use std::collections::{HashMap, VecDeque};
+
+use chrono::Duration;
+
+use crate::domain::{Pipeline, PipelineStatus};
+use crate::id::{PipelineId, ProjectId};
+
+/// Durations and outcomes of finished pipelines, per project and branch. Unlike the
+/// pipelines of a [Project](crate::domain::Project), which only cover the
+/// latest page, runs are retained across polls.
+#[derive(Default)]
+pub struct PipelineHistory {
+    runs: HashMap<(ProjectId, String), VecDeque<PipelineRun>>,
+}
+
+/// A finished pipeline in [PipelineHistory].
+#[derive(Debug, Clone)]
+pub struct PipelineRun {
+    pub pipeline_id: PipelineId,
+    pub duration: Duration,
+    pub status: PipelineStatus,
+}
+
+impl PipelineHistory {
+    /// Number of runs retained per branch.
+    pub const MAX_RUNS: usize = 30;
+
+    /// Records the pipeline, if finished. Runs already recorded are updated,
+    /// as the duration is more accurate once the jobs are fetched.
+    pub fn record(&mut self, pipeline: &Pipeline) {
+        if pipeline.status.is_active() || pipeline.gone {
+            return;
+        }
+
+        let run = PipelineRun {
+            pipeline_id: pipeline.id,
+            duration: Self::duration(pipeline),
+            status: pipeline.status.clone(),
+        };
+
+        let runs = self.runs
+            .entry((pipeline.project_id, pipeline.branch.clone()))
+            .or_default();
+
+        match runs.iter().position(|r| r.pipeline_id == run.pipeline_id) {
+            Some(idx) => runs[idx] = run,
+            None => {
+                // pipelines may arrive out of order, e.g. after a refresh
+                let idx = runs.partition_point(|r| r.pipeline_id < run.pipeline_id);
+                runs.insert(idx, run);
+                if runs.len() > Self::MAX_RUNS {
+                    runs.pop_front();
+                }
+            },
+        }
+    }
+
+    /// Returns the recorded runs of the branch, oldest first.
+    pub fn runs(&self, project_id: ProjectId, branch: &str) -> Vec<PipelineRun> {
+        self.runs.get(&(project_id, branch.to_string()))
+            .map(|runs| runs.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Duration of a finished pipeline; measured by its jobs if fetched, otherwise
+    /// approximated by the time of the last update.
+    fn duration(pipeline: &Pipeline) -> Duration {
+        let jobs_finished = pipeline.jobs.as_ref()
+            .is_some_and(|jobs| jobs.iter().any(|j| j.finished_at.is_some()));
+
+        match jobs_finished {
+            true  => pipeline.duration(),
+            false => pipeline.updated_at.signed_duration_since(pipeline.created_at),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::{Pipeline, Project};
+use crate::id::{PipelineId, ProjectId};
+
+type PipelineKey = (ProjectId, PipelineId);
+/// the fetched pipelines, with their project
+type Pipelines<'a> = HashMap<PipelineKey, (&'a Project, &'a Pipeline)>;
+/// trigger jobs of each pipeline, with the pipeline they started
+type Downstream<'a> = HashMap<PipelineKey, Vec<(&'a str, PipelineKey)>>;
+
+/// A pipeline of a multi-project pipeline tree, as listed by [pipeline_trees].
+pub struct PipelineTreeRow<'a> {
+    /// 0 for the upstream pipeline at the root of the tree
+    pub depth: usize,
+    pub id: PipelineKey,
+    /// the project and pipeline, unless the pipeline has not been fetched,
+    /// e.g. as its project is not in the projects table
+    pub pipeline: Option<(&'a Project, &'a Pipeline)>,
+    /// name of the trigger job starting the pipeline; none for the root
+    pub trigger: Option<&'a str>,
+    /// completed and total pipelines of the tree; only set for the root
+    pub progress: Option<(usize, usize)>,
+}
+
+/// Stitches the pipelines of all projects into trees of upstream and
+/// downstream pipelines, following the trigger jobs of the upstream
+/// pipelines. Pipelines without downstream pipelines of other projects are
+/// left out; trees are ordered by the last update of their root, most
+/// recent first, and listed depth-first.
+pub fn pipeline_trees(projects: &[Project]) -> Vec<PipelineTreeRow<'_>> {
+    let pipelines: Pipelines = projects.iter()
+        .flat_map(|project| project.pipelines.iter().flatten().map(move |p| ((project.id, p.id), (project, p))))
+        .collect();
+
+    let mut downstream: Downstream = HashMap::new();
+    for (&id, &(_, pipeline)) in &pipelines {
+        for job in pipeline.jobs.iter().flatten() {
+            if let Some(child) = job.downstream {
+                // child pipelines of the same project are shown by the pipeline graph
+                if child.0 != id.0 {
+                    downstream.entry(id).or_default().push((job.name.as_str(), child));
+                }
+            }
+        }
+    }
+
+    let children: HashSet<PipelineKey> = downstream.values()
+        .flatten()
+        .map(|(_, child)| *child)
+        .collect();
+
+    let mut roots: Vec<(&Project, &Pipeline)> = downstream.keys()
+        .filter(|id| !children.contains(id))
+        .filter_map(|id| pipelines.get(id).copied())
+        .collect();
+    roots.sort_by_key(|(_, pipeline)| Reverse(pipeline.updated_at));
+
+    let mut rows = Vec::new();
+    for (project, pipeline) in roots {
+        let root_idx = rows.len();
+        let mut visited = HashSet::new();
+        push_tree(&mut rows, &pipelines, &downstream, &mut visited, (project.id, pipeline.id), None, 0);
+
+        let tree = &rows[root_idx..];
+        let completed = tree.iter()
+            .filter(|row| row.pipeline.is_some_and(|(_, p)| !p.status.is_active()))
+            .count();
+        rows[root_idx].progress = Some((completed, tree.len()));
+    }
+
+    rows
+}
+
+fn push_tree<'a>(
+    rows: &mut Vec<PipelineTreeRow<'a>>,
+    pipelines: &Pipelines<'a>,
+    downstream: &Downstream<'a>,
+    visited: &mut HashSet<PipelineKey>,
+    id: PipelineKey,
+    trigger: Option<&'a str>,
+    depth: usize,
+) {
+    // pipelines triggering each other are not expected, but would never end
+    if !visited.insert(id) {
+        return;
+    }
+
+    rows.push(PipelineTreeRow {
+        depth,
+        id,
+        pipeline: pipelines.get(&id).copied(),
+        trigger,
+        progress: None,
+    });
+
+    for (job_name, child) in downstream.get(&id).into_iter().flatten() {
+        push_tree(rows, pipelines, downstream, visited, *child, Some(job_name), depth + 1);
+    }
+}
@@ -0,0 +1,163 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::domain::{Pipeline, PipelineStatus};
+use crate::id::{PipelineId, ProjectId};
+use crate::result::{GlimError, Result};
+
+/// Interval between requests of the awaited pipeline.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Watches a pipeline until it completes, after which glim exits with a
+/// status code reflecting the outcome; see `glim wait`.
+#[derive(Debug, Clone)]
+pub struct PipelineWait {
+    pub project_id: ProjectId,
+    pub pipeline_id: PipelineId,
+    /// shell command run once the pipeline completes
+    hook: Option<String>,
+    /// set once the project has been loaded and the pipeline is followed
+    following: bool,
+    /// status and url of the completed pipeline
+    outcome: Option<(PipelineStatus, String)>,
+    /// the wait fails once passed
+    deadline: Option<Instant>,
+    /// time of the last request of the pipeline, see [Self::poll]
+    last_polled: Option<Instant>,
+    /// why the wait ended without an outcome, e.g. as it timed out
+    error: Option<String>,
+}
+
+impl PipelineWait {
+    pub fn new(
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        hook: Option<String>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            project_id,
+            pipeline_id,
+            hook,
+            following: false,
+            outcome: None,
+            deadline: timeout.map(|timeout| Instant::now() + timeout),
+            last_polled: None,
+            error: None,
+        }
+    }
+
+    /// Returns true the first time it's called; the pipeline is followed
+    /// once its project is known.
+    pub fn start_following(&mut self) -> bool {
+        !std::mem::replace(&mut self.following, true)
+    }
+
+    /// Records the outcome of the pipeline, if it has completed. Returns
+    /// true when the pipeline completed with this update.
+    pub fn update(&mut self, pipeline: &Pipeline) -> bool {
+        if self.is_done() || pipeline.status.is_active() {
+            return false;
+        }
+
+        self.outcome = Some((pipeline.status.clone(), pipeline.url.clone()));
+        true
+    }
+
+    /// Returns true if the pipeline is due to be requested by id, which
+    /// does not depend on its project being loaded.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let due = !self.is_done() && self.last_polled.is_none_or(|at| now - at >= POLL_INTERVAL);
+        if due {
+            self.last_polled = Some(now);
+        }
+
+        due
+    }
+
+    pub fn is_timed_out(&self, now: Instant) -> bool {
+        !self.is_done() && self.deadline.is_some_and(|deadline| now >= deadline)
+    }
+
+    /// Ends the wait without an outcome.
+    pub fn fail(&mut self, error: String) {
+        if !self.is_done() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Why the wait ended without an outcome, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn is_done(&self) -> bool {
+        self.outcome.is_some() || self.error.is_some()
+    }
+
+    /// Exit code of glim: 0 if the pipeline succeeded, 1 if it failed and
+    /// 2 for any other outcome, including errors, timeouts and quitting
+    /// before it completed.
+    pub fn exit_code(&self) -> i32 {
+        match self.outcome.as_ref().map(|(status, _)| status) {
+            Some(PipelineStatus::Success) => 0,
+            Some(PipelineStatus::Failed)  => 1,
+            _                             => 2,
+        }
+    }
+
+    /// Runs the hook command, if any, once the pipeline has completed. The
+    /// outcome is passed to the command through environment variables.
+    pub fn run_hook(&self) -> Result<()> {
+        let (Some(hook), Some((status, url))) = (&self.hook, &self.outcome) else {
+            return Ok(());
+        };
+
+        let exit_status = Command::new("sh")
+            .arg("-c")
+            .arg(hook)
+            .env("GLIM_PROJECT_ID", self.project_id.to_string())
+            .env("GLIM_PIPELINE_ID", self.pipeline_id.to_string())
+            .env("GLIM_PIPELINE_STATUS", status.to_string())
+            .env("GLIM_PIPELINE_URL", url)
+            .status()
+            .map_err(|e| GlimError::GeneralError(format!("failed to run hook: {e}")))?;
+
+        if exit_status.success() {
+            Ok(())
+        } else {
+            Err(GlimError::GeneralError(format!("hook exited with {exit_status}")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_is_polled_until_the_wait_ends() {
+        let mut wait = PipelineWait::new(ProjectId::new(1), PipelineId::new(2), None, None);
+        let now = Instant::now();
+
+        assert!(wait.poll(now));
+        assert!(!wait.poll(now + Duration::from_secs(1)));
+        assert!(wait.poll(now + POLL_INTERVAL));
+
+        wait.fail("not found".to_string());
+        assert!(!wait.poll(now + POLL_INTERVAL * 2));
+        assert_eq!(wait.error(), Some("not found"));
+        assert_eq!(wait.exit_code(), 2);
+    }
+
+    #[test]
+    fn wait_times_out_at_the_deadline() {
+        let timeout = Duration::from_secs(60);
+        let wait = PipelineWait::new(ProjectId::new(1), PipelineId::new(2), None, Some(timeout));
+        let now = Instant::now();
+
+        assert!(!wait.is_timed_out(now));
+        assert!(wait.is_timed_out(now + timeout));
+        assert!(!PipelineWait::new(ProjectId::new(1), PipelineId::new(2), None, None).is_timed_out(now + timeout));
+    }
+}
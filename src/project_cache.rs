@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Project;
+use crate::result::{GlimError, Result};
+
+/// Projects and pipelines from the previous session, written on exit and
+/// read on startup so that the projects table is populated before the
+/// first fetch completes. Restored projects are shown as stale until their
+/// pipelines are fetched again.
+pub struct ProjectCache {
+    path: PathBuf,
+    /// urls of the GitLab instances, as project ids are namespaced by instance
+    instances: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    saved_at: DateTime<Utc>,
+    instances: Vec<String>,
+    projects: Vec<Project>,
+}
+
+/// Projects read from the [ProjectCache].
+pub struct CachedProjects {
+    pub saved_at: DateTime<Utc>,
+    pub projects: Vec<Project>,
+}
+
+impl ProjectCache {
+    pub fn new(path: PathBuf, instances: Vec<String>) -> Self {
+        Self { path, instances }
+    }
+
+    /// Reads the cached projects. Missing or unreadable caches, and caches
+    /// written for other GitLab instances, are ignored.
+    pub fn load(&self) -> Option<CachedProjects> {
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        let cache: CacheFile = serde_json::from_str(&content).ok()?;
+
+        (cache.instances == self.instances).then_some(CachedProjects {
+            saved_at: cache.saved_at,
+            projects: cache.projects,
+        })
+    }
+
+    pub fn save(&self, projects: &[Project]) -> Result<()> {
+        let cache = CacheFile {
+            saved_at: Utc::now(),
+            instances: self.instances.clone(),
+            projects: projects.to_vec(),
+        };
+
+        let json = serde_json::to_string(&cache)
+            .map_err(|e| GlimError::GeneralError(format!("failed to serialize project cache: {e}")))?;
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| GlimError::GeneralError(format!("failed to create cache dir: {e}")))?;
+        }
+
+        write_atomically(&self.path, &json)
+            .map_err(|e| GlimError::GeneralError(format!("failed to write project cache: {e}")))
+    }
+}
+
+/// Writes to a temporary file first, so that an interrupted write does not
+/// leave a truncated cache behind.
+fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(tmp, path)
+}
@@ -0,0 +1,122 @@
+use crate::domain::PipelineStatus;
+use crate::pipeline_history::PipelineRun;
+
+/// Health of a project: the success rate of the latest finished pipelines on
+/// its default branch, and whether it is improving. Canceled and skipped
+/// pipelines are not counted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Health {
+    /// share of successful pipelines, from 0.0 to 1.0
+    pub success_rate: f32,
+    pub trend: Trend,
+}
+
+/// Success rate of the newer half of the pipelines in [Health], relative to
+/// the older half.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Improving,
+    Steady,
+    Declining,
+}
+
+impl Health {
+    /// Number of the latest pipelines the health is computed from.
+    pub const WINDOW: usize = 10;
+
+    /// Computes the health from the runs of the default branch, oldest first;
+    /// none without any succeeded or failed run.
+    pub fn of(runs: &[PipelineRun]) -> Option<Self> {
+        let outcomes: Vec<bool> = runs.iter()
+            .filter(|run| matches!(run.status, PipelineStatus::Success | PipelineStatus::Failed))
+            .map(|run| run.status == PipelineStatus::Success)
+            .collect();
+
+        let latest = &outcomes[outcomes.len().saturating_sub(Self::WINDOW)..];
+        let rate = success_rate(latest)?;
+
+        let (older, newer) = latest.split_at(latest.len() / 2);
+        let trend = match (success_rate(older), success_rate(newer)) {
+            (Some(older), Some(newer)) if newer > older => Trend::Improving,
+            (Some(older), Some(newer)) if newer < older => Trend::Declining,
+            _                                           => Trend::Steady,
+        };
+
+        Some(Self { success_rate: rate, trend })
+    }
+
+    /// Success rate in percent, rounded.
+    pub fn percent(&self) -> u8 {
+        (self.success_rate * 100.0).round() as u8
+    }
+}
+
+fn success_rate(outcomes: &[bool]) -> Option<f32> {
+    if outcomes.is_empty() {
+        return None;
+    }
+
+    let succeeded = outcomes.iter().filter(|succeeded| **succeeded).count();
+    Some(succeeded as f32 / outcomes.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::id::PipelineId;
+
+    use super::*;
+    use PipelineStatus::{Canceled, Failed, Skipped, Success};
+
+    fn runs(statuses: &[PipelineStatus]) -> Vec<PipelineRun> {
+        statuses.iter()
+            .enumerate()
+            .map(|(idx, status)| PipelineRun {
+                pipeline_id: PipelineId::new(idx as u32 + 1),
+                duration: Duration::minutes(5),
+                status: status.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_health_without_finished_runs() {
+        assert_eq!(Health::of(&[]), None);
+        assert_eq!(Health::of(&runs(&[Canceled, Skipped])), None);
+    }
+
+    #[test]
+    fn success_rate_of_the_latest_runs() {
+        let mut statuses = vec![Failed; 5];
+        statuses.extend([Success, Success, Failed, Success, Success, Success, Success, Failed, Success, Success]);
+
+        let health = Health::of(&runs(&statuses)).unwrap();
+        assert_eq!(health.percent(), 80);
+    }
+
+    #[test]
+    fn canceled_and_skipped_runs_are_not_counted() {
+        let health = Health::of(&runs(&[Success, Canceled, Failed, Skipped])).unwrap();
+        assert_eq!(health.percent(), 50);
+    }
+
+    #[test]
+    fn trend_compares_the_newer_half_to_the_older_half() {
+        let improving = Health::of(&runs(&[Failed, Failed, Success, Success])).unwrap();
+        assert_eq!(improving.trend, Trend::Improving);
+
+        let declining = Health::of(&runs(&[Success, Success, Success, Failed])).unwrap();
+        assert_eq!(declining.trend, Trend::Declining);
+
+        let steady = Health::of(&runs(&[Success, Failed, Success, Failed])).unwrap();
+        assert_eq!(steady.trend, Trend::Steady);
+    }
+
+    #[test]
+    fn single_run_is_steady() {
+        let health = Health::of(&runs(&[Failed])).unwrap();
+        assert_eq!(health.percent(), 0);
+        assert_eq!(health.trend, Trend::Steady);
+    }
+}
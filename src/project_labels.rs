@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use ratatui::style::{Color, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Project;
+use crate::gruvbox::Gruvbox;
+
+/// A label shown as a colored chip next to the namespace of matching projects
+/// in the projects table, e.g. to tell "payments" from "infra" projects apart.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectLabel {
+    /// Text of the label chip
+    pub name: String,
+    /// Background color of the chip, as a color name or hex code, e.g. "#458588"
+    pub color: String,
+    /// Labels projects with any of these GitLab topics
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Labels projects with a path starting with any of these prefixes, e.g. "acme/payments/"
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+}
+
+impl ProjectLabel {
+    fn matches(&self, project: &Project) -> bool {
+        self.topics.iter().any(|topic| project.topics.contains(topic))
+            || self.namespaces.iter().any(|prefix| project.path.starts_with(prefix.as_str()))
+    }
+}
+
+/// Configured [ProjectLabel]s with their resolved styles. The first
+/// matching label applies.
+#[derive(Default)]
+pub struct ProjectLabels {
+    labels: Vec<(ProjectLabel, Style)>,
+}
+
+impl ProjectLabels {
+    /// Resolves the colors of the labels; labels with unknown colors are
+    /// skipped, see [Self::validate].
+    pub fn new(labels: &[ProjectLabel]) -> Self {
+        Self {
+            labels: labels.iter()
+                .filter_map(|label| Self::style(label).map(|style| (label.clone(), style)))
+                .collect(),
+        }
+    }
+
+    /// Returns an error naming the first label with an unknown color.
+    pub fn validate(labels: &[ProjectLabel]) -> Result<(), String> {
+        match labels.iter().find(|label| Self::style(label).is_none()) {
+            Some(label) => Err(format!("unknown color '{}' of project label '{}'", label.color, label.name)),
+            None        => Ok(()),
+        }
+    }
+
+    /// Returns the name and style of the label of the project, if any.
+    pub fn label(&self, project: &Project) -> Option<(&str, Style)> {
+        self.labels.iter()
+            .find(|(label, _)| label.matches(project))
+            .map(|(label, style)| (label.name.as_str(), *style))
+    }
+
+    fn style(label: &ProjectLabel) -> Option<Style> {
+        Color::from_str(&label.color).ok()
+            .map(|color| Style::default().fg(Gruvbox::Dark0Hard.into()).bg(color))
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::event::GlimEvent;
+use crate::i18n::Msg;
+
+/// Read-only mode, see [set_read_only].
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Whether read-only mode was requested with `--read-only`, overriding the configuration.
+static READ_ONLY_FORCED: AtomicBool = AtomicBool::new(false);
+
+/// Enables read-only mode regardless of the configuration, for `--read-only`.
+pub fn force_read_only() {
+    READ_ONLY_FORCED.store(true, Ordering::Relaxed);
+    READ_ONLY.store(true, Ordering::Relaxed);
+}
+
+/// Sets read-only mode, see [GlimConfig::read_only](crate::glim_app::GlimConfig::read_only);
+/// it stays enabled if forced by [force_read_only].
+pub fn set_read_only(enabled: bool) {
+    let enabled = enabled || READ_ONLY_FORCED.load(Ordering::Relaxed);
+    READ_ONLY.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether read-only mode is enabled: actions changing anything in GitLab
+/// are hidden, and refused if requested anyway.
+pub fn read_only() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+/// Returns the name of the action, if the event changes anything in GitLab;
+/// these require a token with the `api` scope.
+pub fn mutation(event: &GlimEvent) -> Option<Msg> {
+    match event {
+        GlimEvent::RetryJob(_, _, _)      => Some(Msg::ActionRetryJob),
+        GlimEvent::CreateIssue(_, _)      => Some(Msg::ActionCreateIssue),
+        GlimEvent::TriggerPipeline(_, _)  => Some(Msg::ShortcutTriggerPipeline),
+        GlimEvent::PlaySchedule(_, _)     => Some(Msg::ActionRunSchedule),
+        _                                 => None,
+    }
+}
@@ -0,0 +1,63 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::Local;
+use ratatui::buffer::Buffer;
+
+use crate::result::{GlimError, Result};
+use crate::screen_capture::buffer_to_ansi_string;
+
+/// Records the rendered frames to an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/)
+/// file, for replaying the session with asciinema.
+pub struct ScreenRecorder {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    started: Instant,
+}
+
+impl ScreenRecorder {
+    /// Creates the recording in the directory, named after the current time.
+    pub fn start(dir: &Path, width: u16, height: u16) -> Result<Self> {
+        let path = dir.join(format!("glim-{}.cast", Local::now().format("%Y%m%d-%H%M%S")));
+        let file = File::create(&path)
+            .map_err(|e| GlimError::GeneralError(format!("unable to create recording {}: {e}", path.display())))?;
+
+        let header = serde_json::json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": Local::now().timestamp(),
+            "title": "glim",
+        });
+
+        let mut recorder = Self { writer: BufWriter::new(file), path, started: Instant::now() };
+        recorder.write_line(&header.to_string())?;
+        Ok(recorder)
+    }
+
+    /// Appends the frame as an output event, timestamped relative to the start.
+    pub fn record(&mut self, buffer: &Buffer) -> Result<()> {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let event = serde_json::json!([elapsed, "o", buffer_to_ansi_string(buffer)]);
+        self.write_line(&event.to_string())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Completes the recording, returning the path of the file.
+    pub fn finish(mut self) -> Result<PathBuf> {
+        self.writer.flush()
+            .map_err(|e| GlimError::GeneralError(format!("unable to write recording: {e}")))?;
+
+        Ok(self.path)
+    }
+
+    fn write_line(&mut self, line: &str) -> Result<()> {
+        writeln!(self.writer, "{line}")
+            .map_err(|e| GlimError::GeneralError(format!("unable to write recording: {e}")))
+    }
+}
@@ -1,6 +1,8 @@
+use std::time::Duration;
+use reqwest::StatusCode;
 use serde_json::error::Category;
 use thiserror::Error;
-use crate::id::{PipelineId, ProjectId};
+use crate::id::{JobId, PipelineId, ProjectId};
 
 pub type Result<T> = std::result::Result<T, GlimError>;
 
@@ -16,20 +18,114 @@ pub enum GlimError {
     #[error("{0}")]
     GeneralError(String),
 
-    #[error("{:0} - JSON: {1}")]
+    #[error("{0}")]
+    NetworkError(String),
+    #[error("{1}")]
+    GitlabHttpError(StatusCode, String, Option<Duration>),
+    /// 502 or 503, typically during GitLab maintenance or overload, from the
+    /// GitLab instance with the given index, see [GlimError::with_instance]
+    #[error("GitLab unavailable: {0}")]
+    GitlabUnavailable(StatusCode, Option<Duration>, u8),
+    #[error("gave up after {0} retries: {1}")]
+    RetriesExhausted(u32, String),
+
+    #[error("{0:?} - JSON: {1}")]
     JsonDeserializeError(Category, String),
 
     #[error("project_id={0}/pipeline_id={1}: {2}")]
     GitlabGetJobsError(ProjectId, PipelineId, String),
     #[error("project_id={0}/pipeline_id={1}: {2}")]
     GitlabGetTriggerJobsError(ProjectId, PipelineId, String),
-    #[error("project_id={0}/pipeline_id={1}: {2}")]
-    GitlabGetPipelinesError(ProjectId, PipelineId, String),
+    #[error("project_id={0}: {1}")]
+    GitlabGetPipelinesError(ProjectId, String),
+
+    /// the project is no longer in the store, e.g. hidden while an action was pending
+    #[error("project_id={0} not found")]
+    ProjectNotFound(ProjectId),
+    #[error("project_id={0}/pipeline_id={1} not found")]
+    PipelineNotFound(ProjectId, PipelineId),
+    #[error("project_id={0}/pipeline_id={1}/job_id={2} not found")]
+    JobNotFound(ProjectId, PipelineId, JobId),
+    /// the GitLab instance of a project is no longer configured
+    #[error("GitLab instance {0} is not configured")]
+    InstanceNotFound(u8),
+}
+
+impl GlimError {
+    /// Transient failures which may succeed if the request is repeated.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            GlimError::NetworkError(_) => true,
+            GlimError::GitlabUnavailable(_, _, _) => true,
+            GlimError::GitlabHttpError(status, _, _) => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            _ => false,
+        }
+    }
+
+    /// GitLab is down for maintenance or overloaded; failures are expected
+    /// to persist for a while.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, GlimError::GitlabUnavailable(_, _, _))
+    }
+
+    /// Attributes GitLab being unavailable to the instance with the given index;
+    /// other errors are returned as is.
+    pub fn with_instance(self, instance: u8) -> GlimError {
+        match self {
+            GlimError::GitlabUnavailable(status, retry_after, _) =>
+                GlimError::GitlabUnavailable(status, retry_after, instance),
+            e => e,
+        }
+    }
+
+    /// GitLab could not be reached, e.g. as the network is down.
+    pub fn is_network(&self) -> bool {
+        matches!(self, GlimError::NetworkError(_))
+    }
+
+    /// The request timed out, either locally or at a gateway in front of GitLab.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self,
+            GlimError::NetworkError(_) |
+            GlimError::GitlabHttpError(StatusCode::GATEWAY_TIMEOUT, _, _)
+        )
+    }
+
+    /// The requested resource does not exist, or is not accessible.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, GlimError::GitlabHttpError(StatusCode::NOT_FOUND, _, _))
+    }
+
+    /// Attributes the error of a failed fetch to its project, e.g. as a
+    /// [GlimError::GitlabGetJobsError], for notices naming the project. GitLab
+    /// being unavailable is kept as is, as it is not specific to the project.
+    pub fn attributed(self, attribute: impl FnOnce(String) -> GlimError) -> GlimError {
+        if self.is_unavailable() {
+            self
+        } else {
+            attribute(self.to_string())
+        }
+    }
+
+    /// Delay requested by the server before retrying, as per the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            GlimError::GitlabHttpError(_, _, retry_after) |
+            GlimError::GitlabUnavailable(_, retry_after, _) => *retry_after,
+            _ => None,
+        }
+    }
 }
 
 impl From<reqwest::Error> for GlimError {
     fn from(e: reqwest::Error) -> Self {
         match () {
+            _ if e.is_timeout() || e.is_connect() => GlimError::NetworkError(e.to_string()),
             _ => GlimError::GeneralError(e.to_string()),
         }
     }
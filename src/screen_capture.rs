@@ -0,0 +1,134 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use itertools::Itertools;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use unicode_width::UnicodeWidthStr;
+
+use crate::result::{GlimError, Result};
+
+/// Formats the buffer as ANSI escape sequences, drawing each row from its
+/// first column; for replacing the screen, e.g. in a recording.
+pub fn buffer_to_ansi_string(buffer: &Buffer) -> String {
+    buffer_to_ansi_lines(buffer).iter()
+        .enumerate()
+        .fold(String::new(), |mut out, (y, line)| {
+            write!(out, "\x1b[{};1H{line}", y + 1).unwrap();
+            out
+        })
+}
+
+/// Rows of the buffer with the colors and modifiers of the cells as ANSI
+/// escape sequences; each row ends with the style reset.
+pub fn buffer_to_ansi_lines(buffer: &Buffer) -> Vec<String> {
+    rows(buffer)
+        .map(|cells| {
+            let mut line = String::new();
+            let mut style = None;
+            for cell in cells {
+                let cell_style = (cell.fg, cell.bg, cell.modifier);
+                if style != Some(cell_style) {
+                    line.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                    style = Some(cell_style);
+                }
+                line.push_str(cell.symbol());
+            }
+
+            line.push_str("\x1b[0m");
+            line
+        })
+        .collect()
+}
+
+/// Rows of the buffer as plain text, without trailing whitespace.
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    rows(buffer)
+        .map(|cells| cells.map(Cell::symbol).collect::<String>().trim_end().to_string())
+        .join("\n")
+}
+
+/// Writes the buffer to an ANSI and a plain-text file in the directory,
+/// named after the current time. Returns the path of the ANSI file.
+pub fn save_screenshot(dir: &Path, buffer: &Buffer) -> Result<PathBuf> {
+    let name = format!("glim-{}", Local::now().format("%Y%m%d-%H%M%S"));
+    let ansi_path = dir.join(format!("{name}.ansi"));
+    let text_path = dir.join(format!("{name}.txt"));
+
+    let write = |path: &Path, content: String| std::fs::write(path, content)
+        .map_err(|e| GlimError::GeneralError(format!("unable to write screenshot {}: {e}", path.display())));
+
+    write(&ansi_path, buffer_to_ansi_lines(buffer).join("\n") + "\n")?;
+    write(&text_path, buffer_to_text(buffer) + "\n")?;
+
+    Ok(ansi_path)
+}
+
+/// Cells of each row, skipping the cells covered by a preceding wide character.
+fn rows(buffer: &Buffer) -> impl Iterator<Item = impl Iterator<Item = &Cell>> {
+    let area = buffer.area;
+    (area.top()..area.bottom()).map(move |y| {
+        let mut skip = 0;
+        (area.left()..area.right())
+            .map(move |x| &buffer[(x, y)])
+            .filter(move |cell| {
+                if skip > 0 {
+                    skip -= 1;
+                    return false;
+                }
+                skip = cell.symbol().width().saturating_sub(1);
+                true
+            })
+    })
+}
+
+/// Select Graphic Rendition sequence of the colors and modifiers.
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ].into_iter()
+        .filter(|(m, _)| modifier.contains(*m))
+        .for_each(|(_, code)| codes.push(code.to_string()));
+
+    codes.extend(color_code(fg, false));
+    codes.extend(color_code(bg, true));
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let (extended, named) = if background { ("48", 40) } else { ("38", 30) };
+
+    let code = match color {
+        Color::Reset          => return None,
+        Color::Black          => named,
+        Color::Red            => named + 1,
+        Color::Green          => named + 2,
+        Color::Yellow         => named + 3,
+        Color::Blue           => named + 4,
+        Color::Magenta        => named + 5,
+        Color::Cyan           => named + 6,
+        Color::Gray           => named + 7,
+        Color::DarkGray       => 90 + offset,
+        Color::LightRed       => 91 + offset,
+        Color::LightGreen     => 92 + offset,
+        Color::LightYellow    => 93 + offset,
+        Color::LightBlue      => 94 + offset,
+        Color::LightMagenta   => 95 + offset,
+        Color::LightCyan      => 96 + offset,
+        Color::White          => 97 + offset,
+        Color::Indexed(i)     => return Some(format!("{extended};5;{i}")),
+        Color::Rgb(r, g, b)   => return Some(format!("{extended};2;{r};{g};{b}")),
+    };
+
+    Some(code.to_string())
+}
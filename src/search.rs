@@ -0,0 +1,224 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+
+use itertools::Itertools;
+
+use crate::domain::Project;
+use crate::event::GlimEvent;
+use crate::id::{JobId, PipelineId, ProjectId};
+use crate::job_log::JobLog;
+
+/// Maximum number of results per [SearchKind].
+const MAX_RESULTS_PER_KIND: usize = 10;
+/// Number of job logs kept in the index; older logs are evicted first.
+const MAX_INDEXED_LOGS: usize = 10;
+/// Only the tail of each log is indexed, where failures are reported.
+const MAX_LOG_LINES: usize = 1000;
+const MAX_SNIPPET_LEN: usize = 200;
+
+/// Searchable text from all loaded projects, pipelines, jobs and job logs,
+/// kept up to date from store events.
+#[derive(Default)]
+pub struct SearchIndex {
+    projects: HashMap<ProjectId, ProjectEntries>,
+    logs: VecDeque<(JobId, Vec<SearchEntry>)>,
+    job_pipelines: HashMap<JobId, (ProjectId, PipelineId)>,
+}
+
+#[derive(Default)]
+struct ProjectEntries {
+    project: Vec<SearchEntry>,
+    pipelines: Vec<SearchEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchEntry {
+    pub kind: SearchKind,
+    pub text: String,
+    /// path of the project the entry belongs to
+    pub context: String,
+    pub target: SearchTarget,
+}
+
+/// Search results are grouped by kind, in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchKind {
+    Project,
+    Branch,
+    Commit,
+    Job,
+    Log,
+}
+
+/// The entity to jump to when selecting a search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    Project(ProjectId),
+    Pipeline(ProjectId, PipelineId),
+    JobLog(ProjectId, PipelineId, JobId),
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub entry: SearchEntry,
+    /// byte range of the match in the entry's text
+    pub matched: Range<usize>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent) {
+        match event {
+            GlimEvent::ProjectUpdated(project) => self.index_project(project),
+            GlimEvent::ReceivedJobTrace(_, job_id, trace)
+                | GlimEvent::JobLogDownloaded(_, job_id, trace) => self.index_log(*job_id, trace),
+            _ => (),
+        }
+    }
+
+    /// Returns case-insensitive matches for the query, grouped by kind.
+    pub fn search(&self, query: &str) -> Vec<SearchResult> {
+        if query.trim().is_empty() {
+            return Vec::new();
+        }
+
+        let project_entries = self.projects.values()
+            .flat_map(|p| p.project.iter().chain(p.pipelines.iter()));
+        let log_entries = self.logs.iter()
+            .rev()
+            .flat_map(|(_, entries)| entries.iter());
+
+        project_entries.chain(log_entries)
+            .filter_map(|entry| find_ignore_case(&entry.text, query)
+                .map(|matched| SearchResult { entry: entry.clone(), matched }))
+            .sorted_by_key(|r| r.entry.kind)
+            .chunk_by(|r| r.entry.kind)
+            .into_iter()
+            .flat_map(|(_, results)| results.take(MAX_RESULTS_PER_KIND).collect_vec())
+            .collect()
+    }
+
+    fn index_project(&mut self, project: &Project) {
+        let entries = self.projects.entry(project.id).or_default();
+        entries.project = vec![SearchEntry {
+            kind: SearchKind::Project,
+            text: project.path.clone(),
+            context: String::new(),
+            target: SearchTarget::Project(project.id),
+        }];
+
+        // projects without loaded pipelines keep their previously indexed pipelines
+        let pipelines = match project.pipelines.as_ref() {
+            Some(pipelines) => pipelines,
+            None => return,
+        };
+
+        let entry = |kind, text: &str, target| SearchEntry {
+            kind,
+            text: text.to_string(),
+            context: project.path.clone(),
+            target,
+        };
+
+        // pipelines are ordered by recency; only the most recent match per text is kept
+        let mut pipeline_entries: Vec<SearchEntry> = Vec::new();
+        for p in pipelines {
+            let target = SearchTarget::Pipeline(project.id, p.id);
+            pipeline_entries.push(entry(SearchKind::Branch, &p.branch, target));
+            if let Some(commit) = p.commit.as_ref() {
+                pipeline_entries.push(entry(SearchKind::Commit, &commit.title, target));
+            }
+            p.jobs.iter().flatten().for_each(|job| {
+                self.job_pipelines.insert(job.id, (project.id, p.id));
+                pipeline_entries.push(entry(SearchKind::Job, &job.name, target));
+            });
+        }
+
+        entries.pipelines = pipeline_entries.into_iter()
+            .unique_by(|e| (e.kind, e.text.clone()))
+            .collect();
+    }
+
+    /// Approximate memory used by the indexed job logs.
+    pub fn log_bytes(&self) -> usize {
+        self.logs.iter()
+            .flat_map(|(_, entries)| entries)
+            .map(|e| size_of::<SearchEntry>() + e.text.capacity() + e.context.capacity())
+            .sum()
+    }
+
+    fn index_log(&mut self, job_id: JobId, trace: &str) {
+        let (project_id, pipeline_id) = match self.job_pipelines.get(&job_id) {
+            Some(ids) => *ids,
+            None      => return,
+        };
+
+        let context = self.projects.get(&project_id)
+            .and_then(|p| p.project.first())
+            .map(|e| e.text.clone())
+            .unwrap_or_default();
+
+        let log = JobLog::parse(trace);
+        let entries = log.lines.iter()
+            .skip(log.lines.len().saturating_sub(MAX_LOG_LINES))
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .unique()
+            .map(|line| SearchEntry {
+                kind: SearchKind::Log,
+                text: line.chars().take(MAX_SNIPPET_LEN).collect(),
+                context: context.clone(),
+                target: SearchTarget::JobLog(project_id, pipeline_id, job_id),
+            })
+            .collect();
+
+        self.logs.retain(|(id, _)| *id != job_id);
+        self.logs.push_back((job_id, entries));
+        if self.logs.len() > MAX_INDEXED_LOGS {
+            self.logs.pop_front();
+        }
+    }
+}
+
+impl SearchTarget {
+    /// Returns the events which navigate to the target.
+    pub fn navigation_events(&self) -> Vec<GlimEvent> {
+        match *self {
+            SearchTarget::Project(id) => vec![
+                GlimEvent::SelectedProject(id),
+            ],
+            SearchTarget::Pipeline(id, pipeline_id) => vec![
+                GlimEvent::SelectedProject(id),
+                GlimEvent::OpenProjectDetails(id),
+                GlimEvent::OpenPipelineActions(id, pipeline_id),
+            ],
+            SearchTarget::JobLog(id, pipeline_id, job_id) => vec![
+                GlimEvent::SelectedProject(id),
+                GlimEvent::OpenJobLog(id, pipeline_id, job_id),
+            ],
+        }
+    }
+}
+
+/// Finds the first case-insensitive occurrence of `query` in `text`.
+fn find_ignore_case(text: &str, query: &str) -> Option<Range<usize>> {
+    let lowercase = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let query: Vec<char> = query.chars().map(lowercase).collect();
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut end = start;
+        let mut chars = text[start..].chars();
+        for &q in &query {
+            let c = chars.next()?;
+            if lowercase(c) != q {
+                return None;
+            }
+            end += c.len_utf8();
+        }
+
+        Some(start..end)
+    })
+}
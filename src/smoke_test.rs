@@ -0,0 +1,99 @@
+//! End-to-end smoke test against a GitLab instance, e.g. a local container:
+//!
+//! ```sh
+//! GITLAB_TEST_URL=http://localhost:8080/api/v4 GITLAB_TEST_TOKEN=glpat-... \
+//!     cargo test --features gitlab-smoke-test -- --ignored
+//! ```
+//!
+//! Projects, pipelines and jobs are fetched through [GitlabClient], applied to
+//! the [ProjectStore] and rendered with a headless [TestBackend], exercising the
+//! DTO mappings against the real API rather than fixtures.
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use ratatui::backend::TestBackend;
+use ratatui::widgets::TableState;
+use ratatui::Terminal;
+
+use crate::client::GitlabClient;
+use crate::domain::ProjectRowState;
+use crate::event::GlimEvent;
+use crate::glim_app::GlimConfig;
+use crate::stores::{ProjectSort, ProjectStore};
+use crate::ui::widget::ProjectsTable;
+
+/// Time without events after which fetching is considered complete.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn test_config() -> GlimConfig {
+    let env = |name: &str| std::env::var(name)
+        .unwrap_or_else(|_| panic!("{name} must be set to run the smoke test"));
+
+    GlimConfig {
+        gitlab_url: env("GITLAB_TEST_URL"),
+        gitlab_token: env("GITLAB_TEST_TOKEN"),
+        ..GlimConfig::default()
+    }
+}
+
+#[test]
+#[ignore = "requires a GitLab instance, see GITLAB_TEST_URL"]
+fn fetch_store_render() {
+    let config = test_config();
+    let (sender, receiver) = mpsc::channel();
+
+    let client = GitlabClient::new_from_config(sender.clone(), config.clone(), false);
+    client.validate_configuration().expect("invalid GITLAB_TEST_URL or GITLAB_TEST_TOKEN");
+
+    let mut store = ProjectStore::new(sender.clone(), &config);
+    client.dispatch_list_projects(None);
+
+    // routes requests to the client until GitLab has nothing left to send,
+    // like GlimApp does for the events handled by the store
+    while let Ok(event) = receiver.recv_timeout(IDLE_TIMEOUT) {
+        store.apply(&event);
+        match event {
+            GlimEvent::RequestPipelines(id) =>
+                client.dispatch_get_pipelines(id, None),
+            GlimEvent::RequestJobs(project_id, pipeline_id) =>
+                client.dispatch_get_jobs(project_id, pipeline_id),
+            GlimEvent::Error(e) =>
+                panic!("request failed: {e}"),
+            _ => (),
+        }
+    }
+
+    let projects = store.projects();
+    assert!(!projects.is_empty(), "no projects received from {}", config.gitlab_url);
+
+    for project in projects {
+        for pipeline in project.pipelines.iter().flatten() {
+            assert_eq!(pipeline.project_id, project.id);
+            assert!(!pipeline.branch.is_empty(), "pipeline {} without branch", pipeline.id);
+
+            for job in pipeline.jobs.iter().flatten() {
+                assert!(!job.name.is_empty(), "job {} without name", job.id);
+            }
+        }
+    }
+
+    let mut terminal = Terminal::new(TestBackend::new(160, 48)).unwrap();
+    terminal.draw(|f| {
+        let table = ProjectsTable::new(
+            projects,
+            None,
+            ProjectSort::default(),
+            None,
+            |id| ProjectRowState { fetch: store.fetch(id), ..ProjectRowState::default() },
+        );
+        f.render_stateful_widget(table, f.area(), &mut TableState::default().with_selected(0));
+    }).unwrap();
+
+    let first = &projects[0];
+    let name = first.path.rsplit('/').next().unwrap();
+    let rendered = terminal.backend().buffer().content().iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>();
+    assert!(rendered.contains(name), "project {} not rendered", first.path);
+}
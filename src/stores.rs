@@ -1,63 +1,236 @@
 
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::sync::mpsc::Sender;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::negative_cache::{GoneResource, NegativeCache};
 use crate::dispatcher::Dispatcher;
-use crate::domain::{Job, Pipeline, Project};
+use crate::domain::{Approvals, Job, Pipeline, PipelineStatus, Project, ProjectDto};
 use crate::event::GlimEvent;
-use crate::id::ProjectId;
+use crate::fetch_state::{FetchState, ProjectFetch};
+use crate::fuzzy::fuzzy_match;
+use crate::glim_app::GlimConfig;
+use crate::i18n::{t, Msg};
+use crate::id::{JobId, PipelineId, ProjectId};
+use crate::pipeline_history::{PipelineHistory, PipelineRun};
+use crate::project_health::Health;
+use crate::project_cache::CachedProjects;
+use crate::read_only::read_only;
+use crate::result::{GlimError, Result};
+use crate::token_scopes::{scope_warnings, ScopeWarning};
 
 pub struct ProjectStore {
     sender: Sender<GlimEvent>,
+    /// projects in the order of the projects table, followed by the projects
+    /// not matching the filter and the ignored projects
     projects: Vec<Project>,
     project_id_lookup: HashMap<ProjectId, usize>,
-    sorted: Vec<Project>, // todo: ref projects
+    /// number of projects shown in the projects table, see [Self::projects]
+    visible: usize,
+    pinned: HashSet<ProjectId>,
+    ignored: HashSet<ProjectId>,
+    /// lists the ignored projects after the other projects, instead of hiding them
+    show_ignored: bool,
+    /// order of the projects table, after pinned projects
+    sort: ProjectSort,
+    /// shows only the projects with failed or running pipelines
+    quick_filter: Option<QuickFilter>,
+    /// temporary filter of the projects table, fuzzy matched against the project path
+    filter: String,
+    /// score and matched byte offsets in the path of the projects matching the filter
+    filter_matches: HashMap<ProjectId, (i32, Vec<usize>)>,
+    /// watched projects are always hydrated, see [GlimConfig::watches]
+    watched: HashSet<ProjectId>,
+    /// number of projects with pipelines fetched on the first fetch; see [GlimConfig::initial_fetch_limit]
+    initial_fetch_limit: Option<usize>,
+    /// indices of the rows in view of the projects table
+    viewport: Range<usize>,
+    /// fetch progress of the pipelines and jobs of each project
+    fetch: HashMap<ProjectId, ProjectFetch>,
+    /// durations of finished pipelines, retained beyond the latest page
+    history: PipelineHistory,
+    /// false until the first projects are received; projects restored from
+    /// the [ProjectCache](crate::project_cache::ProjectCache) precede them
+    received_projects: bool,
+    /// projects of the last projects response of each instance, refreshed
+    /// again when the projects are not modified
+    last_received: HashMap<u8, Vec<ProjectId>>,
 }
 
+/// Order of the projects in the projects table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectSort {
+    #[default]
+    LastActivity,
+    Name,
+    /// projects with failed pipelines first, see [PipelineStatus::severity]
+    Status,
+}
+
+impl ProjectSort {
+    pub fn next(self) -> Self {
+        match self {
+            ProjectSort::LastActivity => ProjectSort::Name,
+            ProjectSort::Name         => ProjectSort::Status,
+            ProjectSort::Status       => ProjectSort::LastActivity,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectSort::LastActivity => t(Msg::SortLastActivity),
+            ProjectSort::Name         => t(Msg::SortName),
+            ProjectSort::Status       => t(Msg::SortStatus),
+        }
+    }
+}
+
+/// Filter of the projects table by the status of their pipelines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickFilter {
+    /// projects with a failed latest pipeline
+    Failed,
+    /// projects with running or pending pipelines
+    Active,
+}
+
+impl QuickFilter {
+    fn matches(self, project: &Project) -> bool {
+        match self {
+            QuickFilter::Failed => project.recent_pipelines().first()
+                .is_some_and(|p| p.status == PipelineStatus::Failed),
+            QuickFilter::Active => project.pipelines.iter()
+                .flatten()
+                .any(|p| p.status.is_active()),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QuickFilter::Failed => t(Msg::QuickFilterFailed),
+            QuickFilter::Active => t(Msg::QuickFilterActive),
+        }
+    }
+}
+
+/// Projects above and below the rows in view with pipelines fetched ahead of
+/// scrolling, when the initial fetch is limited.
+const LAZY_FETCH_WINDOW: usize = 10;
+
 impl ProjectStore {
-    pub fn new(sender: Sender<GlimEvent>) -> Self {
+    pub fn new(sender: Sender<GlimEvent>, config: &GlimConfig) -> Self {
         Self {
             sender,
             projects: Vec::new(),
             // pipelines: Vec::new(),
             project_id_lookup: HashMap::new(),
-            sorted: Vec::new(),
+            visible: 0,
+            pinned: config.pinned_projects.iter().copied().collect(),
+            ignored: config.ignored_projects.iter().copied().collect(),
+            show_ignored: false,
+            sort: ProjectSort::default(),
+            quick_filter: None,
+            filter: String::new(),
+            filter_matches: HashMap::new(),
+            watched: config.watches.iter().map(|w| w.project).collect(),
+            initial_fetch_limit: config.initial_fetch_limit,
+            viewport: 0..0,
+            fetch: HashMap::new(),
+            history: PipelineHistory::default(),
+            received_projects: false,
+            last_received: HashMap::new(),
+        }
+    }
+
+    /// Restores the projects of the previous session. Projects with pipelines
+    /// are marked as stale until their pipelines are fetched again.
+    pub fn restore(&mut self, cached: CachedProjects) {
+        for project in cached.projects {
+            if project.pipelines.is_some() {
+                self.fetch.insert(project.id, ProjectFetch::restored(cached.saved_at));
+                project.pipelines.iter()
+                    .flatten()
+                    .for_each(|p| self.history.record(p));
+            }
+
+            self.project_id_lookup.insert(project.id, self.projects.len());
+            self.projects.push(project);
+        }
+
+        self.sort_projects();
+        if let Some(project) = self.projects().first() {
+            self.dispatch(GlimEvent::SelectedProject(project.id));
         }
     }
 
     pub fn apply(&mut self, event: &GlimEvent) {
+        self.apply_fetch_state(event);
+
         match event {
             // requests jobs for pipelines that have not been loaded yet
             GlimEvent::OpenProjectDetails(id) => {
-                let project = self.find(*id).unwrap();
-                project.recent_pipelines()
-                    .into_iter()
-                    .filter(|p| p.jobs.is_none())
-                    .for_each(|p| self.dispatch(GlimEvent::RequestJobs(project.id, p.id)));
+                if let Some(project) = self.find(*id) {
+                    project.recent_pipelines()
+                        .into_iter()
+                        .filter(|p| p.jobs.is_none())
+                        .for_each(|p| self.dispatch(GlimEvent::RequestJobs(project.id, p.id)));
+                }
             },
 
             // updates the projects in the store
             GlimEvent::ReceivedProjects(projects) => {
-                let first_projects = self.sorted.is_empty();
+                let first_projects = !self.received_projects;
+                self.received_projects = true;
+                if let Some(instance) = projects.first().map(|p| p.id.instance()) {
+                    self.last_received.insert(instance, projects.iter().map(|p| p.id).collect());
+                }
+
+                // on large instances, only the most recently active projects are hydrated up front
+                let initial_scope: Option<HashSet<ProjectId>> = self.initial_fetch_limit
+                    .filter(|_| first_projects)
+                    .map(|limit| projects.iter()
+                        .sorted_by(|a, b| b.last_activity_at.cmp(&a.last_activity_at))
+                        .take(limit)
+                        .map(|p| p.id)
+                        .chain(self.watched.iter().copied())
+                        .collect());
+
+                let changed: Vec<ProjectId> = projects.iter()
+                    .filter(|p| self.is_project_changed(p))
+                    .map(|p| p.id)
+                    .collect();
+
                 projects.iter()
                     .map(|p| Project::from(p.clone()))
                     .for_each(|p| {
-                        let project = p.clone();
-                        self.sync_project(p);
-                        let sender = self.sender.clone();
-                        sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project)))
+                        let hydrate = initial_scope.as_ref().is_none_or(|ids| ids.contains(&p.id));
+                        self.sync_project(p, hydrate);
                     });
 
-                self.sorted = self.sorted_projects();
+                // unchanged projects are only refreshed, see [Self::sync_project]
+                changed.iter()
+                    .filter_map(|id| self.find(*id))
+                    .for_each(|p| self.dispatch(GlimEvent::ProjectUpdated(Box::new(p.clone()))));
+
+                if !changed.is_empty() {
+                    self.sort_projects();
+                }
+                // newly active projects may have moved into view
+                self.hydrate_viewport();
                 if first_projects {
-                    self.dispatch(GlimEvent::SelectedProject(self.sorted.first().unwrap().id));
+                    if let Some(project) = self.projects().first() {
+                        self.dispatch(GlimEvent::SelectedProject(project.id));
+                    }
                 }
             },
 
             // updates the pipelines for a project
-            GlimEvent::ReceivedPipelines(pipelines) => {
-                let project_id = pipelines[0].project_id;
+            GlimEvent::ReceivedPipelines(project_id, pipelines) => {
+                let project_id = *project_id;
                 let sender = self.sender.clone();
 
                 if let Some(project) = self.find_mut(project_id) {
@@ -66,14 +239,71 @@ impl ProjectStore {
                         .collect();
 
                     pipelines.iter()
-                        .filter(|&p| p.status.is_active() || p.has_active_jobs()) 
+                        .filter(|&p| p.status.is_active() || p.has_active_jobs())
+                        .filter(|&p| !project.pipeline(p.id).is_some_and(|existing| existing.gone))
                         .for_each(|p| sender.dispatch(GlimEvent::RequestJobs(project_id, p.id)));
 
+                    let previous_statuses: HashMap<PipelineId, PipelineStatus> = project.pipelines.iter()
+                        .flatten()
+                        .map(|p| (p.id, p.status.clone()))
+                        .collect();
+
+                    if !Self::is_pipelines_changed(project, &pipelines) {
+                        return;
+                    }
+
                     project.update_pipelines(pipelines);
-                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())));
+
+                    // approvals of merge requests seen for the first time
+                    project.recent_merge_requests()
+                        .into_iter()
+                        .filter(|iid| !project.approvals.contains_key(iid))
+                        .for_each(|iid| sender.dispatch(GlimEvent::RequestApprovals(project_id, iid)));
+
+                    // only pipelines seen before are reported
+                    project.pipelines.iter()
+                        .flatten()
+                        .filter_map(|p| previous_statuses.get(&p.id)
+                            .filter(|&s| *s != p.status)
+                            .map(|s| (p, s)))
+                        .for_each(|(p, old)| sender.dispatch(GlimEvent::PipelineStatusChanged(project_id, p.id, old.clone(), p.status.clone())));
+                }
+
+                self.record_history(project_id);
+                self.sort_projects();
+            },
+
+            // pipelines preceding the retained pipelines, paged through in the project details
+            GlimEvent::ReceivedOlderPipelines(project_id, pipelines) => {
+                let sender = self.sender.clone();
+                if let Some(project) = self.find_mut(*project_id) {
+                    if pipelines.is_empty() {
+                        project.all_pipelines_fetched = true;
+                    } else {
+                        project.append_older_pipelines(pipelines.iter()
+                            .map(|p| Pipeline::from(p.clone()))
+                            .collect());
+                    }
+
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())));
                 }
 
-                self.sorted = self.sorted_projects();
+                self.record_history(*project_id);
+            },
+
+            // same pipelines as before, only refreshed
+            GlimEvent::ProjectsNotModified(instance) => {
+                self.last_received.get(instance).into_iter()
+                    .flatten()
+                    .filter(|id| !self.is_ignored(**id))
+                    .for_each(|id| self.dispatch(GlimEvent::RequestPipelines(*id)));
+            },
+
+            // jobs of active pipelines are requested as if the pipelines were received
+            GlimEvent::PipelinesNotModified(project_id) => {
+                self.active_pipelines(*project_id).into_iter()
+                    .for_each(|id| self.dispatch(GlimEvent::RequestJobs(*project_id, id)));
             },
 
             GlimEvent::ReceivedJobs(project_id, pipeline_id, job_dtos) => {
@@ -84,36 +314,206 @@ impl ProjectStore {
                 let sender = self.sender.clone();
                 if let Some(project) = self.find_mut(*project_id) {
                     project.update_jobs(*pipeline_id, jobs);
-                    // todo: ugly, fix
-                    project.update_commit(*pipeline_id, job_dtos.first().map(|j| j.commit.clone().into()).unwrap());
+                    // the commit is only known through the jobs; pipelines without jobs keep theirs
+                    if let Some(job) = job_dtos.first() {
+                        project.update_commit(*pipeline_id, job.commit.clone().into());
+                    }
                     sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
                 }
 
-                self.sorted = self.sorted_projects();
+                self.record_history(*project_id);
+                self.sort_projects();
+            },
+
+            GlimEvent::ReceivedApprovals(project_id, iid, approvals) => {
+                let sender = self.sender.clone();
+                if let Some(project) = self.find_mut(*project_id) {
+                    project.approvals.insert(*iid, Approvals::from(approvals.clone()));
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
+                }
+            },
+
+            GlimEvent::JobsPartiallyLoaded(project_id, pipeline_id) => {
+                let sender = self.sender.clone();
+                if let Some(project) = self.find_mut(*project_id) {
+                    project.mark_partial_jobs(*pipeline_id);
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
+                }
+            },
+
+            GlimEvent::ResourceGone(GoneResource::Pipeline(project_id, pipeline_id)) => {
+                let sender = self.sender.clone();
+                if let Some(project) = self.find_mut(*project_id) {
+                    project.mark_gone(*pipeline_id);
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
+                }
+            },
+
+            GlimEvent::ReceivedCommitDetails(project_id, pipeline_id, commit) => {
+                let sender = self.sender.clone();
+                if let Some(project) = self.find_mut(*project_id) {
+                    project.update_commit_details(*pipeline_id, commit.clone().into());
+                    sender.dispatch(GlimEvent::ProjectUpdated(Box::new(project.clone())))
+                }
             },
 
             // requests pipelines for a project if they are not already loaded
-            GlimEvent::SelectedProject(id) => {
-                let mut request_pipelines = false;
-                if let Some(project) = self.find_mut(*id) {
-                    if project.pipelines.is_none() {
-                        project.pipelines = Some(Vec::new());
-                        request_pipelines = true;
-                    }
-                };
+            GlimEvent::SelectedProject(id) => self.hydrate(*id),
+
+            // requests pipelines of the projects in view, and those about to be scrolled into view
+            GlimEvent::ProjectsViewport(rows) => {
+                self.viewport = rows.clone();
+                self.hydrate_viewport();
+            },
+
+            GlimEvent::ToggleWatch(id, _) => {
+                if self.watched.insert(*id) {
+                    self.hydrate(*id);
+                } else {
+                    self.watched.remove(id);
+                }
+            },
+
+            // pinned projects are sorted to the top of the projects table
+            GlimEvent::TogglePinProject(id) => {
+                if !self.pinned.remove(id) {
+                    self.pinned.insert(*id);
+                }
+
+                self.sort_projects();
+                // keeps the selection on the project as it moves
+                self.dispatch(GlimEvent::SelectedProject(*id));
+            },
+
+            // ignored projects are hidden from the projects table, unless shown
+            GlimEvent::ToggleIgnoreProject(id) => {
+                let idx = self.projects().iter().position(|p| p.id == *id);
+                if !self.ignored.remove(id) {
+                    self.ignored.insert(*id);
+                }
+
+                self.sort_projects();
+                // selects the project taking the place of the hidden project
+                if let Some(project) = idx.and_then(|idx| self.projects().get(idx).or(self.projects().last())) {
+                    self.dispatch(GlimEvent::SelectedProject(project.id));
+                }
+            },
+
+            GlimEvent::ToggleShowIgnored => {
+                self.show_ignored = !self.show_ignored;
+                self.sort_projects();
+            },
+
+            GlimEvent::SortProjects(sort) => {
+                self.sort = *sort;
+                self.sort_projects();
+            },
+
+            // toggles the quick filter, replacing any other quick filter
+            GlimEvent::ToggleQuickFilter(filter) => {
+                self.quick_filter = (self.quick_filter != Some(*filter)).then_some(*filter);
+                self.sort_projects();
+                if let Some(project) = self.projects().first() {
+                    self.dispatch(GlimEvent::SelectedProject(project.id));
+                }
+            },
+
+            // the filter ranks matching projects by score, hiding the others
+            GlimEvent::FilterProjects(query) => {
+                self.filter = query.trim().to_string();
+                self.sort_projects();
+                if let Some(project) = self.projects().first() {
+                    self.dispatch(GlimEvent::SelectedProject(project.id));
+                }
+            },
 
-                if request_pipelines {
-                    self.dispatch(GlimEvent::RequestPipelines(*id));
-                };
+            GlimEvent::UpdateConfig(config) => {
+                self.pinned = config.pinned_projects.iter().copied().collect();
+                self.ignored = config.ignored_projects.iter().copied().collect();
+                self.watched = config.watches.iter().map(|w| w.project).collect();
+                self.initial_fetch_limit = config.initial_fetch_limit;
+                self.sort_projects();
             },
             _ => {}
         }
     }
 
-    fn sorted_projects(&mut self) -> Vec<Project> {
-        self.projects.iter()
-            .sorted_by(|a, b| b.last_activity().cmp(&a.last_activity()))
-            .cloned()
+    /// Sorts the projects in place, pinned projects first and ignored projects
+    /// last, and re-indexes them. The remaining projects are ordered by
+    /// [ProjectSort], most recent activity first on ties. With a filter, matching projects are ranked
+    /// by score, followed by the projects not matching it. The projects table borrows the projects
+    /// through [Self::projects], so they are never copied for display.
+    fn sort_projects(&mut self) {
+        self.filter_matches = match self.filter.is_empty() {
+            true  => HashMap::new(),
+            false => self.projects.iter()
+                .filter_map(|p| fuzzy_match(&p.path, &self.filter).map(|m| (p.id, m)))
+                .collect(),
+        };
+
+        let (pinned, ignored) = (&self.pinned, &self.ignored);
+        let (filter, filter_matches) = (&self.filter, &self.filter_matches);
+        let (quick_filter, show_ignored) = (self.quick_filter, self.show_ignored);
+        let is_hidden = |p: &Project| (!show_ignored && ignored.contains(&p.id))
+            || quick_filter.is_some_and(|f| !f.matches(p))
+            || (!filter.is_empty() && !filter_matches.contains_key(&p.id));
+        let score = |p: &Project| filter_matches.get(&p.id).map_or(0, |(score, _)| *score);
+
+        // computed once, as it depends on the pipelines of each project
+        let severity: HashMap<ProjectId, u8> = match self.sort {
+            ProjectSort::Status => self.projects.iter().map(|p| (p.id, p.status_severity())).collect(),
+            _                   => HashMap::new(),
+        };
+        let sort = self.sort;
+        let by_sort = |a: &Project, b: &Project| match sort {
+            ProjectSort::LastActivity => Ordering::Equal,
+            ProjectSort::Name         => a.path.chars().flat_map(char::to_lowercase)
+                .cmp(b.path.chars().flat_map(char::to_lowercase)),
+            ProjectSort::Status       => severity.get(&b.id).cmp(&severity.get(&a.id)),
+        };
+
+        self.projects.sort_by(|a, b| is_hidden(a).cmp(&is_hidden(b))
+            .then_with(|| ignored.contains(&a.id).cmp(&ignored.contains(&b.id)))
+            .then_with(|| score(b).cmp(&score(a)))
+            .then_with(|| pinned.contains(&b.id).cmp(&pinned.contains(&a.id)))
+            .then_with(|| by_sort(a, b))
+            .then_with(|| b.last_activity().cmp(&a.last_activity())));
+
+        self.visible = self.projects.iter()
+            .take_while(|p| !is_hidden(p))
+            .count();
+        self.project_id_lookup = self.projects.iter()
+            .enumerate()
+            .map(|(idx, p)| (p.id, idx))
+            .collect();
+    }
+
+    pub fn is_pinned(&self, id: ProjectId) -> bool {
+        self.pinned.contains(&id)
+    }
+
+    pub fn is_ignored(&self, id: ProjectId) -> bool {
+        self.ignored.contains(&id)
+    }
+
+    /// Returns the pinned projects, ordered by id.
+    pub fn pinned_projects(&self) -> Vec<ProjectId> {
+        self.pinned.iter()
+            .copied()
+            .sorted()
+            .collect()
+    }
+
+    /// Returns true if the ignored projects are listed in the projects table.
+    pub fn is_showing_ignored(&self) -> bool {
+        self.show_ignored
+    }
+
+    /// Returns the ignored projects, ordered by id.
+    pub fn ignored_projects(&self) -> Vec<ProjectId> {
+        self.ignored.iter()
+            .copied()
+            .sorted()
             .collect()
     }
 
@@ -122,8 +522,74 @@ impl ProjectStore {
             .map(|idx| &self.projects[idx])
     }
 
+    /// Returns the project, or an error if it is no longer in the store.
+    pub fn project(&self, id: ProjectId) -> Result<&Project> {
+        self.find(id)
+            .ok_or(GlimError::ProjectNotFound(id))
+    }
+
+    /// Returns the pipeline and its project, or an error if either is no
+    /// longer in the store.
+    pub fn pipeline(&self, project_id: ProjectId, pipeline_id: PipelineId) -> Result<(&Project, &Pipeline)> {
+        let project = self.project(project_id)?;
+        let pipeline = project.pipeline(pipeline_id)
+            .ok_or(GlimError::PipelineNotFound(project_id, pipeline_id))?;
+
+        Ok((project, pipeline))
+    }
+
+    /// Returns the job, or an error if it, its pipeline or its project is no
+    /// longer in the store.
+    pub fn job(&self, project_id: ProjectId, pipeline_id: PipelineId, job_id: JobId) -> Result<&Job> {
+        let (_, pipeline) = self.pipeline(project_id, pipeline_id)?;
+        pipeline.job(job_id)
+            .ok_or(GlimError::JobNotFound(project_id, pipeline_id, job_id))
+    }
+
+    pub fn quick_filter(&self) -> Option<QuickFilter> {
+        self.quick_filter
+    }
+
+    /// Returns the query of the project filter; empty when not filtered.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Returns the byte offsets in the path of the project matching the filter.
+    pub fn filter_matches(&self, id: ProjectId) -> Option<&[usize]> {
+        self.filter_matches.get(&id)
+            .map(|(_, matched)| matched.as_slice())
+    }
+
+    /// Returns the projects shown in the projects table, in display order.
     pub fn projects(&self) -> &[Project] {
-        &self.sorted
+        &self.projects[..self.visible]
+    }
+
+    /// Returns all projects in the store, including the projects hidden by
+    /// the filters and the ignored projects.
+    pub fn all_projects(&self) -> &[Project] {
+        &self.projects
+    }
+
+    /// Returns the health of the project, computed from the finished pipelines
+    /// of its default branch.
+    pub fn health(&self, project_id: ProjectId) -> Option<Health> {
+        let project = self.find(project_id)?;
+        Health::of(&self.history.runs(project_id, &project.default_branch))
+    }
+
+    /// Returns the durations of the finished pipelines of the branch, oldest first.
+    pub fn pipeline_history(&self, project_id: ProjectId, branch: &str) -> Vec<PipelineRun> {
+        self.history.runs(project_id, branch)
+    }
+
+    fn record_history(&mut self, project_id: ProjectId) {
+        if let Some(idx) = self.project_idx(project_id) {
+            self.projects[idx].pipelines.iter()
+                .flatten()
+                .for_each(|p| self.history.record(p));
+        }
     }
 
     fn find_mut(&mut self, id: ProjectId) -> Option<&mut Project> {
@@ -135,18 +601,166 @@ impl ProjectStore {
         self.project_id_lookup.get(&id).copied()
     }
 
-    fn sync_project(&mut self, mut project: Project) {
+    /// Returns true if the project is new, or was active since it was last received.
+    pub fn is_project_changed(&self, project: &ProjectDto) -> bool {
+        self.find(project.id).is_none_or(|existing| existing.last_activity_at != project.last_activity_at
+            || existing.path != project.path_with_namespace
+            || existing.default_branch != project.default_branch
+            || existing.topics != project.topics)
+    }
+
+    /// Returns true if any of the received pipelines is new, or was updated.
+    fn is_pipelines_changed(project: &Project, pipelines: &[Pipeline]) -> bool {
+        let Some(existing) = project.pipelines.as_ref() else { return true };
+
+        // older pipelines retained beyond the latest page are not compared
+        let oldest = pipelines.iter().map(|p| p.updated_at).min();
+        let existing_in_page = existing.iter()
+            .filter(|e| oldest.is_some_and(|oldest| e.updated_at >= oldest))
+            .count();
+
+        existing_in_page != pipelines.len() || pipelines.iter().any(|p| !existing.iter()
+            .any(|e| e.id == p.id && e.status == p.status && e.updated_at == p.updated_at))
+    }
+
+    /// Returns the pipelines of the project which are still running.
+    fn active_pipelines(&self, id: ProjectId) -> Vec<PipelineId> {
+        self.find(id)
+            .and_then(|p| p.pipelines.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|p| !p.gone && (p.status.is_active() || p.has_active_jobs()))
+            .map(|p| p.id)
+            .collect()
+    }
+
+    /// Requests the pipelines of the project, unless already requested.
+    fn hydrate(&mut self, id: ProjectId) {
+        if self.project_idx(id).is_none() {
+            return;
+        }
+
+        let fetch = self.fetch.entry(id).or_default();
+        if !fetch.is_requested() {
+            // transitions before the event is applied, as it is queued behind other events
+            fetch.request_pipelines();
+            self.dispatch(GlimEvent::RequestPipelines(id));
+        }
+    }
+
+    pub fn fetch(&self, id: ProjectId) -> Option<&ProjectFetch> {
+        self.fetch.get(&id)
+    }
+
+    pub fn fetch_state(&self, id: ProjectId) -> FetchState {
+        self.fetch(id)
+            .map(ProjectFetch::state)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of projects in each fetch state.
+    pub fn fetch_summary(&self) -> HashMap<FetchState, usize> {
+        self.projects.iter()
+            .map(|p| self.fetch_state(p.id))
+            .counts()
+    }
+
+    /// Drives the [FetchState] of each project from the requests and responses
+    /// of its pipelines and jobs.
+    fn apply_fetch_state(&mut self, event: &GlimEvent) {
+        match event {
+            GlimEvent::RequestPipelines(id) =>
+                self.fetch.entry(*id).or_default().request_pipelines(),
+            GlimEvent::ReceivedPipelines(project_id, pipelines) => {
+                let project_id = *project_id;
+                let is_gone = |id| self.find(project_id)
+                    .and_then(|project| project.pipeline(id))
+                    .is_some_and(|p| p.gone);
+
+                let active: Vec<PipelineId> = pipelines.iter()
+                    .filter(|p| p.status.is_active() && !is_gone(p.id))
+                    .map(|p| p.id)
+                    .collect();
+
+                self.fetch.entry(project_id).or_default().pipelines_received(active);
+            },
+            GlimEvent::PipelinesNotModified(project_id) => {
+                let active = self.active_pipelines(*project_id);
+                self.fetch.entry(*project_id).or_default().pipelines_received(active);
+            },
+            GlimEvent::RequestJobs(project_id, pipeline_id) =>
+                self.fetch.entry(*project_id).or_default().request_jobs(*pipeline_id),
+            GlimEvent::RequestActiveJobs => {
+                for p in self.projects.iter().flat_map(|p| p.pipelines.iter()).flatten() {
+                    if !p.gone && (p.status.is_active() || p.has_active_jobs()) {
+                        self.fetch.entry(p.project_id).or_default().request_jobs(p.id);
+                    }
+                }
+            },
+            GlimEvent::ReceivedJobs(project_id, pipeline_id, _) =>
+                self.fetch.entry(*project_id).or_default().jobs_received(*pipeline_id),
+            // no jobs to wait for
+            GlimEvent::ResourceGone(GoneResource::Pipeline(project_id, pipeline_id)) =>
+                self.fetch.entry(*project_id).or_default().jobs_received(*pipeline_id),
+            GlimEvent::CircuitOpened(_, project_id) =>
+                self.fetch.entry(*project_id).or_default().failed(),
+
+            // fetches without a response are retried with the next poll
+            GlimEvent::RequestProjects => {
+                let now = Utc::now();
+                self.fetch.values_mut().for_each(|f| f.expire(now));
+
+                // restored projects are refreshed once the projects are received
+                if !self.received_projects {
+                    return;
+                }
+
+                let stale: Vec<ProjectId> = self.fetch.iter()
+                    .filter(|(id, f)| f.state() == FetchState::Stale && !self.is_ignored(**id))
+                    .map(|(id, _)| *id)
+                    .collect();
+
+                stale.into_iter().for_each(|id| self.dispatch(GlimEvent::RequestPipelines(id)));
+            },
+            _ => {}
+        }
+    }
+
+    /// Hydrates the projects in view, including [LAZY_FETCH_WINDOW] projects
+    /// above and below. Only applies when the initial fetch is limited, as all
+    /// recently active projects are hydrated otherwise.
+    fn hydrate_viewport(&mut self) {
+        if self.initial_fetch_limit.is_none() || self.viewport.is_empty() {
+            return;
+        }
+
+        let start = self.viewport.start.saturating_sub(LAZY_FETCH_WINDOW);
+        let end = self.viewport.end + LAZY_FETCH_WINDOW;
+        let ids: Vec<ProjectId> = self.projects().iter()
+            .take(end)
+            .skip(start)
+            .map(|p| p.id)
+            .collect();
+
+        ids.into_iter().for_each(|id| self.hydrate(id));
+    }
+
+    fn sync_project(&mut self, project: Project, hydrate: bool) {
         let sender = self.sender.clone();
+        // pipelines of ignored projects are not polled
+        let ignored = self.is_ignored(project.id);
         match self.find_mut(project.id) {
             Some(existing_entry) => {
-                sender.dispatch(GlimEvent::RequestPipelines(project.id));
+                if !ignored {
+                    sender.dispatch(GlimEvent::RequestPipelines(project.id));
+                }
                 existing_entry.update_project(project.clone())
             }
             None => {
                 self.project_id_lookup.insert(project.id, self.projects.len());
-                if !is_older_than_7d(project.last_activity()) {
+                if hydrate && !ignored && !is_older_than_7d(project.last_activity()) {
+                    self.fetch.entry(project.id).or_default().request_pipelines();
                     sender.dispatch(GlimEvent::RequestPipelines(project.id));
-                    project.pipelines = Some(Vec::new());
                 }
                 self.projects.push(project);
             }
@@ -160,25 +774,122 @@ fn is_older_than_7d(date: DateTime<Utc>) -> bool {
         .num_days() > 7
 }
 
+/// Verbosity of the internal logs, see [GlimConfig::log_level].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    /// errors only
+    Error,
+    /// errors and user actions, without requests and responses
+    Info,
+    /// everything, including requests to and responses from GitLab
+    #[default]
+    Debug,
+}
+
+impl LogLevel {
+    pub fn parse(level: &str) -> Option<Self> {
+        match level.trim().to_lowercase().as_str() {
+            "error" => Some(LogLevel::Error),
+            "info"  => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            _       => None,
+        }
+    }
+
+    /// Level of the log entry of the event.
+    fn of(event: &GlimEvent) -> Self {
+        match event {
+            // level changes are always logged, to confirm the change
+            GlimEvent::Error(_)
+            | GlimEvent::LogLevelChanged(_)      => LogLevel::Error,
+            GlimEvent::RequestProject(_)
+            | GlimEvent::RequestProjects
+            | GlimEvent::RequestActiveJobs
+            | GlimEvent::RequestPipelines(_)
+            | GlimEvent::RequestOlderPipelines(_)
+            | GlimEvent::RequestPipeline(_, _)
+            | GlimEvent::RequestJobs(_, _)
+            | GlimEvent::RequestApprovals(_, _)
+            | GlimEvent::ReceivedProjects(_)
+            | GlimEvent::ReceivedPipelines(_, _)
+            | GlimEvent::ReceivedOlderPipelines(_, _)
+            | GlimEvent::ReceivedPipeline(_, _)
+            | GlimEvent::PipelineUnavailable(_, _, _)
+            | GlimEvent::ProjectsNotModified(_)
+            | GlimEvent::PipelinesNotModified(_)
+            | GlimEvent::ReceivedJobs(_, _, _)
+            | GlimEvent::ReceivedApprovals(_, _, _)
+            | GlimEvent::ReceivedBranches(_, _)
+            | GlimEvent::ReceivedRunners(_, _)
+            | GlimEvent::ReceivedTokenExpiry(_)
+            | GlimEvent::ReceivedTokenScopes(_)
+            | GlimEvent::ReceivedSchedules(_, _)
+            | GlimEvent::ReceivedJobNeeds(_, _, _)
+            | GlimEvent::ReceivedJobTrace(_, _, _)
+            | GlimEvent::JobTraceUnavailable(_, _, _)
+            | GlimEvent::SelectedProject(_)
+            | GlimEvent::SelectedPipeline(_)     => LogLevel::Debug,
+            _                                    => LogLevel::Info,
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let level = match self {
+            LogLevel::Error => "error",
+            LogLevel::Info  => "info",
+            LogLevel::Debug => "debug",
+        };
+        write!(f, "{level}")
+    }
+}
+
 pub struct InternalLogsStore {
     logs: Vec<(DateTime<Local>, String)>,
+    /// entries above the level are not logged
+    level: LogLevel,
 }
 
 impl InternalLogsStore {
-    pub fn new() -> Self {
+    pub fn new(level: LogLevel) -> Self {
         Self {
             logs: Vec::new(),
+            level,
         }
     }
 
+    pub fn level(&self) -> LogLevel {
+        self.level
+    }
+
     pub fn apply(&mut self, event: &GlimEvent) {
+        if let GlimEvent::LogLevelChanged(level) = event {
+            self.level = *level;
+        }
+        if LogLevel::of(event) > self.level {
+            return;
+        }
+
         if let Some(log) = match event {
             GlimEvent::Log(s) => Some(s.to_owned()),
             GlimEvent::ToggleColorDepth => Some("toggling color depth".to_string()),
+            GlimEvent::ToggleRelativeTimes => None,
+            GlimEvent::ToggleRecording => None,
+            GlimEvent::RecordingStarted(path) => Some(format!("recording to {}", path.display())),
+            GlimEvent::RecordingSaved(path) => Some(format!("recording saved to {}", path.display())),
+            GlimEvent::Screenshot => None,
+            GlimEvent::ScreenshotSaved(path) => Some(format!("screenshot saved to {}", path.display())),
+            GlimEvent::ProfileMemory => None,
+            GlimEvent::ResizeSplit(_) => None,
+            GlimEvent::MemoryProfiled(usage) => Some(format!("memory usage {usage}")),
             GlimEvent::Shutdown =>
                 Some("shutting down...".to_string()),
             GlimEvent::RequestProject(id) =>
                 Some(format!("refresh project_id={id}")),
+            GlimEvent::ManualRefresh =>
+                Some("manual refresh".to_string()),
             GlimEvent::RequestProjects =>
                 Some("request all projects since last update".to_string()),
             GlimEvent::RequestActiveJobs =>
@@ -189,10 +900,40 @@ impl InternalLogsStore {
                 Some(format!("request jobs for project_id={project_id} pipeline_id={pipeline_id}")),
             GlimEvent::ReceivedProjects(projects) =>
                 Some(format!("received {:?} projects", projects.len())),
-            GlimEvent::ReceivedPipelines(pipelines) =>
-                Some(format!("received {:?} pipelines", pipelines.len())),
+            GlimEvent::ReceivedPipelines(project_id, pipelines) =>
+                Some(format!("received {:?} pipelines for project_id={project_id}", pipelines.len())),
+            GlimEvent::RequestPipeline(project_id, pipeline_id) =>
+                Some(format!("request pipeline_id={pipeline_id} of project_id={project_id}")),
+            GlimEvent::ReceivedPipeline(project_id, pipeline) =>
+                Some(format!("received pipeline_id={} of project_id={project_id}", pipeline.id)),
+            GlimEvent::PipelineUnavailable(_, pipeline_id, e) =>
+                Some(format!("pipeline_id={pipeline_id} unavailable: {e}")),
+            GlimEvent::RequestOlderPipelines(id) =>
+                Some(format!("request older pipelines for project_id={id}")),
+            GlimEvent::ReceivedOlderPipelines(id, pipelines) =>
+                Some(format!("received {} older pipelines for project_id={id}", pipelines.len())),
+            GlimEvent::ProjectsNotModified(_) =>
+                Some("projects not modified".to_string()),
+            GlimEvent::PipelinesNotModified(project_id) =>
+                Some(format!("pipelines not modified for project_id={project_id}")),
             GlimEvent::ReceivedJobs(project_id, _, jobs) =>
                 Some(format!("received {:?} jobs for project_id={project_id}", jobs.len())),
+            GlimEvent::JobsPartiallyLoaded(_, pipeline_id) =>
+                Some(format!("trigger jobs missing for pipeline_id={pipeline_id}")),
+            GlimEvent::CircuitOpened(endpoint, project_id) =>
+                Some(format!("pausing {endpoint} requests for project_id={project_id} for {}m after repeated failures",
+                    CircuitBreaker::COOL_DOWN.as_secs() / 60)),
+            GlimEvent::ResourceGone(resource) =>
+                Some(format!("{resource} no longer exists, not requested for {}m",
+                    NegativeCache::TTL.as_secs() / 60)),
+            GlimEvent::RequestApprovals(project_id, iid) =>
+                Some(format!("request approvals for project_id={project_id} merge_request=!{iid}")),
+            GlimEvent::ReceivedApprovals(project_id, iid, _) =>
+                Some(format!("received approvals for project_id={project_id} merge_request=!{iid}")),
+            GlimEvent::RequestCommitDetails(project_id, pipeline_id) =>
+                Some(format!("request commit details for project_id={project_id} pipeline_id={pipeline_id}")),
+            GlimEvent::ReceivedCommitDetails(project_id, pipeline_id, _) =>
+                Some(format!("received commit details for project_id={project_id} pipeline_id={pipeline_id}")),
             GlimEvent::OpenProjectDetails(id) =>
                 Some(format!("showing project_id={id} details")),
             GlimEvent::CloseProjectDetails =>
@@ -203,25 +944,141 @@ impl InternalLogsStore {
                 Some(s.to_string()),
             GlimEvent::SelectedProject(id) =>
                 Some(format!("selected project_id={id}")),
+            GlimEvent::ProjectsViewport(_) => None,
             GlimEvent::SelectedPipeline(id) =>
                 Some(format!("selected pipeline_id={id}")),
             GlimEvent::BrowseToProject(id) =>
                 Some(format!("open project_id={id} in browser")),
             GlimEvent::BrowseToPipeline(_, id) =>
                 Some(format!("open pipeline_id={id} in browser")),
+            GlimEvent::BrowseToCommit(_, id) =>
+                Some(format!("open commit of pipeline_id={id} in browser")),
             GlimEvent::BrowseToJob(_, _, job_id) =>
                 Some(format!("open job_id={job_id}  in browser")),
+            GlimEvent::OpenJobActions(_, _, job_id) =>
+                Some(format!("showing actions for job_id={job_id}")),
+            GlimEvent::RetryJob(_, _, job_id) =>
+                Some(format!("retry job_id={job_id}")),
+            GlimEvent::JobRetried(_, _, job_id) =>
+                Some(format!("job_id={job_id} queued for retry")),
             GlimEvent::DownloadErrorLog(_, id) =>
                 Some(format!("download job log for failed pipeline_id={id}")),
             GlimEvent::JobLogDownloaded(_, id, _) => Some(format!("downloaded log for job_id={id}")),
+            GlimEvent::CopyToClipboard(_) => None,
+            GlimEvent::CopyPipelineUrl(_, _) => None,
+            GlimEvent::CopyJobUrl(_, _, _) => None,
+            GlimEvent::CopyBranchName(_, _) => None,
+            GlimEvent::CopyCloneUrl(_) => None,
+            GlimEvent::CopyCloneCommand(_) => None,
+            GlimEvent::OpenLocalClone(id) => Some(format!("opening local clone of project_id={id}")),
+            GlimEvent::OpenJobLog(_, _, id) => Some(format!("showing log for job_id={id}")),
+            GlimEvent::CloseJobLog => None,
+            GlimEvent::OpenPipelineGraph(_, id) => Some(format!("showing stage graph for pipeline_id={id}")),
+            GlimEvent::ClosePipelineGraph => None,
+            GlimEvent::OpenBranches(id) => Some(format!("showing branches for project_id={id}")),
+            GlimEvent::CloseBranches => None,
+            GlimEvent::ReceivedBranches(id, branches) =>
+                Some(format!("received {} branches for project_id={id}", branches.len())),
+            GlimEvent::BrowseToBranch(_, branch) =>
+                Some(format!("open branch {branch} in browser")),
+            GlimEvent::TriggerPipeline(id, branch) =>
+                Some(format!("run pipeline on {branch} for project_id={id}")),
+            GlimEvent::PipelineTriggered(id, branch) =>
+                Some(format!("pipeline created on {branch} for project_id={id}")),
+            GlimEvent::OpenSchedules(id) => Some(format!("showing pipeline schedules for project_id={id}")),
+            GlimEvent::CloseSchedules => None,
+            GlimEvent::OpenArtifacts(_, _, job_id) => Some(format!("showing artifacts of job_id={job_id}")),
+            GlimEvent::CloseArtifacts => None,
+            GlimEvent::DownloadArtifacts(_, job_id) =>
+                Some(format!("downloading artifacts of job_id={job_id}")),
+            GlimEvent::ArtifactsDownloadProgress(_, _, _) => None,
+            GlimEvent::ArtifactsDownloaded(job_id, path) =>
+                Some(format!("downloaded artifacts of job_id={job_id} to {}", path.display())),
+            GlimEvent::OpenRunners(id) => Some(format!("showing runners for project_id={id}")),
+            GlimEvent::CloseRunners => None,
+            GlimEvent::ReceivedRunners(id, runners) =>
+                Some(format!("received {} runners for project_id={id}", runners.len())),
+            GlimEvent::RequestGitlabVersion => None,
+            GlimEvent::ReceivedGitlabVersion(instance, version) => version.map(|version|
+                format!("instance {instance} runs GitLab {version}")),
+            GlimEvent::RequestTokenExpiry => None,
+            GlimEvent::ReceivedTokenExpiry(expires_at) => Some(match expires_at {
+                Some(date) => format!("token expires on {date}"),
+                None       => "token never expires".to_string(),
+            }),
+            GlimEvent::ReceivedTokenScopes(scopes) =>
+                Some(format!("token scopes: {}", scopes.join(", "))),
+            GlimEvent::ReceivedSchedules(id, schedules) =>
+                Some(format!("received {} pipeline schedules for project_id={id}", schedules.len())),
+            GlimEvent::PlaySchedule(id, schedule_id) =>
+                Some(format!("run pipeline schedule {schedule_id} for project_id={id}")),
+            GlimEvent::SchedulePlayed(id, schedule_id) =>
+                Some(format!("pipeline schedule {schedule_id} started for project_id={id}")),
+            GlimEvent::ReceivedJobNeeds(_, id, needs) =>
+                Some(format!("received needs of {} jobs for pipeline_id={id}", needs.len())),
+            GlimEvent::ReceivedJobTrace(_, id, trace) =>
+                Some(format!("received {} bytes of log for job_id={id}", trace.len())),
+            GlimEvent::JobTraceUnavailable(_, id, e) =>
+                Some(format!("log of job_id={id} unavailable: {e}")),
             GlimEvent::DisplayConfig => Some("display config".to_string()),
             GlimEvent::ApplyConfiguration => Some("applying new configuration".to_string()),
             GlimEvent::UpdateConfig(_) => Some("updating configuration".to_string()),
+            GlimEvent::AdvanceOnboarding => None,
+            GlimEvent::LogLevelChanged(level) => Some(format!("log level set to {level}")),
+            GlimEvent::ConnectionLost => Some("GitLab unreachable, offline".to_string()),
+            GlimEvent::ConnectionRestored => Some("GitLab reachable again".to_string()),
+            GlimEvent::FollowPipeline(project_id, pipeline_id) =>
+                Some(format!("following pipeline_id={pipeline_id} for project_id={project_id}")),
+            GlimEvent::UnfollowPipeline => Some("stopped following pipeline".to_string()),
+            GlimEvent::WaitForPipeline(project_id, pipeline_id) =>
+                Some(format!("waiting for pipeline_id={pipeline_id} of project_id={project_id} to complete")),
             GlimEvent::CloseConfig => None,
+            GlimEvent::DisplayAuditLog => Some("display audit log".to_string()),
+            GlimEvent::CloseAuditLog => None,
+            GlimEvent::DisplayApiInspector => None,
+            GlimEvent::CloseApiInspector => None,
+            GlimEvent::DisplayProjectErrors => None,
+            GlimEvent::CloseProjectErrors => None,
             GlimEvent::ClosePipelineActions => None,
             GlimEvent::GlitchOverride(_) => None,
             GlimEvent::Tick => None,
+            GlimEvent::Resized => None,
+            GlimEvent::PipelineStatusChanged(_, id, old, new) =>
+                Some(format!("pipeline_id={id} changed status from {old} to {new}")),
+            GlimEvent::PollProjects => None,
             GlimEvent::ProjectUpdated(_) => None,
+            GlimEvent::TogglePinProject(id) =>
+                Some(format!("toggle pin for project_id={id}")),
+            GlimEvent::ToggleWatch(id, Some(branch)) =>
+                Some(format!("toggle watch for project_id={id} branch={branch}")),
+            GlimEvent::ToggleWatch(id, None) =>
+                Some(format!("toggle watch for project_id={id}")),
+            GlimEvent::WatchMatched(m) =>
+                Some(format!("watch matched project_id={} pipeline_id={} rule={:?}", m.project_id, m.pipeline_id, m.rule)),
+            GlimEvent::ToggleIgnoreProject(id) =>
+                Some(format!("toggle ignore for project_id={id}")),
+            GlimEvent::ToggleShowIgnored => None,
+            GlimEvent::CreateIssue(id, pipeline_id) =>
+                Some(format!("create issue for project_id={id} pipeline_id={pipeline_id}")),
+            GlimEvent::IssueCreated(_, url) => Some(format!("created issue {url}")),
+            GlimEvent::TestWebhook => Some("sending webhook test message".to_string()),
+            GlimEvent::DisplaySearch => None,
+            GlimEvent::CloseSearch => None,
+            GlimEvent::DisplayCommandPalette => None,
+            GlimEvent::CloseCommandPalette => None,
+            GlimEvent::DisplayHelp => None,
+            GlimEvent::CloseHelp => None,
+            GlimEvent::DisplayProjectFilter => None,
+            GlimEvent::CloseProjectFilter => None,
+            GlimEvent::FilterProjects(_) => None,
+            GlimEvent::CycleProjectSort => None,
+            GlimEvent::SortProjects(_) => None,
+            GlimEvent::ToggleQuickFilter(_) => None,
+            GlimEvent::ToggleProjectTree => None,
+            GlimEvent::CollapseProjectGroup => None,
+            GlimEvent::ExpandProjectGroup => None,
+            GlimEvent::TabSelect(_) => None,
+            GlimEvent::SearchQuery(_) => None,
             GlimEvent::Key(_) => None,
             GlimEvent::SelectNextProject => None,
             GlimEvent::ShowLastNotification => None,
@@ -241,8 +1098,193 @@ impl InternalLogsStore {
     }
 }
 
+/// Connection and poll state shown in the status bar.
+pub struct StatusStore {
+    /// host of the primary GitLab instance
+    host: String,
+    last_poll: Option<DateTime<Local>>,
+    /// most recent error, cleared by the next successful poll
+    last_error: Option<String>,
+    token_expires_at: Option<NaiveDate>,
+    /// scopes of the token; none until fetched
+    token_scopes: Option<Vec<String>>,
+    /// days before the token expires at which it is shown as expiring
+    token_warning_days: i64,
+}
+
+impl StatusStore {
+    pub fn new(config: &GlimConfig) -> Self {
+        Self {
+            host: host_of(&config.gitlab_url),
+            last_poll: None,
+            last_error: None,
+            token_expires_at: None,
+            token_scopes: None,
+            token_warning_days: config.token_warning_days(),
+        }
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent) {
+        match event {
+            GlimEvent::ReceivedProjects(_) | GlimEvent::ProjectsNotModified(_) => {
+                self.last_poll = Some(Local::now());
+                self.last_error = None;
+            },
+            GlimEvent::Error(e)             => self.last_error = Some(e.to_string()),
+            GlimEvent::ReceivedTokenExpiry(date) => self.token_expires_at = *date,
+            GlimEvent::ReceivedTokenScopes(scopes) => self.token_scopes = Some(scopes.clone()),
+            GlimEvent::UpdateConfig(config) => {
+                self.host = host_of(&config.gitlab_url);
+                self.token_warning_days = config.token_warning_days();
+            },
+            _ => (),
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn last_poll(&self) -> Option<DateTime<Local>> {
+        self.last_poll
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn token_expires_at(&self) -> Option<NaiveDate> {
+        self.token_expires_at
+    }
+
+    /// Days until the token expires, if it has an expiry.
+    pub fn token_days_left(&self) -> Option<i64> {
+        self.token_expires_at
+            .map(|date| (date - Local::now().date_naive()).num_days())
+    }
+
+    /// Mismatches of the token's scopes and the enabled features; empty until
+    /// the scopes are fetched.
+    pub fn token_scope_warnings(&self) -> Vec<ScopeWarning> {
+        self.token_scopes.as_ref()
+            .map(|scopes| scope_warnings(scopes, read_only()))
+            .unwrap_or_default()
+    }
+
+    /// Whether the token expires within [GlimConfig::token_warning_days].
+    pub fn is_token_expiring(&self) -> bool {
+        self.token_days_left().is_some_and(|days| days <= self.token_warning_days)
+    }
+}
+
+/// Strips the scheme and path from the GitLab url.
+fn host_of(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme.split('/').next().unwrap_or_default().to_string()
+}
+
 impl Dispatcher for ProjectStore {
     fn dispatch(&self, event: GlimEvent) {
         self.sender.send(event).unwrap();
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::domain::PipelineDto;
+
+    #[test]
+    fn actions_on_stale_ids_fail_without_panicking() {
+        let (sender, _receiver) = mpsc::channel();
+        let mut store = ProjectStore::new(sender, &GlimConfig::default());
+
+        let project_id = ProjectId::new(1);
+        let pipeline_id = PipelineId::new(2);
+        let job_id = JobId::new(3);
+
+        store.apply(&GlimEvent::OpenProjectDetails(project_id));
+
+        assert!(matches!(store.project(project_id), Err(GlimError::ProjectNotFound(id)) if id == project_id));
+        assert!(matches!(store.pipeline(project_id, pipeline_id), Err(GlimError::ProjectNotFound(_))));
+        assert!(matches!(store.job(project_id, pipeline_id, job_id), Err(GlimError::ProjectNotFound(_))));
+
+        // the project is loaded, but the pipeline and job are not
+        store.apply(&GlimEvent::ReceivedProjects(vec![ProjectDto { id: project_id, ..Default::default() }]));
+        store.apply(&GlimEvent::ReceivedPipelines(project_id, vec![pipeline_dto(1, 4)]));
+
+        assert!(matches!(store.pipeline(project_id, pipeline_id), Err(GlimError::PipelineNotFound(p, id)) if p == project_id && id == pipeline_id));
+        assert!(matches!(store.job(project_id, pipeline_id, job_id), Err(GlimError::PipelineNotFound(_, _))));
+        assert!(matches!(store.job(project_id, PipelineId::new(4), job_id), Err(GlimError::JobNotFound(_, _, id)) if id == job_id));
+    }
+
+    pub(crate) fn pipeline_dto(project_id: u32, pipeline_id: u32) -> PipelineDto {
+        serde_json::from_value(serde_json::json!({
+            "id": pipeline_id,
+            "iid": 1,
+            "project_id": project_id,
+            "status": "failed",
+            "source": "push",
+            "ref": "main",
+            "sha": "0123abc",
+            "web_url": "https://gitlab.example.com/group/project/-/pipelines/1",
+            "created_at": "2024-01-01T12:00:00Z",
+            "updated_at": "2024-01-01T12:05:00Z",
+        })).unwrap()
+    }
+
+    #[test]
+    fn projects_without_pipelines_are_hydrated() {
+        let (sender, _receiver) = mpsc::channel();
+        let mut store = ProjectStore::new(sender, &GlimConfig::default());
+
+        let project_id = ProjectId::new(1);
+        store.apply(&GlimEvent::ReceivedProjects(vec![ProjectDto { id: project_id, ..Default::default() }]));
+        store.apply(&GlimEvent::RequestPipelines(project_id));
+        assert_eq!(store.fetch_state(project_id), FetchState::FetchingPipelines);
+
+        store.apply(&GlimEvent::ReceivedPipelines(project_id, vec![]));
+        assert_eq!(store.fetch_state(project_id), FetchState::Hydrated);
+    }
+
+    #[test]
+    fn hidden_projects_remain_in_the_store() {
+        let (sender, _receiver) = mpsc::channel();
+        let mut store = ProjectStore::new(sender, &GlimConfig::default());
+
+        let (kept, ignored) = (ProjectId::new(1), ProjectId::new(2));
+        store.apply(&GlimEvent::ReceivedProjects(vec![
+            ProjectDto { id: kept, path_with_namespace: "group/kept".to_string(), ..Default::default() },
+            ProjectDto { id: ignored, path_with_namespace: "group/ignored".to_string(), ..Default::default() },
+        ]));
+        store.apply(&GlimEvent::ToggleIgnoreProject(ignored));
+        store.apply(&GlimEvent::FilterProjects("zzz".to_string()));
+
+        assert!(store.projects().is_empty());
+        assert_eq!(store.all_projects().len(), 2);
+
+        store.apply(&GlimEvent::FilterProjects(String::new()));
+        store.apply(&GlimEvent::ToggleShowIgnored);
+        assert_eq!(store.projects().iter().map(|p| p.id).collect::<Vec<_>>(), vec![kept, ignored]);
+    }
+
+    #[test]
+    fn pipelines_of_instances_are_kept_apart() {
+        let (sender, _receiver) = mpsc::channel();
+        let mut store = ProjectStore::new(sender, &GlimConfig::default());
+
+        // the same project and pipeline ids, on two GitLab instances
+        let (primary, other) = (ProjectId::new(1), ProjectId::new(1).with_instance(1));
+        store.apply(&GlimEvent::ReceivedProjects(vec![ProjectDto { id: primary, ..Default::default() }]));
+        store.apply(&GlimEvent::ReceivedProjects(vec![ProjectDto { id: ProjectId::new(1), ..Default::default() }]).with_instance(1));
+        store.apply(&GlimEvent::ReceivedPipelines(primary, vec![pipeline_dto(1, 2)]));
+        store.apply(&GlimEvent::ReceivedPipelines(other, vec![pipeline_dto(1, 2)]).with_instance(1));
+
+        let pipeline_id = PipelineId::new(2);
+        assert!(store.pipeline(primary, pipeline_id).is_ok());
+        assert!(store.pipeline(other, pipeline_id.with_instance(1)).is_ok());
+        assert!(matches!(store.pipeline(other, pipeline_id), Err(GlimError::PipelineNotFound(_, _))));
+        assert_eq!(serde_json::to_string(&pipeline_id.with_instance(1)).unwrap(), r#""1:2""#);
+    }
+}
@@ -1,7 +1,16 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
 use once_cell::sync::Lazy;
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
 
+use crate::contrast::{contrast_ratio, ensure_contrast, WCAG_AA};
+use crate::domain::PipelineStatus;
 use crate::gruvbox::Gruvbox;
+use crate::result::{GlimError, Result};
 
 pub struct Theme {
     pub project_parents: Style,
@@ -25,6 +34,14 @@ pub struct Theme {
     pub background: Style,
     pub border_title: Style,
     pub log_message: Style,
+    pub job_log_section: Style,
+    pub search_match: Style,
+    pub instance_badge: Style,
+    pub job_status_success: Style,
+    pub job_status_failed: Style,
+    pub job_status_active: Style,
+    pub job_status_inactive: Style,
+    pub graph_edge: Style,
     pub notification: Style,
     pub notification_project: Style,
     pub input: Style,
@@ -33,6 +50,8 @@ pub struct Theme {
     pub input_description_em: Style,
     pub input_label: Style,
     pub configuration_error: Style,
+    pub progress_filled: Style,
+    pub progress_unfilled: Style,
     pub border: ThemeBorder,
 }
 
@@ -42,7 +61,125 @@ pub struct ThemeBorder {
 }
 
 
+/// Theme loaded from a TOML file: a built-in base theme, with the styles
+/// listed under `[styles]` replaced.
+#[derive(Deserialize)]
+struct ThemeFile {
+    /// `dark` or `light`; dark if unset
+    base: Option<String>,
+    #[serde(default)]
+    styles: HashMap<String, StyleDef>,
+}
+
+/// Style of a theme file, e.g. `{ fg = "#fe8019", modifiers = ["bold"] }`.
+/// Colors are names, hex codes or 256-color indices.
+#[derive(Deserialize)]
+struct StyleDef {
+    fg: Option<String>,
+    bg: Option<String>,
+    #[serde(default)]
+    modifiers: Vec<String>,
+}
+
+impl StyleDef {
+    fn to_style(&self) -> std::result::Result<Style, String> {
+        let color = |c: &str| Color::from_str(c).map_err(|_| format!("invalid color {c}"));
+
+        let mut style = Style::default();
+        if let Some(fg) = &self.fg {
+            style = style.fg(color(fg)?);
+        }
+        if let Some(bg) = &self.bg {
+            style = style.bg(color(bg)?);
+        }
+        for name in &self.modifiers {
+            let modifier = Modifier::from_name(&name.to_uppercase())
+                .ok_or_else(|| format!("invalid modifier {name}"))?;
+            style = style.add_modifier(modifier);
+        }
+
+        Ok(style)
+    }
+}
+
+/// Pairs of the dark and light variants of the gruvbox colors; the bright
+/// accents of the dark theme become the faded accents of the light theme.
+const LIGHT_VARIANTS: [(Gruvbox, Gruvbox); 19] = [
+    (Gruvbox::Dark0Hard, Gruvbox::Light0Hard),
+    (Gruvbox::Dark0, Gruvbox::Light0),
+    (Gruvbox::Dark0Soft, Gruvbox::Light0Soft),
+    (Gruvbox::Dark1, Gruvbox::Light1),
+    (Gruvbox::Dark2, Gruvbox::Light2),
+    (Gruvbox::Dark3, Gruvbox::Light3),
+    (Gruvbox::Dark4, Gruvbox::Light4),
+    (Gruvbox::Light0Hard, Gruvbox::Dark0Hard),
+    (Gruvbox::Light0, Gruvbox::Dark0),
+    (Gruvbox::Light0Soft, Gruvbox::Dark0Soft),
+    (Gruvbox::Light1, Gruvbox::Dark1),
+    (Gruvbox::Light2, Gruvbox::Dark2),
+    (Gruvbox::Light3, Gruvbox::Dark3),
+    (Gruvbox::Light4, Gruvbox::Dark4),
+    (Gruvbox::RedBright, Gruvbox::RedDim),
+    (Gruvbox::GreenBright, Gruvbox::GreenDim),
+    (Gruvbox::YellowBright, Gruvbox::YellowDim),
+    (Gruvbox::BlueBright, Gruvbox::BlueDim),
+    (Gruvbox::OrangeBright, Gruvbox::OrangeDim),
+];
+
 impl Theme {
+    /// Loads the theme named by [GlimConfig::theme](crate::glim_app::GlimConfig::theme):
+    /// `dark`, `light` or the path to a theme file.
+    pub fn load(name: Option<&str>) -> Result<Theme> {
+        match name.map(str::trim) {
+            None | Some("") | Some("dark") => Ok(Theme::new()),
+            Some("light")                  => Ok(Theme::light()),
+            Some(path)                     => Theme::from_file(Path::new(path)),
+        }
+    }
+
+    /// The light variant of the gruvbox theme.
+    pub fn light() -> Theme {
+        let mut theme = Theme::new();
+        let light = |color: Option<Color>| color.map(|c| LIGHT_VARIANTS.iter()
+            .find(|(dark, _)| Color::from(*dark) == c)
+            .map_or(c, |(_, light)| (*light).into()));
+
+        for (_, style) in theme.styles_mut() {
+            style.fg = light(style.fg);
+            style.bg = light(style.bg);
+        }
+
+        theme
+    }
+
+    fn from_file(path: &Path) -> Result<Theme> {
+        let error = |e: String| GlimError::ConfigError(format!("theme {}: {e}", path.display()));
+
+        let content = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+        let file: ThemeFile = toml::from_str(&content).map_err(|e| error(e.to_string()))?;
+
+        let mut theme = match file.base.as_deref() {
+            None | Some("dark") => Theme::new(),
+            Some("light")       => Theme::light(),
+            Some(base)          => return Err(error(format!("unknown base theme {base}"))),
+        };
+
+        let mut styles = theme.styles_mut();
+        for (name, def) in &file.styles {
+            let style = def.to_style().map_err(|e| error(format!("{name}: {e}")))?;
+            let mut found = false;
+            for (_, s) in styles.iter_mut().filter(|(n, _)| n == name) {
+                **s = style;
+                found = true;
+            }
+            if !found {
+                return Err(error(format!("unknown style {name}")));
+            }
+        }
+
+        Ok(theme)
+    }
+
     pub fn new() -> Theme {
         Theme {
             project_parents: Style::default()
@@ -105,6 +242,27 @@ impl Theme {
                 .add_modifier(Modifier::BOLD),
             log_message: Style::default()
                 .fg(Gruvbox::Light4.into()),
+            job_log_section: Style::default()
+                .fg(Gruvbox::AquaBright.into())
+                .add_modifier(Modifier::BOLD),
+            search_match: Style::default()
+                .fg(Gruvbox::YellowBright.into())
+                .add_modifier(Modifier::BOLD),
+            instance_badge: Style::default()
+                .fg(Gruvbox::Dark0Hard.into())
+                .bg(Gruvbox::BlueBright.into()),
+            job_status_success: Style::default()
+                .fg(Gruvbox::GreenBright.into()),
+            job_status_failed: Style::default()
+                .fg(Gruvbox::RedBright.into())
+                .add_modifier(Modifier::BOLD),
+            job_status_active: Style::default()
+                .fg(Gruvbox::BlueBright.into())
+                .add_modifier(Modifier::BOLD),
+            job_status_inactive: Style::default()
+                .fg(Gruvbox::Gray244.into()),
+            graph_edge: Style::default()
+                .fg(Gruvbox::Dark4.into()),
             notification: Style::default()
                 .bg(Gruvbox::Dark0.into())
                 .fg(Gruvbox::Orange.into())
@@ -133,6 +291,11 @@ impl Theme {
             configuration_error: Style::default()
                 .fg(Gruvbox::YellowBright.into())
                 .add_modifier(Modifier::BOLD),
+            progress_filled: Style::default()
+                .fg(Gruvbox::BlueBright.into())
+                .add_modifier(Modifier::BOLD),
+            progress_unfilled: Style::default()
+                .fg(Gruvbox::Dark3.into()),
             border: ThemeBorder {
                 title: Style::default()
                     .bg(Gruvbox::Orange.into())
@@ -151,7 +314,111 @@ impl Theme {
             _ => self.table_row_b,
         }
     }
+
+    pub fn job_status(&self, status: &PipelineStatus) -> Style {
+        match status {
+            PipelineStatus::Success => self.job_status_success,
+            PipelineStatus::Failed  => self.job_status_failed,
+            s if s.is_active()      => self.job_status_active,
+            _                       => self.job_status_inactive,
+        }
+    }
+
+    /// Checks the contrast of all styles against `min_ratio`, and raises the
+    /// contrast of their foreground colors if `adjust` is set. Styles without a
+    /// background are checked against the theme background. Returns the styles
+    /// below the threshold, with their original ratio.
+    fn check_contrast(&mut self, min_ratio: f64, adjust: bool) -> Vec<(&'static str, f64)> {
+        let background = self.background.bg.unwrap_or(Color::Reset);
+
+        let mut low_contrast = Vec::new();
+        for (name, style) in self.styles_mut() {
+            let Some(fg) = style.fg else { continue };
+            let bg = style.bg.unwrap_or(background);
+            let reversed = style.add_modifier.contains(Modifier::REVERSED);
+
+            match contrast_ratio(fg, bg) {
+                Some(ratio) if ratio < min_ratio => {
+                    low_contrast.push((name, ratio));
+                    // reversed styles draw the background color as text; left as is
+                    if adjust && !reversed {
+                        style.fg = Some(ensure_contrast(fg, bg, min_ratio));
+                    }
+                },
+                _ => (),
+            }
+        }
+
+        low_contrast
+    }
+
+    fn styles_mut(&mut self) -> Vec<(&'static str, &mut Style)> {
+        let [commits_count, commits_label] = &mut self.project_commits;
+        let [size_value, size_unit] = &mut self.project_size;
+
+        vec![
+            ("project_parents", &mut self.project_parents),
+            ("project_name", &mut self.project_name),
+            ("project_description", &mut self.project_description),
+            ("project_commits", commits_count),
+            ("project_commits", commits_label),
+            ("project_size", size_value),
+            ("project_size", size_unit),
+            ("commit_title", &mut self.commit_title),
+            ("pipeline_source", &mut self.pipeline_source),
+            ("pipeline_branch", &mut self.pipeline_branch),
+            ("pipeline_job", &mut self.pipeline_job),
+            ("pipeline_job_failed", &mut self.pipeline_job_failed),
+            ("date", &mut self.date),
+            ("time", &mut self.time),
+            ("highlight_symbol", &mut self.highlight_symbol),
+            ("table_border", &mut self.table_border),
+            ("table_row_a", &mut self.table_row_a),
+            ("table_row_b", &mut self.table_row_b),
+            ("pipeline_action", &mut self.pipeline_action),
+            ("pipeline_action_selected", &mut self.pipeline_action_selected),
+            ("border_title", &mut self.border_title),
+            ("log_message", &mut self.log_message),
+            ("job_log_section", &mut self.job_log_section),
+            ("search_match", &mut self.search_match),
+            ("instance_badge", &mut self.instance_badge),
+            ("job_status_success", &mut self.job_status_success),
+            ("job_status_failed", &mut self.job_status_failed),
+            ("job_status_active", &mut self.job_status_active),
+            ("job_status_inactive", &mut self.job_status_inactive),
+            ("graph_edge", &mut self.graph_edge),
+            ("notification", &mut self.notification),
+            ("notification_project", &mut self.notification_project),
+            ("input", &mut self.input),
+            ("input_selected", &mut self.input_selected),
+            ("input_description", &mut self.input_description),
+            ("input_description_em", &mut self.input_description_em),
+            ("input_label", &mut self.input_label),
+            ("configuration_error", &mut self.configuration_error),
+            ("progress_filled", &mut self.progress_filled),
+            ("progress_unfilled", &mut self.progress_unfilled),
+            ("border.config_border", &mut self.border.config_border),
+            ("border.title", &mut self.border.title),
+            ("background", &mut self.background),
+        ]
+    }
 }
 
-static THEME: Lazy<Theme> = Lazy::new(Theme::new);
-pub(crate) fn theme() -> &'static Theme { &THEME }
\ No newline at end of file
+// replaced themes are dropped once no longer referenced, as the configuration
+// is reloaded on every change to the configuration file
+static THEME: Lazy<RwLock<Arc<Theme>>> = Lazy::new(|| RwLock::new(Arc::new(Theme::new())));
+pub(crate) fn theme() -> Arc<Theme> { THEME.read().expect("theme lock poisoned").clone() }
+
+/// Replaces the theme with the named theme, see [Theme::load], enforcing
+/// `min_contrast` if set. Returns the styles below `min_contrast`, or below
+/// [WCAG_AA] if unset; the theme is left as is if it fails to load.
+pub(crate) fn configure_theme(
+    name: Option<&str>,
+    min_contrast: Option<f64>,
+) -> Result<Vec<(&'static str, f64)>> {
+    let mut theme = Theme::load(name)?;
+    let low_contrast = theme.check_contrast(min_contrast.unwrap_or(WCAG_AA), min_contrast.is_some());
+
+    *THEME.write().expect("theme lock poisoned") = Arc::new(theme);
+    Ok(low_contrast)
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::i18n::{t, Msg};
+
+/// Scopes granting more than glim ever needs.
+const UNNEEDED_SCOPES: [&str; 6] = [
+    "sudo",
+    "admin_mode",
+    "write_repository",
+    "write_registry",
+    "create_runner",
+    "manage_runner",
+];
+
+/// Mismatch between the scopes of the token and the enabled features.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeWarning {
+    /// actions changing anything in GitLab are enabled, but the token lacks
+    /// the `api` scope
+    MissingApiScope,
+    /// scopes not needed by glim
+    BroadScopes(Vec<String>),
+}
+
+impl ScopeWarning {
+    pub fn message(&self) -> String {
+        match self {
+            ScopeWarning::MissingApiScope     => t(Msg::NoticeTokenMissingApiScope).to_string(),
+            ScopeWarning::BroadScopes(scopes) => t(Msg::NoticeTokenBroadScopes).replace("{scopes}", &scopes.join(", ")),
+        }
+    }
+}
+
+/// Checks the scopes of the token against the features in use: in
+/// read-only mode, `read_api` suffices, otherwise `api` is needed.
+pub fn scope_warnings(scopes: &[String], read_only: bool) -> Vec<ScopeWarning> {
+    let has = |scope: &str| scopes.iter().any(|s| s == scope);
+
+    let mut warnings = Vec::new();
+    if !read_only && !has("api") {
+        warnings.push(ScopeWarning::MissingApiScope);
+    }
+
+    let broad: Vec<String> = scopes.iter()
+        .filter(|scope| UNNEEDED_SCOPES.contains(&scope.as_str()) || (read_only && *scope == "api"))
+        .cloned()
+        .collect();
+    if !broad.is_empty() {
+        warnings.push(ScopeWarning::BroadScopes(broad));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scopes(scopes: &[&str]) -> Vec<String> {
+        scopes.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn read_api_suffices_in_read_only_mode() {
+        assert_eq!(scope_warnings(&scopes(&["read_api"]), true), vec![]);
+        assert_eq!(scope_warnings(&scopes(&["read_api"]), false), vec![ScopeWarning::MissingApiScope]);
+        assert_eq!(scope_warnings(&scopes(&["api"]), false), vec![]);
+    }
+
+    #[test]
+    fn unneeded_scopes_are_too_broad() {
+        assert_eq!(
+            scope_warnings(&scopes(&["api", "sudo", "read_user"]), false),
+            vec![ScopeWarning::BroadScopes(scopes(&["sudo"]))]
+        );
+        assert_eq!(
+            scope_warnings(&scopes(&["api", "read_api"]), true),
+            vec![ScopeWarning::BroadScopes(scopes(&["api"]))]
+        );
+    }
+}
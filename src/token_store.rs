@@ -0,0 +1,148 @@
+use std::process::{Command, Stdio};
+
+use keyring::Entry;
+use serde::{Deserialize, Serialize};
+
+use crate::glim_app::{GlimConfig, InstanceConfig};
+use crate::result::{GlimError, Result};
+
+/// Overrides the token of the primary GitLab instance, taking precedence over
+/// [GlimConfig::gitlab_token_cmd] and [GlimConfig::gitlab_token].
+const TOKEN_ENV_VAR: &str = "GLIM_GITLAB_TOKEN";
+
+/// Service name of the keyring entries; the account is the URL of the GitLab instance.
+const KEYRING_SERVICE: &str = "glim";
+
+/// Prefix of token references in the configuration file, e.g. `keyring:https://gitlab.com`.
+const KEYRING_REFERENCE: &str = "keyring:";
+
+/// Where the GitLab tokens are kept.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenStorage {
+    /// in the configuration file, as plaintext
+    #[default]
+    Plaintext,
+    /// in the OS keyring, with only a reference in the configuration file
+    Keyring,
+}
+
+/// Replaces the keyring references of the configuration with the tokens they refer to.
+pub fn resolve_tokens(config: &mut GlimConfig) -> Result<()> {
+    config.gitlab_token = resolve(&config.gitlab_token)?;
+    for instance in config.instances.iter_mut() {
+        instance.gitlab_token = resolve(&instance.gitlab_token)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the tokens to the keyring, when configured, replacing them with references.
+/// Plaintext tokens of an existing configuration are migrated the same way; tokens
+/// already in the keyring are only written again when changed.
+pub fn store_tokens(config: &mut GlimConfig) -> Result<()> {
+    if config.token_storage != TokenStorage::Keyring {
+        return Ok(());
+    }
+
+    config.gitlab_token = store(&config.gitlab_url, &config.gitlab_token)?;
+    for instance in config.instances.iter_mut() {
+        instance.gitlab_token = store(&instance.gitlab_url, &instance.gitlab_token)?;
+    }
+
+    Ok(())
+}
+
+/// Whether any token of the configuration is still kept as plaintext,
+/// although the keyring is configured.
+pub fn needs_migration(config: &GlimConfig) -> bool {
+    let is_plaintext = |token: &str| !token.is_empty() && !token.starts_with(KEYRING_REFERENCE);
+
+    config.token_storage == TokenStorage::Keyring
+        && (is_plaintext(&config.gitlab_token)
+            || config.instances.iter().any(|i| is_plaintext(&i.gitlab_token)))
+}
+
+/// Token of the primary instance: [TOKEN_ENV_VAR] if set, else the output of
+/// [GlimConfig::gitlab_token_cmd], else [GlimConfig::gitlab_token].
+///
+/// Blocks until the command completes; resolve tokens before building a
+/// [GitlabClient](crate::client::GitlabClient), never from within its runtime.
+pub fn primary_token(config: &GlimConfig) -> Result<String> {
+    match std::env::var(TOKEN_ENV_VAR) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => token_of(&config.gitlab_token, config.gitlab_token_cmd.as_deref()),
+    }
+}
+
+/// Token of an additional instance, from its command if configured.
+pub fn instance_token(config: &InstanceConfig) -> Result<String> {
+    token_of(&config.gitlab_token, config.gitlab_token_cmd.as_deref())
+}
+
+/// Whether the token of the primary instance is sourced from outside the
+/// configuration file, so that `gitlab_token` may be left empty.
+pub fn has_external_token(config: &GlimConfig) -> bool {
+    config.gitlab_token_cmd.as_ref().is_some_and(|cmd| !cmd.trim().is_empty())
+        || std::env::var(TOKEN_ENV_VAR).is_ok_and(|token| !token.trim().is_empty())
+}
+
+fn token_of(token: &str, cmd: Option<&str>) -> Result<String> {
+    match cmd.filter(|cmd| !cmd.trim().is_empty()) {
+        Some(cmd) => run_token_cmd(cmd),
+        None      => Ok(token.to_string()),
+    }
+}
+
+/// Runs the command with `sh -c`, returning the first line of its output.
+fn run_token_cmd(cmd: &str) -> Result<String> {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| GlimError::ConfigError(format!("failed to run gitlab_token_cmd: {e}")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GlimError::ConfigError(format!(
+            "gitlab_token_cmd failed with {}: {}", output.status, stderr.trim()
+        )));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| GlimError::ConfigError("gitlab_token_cmd printed no token".to_string()))
+}
+
+fn resolve(token: &str) -> Result<String> {
+    match token.strip_prefix(KEYRING_REFERENCE) {
+        Some(account) => entry(account)?
+            .get_password()
+            .map_err(|e| GlimError::ConfigError(format!("unable to read token of {account} from keyring: {e}"))),
+        None => Ok(token.to_string()),
+    }
+}
+
+fn store(url: &str, token: &str) -> Result<String> {
+    if token.is_empty() || token.starts_with(KEYRING_REFERENCE) {
+        return Ok(token.to_string());
+    }
+
+    let account = url.trim().trim_end_matches('/');
+    let entry = entry(account)?;
+    if !entry.get_password().is_ok_and(|stored| stored == token) {
+        entry.set_password(token)
+            .map_err(|e| GlimError::ConfigError(format!("unable to write token of {account} to keyring: {e}")))?;
+    }
+
+    Ok(format!("{KEYRING_REFERENCE}{account}"))
+}
+
+fn entry(account: &str) -> Result<Entry> {
+    Entry::new(KEYRING_SERVICE, account)
+        .map_err(|e| GlimError::ConfigError(format!("keyring unavailable: {e}")))
+}
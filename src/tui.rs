@@ -1,4 +1,5 @@
 use std::{io, panic};
+use std::path::Path;
 
 
 use crossterm::{
@@ -8,6 +9,8 @@ use crossterm::{
 use ratatui::Frame;
 use ratatui::layout::Size;
 use crate::event::{GlimEvent, EventHandler};
+use crate::recording::ScreenRecorder;
+use crate::screen_capture::save_screenshot;
 use crate::result::GlimError;
 use crate::result::GlimError::GeneralError;
 
@@ -23,22 +26,61 @@ pub struct Tui {
     terminal: CrosstermTerminal,
     /// Terminal event handler.
     events: EventHandler,
+    /// records each drawn frame while set
+    recorder: Option<ScreenRecorder>,
 }
 
 impl Tui {
     pub fn new(terminal: CrosstermTerminal, events: EventHandler) -> Self {
-        Self { terminal, events }
+        Self { terminal, events, recorder: None }
     }
 
     pub fn draw(
         &mut self,
         render_ui: impl FnOnce(&mut Frame),
     ) -> Result<(), GlimError> {
-        self.terminal.draw(render_ui)
+        let frame = self.terminal.draw(render_ui)
             .map_err(|_| GeneralError("failed to draw UI".to_string()))?;
+
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(frame.buffer)?;
+        }
+
         Ok(())
     }
 
+    /// Draws the frame and writes it to a file in the directory.
+    pub fn screenshot(
+        &mut self,
+        dir: &Path,
+        render_ui: impl FnOnce(&mut Frame),
+    ) -> Result<GlimEvent, GlimError> {
+        let frame = self.terminal.draw(render_ui)
+            .map_err(|_| GeneralError("failed to draw UI".to_string()))?;
+
+        Ok(GlimEvent::ScreenshotSaved(save_screenshot(dir, frame.buffer)?))
+    }
+
+    /// Starts recording the drawn frames to a file in the directory, or
+    /// stops and saves the recording in progress.
+    pub fn toggle_recording(&mut self, dir: &Path) -> Result<GlimEvent, GlimError> {
+        match self.recorder.take() {
+            Some(recorder) => Ok(GlimEvent::RecordingSaved(recorder.finish()?)),
+            None => {
+                let size = self.size();
+                let recorder = ScreenRecorder::start(dir, size.width, size.height)?;
+                let event = GlimEvent::RecordingStarted(recorder.path().to_path_buf());
+                self.recorder = Some(recorder);
+                Ok(event)
+            },
+        }
+    }
+
+    /// Changes the time between ticks, which bounds the frame rate.
+    pub fn set_tick_rate(&self, tick_rate: std::time::Duration) {
+        self.events.set_tick_rate(tick_rate);
+    }
+
     pub fn size(&self) -> Size {
         self.terminal.size().unwrap()
     }
@@ -50,7 +92,6 @@ impl Tui {
     {
         let mut apply_event = |e| match e {
             GlimEvent::ReceivedProjects(p) if p.is_empty() => (),
-            GlimEvent::ReceivedPipelines(p) if p.is_empty() => (),
             GlimEvent::ReceivedJobs(_, _, j) if j.is_empty() => (),
             _ => f(e)
         };
@@ -1,4 +1,5 @@
 mod window;
+mod registry;
 
 use ratatui::layout::Margin;
 use ratatui::style::Color;
@@ -8,6 +9,7 @@ use tachyonfx::fx::{coalesce, Direction, dissolve, fade_from, fade_to, never_com
 use tachyonfx::{Effect, fx, Interpolation, Duration};
 use tachyonfx::CellFilter::{AllOf, Inner, Not, Outer, Text};
 pub use window::*;
+pub use registry::*;
 use crate::gruvbox::Gruvbox::{Dark0, Dark0Hard, Dark3};
 use crate::theme::theme;
 use crate::ui::widget::Shortcuts;
@@ -29,16 +31,23 @@ pub fn open_window(
         Span::from("┣").style(theme().border.config_border),
     ]);
 
-    OpenWindow::builder()
+    let window = OpenWindow::builder()
         .title(title)
         .border_style(theme().border.config_border)
         .border_type(BorderType::Rounded)
         .background(theme().background)
-        .parent_window_fx(fade_screen_bg)
-        .open_window_fx(open_window_fx(Dark0))
-        .shortcuts(shortcuts.map(Shortcuts::from))
-        .build()
-        .unwrap()
+        .shortcuts(shortcuts.map(Shortcuts::from));
+
+    // without transitions, the window opens at once over an undimmed screen
+    if is_enabled(FxId::PopupTransition) {
+        window
+            .parent_window_fx(fade_screen_bg)
+            .open_window_fx(open_window_fx(Dark0))
+            .build()
+            .unwrap()
+    } else {
+        window.build().unwrap()
+    }
 }
 
 /// Animates and fades in a window from the specified background color.
@@ -0,0 +1,174 @@
+use std::sync::RwLock;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use tachyonfx::{fx, Duration, Effect, Interpolation, Shader};
+
+use crate::capabilities::accessible;
+use crate::domain::PipelineStatus;
+use crate::event::GlimEvent;
+use crate::gruvbox::Gruvbox;
+use crate::id::ProjectId;
+
+/// Categories of effects, each of which can be turned off in [AnimationConfig].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FxId {
+    /// opening popups and fading the screen behind them
+    PopupTransition,
+    /// sweeping in the projects table when projects change
+    TableFadeIn,
+    /// ambient glitches, and the bursts on network errors
+    Glitch,
+    /// pulsing text of notifications while shown
+    NotificationBlink,
+    /// flashing rows of projects whose pipeline passed or failed
+    RowFlash,
+}
+
+/// Animations enabled per [FxId]; all are enabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AnimationConfig {
+    pub popup_transitions: bool,
+    pub table_fade_in: bool,
+    pub glitch: bool,
+    pub notification_blink: bool,
+    pub row_flash: bool,
+}
+
+static ANIMATIONS: RwLock<AnimationConfig> = RwLock::new(AnimationConfig::ALL);
+
+impl AnimationConfig {
+    const ALL: AnimationConfig = AnimationConfig {
+        popup_transitions: true,
+        table_fade_in: true,
+        glitch: true,
+        notification_blink: true,
+        row_flash: true,
+    };
+
+    pub fn is_enabled(&self, id: FxId) -> bool {
+        match id {
+            FxId::PopupTransition   => self.popup_transitions,
+            FxId::TableFadeIn       => self.table_fade_in,
+            FxId::Glitch            => self.glitch,
+            FxId::NotificationBlink => self.notification_blink,
+            FxId::RowFlash          => self.row_flash,
+        }
+    }
+
+    /// Comma-separated names of the enabled animations, as edited in the
+    /// configuration popup.
+    pub fn enabled_names(&self) -> String {
+        self.flags()
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Parses [Self::enabled_names]; animations not listed are disabled.
+    pub fn from_enabled_names(names: &str) -> Self {
+        let names: Vec<&str> = names.split(',').map(str::trim).collect();
+        let enabled = |name: &str| names.contains(&name);
+
+        Self {
+            popup_transitions: enabled("popup_transitions"),
+            table_fade_in: enabled("table_fade_in"),
+            glitch: enabled("glitch"),
+            notification_blink: enabled("notification_blink"),
+            row_flash: enabled("row_flash"),
+        }
+    }
+
+    fn flags(&self) -> [(&'static str, bool); 5] {
+        [
+            ("popup_transitions", self.popup_transitions),
+            ("table_fade_in", self.table_fade_in),
+            ("glitch", self.glitch),
+            ("notification_blink", self.notification_blink),
+            ("row_flash", self.row_flash),
+        ]
+    }
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Enables or disables the categories of effects, taking effect for effects
+/// created from now on.
+pub fn configure_animations(config: AnimationConfig) {
+    *ANIMATIONS.write().expect("animations lock poisoned") = config;
+}
+
+/// Whether effects of the category are enabled, see [configure_animations].
+/// All effects are disabled in accessibility mode.
+pub fn is_enabled(id: FxId) -> bool {
+    !accessible() && ANIMATIONS.read().expect("animations lock poisoned").is_enabled(id)
+}
+
+/// Effects bound to rows of the projects table. Row areas are resolved when
+/// rendering, as rows move when projects are sorted or the table scrolls.
+#[derive(Default)]
+pub struct EffectRegistry {
+    row_effects: Vec<(ProjectId, Effect)>,
+}
+
+impl EffectRegistry {
+    pub fn apply(&mut self, event: &GlimEvent) {
+        if let GlimEvent::PipelineStatusChanged(project_id, _, _, status) = event {
+            match status {
+                PipelineStatus::Failed  => self.add_row_effect(*project_id, flash_row(Gruvbox::RedDim)),
+                PipelineStatus::Success => self.add_row_effect(*project_id, flash_row(Gruvbox::GreenDim)),
+                _                       => (),
+            }
+        }
+    }
+
+    /// Adds an effect to the row of the project, replacing any running effect.
+    /// Ignored while row flashes are disabled.
+    pub fn add_row_effect(&mut self, project_id: ProjectId, effect: Effect) {
+        if !is_enabled(FxId::RowFlash) {
+            return;
+        }
+
+        self.row_effects.retain(|(id, _)| *id != project_id);
+        self.row_effects.push((project_id, effect));
+    }
+
+    /// The effect, unless effects of its category are disabled.
+    pub fn filter(&self, id: FxId, effect: Effect) -> Option<Effect> {
+        is_enabled(id).then_some(effect)
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.row_effects.is_empty()
+    }
+
+    /// Processes the row effects; `row_area` returns the area of the project's
+    /// row, or none if the row is not visible. Completed effects are removed.
+    pub fn process_row_effects(
+        &mut self,
+        last_frame: Duration,
+        buf: &mut Buffer,
+        row_area: impl Fn(ProjectId) -> Option<Rect>,
+    ) {
+        self.row_effects.retain_mut(|(project_id, effect)| {
+            if let Some(area) = row_area(*project_id) {
+                effect.process(last_frame, buf, area);
+            }
+
+            !effect.done()
+        });
+    }
+}
+
+/// Fades the background of a row from the given color.
+fn flash_row(color: Gruvbox) -> Effect {
+    fx::fade_from(Gruvbox::Light0, color, (1500, Interpolation::QuadOut))
+}
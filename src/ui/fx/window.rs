@@ -42,7 +42,7 @@ impl OpenWindow {
         }
     }
 
-    fn window_block(&self) -> Block {
+    fn window_block(&self) -> Block<'_> {
         let w = Block::new()
             .borders(Borders::ALL)
             .title_style(self.border_style)
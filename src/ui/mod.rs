@@ -1,4 +1,10 @@
-use chrono::Duration;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use chrono::{DateTime, Duration, Local};
+use ratatui::text::Span;
+
+use crate::i18n::{t, Msg};
+use crate::theme::theme;
 
 pub mod popup;
 pub mod widget;
@@ -7,6 +13,38 @@ mod stateful_widgets;
 
 pub use stateful_widgets::StatefulWidgets;
 
+/// Whether timestamps are shown as ages, see [set_relative_times].
+static RELATIVE_TIMES: AtomicBool = AtomicBool::new(false);
+
+/// Shows timestamps as ages, e.g. "3m ago", in place of dates and times.
+pub fn set_relative_times(relative: bool) {
+    RELATIVE_TIMES.store(relative, Ordering::Relaxed);
+}
+
+pub fn relative_times() -> bool {
+    RELATIVE_TIMES.load(Ordering::Relaxed)
+}
+
+/// Branches shown per project row, see [set_branch_lines].
+static BRANCH_LINES: AtomicUsize = AtomicUsize::new(3);
+/// Whether the default branch is always shown, see [set_branch_lines].
+static SHOW_DEFAULT_BRANCH: AtomicBool = AtomicBool::new(false);
+
+/// Sets the number of branches shown with their latest pipeline per project
+/// row, and whether the default branch is always among them.
+pub fn set_branch_lines(lines: usize, show_default_branch: bool) {
+    BRANCH_LINES.store(lines.max(1), Ordering::Relaxed);
+    SHOW_DEFAULT_BRANCH.store(show_default_branch, Ordering::Relaxed);
+}
+
+pub fn branch_lines() -> usize {
+    BRANCH_LINES.load(Ordering::Relaxed)
+}
+
+pub fn shows_default_branch() -> bool {
+    SHOW_DEFAULT_BRANCH.load(Ordering::Relaxed)
+}
+
 pub fn format_duration(duration: Duration) -> String {
     let total_seconds = duration.abs().num_seconds();
     let hours = total_seconds / 3600;
@@ -18,4 +56,30 @@ pub fn format_duration(duration: Duration) -> String {
         _ if minutes > 0 => format!("{}:{:02}", minutes, seconds),
         _                => format!("0:{:02}", seconds),
     }
-}
\ No newline at end of file
+}
+
+/// Formats the duration as the largest whole unit, e.g. "12s" or "3m".
+pub fn format_age(duration: Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    match seconds {
+        s if s < 60    => format!("{s}s"),
+        s if s < 3600  => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s              => format!("{}d", s / 86400),
+    }
+}
+
+/// Spans of the date and time of the timestamp, or of its age if
+/// [relative_times] is set; ages are computed anew on every render.
+pub fn timestamp_spans(at: DateTime<Local>) -> Vec<Span<'static>> {
+    if relative_times() {
+        let age = t(Msg::TimeAgo).replace("{age}", &format_age(Local::now() - at));
+        vec![Span::from(age).style(theme().time)]
+    } else {
+        vec![
+            Span::from(at.format("%a, %d %b").to_string()).style(theme().date),
+            Span::from(" "),
+            Span::from(at.format("%H:%M:%S").to_string()).style(theme().time),
+        ]
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Borders, List, ListState, Paragraph};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::api_inspector::ApiExchange;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::i18n::{t, Msg};
+
+/// api inspector popup
+pub struct ApiInspectorPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the api inspector popup; a snapshot of the recorded exchanges
+pub struct ApiInspectorPopupState {
+    pub exchanges: Vec<ApiExchange>,
+    pub list_state: ListState,
+    /// pretty-printed body of the selected exchange
+    body: String,
+    /// scroll offset of the body of the selected exchange
+    pub body_offset: u16,
+    window_fx: OpenWindow,
+}
+
+impl ApiInspectorPopupState {
+    /// number of exchanges listed at once
+    const LIST_HEIGHT: u16 = 10;
+
+    pub fn new(exchanges: Vec<ApiExchange>) -> Self {
+        let selected = exchanges.len().checked_sub(1);
+        let body = selected
+            .map(|idx| exchanges[idx].pretty_body())
+            .unwrap_or_default();
+
+        Self {
+            exchanges,
+            list_state: ListState::default().with_selected(selected),
+            body,
+            body_offset: 0,
+            window_fx: open_window(t(Msg::TitleApiInspector), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("PgUp PgDn", t(Msg::ShortcutScrollBody)),
+            ])),
+        }
+    }
+
+    pub fn select(&mut self, direction: i32) {
+        if let Some(current) = self.list_state.selected() {
+            let new_index = (current as i32 + direction)
+                .clamp(0, self.exchanges.len().saturating_sub(1) as i32) as usize;
+
+            self.list_state.select(Some(new_index));
+            self.body = self.exchanges[new_index].pretty_body();
+            self.body_offset = 0;
+        }
+    }
+
+    pub fn scroll_body(&mut self, delta: i32) {
+        self.body_offset = (self.body_offset as i32 + delta).max(0) as u16;
+    }
+
+    fn exchanges_as_lines(&self) -> Vec<Line<'static>> {
+        if self.exchanges.is_empty() {
+            return vec![Line::from(t(Msg::NoRequestsRecorded)).style(theme().log_message)];
+        }
+
+        self.exchanges.iter()
+            .map(|exchange| {
+                let status = exchange.status
+                    .map(|s| s.as_u16().to_string())
+                    .unwrap_or_else(|| "---".to_string());
+
+                Line::from(vec![
+                    Span::from(exchange.timestamp.format("%H:%M:%S ").to_string()).style(theme().time),
+                    Span::from(format!("{:<6} {status} ", exchange.method)).style(theme().log_message),
+                    Span::from(format!("{:>6}ms {:>9} ", exchange.duration.as_millis(), format_size(exchange.body.len())))
+                        .style(theme().time),
+                    Span::from(exchange.url.clone()).style(theme().log_message),
+                ])
+            })
+            .collect()
+    }
+}
+
+/// Size of the body, e.g. `12.3 kB`.
+fn format_size(bytes: usize) -> String {
+    match bytes {
+        0..=999          => format!("{bytes} B"),
+        1000..=999_999   => format!("{:.1} kB", bytes as f64 / 1000.0),
+        _                => format!("{:.1} MB", bytes as f64 / 1_000_000.0),
+    }
+}
+
+impl ApiInspectorPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for ApiInspectorPopup {
+    type State = ApiInspectorPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let area = area.inner(Margin::new(4, 2));
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(1, 1));
+        let [list_area, body_area] = Layout::vertical([
+            Constraint::Length(ApiInspectorPopupState::LIST_HEIGHT),
+            Constraint::Percentage(100),
+        ]).areas(content_area);
+
+        let exchanges = List::new(state.exchanges_as_lines())
+            .style(theme().table_row_b)
+            .highlight_style(theme().highlight_symbol);
+        StatefulWidget::render(exchanges, list_area, buf, &mut state.list_state);
+
+        Paragraph::new(state.body.as_str())
+            .style(theme().log_message)
+            .scroll((state.body_offset, 0))
+            .block(Block::new().borders(Borders::TOP).border_style(theme().table_border))
+            .render(body_area, buf);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,182 @@
+use std::path::PathBuf;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{Cell, LineGauge, Row, Table, TableState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::domain::{Artifact, Job};
+use crate::i18n::{t, Msg};
+use crate::id::{JobId, ProjectId};
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// maximum number of visible artifacts
+const MAX_ARTIFACT_ROWS: usize = 10;
+
+/// artifacts popup, listing the files kept by a job
+pub struct ArtifactsPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the artifacts popup
+pub struct ArtifactsPopupState {
+    pub project_id: ProjectId,
+    pub job_id: JobId,
+    job_name: String,
+    artifacts: Vec<Artifact>,
+    /// the archive can be downloaded
+    pub has_archive: bool,
+    pub download: Option<ArtifactsDownload>,
+    pub table_state: TableState,
+    window_fx: OpenWindow,
+}
+
+/// Progress of downloading the artifacts archive.
+pub enum ArtifactsDownload {
+    /// bytes received, and total size if known
+    InProgress(u64, Option<u64>),
+    Done(PathBuf),
+}
+
+impl ArtifactsPopupState {
+    pub fn new(project_id: ProjectId, job: &Job) -> Self {
+        Self {
+            project_id,
+            job_id: job.id,
+            job_name: job.name.clone(),
+            artifacts: job.artifacts.clone(),
+            has_archive: job.has_artifacts_archive(),
+            download: None,
+            table_state: TableState::default().with_selected(Some(0)),
+            window_fx: open_window(t(Msg::TitleArtifacts), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("d",   t(Msg::ShortcutDownload)),
+            ])),
+        }
+    }
+
+    pub fn select(&mut self, direction: i32) {
+        if let Some(current) = self.table_state.selected() {
+            let new_index = (current as i32 + direction)
+                .clamp(0, self.artifacts.len().saturating_sub(1) as i32);
+
+            self.table_state.select(Some(new_index as usize));
+        }
+    }
+
+    pub fn is_downloading(&self) -> bool {
+        matches!(self.download, Some(ArtifactsDownload::InProgress(..)))
+    }
+
+    fn artifact_row(artifact: &Artifact) -> Row<'static> {
+        Row::new(vec![
+            Cell::from(Span::from(artifact.file_type.clone()).style(theme().pipeline_source)),
+            Cell::from(Span::from(artifact.filename.clone()).style(theme().commit_title)),
+            Cell::from(Line::from(format_size(artifact.size)).style(theme().time).right_aligned()),
+        ])
+    }
+
+    fn render_download(&self, area: Rect, buf: &mut Buffer) {
+        match &self.download {
+            None if self.has_archive => Line::from(vec![
+                Span::from(self.job_name.clone()).style(theme().pipeline_job),
+                Span::from(" "),
+                Span::from(t(Msg::ArtifactsDownloadHint)).style(theme().log_message),
+            ]).render(area, buf),
+            None => Line::from(t(Msg::ArtifactsNoArchive))
+                .style(theme().log_message)
+                .render(area, buf),
+            Some(ArtifactsDownload::InProgress(downloaded, total)) => {
+                let ratio = total.filter(|&t| t > 0)
+                    .map_or(0.0, |t| (*downloaded as f64 / t as f64).min(1.0));
+                let label = match total {
+                    Some(total) => format!("{} / {}", format_size(*downloaded), format_size(*total)),
+                    None        => format_size(*downloaded),
+                };
+
+                LineGauge::default()
+                    .ratio(ratio)
+                    .label(label)
+                    .style(theme().time)
+                    .filled_style(theme().progress_filled)
+                    .unfilled_style(theme().progress_unfilled)
+                    .render(area, buf);
+            },
+            Some(ArtifactsDownload::Done(path)) => Line::from(vec![
+                Span::from(t(Msg::NoticeArtifactsDownloaded)).style(theme().log_message),
+                Span::from(path.display().to_string()).style(theme().pipeline_branch),
+            ]).render(area, buf),
+        }
+    }
+}
+
+/// Formats a size in bytes, e.g. "12.3 MiB".
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+impl ArtifactsPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for ArtifactsPopup {
+    type State = ArtifactsPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let rows = state.artifacts.len().clamp(1, MAX_ARTIFACT_ROWS) as u16;
+        let area = area.inner_centered(80, 4 + rows);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let [table_area, _, download_area] = Layout::vertical([
+            Constraint::Length(rows),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ]).areas(area.inner(Margin::new(2, 1)));
+
+        let rows: Vec<Row> = state.artifacts.iter()
+            .map(ArtifactsPopupState::artifact_row)
+            .enumerate()
+            .map(|(idx, r)| r.style(theme().table_row(idx)))
+            .collect();
+
+        let table = Table::new(rows, [
+                Constraint::Length(10),
+                Constraint::Percentage(100),
+                Constraint::Length(10),
+            ])
+            .highlight_style(theme().highlight_symbol)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, table_area, buf, &mut state.table_state);
+        state.render_download(download_area, buf);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,85 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget};
+use ratatui::widgets::{List, ListState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::audit_log::AuditEntry;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+use crate::i18n::{t, Msg};
+
+/// audit log popup
+pub struct AuditLogPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the audit log popup
+pub struct AuditLogPopupState {
+    pub entries: Vec<AuditEntry>,
+    pub list_state: ListState,
+    window_fx: OpenWindow,
+}
+
+impl AuditLogPopupState {
+    pub fn new(entries: Vec<AuditEntry>) -> Self {
+        let selected = entries.len().checked_sub(1);
+        Self {
+            entries,
+            list_state: ListState::default().with_selected(selected),
+            window_fx: open_window(t(Msg::TitleAuditLog), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+            ])),
+        }
+    }
+
+    fn entries_as_lines(&self) -> Vec<Line<'static>> {
+        if self.entries.is_empty() {
+            return vec![Line::from(t(Msg::NoActionsRecorded)).style(theme().log_message)];
+        }
+
+        self.entries.iter()
+            .map(|entry| Line::from(vec![
+                Span::from(entry.timestamp.format("%Y-%m-%d ").to_string()).style(theme().date),
+                Span::from(entry.timestamp.format("%H:%M:%S").to_string()).style(theme().time),
+                Span::from(" "),
+                Span::from(entry.action.description()).style(theme().log_message),
+            ]))
+            .collect()
+    }
+}
+
+impl AuditLogPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for AuditLogPopup {
+    type State = AuditLogPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let height = 2 + state.entries.len().clamp(1, 20) as u16;
+        let area = area.inner_centered(80, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let entries = List::new(state.entries_as_lines())
+            .style(theme().table_row_b)
+            .highlight_style(theme().highlight_symbol);
+
+        let inner_area = area.inner(Margin::new(1, 1));
+        StatefulWidget::render(entries, inner_area, buf, &mut state.list_state);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::Utc;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{Cell, Row, Table, TableState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::capabilities::ascii_icons;
+use crate::domain::{Branch, IconRepresentable, Project};
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::read_only::read_only;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// maximum number of visible branches
+const MAX_BRANCH_ROWS: usize = 20;
+
+/// branch browser popup
+pub struct BranchesPopup<'a> {
+    last_frame_ms: Duration,
+    project: &'a Project,
+}
+
+/// state of the branch browser popup
+pub struct BranchesPopupState {
+    pub project_id: ProjectId,
+    /// default branch first, then by most recent commit; none until fetched
+    branches: Option<Vec<Branch>>,
+    pub table_state: TableState,
+    window_fx: OpenWindow,
+}
+
+impl BranchesPopupState {
+    pub fn new(project_id: ProjectId) -> Self {
+        Self {
+            project_id,
+            branches: None,
+            table_state: TableState::default(),
+            window_fx: open_window(t(Msg::TitleBranches), Some(Self::shortcuts())),
+        }
+    }
+
+    /// Running a pipeline is hidden in read-only mode.
+    fn shortcuts() -> Vec<(&'static str, &'static str)> {
+        let mut shortcuts = vec![
+            ("ESC", t(Msg::ShortcutClose)),
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("w",   t(Msg::ShortcutOpenWeb)),
+        ];
+        if !read_only() {
+            shortcuts.push(("r", t(Msg::ShortcutTriggerPipeline)));
+        }
+        shortcuts
+    }
+
+    pub fn set_branches(&mut self, mut branches: Vec<Branch>) {
+        branches.sort_by(|a, b| b.default.cmp(&a.default)
+            .then_with(|| b.committed_at.cmp(&a.committed_at)));
+
+        self.table_state.select((!branches.is_empty()).then_some(0));
+        self.branches = Some(branches);
+    }
+
+    pub fn selected_branch(&self) -> Option<&Branch> {
+        self.table_state.selected()
+            .and_then(|idx| self.branches.as_ref()?.get(idx))
+    }
+
+    pub fn select(&mut self, direction: i32) {
+        let count = self.branches.as_ref().map_or(0, Vec::len);
+        if let Some(current) = self.table_state.selected() {
+            let new_index = (current as i32 + direction)
+                .clamp(0, count.saturating_sub(1) as i32);
+
+            self.table_state.select(Some(new_index as usize));
+        }
+    }
+
+    fn branch_row(branch: &Branch, project: &Project) -> Row<'static> {
+        let status = project.latest_pipeline_on(&branch.name)
+            .map(|p| p.status.labeled_icon())
+            .unwrap_or_else(|| "  ".to_string());
+
+        let mut name = vec![Span::from(branch.name.clone()).style(theme().pipeline_branch)];
+        if branch.default {
+            name.push(Span::from(" ★").style(theme().pipeline_source));
+        }
+        if branch.protected {
+            let lock = if ascii_icons() { " [p]" } else { " 🔒" };
+            name.push(Span::from(lock).style(theme().pipeline_source));
+        }
+
+        Row::new(vec![
+            Cell::from(status),
+            Cell::from(Line::from(name)),
+            Cell::from(Span::from(format_age(branch)).style(theme().time)),
+            Cell::from(Span::from(branch.commit_title.clone()).style(theme().commit_title)),
+        ])
+    }
+}
+
+/// Age of the last commit on the branch, e.g. "3d".
+fn format_age(branch: &Branch) -> String {
+    let age = Utc::now() - branch.committed_at;
+    match () {
+        _ if age.num_weeks() > 0   => format!("{}w", age.num_weeks()),
+        _ if age.num_days() > 0    => format!("{}d", age.num_days()),
+        _ if age.num_hours() > 0   => format!("{}h", age.num_hours()),
+        _                          => format!("{}m", age.num_minutes().max(0)),
+    }
+}
+
+impl<'a> BranchesPopup<'a> {
+    pub fn new(last_frame_ms: Duration, project: &'a Project) -> Self {
+        Self { last_frame_ms, project }
+    }
+}
+
+impl StatefulWidget for BranchesPopup<'_> {
+    type State = BranchesPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let rows = state.branches.as_ref().map_or(0, Vec::len);
+        let height = 2 + rows.clamp(1, MAX_BRANCH_ROWS) as u16;
+        let area = area.inner(Margin::new(4, 0)).inner_centered(area.width, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        match state.branches.as_ref() {
+            None => Line::from(t(Msg::LoadingBranches))
+                .style(theme().log_message)
+                .render(content_area, buf),
+            Some(branches) => {
+                let max_name = branches.iter()
+                    .map(|b| b.name.chars().count() + 5)
+                    .max()
+                    .unwrap_or(0);
+
+                let rows: Vec<Row> = branches.iter()
+                    .map(|b| BranchesPopupState::branch_row(b, self.project))
+                    .enumerate()
+                    .map(|(idx, r)| r.style(theme().table_row(idx)))
+                    .collect();
+
+                let table = Table::new(rows, [
+                        Constraint::Length(2),
+                        Constraint::Length(max_name as u16),
+                        Constraint::Length(4),
+                        Constraint::Percentage(100),
+                    ])
+                    .highlight_style(theme().highlight_symbol)
+                    .column_spacing(1);
+
+                StatefulWidget::render(table, content_area, buf, &mut state.table_state);
+            },
+        }
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,131 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Position, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{List, ListState};
+use tachyonfx::{Duration, EffectRenderer, Shader};
+use tui_input::Input;
+
+use crate::command::{filter_commands, Command, CommandMatch};
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// command palette popup, listing the actions of the projects table
+pub struct CommandPalettePopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the command palette popup
+pub struct CommandPalettePopupState {
+    pub input: Input,
+    pub matches: Vec<CommandMatch>,
+    pub list_state: ListState,
+    pub cursor_position: Position,
+    /// project selected in the projects table when the palette was opened
+    pub project_id: Option<ProjectId>,
+    window_fx: OpenWindow,
+}
+
+impl CommandPalettePopupState {
+    pub fn new(project_id: Option<ProjectId>) -> Self {
+        let mut state = Self {
+            input: Input::default(),
+            matches: Vec::new(),
+            list_state: ListState::default(),
+            cursor_position: Position::default(),
+            project_id,
+            window_fx: open_window(t(Msg::TitleCommands), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("↵",   t(Msg::ShortcutApply)),
+            ])),
+        };
+        state.update_matches();
+        state
+    }
+
+    pub fn is_open_complete(&self) -> bool {
+        self.window_fx.done()
+    }
+
+    /// Filters the commands by the current input, selecting the best match.
+    pub fn update_matches(&mut self) {
+        self.matches = filter_commands(self.input.value());
+        self.list_state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    pub fn selected_command(&self) -> Option<Command> {
+        self.list_state.selected()
+            .and_then(|idx| self.matches.get(idx))
+            .map(|m| m.command)
+    }
+
+    fn matches_as_lines(&self) -> Vec<Line<'static>> {
+        if self.matches.is_empty() {
+            return vec![Line::from(t(Msg::NoMatchingCommands)).style(theme().log_message)];
+        }
+
+        self.matches.iter()
+            .map(|m| {
+                let label = m.command.label();
+                let key = Span::from(format!("{:>4}  ", m.command.key())).style(theme().input_label);
+
+                let chars = label.char_indices().map(|(idx, c)| match m.matched.contains(&idx) {
+                    true  => Span::from(c.to_string()).style(theme().search_match),
+                    false => Span::from(c.to_string()),
+                });
+
+                Line::from(std::iter::once(key).chain(chars).collect::<Vec<_>>())
+            })
+            .collect()
+    }
+}
+
+impl CommandPalettePopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for CommandPalettePopup {
+    type State = CommandPalettePopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let height = 4 + state.matches.len().clamp(1, Command::ALL.len()) as u16;
+        let area = area.inner_centered(60, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(1, 1));
+        let [input_area, _, matches_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Percentage(100),
+        ]).areas(content_area);
+
+        Line::from(state.input.value().to_string())
+            .style(theme().input_selected)
+            .render(input_area, buf);
+
+        let matches = List::new(state.matches_as_lines())
+            .style(theme().table_row_b)
+            .highlight_style(theme().highlight_symbol);
+
+        StatefulWidget::render(matches, matches_area, buf, &mut state.list_state);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+        state.cursor_position = Position::new(
+            input_area.x + state.input.cursor() as u16,
+            input_area.y,
+        );
+    }
+}
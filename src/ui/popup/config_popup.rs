@@ -10,9 +10,11 @@ use tachyonfx::{Duration, EffectRenderer, Shader};
 use tui_input::Input;
 
 use crate::glim_app::GlimConfig;
+use crate::stores::LogLevel;
 use crate::theme::theme;
-use crate::ui::fx::{open_window, OpenWindow};
-use crate::ui::popup::utility::CenteredShrink;
+use crate::ui::fx::{open_window, AnimationConfig, OpenWindow};
+use crate::ui::popup::utility::{description_line, CenteredShrink};
+use crate::i18n::{t, Msg};
 
 /// configuration popup
 pub struct ConfigPopup {
@@ -21,6 +23,7 @@ pub struct ConfigPopup {
 
 pub struct ConfigPopupState {
     // pub duration_ms: u32,
+    config: GlimConfig,
     active_input_idx: u16,
     pub cursor_position: Position,
     input_fields: Vec<InputField>,
@@ -75,41 +78,74 @@ impl ConfigPopupState {
             error_message: None,
             input_fields: vec![
                 InputField::builder()
-                    .label("gitlab url")
+                    .label(t(Msg::ConfigGitlabUrl))
                     .description(url_description())
                     .input(Input::new(config.gitlab_url.clone()))
                     .into(),
                 InputField::builder()
-                    .label("gitlab token")
+                    .label(t(Msg::ConfigGitlabToken))
                     .description(token_description())
                     .input(Input::new(config.gitlab_token.clone()))
                     .mask_input(true)
                     .into(),
                 InputField::builder()
-                    .label("search filter")
+                    .label(t(Msg::ConfigSearchFilter))
                     .description(filter_description())
                     .input(Input::new(config.search_filter.clone().unwrap_or("".to_string())))
                     .into(),
+                InputField::builder()
+                    .label(t(Msg::ConfigProxy))
+                    .description(proxy_description())
+                    .input(Input::new(config.proxy.clone().unwrap_or_default()))
+                    .into(),
+                InputField::builder()
+                    .label(t(Msg::ConfigNoProxy))
+                    .description(no_proxy_description())
+                    .input(Input::new(config.no_proxy.join(", ")))
+                    .into(),
+                InputField::builder()
+                    .label(t(Msg::ConfigAnimations))
+                    .description(animations_description())
+                    .input(Input::new(config.animations.enabled_names()))
+                    .into(),
+                InputField::builder()
+                    .label(t(Msg::ConfigLogLevel))
+                    .description(log_level_description())
+                    .input(Input::new(config.log_level.to_string()))
+                    .into(),
             ],
-            window_fx: open_window("configuration", Some(vec![
-                ("ESC", "close"),
-                ("↑ ↓", "selection"),
-                ("↵",   "apply"),
+            config,
+            window_fx: open_window(t(Msg::TitleConfiguration), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("↵",   t(Msg::ShortcutApply)),
+                ("^t",  t(Msg::ShortcutTestWebhook)),
             ])),
         }
     }
 
+    /// Shows the days until the token expires below the token field.
+    pub fn with_token_days_left(mut self, days_left: Option<i64>) -> Self {
+        if let Some(days) = days_left {
+            let mut description = token_description();
+            description.spans.push(Span::from(t(Msg::ConfigTokenExpiresIn).replace("{days}", &days.to_string()))
+                .style(theme().input_description_em));
+            self.input_fields[1].description = description;
+        }
+        self
+    }
+
     pub fn is_open_complete(&self) -> bool {
         self.window_fx.done()
     }
     
     pub fn select_next_input(&mut self) {
-        self.active_input_idx = (self.active_input_idx + 1) % 3;
+        self.active_input_idx = (self.active_input_idx + 1) % self.input_fields.len() as u16;
     }
 
     pub fn select_previous_input(&mut self) {
         self.active_input_idx = if self.active_input_idx == 0 {
-            2
+            self.input_fields.len() as u16 - 1
         } else {
             self.active_input_idx - 1
         };
@@ -124,21 +160,32 @@ impl ConfigPopupState {
     }
 
     pub fn to_config(&self) -> GlimConfig {
-        let (gitlab_url, gitlab_token, search_filter) = self.input_fields.iter()
+        let (gitlab_url, gitlab_token, search_filter, proxy, no_proxy, animations, log_level) = self.input_fields.iter()
             .map(|field| field.input.value())
             .collect_tuple()
             .unwrap();
 
-        let search_filter = if search_filter.trim().is_empty() {
+        let optional = |value: &str| if value.trim().is_empty() {
             None
         } else {
-            Some(search_filter.trim().to_string())
+            Some(value.trim().to_string())
         };
 
+        let no_proxy = no_proxy.split(',')
+            .map(str::trim)
+            .filter(|host| !host.is_empty())
+            .map(String::from)
+            .collect();
+
         GlimConfig {
             gitlab_url: gitlab_url.trim().to_string(),
             gitlab_token: gitlab_token.trim().to_string(),
-            search_filter,
+            search_filter: optional(search_filter),
+            proxy: optional(proxy),
+            no_proxy,
+            animations: AnimationConfig::from_enabled_names(animations),
+            log_level: LogLevel::parse(log_level).unwrap_or(self.config.log_level),
+            ..self.config.clone()
         }
     }
 
@@ -164,7 +211,7 @@ impl StatefulWidget for ConfigPopup {
     type State = ConfigPopupState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let area = area.inner_centered(80, 12);
+        let area = area.inner_centered(80, 24);
 
         state.window_fx.screen_area(buf.area); // for the parent window fx
         let last_tick = self.last_frame_time;
@@ -194,28 +241,29 @@ impl StatefulWidget for ConfigPopup {
 }
 
 fn url_description() -> Line<'static> {
-    Line::from(vec![
-        Span::from("base url of the gitlab instance, e.g. ")
-            .style(theme().input_description),
-        Span::from("https://mygitlab.com/api/v4")
-            .style(theme().input_description_em),
-    ])
+    description_line(t(Msg::ConfigUrlHelp))
 }
 
 fn token_description() -> Line<'static> {
-    Line::from(vec![
-        Span::from("personal access token ")
-            .style(theme().input_description_em),
-        Span::from("for the gitlab api; scoped to ")
-            .style(theme().input_description),
-        Span::from("read_api")
-            .style(theme().input_description_em),
-    ])
+    description_line(t(Msg::ConfigTokenHelp))
+}
+
+fn proxy_description() -> Line<'static> {
+    description_line(t(Msg::ConfigProxyHelp))
+}
+
+fn no_proxy_description() -> Line<'static> {
+    description_line(t(Msg::ConfigNoProxyHelp))
+}
+
+fn animations_description() -> Line<'static> {
+    description_line(t(Msg::ConfigAnimationsHelp))
+}
+
+fn log_level_description() -> Line<'static> {
+    description_line(t(Msg::ConfigLogLevelHelp))
 }
 
 fn filter_description() -> Line<'static> {
-    Line::from(vec![
-        Span::from("optional project filter, applied to project namespace")
-            .style(theme().input_description),
-    ])
-}
\ No newline at end of file
+    description_line(t(Msg::ConfigFilterHelp))
+}
@@ -0,0 +1,120 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::Paragraph;
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::i18n::{t, Msg};
+use crate::input::keymap::KeyBinding;
+use crate::input::processor::{CommandPaletteProcessor, ConfigProcessor, NormalModeProcessor, PipelineActionsProcessor, ProjectDetailsProcessor, ProjectFilterProcessor, SearchProcessor};
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// help popup, listing the keybindings of each input mode
+pub struct HelpPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the help popup
+pub struct HelpPopupState {
+    lines: Vec<Line<'static>>,
+    pub scroll: u16,
+    window_fx: OpenWindow,
+}
+
+impl HelpPopupState {
+    pub fn new() -> Self {
+        Self {
+            lines: help_lines(),
+            scroll: 0,
+            window_fx: open_window(t(Msg::TitleHelp), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutScroll)),
+            ])),
+        }
+    }
+
+    pub fn scroll(&mut self, direction: i32) {
+        let max = self.lines.len().saturating_sub(1) as i32;
+        self.scroll = (self.scroll as i32 + direction).clamp(0, max) as u16;
+    }
+}
+
+/// Lines of the keybindings of each mode, read from the bindings of the
+/// input processors. Keys with the same description are listed together.
+fn help_lines() -> Vec<Line<'static>> {
+    let sections = [
+        (Msg::HelpModeProjects, describe(&NormalModeProcessor::keybindings())),
+        (Msg::HelpModeProjectDetails, describe(&ProjectDetailsProcessor::KEYBINDINGS)),
+        (Msg::HelpModePipelineActions, describe(&PipelineActionsProcessor::KEYBINDINGS)),
+        (Msg::HelpModeConfig, describe(&ConfigProcessor::KEYBINDINGS)),
+        (Msg::HelpModeProjectFilter, describe(&ProjectFilterProcessor::KEYBINDINGS)),
+        (Msg::HelpModeSearch, describe(&SearchProcessor::KEYBINDINGS)),
+        (Msg::HelpModeCommandPalette, describe(&CommandPaletteProcessor::KEYBINDINGS)),
+    ];
+
+    sections.into_iter()
+        .enumerate()
+        .flat_map(|(idx, (title, bindings))| {
+            let separator = (idx > 0).then(|| Line::from(""));
+            let title = Line::from(t(title)).style(theme().border_title);
+            let bindings = bindings.into_iter().map(|(keys, description)| Line::from(vec![
+                Span::from(format!("{keys:>10}  ")).style(theme().input_label),
+                Span::from(description).style(theme().log_message),
+            ]));
+
+            separator.into_iter()
+                .chain(std::iter::once(title))
+                .chain(bindings)
+        })
+        .collect()
+}
+
+/// Keys and descriptions of the bindings, merging consecutive keys with the
+/// same description, e.g. "↑ ↓".
+fn describe<A: Copy>(bindings: &[KeyBinding<A>]) -> Vec<(String, &'static str)> {
+    let mut described: Vec<(String, &'static str)> = Vec::new();
+    for (key, description) in bindings.iter().map(KeyBinding::describe) {
+        match described.last_mut() {
+            Some((keys, last)) if *last == description => {
+                keys.push(' ');
+                keys.push_str(&key);
+            },
+            _ => described.push((key, description)),
+        }
+    }
+
+    described
+}
+
+impl HelpPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for HelpPopup {
+    type State = HelpPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let height = 2 + state.lines.len() as u16;
+        let area = area.inner_centered(60, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        Paragraph::new(state.lines.clone())
+            .style(theme().table_row_b)
+            .scroll((state.scroll, 0))
+            .render(area.inner(Margin::new(2, 1)), buf);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
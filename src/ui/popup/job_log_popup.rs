@@ -0,0 +1,283 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::text::Text;
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::i18n::{t, Msg};
+use crate::id::{JobId, ProjectId};
+use crate::job_log::{JobLog, LogRow};
+use crate::result::GlimError;
+use crate::theme::theme;
+use crate::ui::format_duration;
+use crate::ui::fx::{open_window, OpenWindow};
+
+/// job log popup
+pub struct JobLogPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the job log popup
+pub struct JobLogPopupState {
+    pub project_id: ProjectId,
+    pub job_id: JobId,
+    job_name: String,
+    log: Option<JobLog>, // none while the trace is being fetched
+    error: Option<String>,
+    collapsed: Vec<bool>,
+    rows: Vec<LogRow>,
+    cursor: usize,
+    offset: usize,
+    page_height: usize,
+    pub zoomed: bool,
+    window_fx: OpenWindow,
+}
+
+impl JobLogPopupState {
+    pub fn new(
+        project_id: ProjectId,
+        job_id: JobId,
+        job_name: String,
+    ) -> Self {
+        Self {
+            project_id,
+            job_id,
+            job_name,
+            log: None,
+            error: None,
+            collapsed: Vec::new(),
+            rows: Vec::new(),
+            cursor: 0,
+            offset: 0,
+            page_height: 1,
+            zoomed: false,
+            window_fx: open_window(t(Msg::TitleJobLog), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutScroll)),
+                ("← → ↵", t(Msg::ShortcutFold)),
+                ("- +", t(Msg::ShortcutFoldAll)),
+                ("z", t(Msg::ShortcutZoom)),
+            ])),
+        }
+    }
+
+    pub fn set_trace(&mut self, trace: &str) {
+        let log = JobLog::parse(trace);
+        self.collapsed = log.sections.iter().map(|s| s.collapsed).collect();
+        self.rows = log.visible_rows(&self.collapsed);
+        self.log = Some(log);
+
+        // start at the end of the log, where failures are reported
+        self.cursor = self.rows.len().saturating_sub(1);
+    }
+
+    /// Shows why the trace could not be fetched, in place of the log.
+    pub fn set_error(&mut self, e: &GlimError) {
+        self.error = Some(e.to_string());
+    }
+
+    pub fn move_cursor(&mut self, delta: i32) {
+        let max = self.rows.len().saturating_sub(1) as i32;
+        self.cursor = (self.cursor as i32 + delta).clamp(0, max) as usize;
+    }
+
+    pub fn page(&mut self, direction: i32) {
+        self.move_cursor(direction * self.page_height as i32);
+    }
+
+    pub fn cursor_to_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn cursor_to_end(&mut self) {
+        self.cursor = self.rows.len().saturating_sub(1);
+    }
+
+    pub fn toggle_zoom(&mut self) {
+        self.zoomed = !self.zoomed;
+    }
+
+    /// Toggles the section under the cursor.
+    pub fn toggle_section(&mut self) {
+        match self.rows.get(self.cursor) {
+            Some(LogRow::Section(s)) => {
+                let s = *s;
+                self.set_collapsed(s, !self.collapsed[s]);
+            },
+            Some(LogRow::Line(_)) => self.collapse_section(),
+            None => (),
+        }
+    }
+
+    /// Collapses the section under the cursor, or the section enclosing
+    /// the line under the cursor.
+    pub fn collapse_section(&mut self) {
+        let section = match (self.rows.get(self.cursor), self.log.as_ref()) {
+            (Some(LogRow::Section(s)), _) if !self.collapsed[*s] => Some(*s),
+            (Some(LogRow::Section(s)), Some(log)) => log.parent_section(*s),
+            (Some(LogRow::Line(line)), Some(log)) => log.enclosing_section(*line),
+            _ => None,
+        };
+
+        if let Some(s) = section {
+            self.set_collapsed(s, true);
+        }
+    }
+
+    /// Expands the section under the cursor.
+    pub fn expand_section(&mut self) {
+        if let Some(LogRow::Section(s)) = self.rows.get(self.cursor) {
+            let s = *s;
+            self.set_collapsed(s, false);
+        }
+    }
+
+    /// Collapses all sections, e.g. to get an overview of the job's steps.
+    pub fn collapse_all(&mut self) {
+        self.set_all_collapsed(true);
+    }
+
+    pub fn expand_all(&mut self) {
+        self.set_all_collapsed(false);
+    }
+
+    fn set_all_collapsed(&mut self, collapsed: bool) {
+        let log = match self.log.as_ref() {
+            Some(log) => log,
+            None      => return,
+        };
+
+        let anchor = self.rows.get(self.cursor).copied();
+        self.collapsed.iter_mut().for_each(|c| *c = collapsed);
+        self.rows = log.visible_rows(&self.collapsed);
+
+        // keep the cursor on the same row, or the outermost section hiding it
+        let line = match anchor {
+            Some(LogRow::Section(s)) => log.sections[s].start_line,
+            Some(LogRow::Line(line)) => line,
+            None                     => 0,
+        };
+        let outermost = |row: &LogRow| matches!(row, LogRow::Section(s)
+            if log.sections[*s].depth == 0 && log.sections[*s].start_line <= line && line < log.sections[*s].end_line);
+
+        self.cursor = self.rows.iter()
+            .position(|r| Some(*r) == anchor)
+            .or_else(|| self.rows.iter().position(outermost))
+            .unwrap_or(self.cursor.min(self.rows.len().saturating_sub(1)));
+    }
+
+    fn set_collapsed(&mut self, section: usize, collapsed: bool) {
+        let log = match self.log.as_ref() {
+            Some(log) => log,
+            None      => return,
+        };
+
+        self.collapsed[section] = collapsed;
+        self.rows = log.visible_rows(&self.collapsed);
+
+        // keep the cursor on the section header
+        self.cursor = self.rows.iter()
+            .position(|r| *r == LogRow::Section(section))
+            .unwrap_or(self.cursor.min(self.rows.len().saturating_sub(1)));
+    }
+
+    /// Scrolls the viewport so that the cursor remains visible.
+    fn update_offset(&mut self, height: usize) {
+        self.page_height = height.max(1);
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + self.page_height {
+            self.offset = self.cursor + 1 - self.page_height;
+        }
+    }
+
+    fn row_as_line(&self, log: &JobLog, row: LogRow) -> Line<'static> {
+        match row {
+            LogRow::Section(s) => {
+                let section = &log.sections[s];
+                let fold_icon = if self.collapsed[s] { "▸ " } else { "▾ " };
+                let duration = section.duration()
+                    .map(format_duration)
+                    .unwrap_or_default();
+
+                Line::from(vec![
+                    Span::from(" ".repeat(2 * section.depth)),
+                    Span::from(fold_icon).style(theme().job_log_section),
+                    Span::from(log.section_title(s).to_string()).style(theme().job_log_section),
+                    Span::from(" "),
+                    Span::from(duration).style(theme().time),
+                ])
+            },
+            LogRow::Line(line) => Line::from(log.lines[line].clone())
+                .style(theme().log_message),
+        }
+    }
+}
+
+/// Elapsed time since the start of the job, right-aligned.
+fn gutter_span(elapsed: Option<chrono::Duration>) -> Span<'static> {
+    let elapsed = elapsed
+        .map(|d| format!("+{}", format_duration(d)))
+        .unwrap_or_default();
+
+    Span::from(format!("{elapsed:>9} │ ")).style(theme().time)
+}
+
+impl JobLogPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for JobLogPopup {
+    type State = JobLogPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let area = if state.zoomed { area } else { area.inner(Margin::new(4, 2)) };
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        let [title_area, log_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Percentage(100),
+        ]).areas(content_area);
+
+        Line::from(state.job_name.clone())
+            .style(theme().pipeline_job)
+            .render(title_area, buf);
+
+        state.update_offset(log_area.height as usize);
+        let lines: Vec<Line> = match (state.log.as_ref(), state.error.as_ref()) {
+            (None, None) => vec![Line::from(t(Msg::LoadingJobLog)).style(theme().log_message)],
+            (None, Some(e)) => vec![Line::from(vec![
+                Span::from(t(Msg::JobLogUnavailable)),
+                Span::from(e.clone()),
+            ]).style(theme().job_status_failed)],
+            (Some(log), _) => state.rows.iter()
+                .enumerate()
+                .skip(state.offset)
+                .take(state.page_height)
+                .map(|(idx, row)| {
+                    let mut line = state.row_as_line(log, *row);
+                    if log.has_timings() {
+                        line.spans.insert(0, gutter_span(log.elapsed(*row)));
+                    }
+
+                    if idx == state.cursor { line.patch_style(theme().highlight_symbol) } else { line }
+                })
+                .collect(),
+        };
+
+        Text::from(lines).render(log_area, buf);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -1,8 +1,34 @@
 mod config_popup;
+mod onboarding_popup;
 mod project_details_popup;
 mod pipeline_actions_popup;
+mod audit_log_popup;
+mod api_inspector_popup;
+mod project_errors_popup;
+mod job_log_popup;
+mod search_popup;
+mod pipeline_graph_popup;
+mod branches_popup;
+mod schedules_popup;
+mod runners_popup;
+mod artifacts_popup;
+mod command_palette_popup;
+mod help_popup;
 mod utility;
 
 pub use config_popup::*;
+pub use onboarding_popup::*;
 pub use project_details_popup::*;
 pub use pipeline_actions_popup::*;
+pub use audit_log_popup::*;
+pub use api_inspector_popup::*;
+pub use project_errors_popup::*;
+pub use job_log_popup::*;
+pub use search_popup::*;
+pub use pipeline_graph_popup::*;
+pub use branches_popup::*;
+pub use schedules_popup::*;
+pub use runners_popup::*;
+pub use artifacts_popup::*;
+pub use command_palette_popup::*;
+pub use help_popup::*;
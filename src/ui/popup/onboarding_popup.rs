@@ -0,0 +1,225 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Position, Rect};
+use ratatui::prelude::{Line, StatefulWidget, Text, Widget};
+use tachyonfx::{Duration, EffectRenderer, Shader};
+use tui_input::Input;
+
+use crate::glim_app::GlimConfig;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::{description_line, CenteredShrink};
+use crate::i18n::{t, Msg};
+
+/// first-run wizard, creating the configuration one step at a time
+pub struct OnboardingPopup {
+    last_frame_time: Duration,
+}
+
+/// step of the onboarding wizard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    Instance,
+    Token,
+    Filter,
+    Polling,
+}
+
+impl OnboardingStep {
+    const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::Instance,
+        OnboardingStep::Token,
+        OnboardingStep::Filter,
+        OnboardingStep::Polling,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|step| *step == self).unwrap()
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            OnboardingStep::Instance => t(Msg::OnboardingStepInstance),
+            OnboardingStep::Token    => t(Msg::OnboardingStepToken),
+            OnboardingStep::Filter   => t(Msg::OnboardingStepFilter),
+            OnboardingStep::Polling  => t(Msg::OnboardingStepPolling),
+        }
+    }
+
+    fn description(self) -> Vec<Line<'static>> {
+        let description = match self {
+            OnboardingStep::Instance => t(Msg::OnboardingInstanceHelp).to_string(),
+            OnboardingStep::Token    => t(Msg::OnboardingTokenHelp).to_string(),
+            OnboardingStep::Filter   => t(Msg::OnboardingFilterHelp).to_string(),
+            OnboardingStep::Polling  => t(Msg::OnboardingPollingHelp)
+                .replace("{min}", &GlimConfig::MIN_POLL_INTERVAL.to_string()),
+        };
+
+        description.lines().map(description_line).collect()
+    }
+}
+
+pub struct OnboardingPopupState {
+    step: OnboardingStep,
+    /// gitlab url, token, search filter and poll interval; one per step
+    inputs: [Input; 4],
+    /// scopes of the token, once validated
+    token_scopes: Option<Vec<String>>,
+    pub error_message: Option<String>,
+    pub cursor_position: Position,
+    window_fx: OpenWindow,
+}
+
+impl OnboardingPopupState {
+    pub fn new() -> Self {
+        Self {
+            step: OnboardingStep::Instance,
+            inputs: [
+                Input::default(),
+                Input::default(),
+                Input::default(),
+                Input::new(GlimConfig::DEFAULT_POLL_INTERVAL.to_string()),
+            ],
+            token_scopes: None,
+            error_message: None,
+            cursor_position: Position::default(),
+            window_fx: open_window(t(Msg::TitleOnboarding), Some(vec![
+                ("ESC", t(Msg::ShortcutBack)),
+                ("↵",   t(Msg::ShortcutNext)),
+            ])),
+        }
+    }
+
+    pub fn step(&self) -> OnboardingStep {
+        self.step
+    }
+
+    pub fn is_last_step(&self) -> bool {
+        self.step == OnboardingStep::Polling
+    }
+
+    pub fn is_open_complete(&self) -> bool {
+        self.window_fx.done()
+    }
+
+    /// Moves to the next step; the current step is expected to be validated.
+    pub fn next(&mut self) {
+        self.error_message = None;
+        if let Some(step) = OnboardingStep::ALL.get(self.step.index() + 1) {
+            self.step = *step;
+        }
+    }
+
+    /// Moves to the previous step, returning false on the first step.
+    pub fn back(&mut self) -> bool {
+        self.error_message = None;
+        match self.step.index().checked_sub(1) {
+            Some(idx) => {
+                self.step = OnboardingStep::ALL[idx];
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn input_mut(&mut self) -> &mut Input {
+        // the validated token no longer applies once the url or token changes
+        if matches!(self.step, OnboardingStep::Instance | OnboardingStep::Token) {
+            self.token_scopes = None;
+        }
+        &mut self.inputs[self.step.index()]
+    }
+
+    /// Marks the token as validated, with its scopes if they could be fetched.
+    pub fn set_token_scopes(&mut self, scopes: Vec<String>) {
+        self.token_scopes = Some(scopes);
+    }
+
+    pub fn is_token_validated(&self) -> bool {
+        self.token_scopes.is_some()
+    }
+
+    /// The poll interval in seconds, if valid.
+    pub fn poll_interval(&self) -> Option<u64> {
+        self.inputs[3].value().trim().parse().ok()
+            .filter(|secs| *secs >= GlimConfig::MIN_POLL_INTERVAL)
+    }
+
+    pub fn to_config(&self) -> GlimConfig {
+        let [gitlab_url, gitlab_token, search_filter, _] = self.inputs.each_ref()
+            .map(|input| input.value().trim().to_string());
+
+        GlimConfig {
+            gitlab_url,
+            gitlab_token,
+            search_filter: Some(search_filter).filter(|filter| !filter.is_empty()),
+            poll_interval: self.poll_interval(),
+            ..GlimConfig::default()
+        }
+    }
+
+    fn input_display(&self) -> String {
+        let value = self.inputs[self.step.index()].value();
+        if self.step == OnboardingStep::Token {
+            value.chars().map(|_| '*').collect()
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn status_line(&self) -> Option<Line<'static>> {
+        if let Some(error) = &self.error_message {
+            Some(Line::from(error.clone()).style(theme().configuration_error))
+        } else if self.step == OnboardingStep::Token {
+            self.token_scopes.as_ref().map(|scopes| {
+                let status = if scopes.is_empty() {
+                    t(Msg::OnboardingTokenValid).to_string()
+                } else {
+                    t(Msg::OnboardingTokenValidScopes).replace("{scopes}", &scopes.join(", "))
+                };
+                Line::from(status).style(theme().input_description_em)
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl OnboardingPopup {
+    pub fn new(last_frame_time: Duration) -> Self {
+        Self { last_frame_time }
+    }
+}
+
+impl StatefulWidget for OnboardingPopup {
+    type State = OnboardingPopupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.inner_centered(80, 14);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_time);
+
+        let content_area = area.inner(Margin::new(1, 1));
+        let heading = t(Msg::OnboardingStepHeading)
+            .replace("{step}", &(state.step.index() + 1).to_string())
+            .replace("{steps}", &OnboardingStep::ALL.len().to_string())
+            .replace("{label}", state.step.label());
+
+        let mut text = vec![Line::from(heading).style(theme().input_label), Line::default()];
+        text.extend(state.step.description());
+        text.push(Line::default());
+
+        let input_row = text.len() as u16;
+        text.push(Line::from(state.input_display()).style(theme().input_selected));
+        text.push(Line::default());
+        text.extend(state.status_line());
+
+        Widget::render(Text::from(text), content_area, buf);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_time, buf, area);
+
+        let cursor = state.inputs[state.step.index()].cursor() as u16;
+        state.cursor_position = Position::new(content_area.x + cursor, content_area.y + input_row);
+    }
+}
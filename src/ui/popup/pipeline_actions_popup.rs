@@ -1,14 +1,15 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Margin, Rect};
-use ratatui::prelude::{Line, StatefulWidget};
+use ratatui::prelude::{Line, Span, StatefulWidget};
 use ratatui::widgets::{List, ListState};
 use tachyonfx::{Duration, EffectRenderer};
 
 use crate::event::GlimEvent;
-use crate::id::{PipelineId, ProjectId};
+use crate::id::{JobId, PipelineId, ProjectId};
 use crate::theme::theme;
 use crate::ui::fx::{open_window, OpenWindow};
 use crate::ui::popup::utility::CenteredShrink;
+use crate::i18n::{t, Msg};
 
 /// pipeline actions popup
 pub struct PipelineActionsPopup {
@@ -20,6 +21,10 @@ pub struct PipelineActionsPopupState {
     pub actions: Vec<GlimEvent>,
     pub project_id: ProjectId,
     pub pipeline_id: PipelineId,
+    /// set when the actions apply to a single job
+    pub job_id: Option<JobId>,
+    /// additions and deletions of the pipeline's commit, once fetched
+    pub commit_stats: Option<(u32, u32)>,
     pub list_state: ListState,
     window_fx: OpenWindow,
 }
@@ -30,48 +35,114 @@ impl PipelineActionsPopupState {
         project_id: ProjectId,
         pipeline_id: PipelineId,
     ) -> Self {
+        Self::create(actions, project_id, pipeline_id, None)
+    }
+
+    pub fn for_job(
+        actions: Vec<GlimEvent>,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        job_id: JobId,
+    ) -> Self {
+        Self::create(actions, project_id, pipeline_id, Some(job_id))
+    }
+
+    fn create(
+        mut actions: Vec<GlimEvent>,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        job_id: Option<JobId>,
+    ) -> Self {
+        // only actions with a label are listed, and can be applied
+        actions.retain(|action| Self::label(action, job_id.is_some()).is_some());
+        let title = if job_id.is_some() { t(Msg::TitleJobActions) } else { t(Msg::TitlePipelineActions) };
+
         Self {
             actions,
             project_id,
             pipeline_id,
+            job_id,
+            commit_stats: None,
             list_state: ListState::default().with_selected(Some(0)),
-            window_fx: open_window("pipeline actions", Some(vec![
-                ("ESC", "close"),
-                ("↑ ↓", "selection"),
-                ("↵",   "apply"),
+            window_fx: open_window(title, Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("u",   t(Msg::ShortcutCopyPipelineUrl)),
+                ("j",   t(Msg::ShortcutCopyJobUrl)),
+                ("b",   t(Msg::ShortcutCopyBranchName)),
+                ("↵",   t(Msg::ShortcutApply)),
             ])),
         }
     }
 
     pub fn copy_action(&self) -> GlimEvent {
-        match &self.actions[self.list_state.selected().unwrap()] {
-            GlimEvent::BrowseToJob(id, p_id, j_id) =>
-                GlimEvent::BrowseToJob(*id, *p_id, *j_id),
-            GlimEvent::BrowseToPipeline(id, p_id) =>
-                GlimEvent::BrowseToPipeline(*id, *p_id),
-            GlimEvent::BrowseToProject(id) =>
-                GlimEvent::BrowseToProject(*id),
-            GlimEvent::DownloadErrorLog(id, pipeline_id) =>
-                GlimEvent::DownloadErrorLog(*id, *pipeline_id),
-            _ => panic!("unsupported action")
+        self.actions[self.list_state.selected().unwrap()].clone()
+    }
+
+    /// Returns the first action matching, e.g. the action bound to a key.
+    pub fn find_action(&self, matches: impl Fn(&GlimEvent) -> bool) -> Option<GlimEvent> {
+        self.actions.iter()
+            .find(|action| matches(action))
+            .cloned()
+    }
+
+    /// Label of the action, or None if the event is not an action of this popup.
+    fn label(action: &GlimEvent, is_job_menu: bool) -> Option<&'static str> {
+        match action {
+            GlimEvent::OpenJobLog(_, _, _) if is_job_menu =>
+                Some(t(Msg::ActionViewLog)),
+            GlimEvent::OpenJobLog(_, _, _) =>
+                Some(t(Msg::ActionViewJobLog)),
+            GlimEvent::BrowseToJob(_, _, _) if is_job_menu =>
+                Some(t(Msg::ActionBrowseToJob)),
+            GlimEvent::BrowseToJob(_, _, _) =>
+                Some(t(Msg::ActionBrowseToFailedJob)),
+            GlimEvent::RetryJob(_, _, _) =>
+                Some(t(Msg::ActionRetryJob)),
+            GlimEvent::CreateIssue(_, _) =>
+                Some(t(Msg::ActionCreateIssue)),
+            GlimEvent::OpenPipelineGraph(_, _) =>
+                Some(t(Msg::ActionViewStageGraph)),
+            GlimEvent::BrowseToPipeline(_, _) =>
+                Some(t(Msg::ActionBrowseToPipeline)),
+            GlimEvent::BrowseToProject(_) =>
+                Some(t(Msg::ActionBrowseToProject)),
+            GlimEvent::BrowseToCommit(_, _) =>
+                Some(t(Msg::ActionBrowseToCommit)),
+            GlimEvent::DownloadErrorLog(_, _) =>
+                Some(t(Msg::ActionDownloadErrorLog)),
+            GlimEvent::FollowPipeline(_, _) =>
+                Some(t(Msg::ActionFollowPipeline)),
+            GlimEvent::UnfollowPipeline =>
+                Some(t(Msg::ActionUnfollowPipeline)),
+            GlimEvent::WaitForPipeline(_, _) =>
+                Some(t(Msg::ActionWaitForPipeline)),
+            GlimEvent::OpenArtifacts(_, _, _) =>
+                Some(t(Msg::ActionBrowseArtifacts)),
+            GlimEvent::CopyPipelineUrl(_, _) =>
+                Some(t(Msg::ActionCopyPipelineUrl)),
+            GlimEvent::CopyJobUrl(_, _, _) if is_job_menu =>
+                Some(t(Msg::ActionCopyJobUrl)),
+            GlimEvent::CopyJobUrl(_, _, _) =>
+                Some(t(Msg::ActionCopyFailedJobUrl)),
+            GlimEvent::CopyBranchName(_, _) =>
+                Some(t(Msg::ActionCopyBranchName)),
+            _ => None
         }
     }
 
     fn actions_as_lines(&self) -> Vec<Line<'static>> {
+        let is_job_menu = self.job_id.is_some();
         self.actions.iter()
-            .map(|action| {
-                let action = match action {
-                    GlimEvent::BrowseToJob(_, _, _) =>
-                        "browse to failed job".to_string(),
-                    GlimEvent::BrowseToPipeline(_, _) =>
-                        "browse to pipeline".to_string(),
-                    GlimEvent::BrowseToProject(_) =>
-                        "browse to project".to_string(),
-                    GlimEvent::DownloadErrorLog(_, _) =>
-                        "download failed job log to clipboard".to_string(),
-                    _ => panic!("unsupported action")
-                };
-                Line::from(action).style(theme().pipeline_action)
+            .filter_map(|action_event| {
+                let action = Self::label(action_event, is_job_menu)?;
+
+                let mut line = Line::from(action).style(theme().pipeline_action);
+                if let (GlimEvent::BrowseToCommit(_, _), Some((additions, deletions))) = (action_event, self.commit_stats) {
+                    line.spans.push(Span::from(format!(" +{additions}")).style(theme().job_status_success));
+                    line.spans.push(Span::from(format!(" -{deletions}")).style(theme().job_status_failed));
+                }
+                Some(line)
             })
             .collect()
     }
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Line, StatefulWidget, Widget};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::domain::Job;
+use crate::i18n::{t, Msg};
+use crate::id::{PipelineId, ProjectId};
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+use crate::ui::widget::PipelineGraph;
+
+/// pipeline stage graph popup
+pub struct PipelineGraphPopup<'a> {
+    last_frame_ms: Duration,
+    jobs: &'a [Job],
+}
+
+/// state of the pipeline stage graph popup
+pub struct PipelineGraphPopupState {
+    pub project_id: ProjectId,
+    pub pipeline_id: PipelineId,
+    /// `needs` of each job, by job name; none until fetched
+    needs: Option<HashMap<String, Vec<String>>>,
+    /// index of the first visible stage
+    offset: usize,
+    stage_count: usize,
+    window_fx: OpenWindow,
+}
+
+impl PipelineGraphPopupState {
+    pub fn new(project_id: ProjectId, pipeline_id: PipelineId) -> Self {
+        Self {
+            project_id,
+            pipeline_id,
+            needs: None,
+            offset: 0,
+            stage_count: 0,
+            window_fx: open_window(t(Msg::TitlePipelineGraph), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("← →", t(Msg::ShortcutScroll)),
+            ])),
+        }
+    }
+
+    pub fn set_needs(&mut self, needs: HashMap<String, Vec<String>>) {
+        self.needs = Some(needs);
+    }
+
+    pub fn scroll(&mut self, direction: i32) {
+        let max = self.stage_count.saturating_sub(1) as i32;
+        self.offset = (self.offset as i32 + direction).clamp(0, max) as usize;
+    }
+}
+
+impl<'a> PipelineGraphPopup<'a> {
+    pub fn new(last_frame_ms: Duration, jobs: &'a [Job]) -> Self {
+        Self { last_frame_ms, jobs }
+    }
+}
+
+impl StatefulWidget for PipelineGraphPopup<'_> {
+    type State = PipelineGraphPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let graph = PipelineGraph::new(self.jobs, state.needs.as_ref(), state.offset);
+        state.stage_count = graph.stage_count();
+
+        let height = 2 + graph.height().max(1);
+        let area = area.inner(Margin::new(4, 0)).inner_centered(area.width, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        if self.jobs.is_empty() {
+            Line::from(t(Msg::LoadingJobs))
+                .style(theme().log_message)
+                .render(content_area, buf);
+        } else {
+            graph.render(content_area, buf);
+        }
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
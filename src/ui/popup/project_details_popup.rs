@@ -1,19 +1,35 @@
+use std::collections::HashSet;
+
+use chrono::Local;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Position, Rect};
 use ratatui::prelude::{Line, StatefulWidget, Text};
 use ratatui::text::Span;
 use ratatui::widgets::{TableState, Widget};
 use tachyonfx::{Duration, EffectRenderer};
+use tui_input::Input;
 
-use crate::domain::{Pipeline, Project};
+use crate::domain::{CommitDetails, Job, Pipeline, Project};
+use crate::id::{JobId, PipelineId};
 use crate::theme::theme;
 use crate::ui::fx::{open_window, OpenWindow};
 use crate::ui::popup::utility::CenteredShrink;
-use crate::ui::widget::PipelineTable;
+use crate::pipeline_history::PipelineRun;
+use crate::ui::widget::{DurationHistory, JobDurationChart, JobRow, JobTable, PipelineTable};
+use crate::i18n::{t, Msg};
+
+/// maximum number of visible rows in the jobs pane
+const MAX_JOB_ROWS: usize = 12;
+/// maximum number of visible lines of the commit message
+const MAX_COMMIT_MESSAGE_ROWS: usize = 4;
+/// shown before the pipeline filter
+const PIPELINE_FILTER_PREFIX: &str = "/ ";
 
 /// project details popup
 pub struct ProjectDetailsPopup {
     last_frame_time: Duration,
+    /// finished runs on the branch of the selected pipeline
+    history: Vec<PipelineRun>,
 }
 
 /// state of the project details popup
@@ -22,17 +38,45 @@ pub struct ProjectDetailsPopupState {
     // duration_ms: u32,
     project_namespace: Text<'static>,
     project_stat_summary: Text<'static>,
-    pub pipelines: PipelineTable, // widget
+    /// page of the pipelines table, 0 being the most recent pipelines
+    pub pipeline_page: usize,
+    /// whether older pipelines were requested, and have not been received yet
+    loading_older: bool,
+    /// branch or status filter of the pipelines, see [Pipeline::matches]
+    pub pipeline_filter: Input,
+    /// whether the pipeline filter is being edited
+    pub editing_filter: bool,
+    /// position of the cursor while editing the pipeline filter
+    pub cursor_position: Option<Position>,
     pub pipelines_table_state: TableState,
+    pub jobs_table_state: TableState,
+    pub focus: DetailsFocus,
+    /// names of the stages with their jobs hidden; kept across pipelines
+    pub collapsed_stages: HashSet<String>,
+    /// whether the commit details of the selected pipeline are expanded
+    pub show_commit: bool,
+    /// whether the job duration chart of the selected pipeline is shown
+    pub show_durations: bool,
+    /// whether the duration history of the selected pipeline's branch is shown
+    pub show_history: bool,
     window_fx: OpenWindow,
 }
 
+/// the pane receiving up/down and enter in the project details popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailsFocus {
+    Pipelines,
+    Jobs,
+}
+
 impl ProjectDetailsPopup {
     pub fn new(
-        last_frame_time: Duration
+        last_frame_time: Duration,
+        history: Vec<PipelineRun>,
     ) -> ProjectDetailsPopup {
         Self {
-            last_frame_time
+            last_frame_time,
+            history,
         }
     }
 }
@@ -41,9 +85,151 @@ impl ProjectDetailsPopupState {
     pub fn with_project(&self, project: Project) -> Self {
         let mut state = Self::new(project);
         state.window_fx = self.window_fx.clone();
+        state.pipeline_page = self.pipeline_page;
+        state.loading_older = self.loading_older && !Self::received_older(&self.project, &state.project);
+        state.pipeline_filter = self.pipeline_filter.clone();
+        state.editing_filter = self.editing_filter;
+        state.pipelines_table_state = self.pipelines_table_state.clone();
+        state.jobs_table_state = self.jobs_table_state.clone();
+        state.focus = self.focus;
+        state.collapsed_stages.clone_from(&self.collapsed_stages);
+        state.show_commit = self.show_commit;
+        state.show_durations = self.show_durations;
+        state.show_history = self.show_history;
         state
     }
 
+    /// Returns true if older pipelines were received since the previous state of the project.
+    fn received_older(previous: &Project, project: &Project) -> bool {
+        let count = |p: &Project| p.pipelines.as_ref().map_or(0, Vec::len);
+        project.all_pipelines_fetched || count(project) > count(previous)
+    }
+
+    /// Returns the pipelines of the current page matching the filter.
+    pub fn pipelines(&self) -> Vec<&Pipeline> {
+        self.page(self.pipeline_page)
+    }
+
+    fn page(&self, page: usize) -> Vec<&Pipeline> {
+        self.project.matching_pipeline_page(page, self.pipeline_filter.value())
+    }
+
+    /// Selects the pipeline, if among the recent pipelines of the project.
+    pub fn select_pipeline(&mut self, id: PipelineId) {
+        if let Some(idx) = self.page(0).iter().position(|p| p.id == id) {
+            self.pipeline_page = 0;
+            self.pipelines_table_state.select(Some(idx));
+        }
+    }
+
+    pub fn selected_pipeline(&self) -> Option<&Pipeline> {
+        self.pipelines_table_state.selected()
+            .and_then(|idx| self.pipelines().get(idx).copied())
+    }
+
+    /// Moves to the page of older pipelines, if any are retained. Returns
+    /// true if the pipelines following the page are yet to be fetched, and
+    /// marks them as loading.
+    pub fn show_older_pipelines(&mut self) -> bool {
+        if !self.page(self.pipeline_page + 1).is_empty() {
+            self.show_page(self.pipeline_page + 1);
+        }
+
+        let next_page = self.page(self.pipeline_page + 1);
+        let fetch = next_page.len() < Project::PIPELINES_PER_PAGE
+            && !self.project.all_pipelines_fetched
+            && !self.loading_older;
+
+        self.loading_older |= fetch;
+        fetch
+    }
+
+    /// Moves to the page of newer pipelines; returns false on the first page.
+    pub fn show_newer_pipelines(&mut self) -> bool {
+        match self.pipeline_page.checked_sub(1) {
+            Some(page) => {
+                self.show_page(page);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Shows the first page of the pipelines matching the changed filter.
+    pub fn apply_pipeline_filter(&mut self) {
+        self.show_page(0);
+    }
+
+    /// Clears the pipeline filter, and stops editing it.
+    pub fn clear_pipeline_filter(&mut self) {
+        self.pipeline_filter.reset();
+        self.editing_filter = false;
+        self.show_page(0);
+    }
+
+    fn show_page(&mut self, page: usize) {
+        self.pipeline_page = page;
+        self.pipelines_table_state.select(Some(0));
+        self.jobs_table_state.select(None);
+        self.focus = DetailsFocus::Pipelines;
+    }
+
+    /// Returns the commit details of the selected pipeline, if fetched.
+    pub fn selected_commit(&self) -> Option<&CommitDetails> {
+        self.selected_pipeline()
+            .and_then(|p| p.commit_details.as_ref())
+    }
+
+    /// Returns the jobs of the selected pipeline.
+    pub fn selected_jobs(&self) -> &[Job] {
+        self.selected_pipeline()
+            .and_then(|p| p.jobs.as_deref())
+            .unwrap_or(&[])
+    }
+
+    /// Returns the rows of the jobs pane: the stages of the selected pipeline,
+    /// each followed by its jobs unless collapsed.
+    pub fn job_rows(&self) -> Vec<JobRow<'_>> {
+        self.selected_pipeline()
+            .map(|p| JobRow::list(p.stages(), &self.collapsed_stages))
+            .unwrap_or_default()
+    }
+
+    /// Returns the selected job; none when a stage is selected.
+    pub fn selected_job_id(&self) -> Option<JobId> {
+        match self.jobs_table_state.selected().and_then(|idx| self.job_rows().get(idx).cloned()) {
+            Some(JobRow::Job(job)) => Some(job.id),
+            _                      => None,
+        }
+    }
+
+    /// Collapses or expands the stage of the selected row, keeping the
+    /// selection on the stage.
+    pub fn toggle_selected_stage(&mut self) {
+        let stage = self.jobs_table_state.selected()
+            .and_then(|idx| self.job_rows().get(idx).map(|row| row.stage_name().to_string()));
+        let Some(stage) = stage else { return };
+
+        if !self.collapsed_stages.remove(&stage) {
+            self.collapsed_stages.insert(stage.clone());
+        }
+
+        let header = self.job_rows().iter()
+            .position(|row| matches!(row, JobRow::Stage { stage: s, .. } if s.name == stage));
+        self.jobs_table_state.select(header);
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            DetailsFocus::Pipelines if !self.selected_jobs().is_empty() => DetailsFocus::Jobs,
+            _ => DetailsFocus::Pipelines,
+        };
+
+        if self.focus == DetailsFocus::Jobs && self.jobs_table_state.selected().is_none() {
+            self.jobs_table_state.select(Some(0));
+        }
+    }
+
     pub fn new(
         project: Project,
     ) -> ProjectDetailsPopupState {
@@ -60,24 +246,43 @@ impl ProjectDetailsPopupState {
 
         let project_stat_summary = Text::from(vec![
             Self::commit_count_line(project.commit_count),
-            Self::storage_size_line(project.repo_size_kb, "in repository"),
-            Self::storage_size_line(project.artifacts_size_kb, "in artifacts"),
+            Self::storage_size_line(project.repo_size_kb, t(Msg::ProjectSizeInRepository)),
+            Self::storage_size_line(project.artifacts_size_kb, t(Msg::ProjectSizeInArtifacts)),
         ]);
 
 
-        let pipelines: Vec<&Pipeline> = project.recent_pipelines();
-        let pipelines = PipelineTable::new(&pipelines);
-
         ProjectDetailsPopupState {
             project,
             project_namespace,
             project_stat_summary,
-            pipelines,
+            pipeline_page: 0,
+            loading_older: false,
+            pipeline_filter: Input::default(),
+            editing_filter: false,
+            cursor_position: None,
             pipelines_table_state: TableState::default().with_selected(0),
-            window_fx: open_window("project details", Some(vec![
-                ("ESC", "close"),
-                ("↑ ↓", "selection"),
-                ("↵",   "actions..."),
+            jobs_table_state: TableState::default(),
+            focus: DetailsFocus::Pipelines,
+            collapsed_stages: HashSet::new(),
+            show_commit: false,
+            show_durations: false,
+            show_history: false,
+            window_fx: open_window(t(Msg::TitleProjectDetails), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("⇥",   t(Msg::ShortcutFocus)),
+                ("n",   t(Msg::ShortcutWatchBranch)),
+                ("d",   t(Msg::ShortcutCommitDetails)),
+                ("t",   t(Msg::ShortcutJobDurations)),
+                ("h",   t(Msg::ShortcutDurationHistory)),
+                ("y",   t(Msg::ShortcutCopySha)),
+                ("c",   t(Msg::ShortcutCopyCloneUrl)),
+                ("C",   t(Msg::ShortcutCopyCloneCommand)),
+                ("e",   t(Msg::ShortcutOpenClone)),
+                ("SPACE", t(Msg::ShortcutToggleStage)),
+                ("PgDn", t(Msg::ShortcutOlderPipelines)),
+                ("/",   t(Msg::ShortcutFilter)),
+                ("↵",   t(Msg::ShortcutActions)),
             ])),
         }
     }
@@ -86,7 +291,7 @@ impl ProjectDetailsPopupState {
         Line::from(vec![
             Span::from(commit_count.to_string())
                 .style(theme().project_commits[0]),
-            Span::from(" commits")
+            Span::from(format!(" {}", t(Msg::ProjectCommits)))
                 .style(theme().project_commits[1]),
         ])
     }
@@ -107,10 +312,93 @@ impl ProjectDetailsPopupState {
         ])
     }
 
+    /// Page of older pipelines, shown below the pipelines table; empty on the first page.
+    fn pipeline_page_line(&self) -> Line<'static> {
+        let page = match self.pipeline_page {
+            0    => String::new(),
+            page => t(Msg::PipelinePage).replace("{page}", &(page + 1).to_string()),
+        };
+        let loading = if self.loading_older { format!(" {}", t(Msg::LoadingOlderPipelines)) } else { String::new() };
+
+        Line::from(format!("{page}{loading}"))
+            .style(theme().log_message)
+            .alignment(Alignment::Right)
+    }
+
+    /// Filter of the pipelines, shown below the pipelines table while edited
+    /// or applied.
+    fn pipeline_filter_line(&self) -> Option<Line<'static>> {
+        let query = self.pipeline_filter.value();
+        (self.editing_filter || !query.is_empty()).then(|| {
+            Line::from(format!("{PIPELINE_FILTER_PREFIX}{query}"))
+                .style(theme().input_selected)
+        })
+    }
+
+    /// Height of the jobs pane, including its title.
+    fn job_table_height(&self) -> u16 {
+        1 + self.job_rows().len().clamp(1, MAX_JOB_ROWS) as u16
+    }
+
+    /// Lines of the expanded commit section, excluding its title.
+    fn commit_lines(&self) -> Vec<Line<'static>> {
+        let Some(commit) = self.selected_commit() else {
+            return vec![Line::from(t(Msg::LoadingCommit)).style(theme().log_message)];
+        };
+
+        let authored_at = commit.authored_at.with_timezone(&Local);
+        let header = Line::from(vec![
+            Span::from(commit.short_sha.clone()).style(theme().pipeline_job),
+            Span::from(" "),
+            Span::from(commit.author_name.clone()).style(theme().project_name),
+            Span::from(format!(" <{}> ", commit.author_email)).style(theme().project_parents),
+            Span::from(authored_at.format("%Y-%m-%d ").to_string()).style(theme().date),
+            Span::from(authored_at.format("%H:%M").to_string()).style(theme().time),
+            Span::from(format!("  +{}", commit.additions)).style(theme().job_status_success),
+            Span::from(format!(" -{}", commit.deletions)).style(theme().job_status_failed),
+        ]);
+
+        // the title is already shown by the pipeline table
+        let body = commit.message.lines()
+            .skip(1)
+            .skip_while(|l| l.trim().is_empty())
+            .take(MAX_COMMIT_MESSAGE_ROWS)
+            .map(|l| Line::from(l.to_string()).style(theme().commit_title));
+
+        std::iter::once(header).chain(body).collect()
+    }
+
+    /// Height of the commit section, including its title; zero when collapsed.
+    fn commit_section_height(&self) -> u16 {
+        match self.show_commit {
+            true  => 1 + self.commit_lines().len() as u16 + 1,
+            false => 0,
+        }
+    }
+
+    /// Height of the job durations section, including its title; zero when hidden.
+    fn durations_section_height(&self) -> u16 {
+        match self.show_durations {
+            true  => 1 + self.selected_jobs().len().clamp(1, MAX_JOB_ROWS) as u16 + 1,
+            false => 0,
+        }
+    }
+
+    /// Height of the duration history section, including its title; zero when hidden.
+    fn history_section_height(&self) -> u16 {
+        match self.show_history {
+            true  => 1 + DurationHistory::HEIGHT + 1,
+            false => 0,
+        }
+    }
+
     pub fn popup_area(&self, screen: Rect) -> Rect {
-        let pipeline_table_h = 2 * self.pipelines.rows.len() as u16;
+        let pipeline_table_h = 2 * self.pipelines().len() as u16;
         let project_details_h = 4;
-        let total_height = 2 + project_details_h + pipeline_table_h;
+        let total_height = 2 + project_details_h + pipeline_table_h + 1
+            + self.commit_section_height() + self.history_section_height()
+            + self.durations_section_height()
+            + self.job_table_height();
 
         screen.inner_centered(screen.width, total_height)
     }
@@ -125,8 +413,12 @@ impl StatefulWidget for ProjectDetailsPopup {
         buf: &mut Buffer,
         state: &mut Self::State
     ) {
-        let pipeline_table_h = 2 * state.pipelines.rows.len() as u16;
+        let pipeline_table_h = 2 * state.pipelines().len() as u16;
         let project_details_h = 4;
+        let job_table_h = state.job_table_height();
+        let commit_h = state.commit_section_height();
+        let history_h = state.history_section_height();
+        let durations_h = state.durations_section_height();
 
         let area = state.popup_area(area);
 
@@ -139,6 +431,11 @@ impl StatefulWidget for ProjectDetailsPopup {
             .constraints([
                 Constraint::Length(project_details_h),
                 Constraint::Length(pipeline_table_h),
+                Constraint::Length(1),
+                Constraint::Length(commit_h),
+                Constraint::Length(history_h),
+                Constraint::Length(durations_h),
+                Constraint::Length(job_table_h),
             ])
             .split(content_area);
 
@@ -155,8 +452,76 @@ impl StatefulWidget for ProjectDetailsPopup {
         state.project_stat_summary.clone()
             .render(project_details_layout[1], buf);
 
-        PipelineTable::new(&state.project.recent_pipelines())
+        PipelineTable::new(&state.pipelines(), &state.project.approvals)
             .render(outer_layout[1], buf, &mut state.pipelines_table_state);
+        state.pipeline_page_line()
+            .render(outer_layout[2], buf);
+        if let Some(filter) = state.pipeline_filter_line() {
+            filter.render(outer_layout[2], buf);
+        }
+        state.cursor_position = state.editing_filter.then(|| {
+            let prefix = Line::from(PIPELINE_FILTER_PREFIX).width();
+            Position::new(outer_layout[2].x + (prefix + state.pipeline_filter.cursor()) as u16, outer_layout[2].y)
+        });
+
+        if state.show_commit {
+            let [commit_title_area, commit_area] = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Percentage(100),
+            ]).areas(outer_layout[3]);
+
+            Line::from(t(Msg::CommitPaneTitle))
+                .style(theme().input_label)
+                .render(commit_title_area, buf);
+            Text::from(state.commit_lines())
+                .render(commit_area, buf);
+        }
+
+        if state.show_history {
+            let [history_title_area, history_area] = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Percentage(100),
+            ]).areas(outer_layout[4]);
+
+            let branch = state.selected_pipeline()
+                .map(|p| p.branch.clone())
+                .unwrap_or_default();
+            Line::from(format!("{} {branch}", t(Msg::DurationHistoryPaneTitle)))
+                .style(theme().input_label)
+                .render(history_title_area, buf);
+            DurationHistory::new(self.history)
+                .render(history_area, buf);
+        }
+
+        if state.show_durations {
+            let [durations_title_area, durations_area] = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Percentage(100),
+            ]).areas(outer_layout[5]);
+
+            Line::from(t(Msg::JobDurationsPaneTitle))
+                .style(theme().input_label)
+                .render(durations_title_area, buf);
+            JobDurationChart::new(state.selected_jobs())
+                .render(durations_area, buf);
+        }
+
+        let [jobs_title_area, jobs_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Percentage(100),
+        ]).areas(outer_layout[6]);
+
+        let jobs_title_style = if state.focus == DetailsFocus::Jobs {
+            theme().input_selected
+        } else {
+            theme().input_label
+        };
+        Line::from(t(Msg::JobsPaneTitle))
+            .style(jobs_title_style)
+            .render(jobs_title_area, buf);
+
+        let jobs = JobTable::new(&state.job_rows(), state.focus == DetailsFocus::Jobs);
+        jobs.render(jobs_area, buf, &mut state.jobs_table_state);
 
         state.window_fx.process_opening(self.last_frame_time, buf, area);
     }
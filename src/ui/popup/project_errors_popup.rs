@@ -0,0 +1,86 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget};
+use ratatui::widgets::{List, ListState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::notice_service::ProjectError;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+use crate::i18n::{t, Msg};
+
+/// project errors popup
+pub struct ProjectErrorsPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the project errors popup
+pub struct ProjectErrorsPopupState {
+    /// the errors, with the title of their project
+    pub errors: Vec<(ProjectError, String)>,
+    pub list_state: ListState,
+    window_fx: OpenWindow,
+}
+
+impl ProjectErrorsPopupState {
+    pub fn new(errors: Vec<(ProjectError, String)>) -> Self {
+        let selected = errors.len().checked_sub(1);
+        Self {
+            errors,
+            list_state: ListState::default().with_selected(selected),
+            window_fx: open_window(t(Msg::TitleProjectErrors), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+            ])),
+        }
+    }
+
+    fn errors_as_lines(&self) -> Vec<Line<'static>> {
+        if self.errors.is_empty() {
+            return vec![Line::from(t(Msg::NoProjectErrors)).style(theme().log_message)];
+        }
+
+        self.errors.iter()
+            .map(|(error, project)| Line::from(vec![
+                Span::from(error.timestamp.format("%H:%M:%S ").to_string()).style(theme().time),
+                Span::from(t(error.kind)).style(theme().log_message),
+                Span::from(project.clone()).style(theme().notification_project),
+                Span::from(format!(": {}", error.error)).style(theme().log_message),
+            ]))
+            .collect()
+    }
+}
+
+impl ProjectErrorsPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for ProjectErrorsPopup {
+    type State = ProjectErrorsPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let height = 2 + state.errors.len().clamp(1, 20) as u16;
+        let area = area.inner_centered(100, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let errors = List::new(state.errors_as_lines())
+            .style(theme().table_row_b)
+            .highlight_style(theme().highlight_symbol);
+
+        let inner_area = area.inner(Margin::new(1, 1));
+        StatefulWidget::render(errors, inner_area, buf, &mut state.list_state);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::{Local, Utc};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{Cell, Row, Table, TableState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::domain::{IconRepresentable, Runner, RunnerStatus};
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// maximum number of visible runners
+const MAX_RUNNER_ROWS: usize = 16;
+
+/// runners popup, with the status of the runners available to a project
+pub struct RunnersPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the runners popup
+pub struct RunnersPopupState {
+    pub project_id: ProjectId,
+    /// offline runners first; none until fetched
+    runners: Option<Vec<Runner>>,
+    pub table_state: TableState,
+    window_fx: OpenWindow,
+}
+
+impl RunnersPopupState {
+    pub fn new(project_id: ProjectId) -> Self {
+        Self {
+            project_id,
+            runners: None,
+            table_state: TableState::default(),
+            window_fx: open_window(t(Msg::TitleRunners), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+            ])),
+        }
+    }
+
+    pub fn set_runners(&mut self, mut runners: Vec<Runner>) {
+        // runners in need of attention are listed first
+        runners.sort_by_key(|r| (r.status == RunnerStatus::Online, r.paused, r.shared, r.id));
+
+        self.table_state.select((!runners.is_empty()).then_some(0));
+        self.runners = Some(runners);
+    }
+
+    pub fn select(&mut self, direction: i32) {
+        let count = self.runners.as_ref().map_or(0, Vec::len);
+        if let Some(current) = self.table_state.selected() {
+            let new_index = (current as i32 + direction)
+                .clamp(0, count.saturating_sub(1) as i32);
+
+            self.table_state.select(Some(new_index as usize));
+        }
+    }
+
+    /// Number of online runners, out of all runners, e.g. "3/4 online".
+    fn summary(runners: &[Runner]) -> Line<'static> {
+        let online = runners.iter()
+            .filter(|r| r.status == RunnerStatus::Online)
+            .count();
+
+        let style = if online == runners.len() {
+            theme().job_status_success
+        } else {
+            theme().job_status_failed
+        };
+
+        Line::from(vec![
+            Span::from(format!("{online}/{} ", runners.len())).style(style),
+            Span::from(t(Msg::RunnersOnline)).style(theme().log_message),
+        ])
+    }
+
+    fn runner_row(runner: &Runner) -> Row<'static> {
+        let status_style = match runner.status {
+            _ if runner.paused           => theme().job_status_inactive,
+            RunnerStatus::Online         => theme().job_status_success,
+            RunnerStatus::Offline        => theme().job_status_failed,
+            _                            => theme().job_status_inactive,
+        };
+
+        let status = if runner.paused {
+            t(Msg::RunnerPaused).to_string()
+        } else {
+            runner.status.to_string()
+        };
+
+        let mut description = vec![Span::from(runner.description.clone()).style(theme().project_name)];
+        if runner.shared {
+            description.push(Span::from(format!(" {}", t(Msg::RunnerShared))).style(theme().project_parents));
+        }
+
+        Row::new(vec![
+            Cell::from(runner.status.icon()),
+            Cell::from(Line::from(description)),
+            Cell::from(Span::from(status).style(status_style)),
+            Cell::from(format_contacted_at(runner)),
+            Cell::from(Span::from(runner.tags.join(", ")).style(theme().pipeline_branch)),
+        ])
+    }
+}
+
+/// Time of the last contact with the runner; the date is omitted for today.
+fn format_contacted_at(runner: &Runner) -> Line<'static> {
+    let Some(contacted_at) = runner.contacted_at else {
+        return Line::from("-").style(theme().log_message);
+    };
+
+    let contacted_at = contacted_at.with_timezone(&Local);
+    let mut spans = Vec::new();
+    if contacted_at.date_naive() != Utc::now().with_timezone(&Local).date_naive() {
+        spans.push(Span::from(contacted_at.format("%d %b ").to_string()).style(theme().date));
+    }
+    spans.push(Span::from(contacted_at.format("%H:%M").to_string()).style(theme().time));
+
+    Line::from(spans)
+}
+
+impl RunnersPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for RunnersPopup {
+    type State = RunnersPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let rows = state.runners.as_ref().map_or(0, Vec::len);
+        let height = 4 + rows.clamp(1, MAX_RUNNER_ROWS) as u16;
+        let area = area.inner(Margin::new(4, 0)).inner_centered(area.width, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        match state.runners.as_ref() {
+            None => Line::from(t(Msg::LoadingRunners))
+                .style(theme().log_message)
+                .render(content_area, buf),
+            Some(runners) if runners.is_empty() => Line::from(t(Msg::NoRunners))
+                .style(theme().log_message)
+                .render(content_area, buf),
+            Some(runners) => {
+                let [summary_area, _, table_area] = Layout::vertical([
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Percentage(100),
+                ]).areas(content_area);
+
+                RunnersPopupState::summary(runners).render(summary_area, buf);
+
+                let max_description = runners.iter()
+                    .map(|r| r.description.chars().count() + 9)
+                    .max()
+                    .unwrap_or(0);
+
+                let rows: Vec<Row> = runners.iter()
+                    .map(RunnersPopupState::runner_row)
+                    .enumerate()
+                    .map(|(idx, r)| r.style(theme().table_row(idx)))
+                    .collect();
+
+                let table = Table::new(rows, [
+                        Constraint::Length(2),
+                        Constraint::Length(max_description as u16),
+                        Constraint::Length(15),
+                        Constraint::Length(12),
+                        Constraint::Percentage(100),
+                    ])
+                    .highlight_style(theme().highlight_symbol)
+                    .column_spacing(1);
+
+                StatefulWidget::render(table, table_area, buf, &mut state.table_state);
+            },
+        }
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
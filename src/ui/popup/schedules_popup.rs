@@ -0,0 +1,158 @@
+use chrono::Local;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row, Table, TableState};
+use tachyonfx::{Duration, EffectRenderer};
+
+use crate::domain::Schedule;
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::read_only::read_only;
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// maximum number of visible schedules
+const MAX_SCHEDULE_ROWS: usize = 10;
+
+/// pipeline schedules popup
+pub struct SchedulesPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the pipeline schedules popup
+pub struct SchedulesPopupState {
+    pub project_id: ProjectId,
+    /// ordered by next run; none until fetched
+    schedules: Option<Vec<Schedule>>,
+    pub table_state: TableState,
+    window_fx: OpenWindow,
+}
+
+impl SchedulesPopupState {
+    pub fn new(project_id: ProjectId) -> Self {
+        Self {
+            project_id,
+            schedules: None,
+            table_state: TableState::default(),
+            window_fx: open_window(t(Msg::TitleSchedules), Some(Self::shortcuts())),
+        }
+    }
+
+    /// Running a schedule is hidden in read-only mode.
+    fn shortcuts() -> Vec<(&'static str, &'static str)> {
+        let mut shortcuts = vec![
+            ("ESC", t(Msg::ShortcutClose)),
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+        ];
+        if !read_only() {
+            shortcuts.push(("r", t(Msg::ShortcutRunSchedule)));
+        }
+        shortcuts
+    }
+
+    pub fn set_schedules(&mut self, mut schedules: Vec<Schedule>) {
+        // inactive schedules, without a next run, are listed last
+        schedules.sort_by_key(|s| (s.next_run_at.is_none(), s.next_run_at));
+
+        self.table_state.select((!schedules.is_empty()).then_some(0));
+        self.schedules = Some(schedules);
+    }
+
+    pub fn selected_schedule(&self) -> Option<&Schedule> {
+        self.table_state.selected()
+            .and_then(|idx| self.schedules.as_ref()?.get(idx))
+    }
+
+    pub fn select(&mut self, direction: i32) {
+        let count = self.schedules.as_ref().map_or(0, Vec::len);
+        if let Some(current) = self.table_state.selected() {
+            let new_index = (current as i32 + direction)
+                .clamp(0, count.saturating_sub(1) as i32);
+
+            self.table_state.select(Some(new_index as usize));
+        }
+    }
+
+    /// Each schedule spans two lines: description and branch, then cron and next run.
+    fn schedule_row(schedule: &Schedule) -> Row<'static> {
+        let next_run = match schedule.next_run_at {
+            Some(at) => {
+                let at = at.with_timezone(&Local);
+                Line::from(vec![
+                    Span::from(at.format("%a, %d %b ").to_string()).style(theme().date),
+                    Span::from(at.format("%H:%M").to_string()).style(theme().time),
+                ])
+            },
+            None => Line::from(t(Msg::ScheduleInactive)).style(theme().log_message),
+        };
+
+        Row::new(vec![
+            Cell::from(Text::from(vec![
+                Line::from(schedule.description.clone()).style(theme().commit_title),
+                Line::from(schedule.branch.clone()).style(theme().pipeline_branch),
+            ])),
+            Cell::from(Text::from(vec![
+                Line::from(schedule.cron.clone()).style(theme().pipeline_source),
+                Line::from(schedule.cron_timezone.clone()).style(theme().project_parents),
+            ])),
+            Cell::from(next_run),
+        ]).height(2)
+    }
+}
+
+impl SchedulesPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for SchedulesPopup {
+    type State = SchedulesPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let rows = state.schedules.as_ref().map_or(0, Vec::len);
+        let height = 2 + 2 * rows.clamp(1, MAX_SCHEDULE_ROWS) as u16;
+        let area = area.inner_centered(80, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        match state.schedules.as_ref() {
+            None => Line::from(t(Msg::LoadingSchedules))
+                .style(theme().log_message)
+                .render(content_area, buf),
+            Some(schedules) if schedules.is_empty() => Line::from(t(Msg::NoSchedules))
+                .style(theme().log_message)
+                .render(content_area, buf),
+            Some(schedules) => {
+                let rows: Vec<Row> = schedules.iter()
+                    .map(SchedulesPopupState::schedule_row)
+                    .enumerate()
+                    .map(|(idx, r)| r.style(theme().table_row(idx)))
+                    .collect();
+
+                let table = Table::new(rows, [
+                        Constraint::Percentage(100),
+                        Constraint::Length(20),
+                        Constraint::Length(18),
+                    ])
+                    .highlight_style(theme().highlight_symbol)
+                    .column_spacing(1);
+
+                StatefulWidget::render(table, content_area, buf, &mut state.table_state);
+            },
+        }
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+    }
+}
@@ -0,0 +1,140 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Position, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget, Widget};
+use ratatui::widgets::{List, ListState};
+use tachyonfx::{Duration, EffectRenderer, Shader};
+use tui_input::Input;
+
+use crate::i18n::{t, Msg};
+use crate::search::{SearchKind, SearchResult};
+use crate::theme::theme;
+use crate::ui::fx::{open_window, OpenWindow};
+use crate::ui::popup::utility::CenteredShrink;
+
+/// global search popup
+pub struct SearchPopup {
+    last_frame_ms: Duration,
+}
+
+/// state of the global search popup
+pub struct SearchPopupState {
+    pub input: Input,
+    pub results: Vec<SearchResult>,
+    pub list_state: ListState,
+    pub cursor_position: Position,
+    window_fx: OpenWindow,
+}
+
+impl SearchPopupState {
+    pub fn new() -> Self {
+        Self {
+            input: Input::default(),
+            results: Vec::new(),
+            list_state: ListState::default(),
+            cursor_position: Position::default(),
+            window_fx: open_window(t(Msg::TitleSearch), Some(vec![
+                ("ESC", t(Msg::ShortcutClose)),
+                ("↑ ↓", t(Msg::ShortcutSelection)),
+                ("↵",   t(Msg::ShortcutJump)),
+            ])),
+        }
+    }
+
+    pub fn is_open_complete(&self) -> bool {
+        self.window_fx.done()
+    }
+
+    pub fn set_results(&mut self, results: Vec<SearchResult>) {
+        self.list_state.select((!results.is_empty()).then_some(0));
+        self.results = results;
+    }
+
+    pub fn selected_result(&self) -> Option<&SearchResult> {
+        self.list_state.selected()
+            .and_then(|idx| self.results.get(idx))
+    }
+
+    fn results_as_lines(&self) -> Vec<Line<'static>> {
+        if self.results.is_empty() {
+            let message = if self.input.value().trim().is_empty() { "" } else { t(Msg::NoSearchResults) };
+            return vec![Line::from(message).style(theme().log_message)];
+        }
+
+        self.results.iter()
+            .enumerate()
+            .map(|(idx, result)| {
+                // the kind is only shown for the first result of each group
+                let first_of_kind = idx == 0 || self.results[idx - 1].entry.kind != result.entry.kind;
+                let kind = if first_of_kind { kind_label(result.entry.kind) } else { "" };
+
+                let text = &result.entry.text;
+                let matched = result.matched.clone();
+                Line::from(vec![
+                    Span::from(format!("{kind:>8} ")).style(theme().input_label),
+                    Span::from(text[..matched.start].to_string()),
+                    Span::from(text[matched.clone()].to_string()).style(theme().search_match),
+                    Span::from(text[matched.end..].to_string()),
+                    Span::from(" "),
+                    Span::from(result.entry.context.clone()).style(theme().project_parents),
+                ])
+            })
+            .collect()
+    }
+}
+
+fn kind_label(kind: SearchKind) -> &'static str {
+    match kind {
+        SearchKind::Project => t(Msg::SearchKindProject),
+        SearchKind::Branch  => t(Msg::SearchKindBranch),
+        SearchKind::Commit  => t(Msg::SearchKindCommit),
+        SearchKind::Job     => t(Msg::SearchKindJob),
+        SearchKind::Log     => t(Msg::SearchKindLog),
+    }
+}
+
+impl SearchPopup {
+    pub fn new(last_frame_ms: Duration) -> Self {
+        Self { last_frame_ms }
+    }
+}
+
+impl StatefulWidget for SearchPopup {
+    type State = SearchPopupState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        let height = 4 + state.results.len().clamp(1, 20) as u16;
+        let area = area.inner_centered(100, height);
+
+        state.window_fx.screen_area(buf.area); // for the parent window fx
+        buf.render_effect(&mut state.window_fx, area, self.last_frame_ms);
+
+        let content_area = area.inner(Margin::new(1, 1));
+        let [input_area, _, results_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Percentage(100),
+        ]).areas(content_area);
+
+        Line::from(state.input.value().to_string())
+            .style(theme().input_selected)
+            .render(input_area, buf);
+
+        let results = List::new(state.results_as_lines())
+            .style(theme().table_row_b)
+            .highlight_style(theme().highlight_symbol);
+
+        StatefulWidget::render(results, results_area, buf, &mut state.list_state);
+
+        // window decoration and animation
+        state.window_fx.process_opening(self.last_frame_ms, buf, area);
+        state.cursor_position = Position::new(
+            input_area.x + state.input.cursor() as u16,
+            input_area.y,
+        );
+    }
+}
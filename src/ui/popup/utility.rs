@@ -1,4 +1,7 @@
 use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+
+use crate::theme::theme;
 
 pub trait CenteredShrink {
     fn inner_centered(&self, width: u16, height: u16) -> Rect;
@@ -10,4 +13,17 @@ impl CenteredShrink for Rect {
         let y = self.y + (self.height.saturating_sub(height) / 2);
         Rect::new(x, y, width.min(self.width), height.min(self.height))
     }
-}
\ No newline at end of file
+}
+
+/// Styles a line of input description, emphasizing the text enclosed
+/// in backticks.
+pub fn description_line(text: &str) -> Line<'static> {
+    text.split('`')
+        .enumerate()
+        .filter(|(_, s)| !s.is_empty())
+        .map(|(i, s)| {
+            let style = if i % 2 == 1 { theme().input_description_em } else { theme().input_description };
+            Span::from(s.to_string()).style(style)
+        })
+        .collect()
+}
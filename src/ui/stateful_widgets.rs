@@ -1,15 +1,22 @@
+use std::ops::Range;
 use std::sync::mpsc::Sender;
 use ratatui::widgets::{ListState, TableState};
 use tachyonfx::{fx, Duration, Effect, Interpolation, IntoEffect};
 use tachyonfx::fx::{parallel, Direction, Glitch};
+use tui_input::Input;
+use crate::api_inspector::recent_exchanges;
 use crate::dispatcher::Dispatcher;
-use crate::domain::Project;
+use crate::domain::{Branch, Job, PipelineStatus, Project, Runner, Schedule};
 use crate::event::{GlimEvent, GlitchState};
 use crate::glim_app::{GlimApp, GlimConfig, Modulo};
 use crate::gruvbox::Gruvbox::{Dark0Hard, Dark3};
-use crate::id::PipelineId;
-use crate::ui::popup::{ConfigPopupState, PipelineActionsPopupState, ProjectDetailsPopupState};
-use crate::ui::widget::NotificationState;
+use crate::id::{JobId, PipelineId, ProjectId};
+use crate::read_only::read_only;
+use crate::result::GlimError;
+use crate::stores::ProjectSort;
+use crate::ui::popup::{ApiInspectorPopupState, ProjectErrorsPopupState, AuditLogPopupState, ArtifactsDownload, ArtifactsPopupState, BranchesPopupState, RunnersPopupState, SchedulesPopupState, CommandPalettePopupState, ConfigPopupState, OnboardingPopupState, HelpPopupState, JobLogPopupState, PipelineActionsPopupState, PipelineGraphPopupState, ProjectDetailsPopupState, SearchPopupState};
+use crate::ui::fx::{is_enabled, EffectRegistry, FxId};
+use crate::ui::widget::{NotificationState, ProjectTreeState, Tab};
 
 pub struct StatefulWidgets {
     pub last_frame: Duration,
@@ -17,11 +24,51 @@ pub struct StatefulWidgets {
     pub project_table_state: TableState,
     pub logs_state: ListState,
     pub config_popup_state: Option<ConfigPopupState>,
+    /// first-run wizard, shown instead of the configuration popup
+    pub onboarding: Option<OnboardingPopupState>,
     pub table_fade_in: Option<Effect>,
     pub project_details: Option<ProjectDetailsPopupState>,
     pub pipeline_actions: Option<PipelineActionsPopupState>,
+    pub audit_log: Option<AuditLogPopupState>,
+    pub api_inspector: Option<ApiInspectorPopupState>,
+    pub project_errors: Option<ProjectErrorsPopupState>,
+    pub job_log: Option<JobLogPopupState>,
+    pub pipeline_graph: Option<PipelineGraphPopupState>,
+    pub search: Option<SearchPopupState>,
+    pub command_palette: Option<CommandPalettePopupState>,
+    pub help: Option<HelpPopupState>,
+    /// pipeline selected once the project details open, instead of the latest
+    /// pipeline; see [GlimConfig::open_details_on_failure]
+    details_pipeline: Option<PipelineId>,
+    /// order of the projects table, applied by the project store
+    pub project_sort: ProjectSort,
+    /// selection of the pipelines tab, see [GlimApp::recent_pipelines]
+    pub recent_pipelines_state: TableState,
+    /// pipeline selected in the pipelines tab, as of the last render
+    pub selected_recent_pipeline: Option<(ProjectId, PipelineId)>,
+    /// selection of the failed jobs tab, see [GlimApp::failed_jobs]
+    pub failed_jobs_state: TableState,
+    /// job selected in the failed jobs tab, as of the last render
+    pub selected_failed_job: Option<(ProjectId, PipelineId, JobId)>,
+    /// selection of the multi-project tab, see [GlimApp::pipeline_trees]
+    pub pipeline_trees_state: TableState,
+    /// pipeline selected in the multi-project tab, as of the last render
+    pub selected_tree_pipeline: Option<(ProjectId, PipelineId)>,
+    /// project selected in the projects tab
+    pub selected_project: Option<ProjectId>,
+    /// grouped view of the projects table, replacing the flat table while set
+    pub project_tree: Option<ProjectTreeState>,
+    /// query of the project filter, while edited
+    pub project_filter: Option<Input>,
+    pub branches: Option<BranchesPopupState>,
+    pub schedules: Option<SchedulesPopupState>,
+    pub runners: Option<RunnersPopupState>,
+    pub artifacts: Option<ArtifactsPopupState>,
     pub shader_pipeline: Option<Effect>,
     pub notice: Option<NotificationState>,
+    pub effects: EffectRegistry,
+    /// rows in view of the projects table, as last reported by the renderer
+    projects_viewport: Range<usize>,
     glitch_override: Option<Effect>,
     glitch: Effect,
 }
@@ -35,11 +82,37 @@ impl StatefulWidgets {
             logs_state: ListState::default().with_selected(Some(0)),
             table_fade_in: None,
             config_popup_state: None,
+            onboarding: None,
             project_details: None,
             pipeline_actions: None,
+            audit_log: None,
+            api_inspector: None,
+            project_errors: None,
+            job_log: None,
+            pipeline_graph: None,
+            search: None,
+            command_palette: None,
+            help: None,
+            project_sort: ProjectSort::default(),
+            recent_pipelines_state: TableState::default().with_selected(0),
+            selected_recent_pipeline: None,
+            failed_jobs_state: TableState::default().with_selected(0),
+            selected_failed_job: None,
+            pipeline_trees_state: TableState::default().with_selected(0),
+            selected_tree_pipeline: None,
+            selected_project: None,
+            project_tree: None,
+            project_filter: None,
+            branches: None,
+            schedules: None,
+            runners: None,
+            artifacts: None,
             shader_pipeline: None,
+            details_pipeline: None,
             glitch_override: None,
             notice: None,
+            effects: EffectRegistry::default(),
+            projects_viewport: 0..0,
             glitch: Glitch::builder()
                 .action_ms(100..500)
                 .action_start_delay_ms(0..2000)
@@ -55,31 +128,180 @@ impl StatefulWidgets {
         event: &GlimEvent
     ) {
         match event {
-            GlimEvent::GlitchOverride(g)            => self.glitch_override = make_glitch_effect(*g),
-
+            GlimEvent::GlitchOverride(g)            =>
+                self.glitch_override = make_glitch_effect(*g).and_then(|fx| self.effects.filter(FxId::Glitch, fx)),
+
+            GlimEvent::SelectNextProject if app.ui.tab == Tab::Pipelines     =>
+                self.handle_recent_pipeline_selection(1, app),
+            GlimEvent::SelectPreviousProject if app.ui.tab == Tab::Pipelines =>
+                self.handle_recent_pipeline_selection(-1, app),
+            GlimEvent::SelectNextProject if app.ui.tab == Tab::FailedJobs    =>
+                self.handle_failed_job_selection(1, app),
+            GlimEvent::SelectPreviousProject if app.ui.tab == Tab::FailedJobs =>
+                self.handle_failed_job_selection(-1, app),
+            GlimEvent::SelectNextProject if app.ui.tab == Tab::MultiProject  =>
+                self.handle_tree_pipeline_selection(1, app),
+            GlimEvent::SelectPreviousProject if app.ui.tab == Tab::MultiProject =>
+                self.handle_tree_pipeline_selection(-1, app),
             GlimEvent::SelectNextProject            => self.handle_project_selection(1, app),
             GlimEvent::SelectPreviousProject        => self.handle_project_selection(-1, app),
+            GlimEvent::SelectedProject(id)          => {
+                self.selected_project = Some(*id);
+                self.sync_project_selection(*id, app);
+            },
+            GlimEvent::CycleProjectSort             => self.cycle_project_sort(app),
+            GlimEvent::ToggleProjectTree            => self.toggle_project_tree(app),
+            GlimEvent::CollapseProjectGroup         => {
+                if let Some(tree) = self.project_tree.as_mut() {
+                    tree.collapse(app.projects());
+                }
+            },
+            GlimEvent::ExpandProjectGroup           => {
+                if let Some(tree) = self.project_tree.as_mut() {
+                    tree.expand();
+                }
+            },
 
-            GlimEvent::ReceivedProjects(_)          => self.fade_in_projects_table(),
+            GlimEvent::ReceivedProjects(projects) if app.has_project_changes(projects) =>
+                self.fade_in_projects_table(),
+            GlimEvent::ManualRefresh                => self.fade_in_projects_table(),
 
-            GlimEvent::OpenProjectDetails(id)       => self.open_project_details(app.project(*id).clone(), app.sender.clone()),
+            GlimEvent::OpenProjectDetails(id)       => {
+                let pipeline_id = self.details_pipeline.take();
+                match app.project(*id) {
+                    Ok(project) => self.open_project_details(project.clone(), pipeline_id, app.sender.clone()),
+                    Err(e)      => self.abort_popup(e, GlimEvent::CloseProjectDetails),
+                }
+            },
             GlimEvent::CloseProjectDetails          => self.project_details = {
                 let fade_in = fx::fade_from(Dark3, Dark0Hard, (300, Interpolation::CircIn));
-                self.shader_pipeline = Some(fade_in);
+                self.shader_pipeline = self.effects.filter(FxId::PopupTransition, fade_in);
 
                 None
             },
-            GlimEvent::ProjectUpdated(p)            => self.refresh_project_details(p),
+            GlimEvent::ProjectUpdated(p)            => {
+                self.refresh_project_details(p);
+                self.refresh_pipeline_actions(p);
+            },
+            GlimEvent::PipelineStatusChanged(project_id, pipeline_id, _, PipelineStatus::Failed)
+                if app.opens_details_on_failure(*project_id) && self.can_open_details() =>
+            {
+                self.effects.apply(event);
+                self.details_pipeline = Some(*pipeline_id);
+                self.sender.dispatch(GlimEvent::OpenProjectDetails(*project_id));
+            },
+            GlimEvent::PipelineStatusChanged(..)    => self.effects.apply(event),
 
             GlimEvent::ClosePipelineActions         => self.close_pipeline_actions(),
-            GlimEvent::OpenPipelineActions(project_id, pipeline_id) => {
-                let project = app.project(*project_id);
-                self.open_pipeline_actions(project, *pipeline_id);
+            GlimEvent::OpenPipelineActions(project_id, pipeline_id) => match app.project(*project_id) {
+                Ok(project) => {
+                    let followed = app.followed_pipeline_id() == Some(*pipeline_id);
+                    self.open_pipeline_actions(project, *pipeline_id, followed);
+                },
+                Err(e) => self.abort_popup(e, GlimEvent::ClosePipelineActions),
+            },
+            GlimEvent::OpenJobActions(project_id, pipeline_id, job_id) => {
+                let job = app.job(*project_id, *pipeline_id, *job_id).ok();
+                self.open_job_actions(*project_id, *pipeline_id, *job_id, job);
             },
 
-            GlimEvent::DisplayConfig                => self.open_config(app.load_config().unwrap_or_default()),
+            GlimEvent::OpenArtifacts(project_id, pipeline_id, job_id) => match app.job(*project_id, *pipeline_id, *job_id) {
+                Ok(job) => self.artifacts = Some(ArtifactsPopupState::new(*project_id, job)),
+                Err(e)  => self.abort_popup(e, GlimEvent::CloseArtifacts),
+            },
+            GlimEvent::DownloadArtifacts(_, job_id) => self.update_artifacts_download(*job_id, ArtifactsDownload::InProgress(0, None)),
+            GlimEvent::ArtifactsDownloadProgress(job_id, downloaded, total) =>
+                self.update_artifacts_download(*job_id, ArtifactsDownload::InProgress(*downloaded, *total)),
+            GlimEvent::ArtifactsDownloaded(job_id, path) =>
+                self.update_artifacts_download(*job_id, ArtifactsDownload::Done(path.clone())),
+            GlimEvent::CloseArtifacts               => self.artifacts = None,
+
+            GlimEvent::DisplayConfig                => self.open_config(app.load_config().unwrap_or_default(), app.status().token_days_left()),
             GlimEvent::CloseConfig                  => self.config_popup_state = None,
 
+            GlimEvent::DisplayAuditLog              => self.audit_log = Some(AuditLogPopupState::new(app.audit_entries().to_vec())),
+            GlimEvent::CloseAuditLog                => self.audit_log = None,
+            GlimEvent::DisplayApiInspector          => self.api_inspector = Some(ApiInspectorPopupState::new(recent_exchanges())),
+            GlimEvent::CloseApiInspector            => self.api_inspector = None,
+            GlimEvent::DisplayProjectErrors         => self.project_errors = Some(ProjectErrorsPopupState::new(app.project_errors())),
+            GlimEvent::CloseProjectErrors           => self.project_errors = None,
+
+            GlimEvent::OpenJobLog(project_id, pipeline_id, job_id) => {
+                let job_name = app.job(*project_id, *pipeline_id, *job_id)
+                    .map(|j| j.name.clone())
+                    .unwrap_or_default();
+
+                self.job_log = Some(JobLogPopupState::new(*project_id, *job_id, job_name));
+            },
+            GlimEvent::ReceivedJobTrace(_, job_id, trace) => {
+                if let Some(job_log) = self.job_log.as_mut().filter(|l| l.job_id == *job_id) {
+                    job_log.set_trace(trace);
+                }
+            },
+            GlimEvent::JobTraceUnavailable(_, job_id, e) => {
+                if let Some(job_log) = self.job_log.as_mut().filter(|l| l.job_id == *job_id) {
+                    job_log.set_error(e);
+                }
+            },
+            GlimEvent::CloseJobLog                  => self.job_log = None,
+
+            GlimEvent::OpenPipelineGraph(project_id, pipeline_id) =>
+                self.pipeline_graph = Some(PipelineGraphPopupState::new(*project_id, *pipeline_id)),
+            GlimEvent::ReceivedJobNeeds(_, pipeline_id, needs) => {
+                if let Some(graph) = self.pipeline_graph.as_mut().filter(|g| g.pipeline_id == *pipeline_id) {
+                    graph.set_needs(needs.clone());
+                }
+            },
+            GlimEvent::ClosePipelineGraph           => self.pipeline_graph = None,
+
+            GlimEvent::OpenBranches(project_id)     => self.branches = Some(BranchesPopupState::new(*project_id)),
+            GlimEvent::ReceivedBranches(project_id, branches) => {
+                if let Some(popup) = self.branches.as_mut().filter(|b| b.project_id == *project_id) {
+                    popup.set_branches(branches.iter().cloned().map(Branch::from).collect());
+                }
+            },
+            GlimEvent::CloseBranches                => self.branches = None,
+
+            GlimEvent::OpenSchedules(project_id)    => self.schedules = Some(SchedulesPopupState::new(*project_id)),
+            GlimEvent::ReceivedSchedules(project_id, schedules) => {
+                if let Some(popup) = self.schedules.as_mut().filter(|s| s.project_id == *project_id) {
+                    popup.set_schedules(schedules.iter().cloned().map(Schedule::from).collect());
+                }
+            },
+            GlimEvent::CloseSchedules               => self.schedules = None,
+
+            GlimEvent::OpenRunners(project_id)      => self.runners = Some(RunnersPopupState::new(*project_id)),
+            GlimEvent::ReceivedRunners(project_id, runners) => {
+                if let Some(popup) = self.runners.as_mut().filter(|r| r.project_id == *project_id) {
+                    popup.set_runners(runners.iter().cloned().map(Runner::from).collect());
+                }
+            },
+            GlimEvent::CloseRunners                 => self.runners = None,
+
+            GlimEvent::DisplaySearch                => self.search = Some(SearchPopupState::new()),
+            GlimEvent::SearchQuery(query)           => {
+                if let Some(search) = self.search.as_mut() {
+                    search.set_results(app.search(query));
+                }
+            },
+            GlimEvent::CloseSearch                  => self.search = None,
+
+            GlimEvent::DisplayCommandPalette        => {
+                let selected = self.project_table_state.selected()
+                    .and_then(|idx| app.projects().get(idx))
+                    .map(|p| p.id);
+                self.command_palette = Some(CommandPalettePopupState::new(selected));
+            },
+            GlimEvent::CloseCommandPalette          => self.command_palette = None,
+
+            GlimEvent::DisplayHelp                  => self.help = Some(HelpPopupState::new()),
+            GlimEvent::CloseHelp                    => self.help = None,
+
+            GlimEvent::DisplayProjectFilter         => {
+                self.project_filter = Some(Input::new(app.project_filter().to_string()));
+            },
+            GlimEvent::CloseProjectFilter           => self.project_filter = None,
+
             _ => (),
         }
     }
@@ -89,12 +311,18 @@ impl StatefulWidgets {
             fx::coalesce(550),
             fx::sweep_in(Direction::LeftToRight, 50, 0, Dark0Hard, (450, Interpolation::QuadIn))
         ]);
-        self.table_fade_in = Some(effect);
+        self.table_fade_in = self.effects.filter(FxId::TableFadeIn, effect);
+    }
+
+    fn refresh_pipeline_actions(&mut self, project: &Project) {
+        if let Some(actions) = self.pipeline_actions.as_mut().filter(|a| a.project_id == project.id) {
+            actions.commit_stats = Self::commit_stats(project, actions.pipeline_id);
+        }
     }
 
     fn refresh_project_details(&mut self, project: &Project) {
         let requires_refresh = self.project_details.as_ref()
-            .map_or(false, |pd| pd.project.id == project.id);
+            .is_some_and(|pd| pd.project.id == project.id);
 
         if requires_refresh {
             let existing = self.project_details.take().unwrap();
@@ -102,52 +330,161 @@ impl StatefulWidgets {
         }
     }
 
-    fn open_project_details(&mut self, project: Project, sender: Sender<GlimEvent>) {
-        project.recent_pipelines().first()
+    /// Returns true if the project details can open without interrupting the
+    /// user, i.e. neither a popup nor the project filter is open, and no
+    /// project details are about to open.
+    fn can_open_details(&self) -> bool {
+        !self.is_popup_open() && self.project_filter.is_none() && self.details_pipeline.is_none()
+    }
+
+    fn open_project_details(
+        &mut self,
+        project: Project,
+        pipeline_id: Option<PipelineId>,
+        sender: Sender<GlimEvent>,
+    ) {
+        let mut details = ProjectDetailsPopupState::new(project);
+        if let Some(pipeline_id) = pipeline_id {
+            details.select_pipeline(pipeline_id);
+        }
+
+        details.selected_pipeline()
             .map(|p| sender.dispatch(GlimEvent::SelectedPipeline(p.id)))
             .unwrap_or(());
 
-        self.project_details = Some(ProjectDetailsPopupState::new(project));
+        self.project_details = Some(details);
     }
 
-    fn open_config(&mut self, config: GlimConfig) {
-        self.config_popup_state = Some(ConfigPopupState::new(config));
+    /// Reports the error of a popup which could not be opened, closing it
+    /// again for the input processor pushed when it was opened.
+    fn abort_popup(&self, error: GlimError, close: GlimEvent) {
+        self.sender.dispatch(GlimEvent::Error(error));
+        self.sender.dispatch(close);
+    }
+
+    fn open_config(&mut self, config: GlimConfig, token_days_left: Option<i64>) {
+        self.config_popup_state = Some(ConfigPopupState::new(config).with_token_days_left(token_days_left));
     }
 
     fn open_pipeline_actions(
         &mut self,
         project: &Project,
-        pipeline_id: PipelineId
+        pipeline_id: PipelineId,
+        followed: bool,
     ) {
-        let failed_job = project
-            .pipeline(pipeline_id)
-            .and_then(|p| p.failed_job());
+        let pipeline = project.pipeline(pipeline_id);
+        let failed_job = pipeline.and_then(|p| p.failed_job());
 
-        let actions = if let Some(job) = failed_job {
+        let mut actions = if let Some(job) = failed_job {
             vec![
+                GlimEvent::OpenJobLog(project.id, pipeline_id, job.id),
+                GlimEvent::BrowseToCommit(project.id, pipeline_id),
                 GlimEvent::BrowseToJob(project.id, pipeline_id, job.id),
                 GlimEvent::BrowseToPipeline(project.id, pipeline_id),
                 GlimEvent::BrowseToProject(project.id),
                 GlimEvent::DownloadErrorLog(project.id, pipeline_id),
+                GlimEvent::OpenPipelineGraph(project.id, pipeline_id),
+                GlimEvent::CopyPipelineUrl(project.id, pipeline_id),
+                GlimEvent::CopyJobUrl(project.id, pipeline_id, job.id),
+                GlimEvent::CopyBranchName(project.id, pipeline_id),
             ]
         } else {
             vec![
+                GlimEvent::OpenPipelineGraph(project.id, pipeline_id),
                 GlimEvent::BrowseToPipeline(project.id, pipeline_id),
                 GlimEvent::BrowseToProject(project.id),
+                GlimEvent::CopyPipelineUrl(project.id, pipeline_id),
+                GlimEvent::CopyBranchName(project.id, pipeline_id),
             ]
         };
 
-        self.pipeline_actions = Some(PipelineActionsPopupState::new(actions, project.id, pipeline_id));
+        // mutating actions are hidden in read-only mode
+        if failed_job.is_some() && !read_only() {
+            actions.insert(2, GlimEvent::CreateIssue(project.id, pipeline_id));
+        }
+
+        if followed {
+            actions.push(GlimEvent::UnfollowPipeline);
+        } else if pipeline.is_some_and(|p| p.status.is_active() || p.has_active_jobs()) {
+            actions.push(GlimEvent::FollowPipeline(project.id, pipeline_id));
+        }
+
+        if pipeline.is_some_and(|p| p.status.is_active()) {
+            actions.push(GlimEvent::WaitForPipeline(project.id, pipeline_id));
+        }
+
+        // diff stats of the commit, shown next to the browse to commit action
+        if failed_job.is_some() && pipeline.is_some_and(|p| p.commit_details.is_none()) {
+            self.sender.dispatch(GlimEvent::RequestCommitDetails(project.id, pipeline_id));
+        }
+
+        let mut state = PipelineActionsPopupState::new(actions, project.id, pipeline_id);
+        state.commit_stats = Self::commit_stats(project, pipeline_id);
+        self.pipeline_actions = Some(state);
+    }
+
+    fn commit_stats(project: &Project, pipeline_id: PipelineId) -> Option<(u32, u32)> {
+        project.pipeline(pipeline_id)
+            .and_then(|p| p.commit_details.as_ref())
+            .map(|c| (c.additions, c.deletions))
+    }
+
+    fn open_job_actions(
+        &mut self,
+        project_id: ProjectId,
+        pipeline_id: PipelineId,
+        job_id: JobId,
+        job: Option<&Job>,
+    ) {
+        let mut actions = vec![
+            GlimEvent::OpenJobLog(project_id, pipeline_id, job_id),
+            GlimEvent::BrowseToJob(project_id, pipeline_id, job_id),
+            GlimEvent::CopyJobUrl(project_id, pipeline_id, job_id),
+        ];
+
+        if !read_only() {
+            actions.insert(2, GlimEvent::RetryJob(project_id, pipeline_id, job_id));
+        }
+
+        if job.is_some_and(|j| !j.artifacts.is_empty()) {
+            actions.push(GlimEvent::OpenArtifacts(project_id, pipeline_id, job_id));
+        }
+
+        self.pipeline_actions = Some(PipelineActionsPopupState::for_job(actions, project_id, pipeline_id, job_id));
+    }
+
+    /// Updates the download progress, if the artifacts popup of the job is open.
+    fn update_artifacts_download(&mut self, job_id: JobId, download: ArtifactsDownload) {
+        if let Some(popup) = self.artifacts.as_mut().filter(|a| a.job_id == job_id) {
+            popup.download = Some(download);
+        }
     }
 
     fn close_pipeline_actions(&mut self) {
         self.pipeline_actions = None;
     }
 
+    /// Reports the rows in view of the projects table, for fetching the
+    /// pipelines of projects as they are scrolled into view.
+    pub fn update_projects_viewport(&mut self, rows: Range<usize>) {
+        if self.projects_viewport != rows {
+            self.projects_viewport = rows.clone();
+            self.sender.dispatch(GlimEvent::ProjectsViewport(rows));
+        }
+    }
+
     fn handle_project_selection(&mut self, direction: i32, app: &GlimApp) {
         let projects = app.projects();
         if projects.is_empty() { return; }
 
+        // group rows of the grouped view leave the selected project as is
+        if let Some(tree) = self.project_tree.as_mut() {
+            if let Some(id) = tree.select(direction, projects) {
+                app.dispatch(GlimEvent::SelectedProject(id));
+            }
+            return;
+        }
+
         if let Some(current) = self.project_table_state.selected() {
             let new_index = match direction {
                 1  => current.saturating_add(1),
@@ -163,12 +500,78 @@ impl StatefulWidgets {
         }
     }
 
+    /// Sorts the projects table by the next [ProjectSort], keeping the
+    /// selection on the selected project.
+    fn cycle_project_sort(&mut self, app: &GlimApp) {
+        self.project_sort = self.project_sort.next();
+        app.dispatch(GlimEvent::SortProjects(self.project_sort));
+
+        let selected = self.project_table_state.selected()
+            .and_then(|idx| app.projects().get(idx));
+        if let Some(project) = selected {
+            app.dispatch(GlimEvent::SelectedProject(project.id));
+        }
+    }
+
+    fn handle_recent_pipeline_selection(&mut self, direction: i32, app: &GlimApp) {
+        let count = app.recent_pipelines().len();
+        if count == 0 { return; }
+
+        let current = self.recent_pipelines_state.selected().unwrap_or(0) as i32;
+        let selected = (current + direction).clamp(0, count as i32 - 1) as usize;
+        self.recent_pipelines_state.select(Some(selected));
+    }
+
+    fn handle_failed_job_selection(&mut self, direction: i32, app: &GlimApp) {
+        let count = app.failed_jobs().len();
+        if count == 0 { return; }
+
+        let current = self.failed_jobs_state.selected().unwrap_or(0) as i32;
+        let selected = (current + direction).clamp(0, count as i32 - 1) as usize;
+        self.failed_jobs_state.select(Some(selected));
+    }
+
+    fn handle_tree_pipeline_selection(&mut self, direction: i32, app: &GlimApp) {
+        let count = app.pipeline_trees().len();
+        if count == 0 { return; }
+
+        let current = self.pipeline_trees_state.selected().unwrap_or(0) as i32;
+        let selected = (current + direction).clamp(0, count as i32 - 1) as usize;
+        self.pipeline_trees_state.select(Some(selected));
+    }
+
+    /// Keeps the table selection on the project, e.g. after it has been
+    /// re-sorted by pinning.
+    fn sync_project_selection(&mut self, id: ProjectId, app: &GlimApp) {
+        if let Some(idx) = app.projects().iter().position(|p| p.id == id) {
+            self.project_table_state.select(Some(idx));
+        }
+
+        if let Some(tree) = self.project_tree.as_mut() {
+            tree.reveal(id, app.projects());
+        }
+    }
+
+    /// Switches between the flat and the grouped view of the projects table,
+    /// keeping the selected project.
+    fn toggle_project_tree(&mut self, app: &GlimApp) {
+        self.project_tree = match self.project_tree {
+            Some(_) => None,
+            None    => {
+                let selected = self.project_table_state.selected()
+                    .and_then(|idx| app.projects().get(idx))
+                    .map(|p| p.id);
+                Some(ProjectTreeState::new(selected))
+            },
+        };
+    }
+
     pub fn handle_pipeline_selection(&mut self, direction: i32) {
         if self.project_details.is_none() { return; }
         let pd = self.project_details.as_mut().unwrap();
 
         if let Some(current) = pd.pipelines_table_state.selected() {
-            let pipelines = pd.project.recent_pipelines();
+            let pipelines: Vec<PipelineId> = pd.pipelines().iter().map(|p| p.id).collect();
 
             let new_index = (current as i32 + direction)
                 .modulo(pipelines.len() as i32) as usize;
@@ -177,10 +580,27 @@ impl StatefulWidgets {
                 pd.pipelines_table_state.select(None);
             } else {
                 pd.pipelines_table_state.select(Some(new_index));
-                let pipeline = &pipelines[new_index];
-                self.sender.dispatch(GlimEvent::SelectedPipeline(pipeline.id));
+                self.sender.dispatch(GlimEvent::SelectedPipeline(pipelines[new_index]));
             }
         }
+
+        pd.jobs_table_state.select(None);
+    }
+
+    pub fn handle_job_selection(&mut self, direction: i32) {
+        if self.project_details.is_none() { return; }
+        let pd = self.project_details.as_mut().unwrap();
+
+        let job_count = pd.job_rows().len();
+        if job_count == 0 {
+            pd.jobs_table_state.select(None);
+        } else {
+            let current = pd.jobs_table_state.selected().unwrap_or(0);
+            let new_index = (current as i32 + direction)
+                .modulo(job_count as i32) as usize;
+
+            pd.jobs_table_state.select(Some(new_index));
+        }
     }
 
     pub fn handle_pipeline_action_selection(&mut self, direction: i32) {
@@ -195,10 +615,100 @@ impl StatefulWidgets {
         }
     }
 
-    pub fn glitch(&mut self) -> &mut Effect {
+    pub fn handle_search_selection(&mut self, direction: i32) {
+        if let Some(search) = self.search.as_mut() {
+            if let Some(current) = search.list_state.selected() {
+                let new_index = (current as i32 + direction)
+                    .modulo(search.results.len() as i32);
+
+                search.list_state.select(Some(new_index as usize));
+            }
+        }
+    }
+
+    pub fn handle_command_palette_selection(&mut self, direction: i32) {
+        if let Some(palette) = self.command_palette.as_mut() {
+            if let Some(current) = palette.list_state.selected() {
+                let new_index = (current as i32 + direction)
+                    .modulo(palette.matches.len() as i32);
+
+                palette.list_state.select(Some(new_index as usize));
+            }
+        }
+    }
+
+    pub fn handle_audit_log_selection(&mut self, direction: i32) {
+        if let Some(audit_log) = self.audit_log.as_mut() {
+            if let Some(current) = audit_log.list_state.selected() {
+                let new_index = (current as i32 + direction)
+                    .clamp(0, audit_log.entries.len().saturating_sub(1) as i32);
+
+                audit_log.list_state.select(Some(new_index as usize));
+            }
+        }
+    }
+
+    pub fn handle_api_inspector_selection(&mut self, direction: i32) {
+        if let Some(api_inspector) = self.api_inspector.as_mut() {
+            api_inspector.select(direction);
+        }
+    }
+
+    pub fn handle_project_errors_selection(&mut self, direction: i32) {
+        if let Some(project_errors) = self.project_errors.as_mut() {
+            if let Some(current) = project_errors.list_state.selected() {
+                let new_index = (current as i32 + direction)
+                    .clamp(0, project_errors.errors.len().saturating_sub(1) as i32);
+
+                project_errors.list_state.select(Some(new_index as usize));
+            }
+        }
+    }
+
+    pub fn handle_api_inspector_scroll(&mut self, delta: i32) {
+        if let Some(api_inspector) = self.api_inspector.as_mut() {
+            api_inspector.scroll_body(delta);
+        }
+    }
+
+    /// Returns true while effects are running, requiring the UI to be redrawn
+    /// every tick. The ambient glitch effect is not counted.
+    pub fn is_animating(&self) -> bool {
+        self.table_fade_in.is_some()
+            || self.shader_pipeline.is_some()
+            || self.notice.is_some()
+            || self.glitch_override.is_some()
+            || self.effects.is_running()
+    }
+
+    /// Returns true while any popup is open.
+    pub fn is_popup_open(&self) -> bool {
+        self.config_popup_state.is_some()
+            || self.project_details.is_some()
+            || self.pipeline_actions.is_some()
+            || self.audit_log.is_some()
+            || self.api_inspector.is_some()
+            || self.project_errors.is_some()
+            || self.job_log.is_some()
+            || self.pipeline_graph.is_some()
+            || self.search.is_some()
+            || self.command_palette.is_some()
+            || self.help.is_some()
+            || self.branches.is_some()
+            || self.schedules.is_some()
+            || self.runners.is_some()
+            || self.artifacts.is_some()
+    }
+
+    /// The glitch effect to render, if glitches are enabled.
+    pub fn glitch(&mut self) -> Option<&mut Effect> {
+        if !is_enabled(FxId::Glitch) {
+            return None;
+        }
+
         match self.glitch_override.as_mut() {
-            Some(g) => g,
-            None => &mut self.glitch
+            Some(g) => Some(g),
+            None => Some(&mut self.glitch)
         }
     }
 }
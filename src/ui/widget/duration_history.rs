@@ -0,0 +1,75 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::Sparkline;
+
+use crate::i18n::{t, Msg};
+use crate::pipeline_history::PipelineRun;
+use crate::theme::theme;
+use crate::ui::format_duration;
+
+/// pipeline duration history widget. used inside the project details popup,
+/// drawing the durations of the last runs on the branch of the selected
+/// pipeline as a sparkline, annotated with the average and slowest run:
+/// ```
+/// ▂▃▂▅▃▂▂█▃▂▃
+/// avg 3m12s  slowest 7m40s #12345  11 runs
+/// ```
+pub struct DurationHistory {
+    runs: Vec<PipelineRun>,
+}
+
+impl DurationHistory {
+    /// Height of the widget.
+    pub const HEIGHT: u16 = 3;
+
+    pub fn new(runs: Vec<PipelineRun>) -> Self {
+        Self { runs }
+    }
+
+    fn annotation(&self) -> Line<'static> {
+        let average = self.runs.iter()
+            .map(|r| r.duration.num_seconds().max(0))
+            .sum::<i64>() / self.runs.len() as i64;
+        let slowest = self.runs.iter()
+            .max_by_key(|r| r.duration)
+            .unwrap();
+
+        Line::from(vec![
+            Span::from(t(Msg::DurationAverage)).style(theme().input_label),
+            Span::from(format_duration(chrono::Duration::seconds(average))).style(theme().time),
+            Span::from("  "),
+            Span::from(t(Msg::DurationSlowest)).style(theme().input_label),
+            Span::from(format_duration(slowest.duration)).style(theme().time),
+            Span::from(format!(" #{}", slowest.pipeline_id)).style(theme().pipeline_job),
+            Span::from(format!("  {} {}", self.runs.len(), t(Msg::DurationRuns))).style(theme().log_message),
+        ])
+    }
+}
+
+impl Widget for DurationHistory {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.runs.is_empty() {
+            return Line::from(t(Msg::NoDurationHistory))
+                .style(theme().log_message)
+                .render(area, buf);
+        }
+
+        let [sparkline_area, annotation_area] = Layout::vertical([
+            Constraint::Length(Self::HEIGHT - 1),
+            Constraint::Length(1),
+        ]).areas(area);
+
+        let durations: Vec<u64> = self.runs.iter()
+            .map(|r| r.duration.num_seconds().max(0) as u64)
+            .collect();
+
+        Sparkline::default()
+            .data(&durations)
+            .style(theme().progress_filled)
+            .render(sparkline_area, buf);
+
+        self.annotation()
+            .render(annotation_area, buf);
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::Utc;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::{Line, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Row, Table, TableState};
+use crate::domain::{Job, Pipeline, Project};
+use crate::i18n::{t, Msg};
+use crate::theme::theme;
+use crate::ui::format_age;
+use crate::ui::widget::Shortcuts;
+use super::projects_table::scroll_to_selected;
+
+/// Failed jobs of the latest pipeline of each branch, across all projects,
+/// most recently failed first. Rows are only built for the jobs in view.
+pub struct FailedJobsView<'a> {
+    jobs: &'a [(&'a Project, &'a Pipeline, &'a Job)],
+}
+
+impl<'a> FailedJobsView<'a> {
+    pub fn new(jobs: &'a [(&'a Project, &'a Pipeline, &'a Job)]) -> Self {
+        Self { jobs }
+    }
+}
+
+impl StatefulWidget for FailedJobsView<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+
+        let title = t(Msg::TitleFailedJobs)
+            .replace("{count}", &self.jobs.len().to_string());
+
+        let shortcuts = Shortcuts::from(vec![
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("↵",   t(Msg::ShortcutActions)),
+        ]);
+
+        Block::new()
+            .title(format!(" {title} "))
+            .title_style(theme().border_title)
+            .title_bottom(shortcuts.as_line())
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        let row_count = content_area.height as usize;
+        scroll_to_selected(state, row_count, self.jobs.len());
+
+        let now = Utc::now();
+        let offset = state.offset();
+        let rows: Vec<Row> = self.jobs.iter()
+            .enumerate()
+            .skip(offset)
+            .take(row_count)
+            .map(|(idx, (project, pipeline, job))| {
+                let failed_at = job.finished_at.unwrap_or(pipeline.updated_at);
+                Row::new([
+                    Line::from(format_age(now - failed_at)).style(theme().time),
+                    Line::from(project.title()).style(theme().project_name),
+                    Line::from(pipeline.branch.as_str()).style(theme().pipeline_branch),
+                    Line::from(job.stage.as_str()).style(theme().log_message),
+                    Line::from(job.name.as_str()).style(theme().pipeline_job_failed),
+                ]).style(theme().table_row(idx))
+            })
+            .collect();
+
+        let mut window_state = TableState::default()
+            .with_selected(state.selected().map(|idx| idx.saturating_sub(offset)));
+
+        let table = Table::new(rows, COLUMN_CONSTRAINTS)
+            .highlight_style(theme().highlight_symbol)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, content_area, buf, &mut window_state);
+    }
+}
+
+const COLUMN_CONSTRAINTS: [Constraint; 5] = [
+    Constraint::Length(4),       // age
+    Constraint::Min(24),         // project name
+    Constraint::Length(24),      // branch
+    Constraint::Length(12),      // stage
+    Constraint::Percentage(100), // job name
+];
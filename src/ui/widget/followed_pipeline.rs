@@ -0,0 +1,74 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, LineGauge};
+use crate::domain::{IconRepresentable, Pipeline, Project};
+use crate::theme::theme;
+use crate::ui::format_duration;
+use crate::i18n::{t, Msg};
+
+/// progress header for the pipeline currently being followed
+pub struct FollowedPipelineHeader<'a> {
+    project: &'a Project,
+    pipeline: &'a Pipeline,
+}
+
+impl<'a> FollowedPipelineHeader<'a> {
+    pub const HEIGHT: u16 = 3;
+
+    pub fn new(project: &'a Project, pipeline: &'a Pipeline) -> Self {
+        Self { project, pipeline }
+    }
+
+    /// returns the number of finished jobs and the total number of jobs
+    fn job_progress(&self) -> (usize, usize) {
+        self.pipeline.jobs.as_ref()
+            .map(|jobs| (jobs.iter().filter(|j| !j.status.is_active()).count(), jobs.len()))
+            .unwrap_or((0, 0))
+    }
+}
+
+impl Widget for FollowedPipelineHeader<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        Block::new()
+            .title(format!(" {} ", t(Msg::TitleFollowingPipeline)))
+            .title_style(theme().border_title)
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let (finished, total) = self.job_progress();
+        let ratio = if total == 0 { 0.0 } else { finished as f64 / total as f64 };
+
+        let summary = Line::from(vec![
+            Span::from(self.project.title()).style(theme().project_name),
+            Span::from(" "),
+            Span::from(self.pipeline.branch.as_str()).style(theme().pipeline_branch),
+            Span::from(" "),
+            Span::from(self.pipeline.labeled_icon()),
+            Span::from(" "),
+            Span::from(self.pipeline.active_job_name()).style(theme().pipeline_job),
+            Span::from(" "),
+            Span::from(format_duration(self.pipeline.duration())).style(theme().time),
+        ]);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        let [summary_area, gauge_area] = Layout::horizontal([
+            Constraint::Percentage(60),
+            Constraint::Percentage(40),
+        ]).areas(content_area);
+
+        summary.render(summary_area, buf);
+        LineGauge::default()
+            .ratio(ratio)
+            .label(format!("{finished}/{total} jobs"))
+            .style(theme().time)
+            .filled_style(theme().progress_filled)
+            .unfilled_style(theme().progress_unfilled)
+            .render(gauge_area, buf);
+    }
+}
@@ -1,13 +1,25 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Local};
+use itertools::Itertools;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Margin, Rect};
 use ratatui::prelude::{Line, Span, StatefulWidget};
 use ratatui::widgets::{Block, Borders, BorderType, Clear, List, ListState, Widget};
+use crate::fetch_state::FetchState;
+use crate::stores::LogLevel;
 use crate::theme::theme;
+use crate::i18n::{t, Msg};
 
 /// logs widget
 pub struct LogsWidget<'a> {
     logs: Vec<Line<'a>>,
+    /// number of projects in each fetch state, shown below the logs
+    fetch_summary: Option<String>,
+    /// effective page size of pipeline requests, shown below the logs
+    page_size: Option<u16>,
+    /// level of the logs, shown in the title
+    level: Option<LogLevel>,
 }
 
 impl<'a> LogsWidget<'a> {
@@ -21,9 +33,32 @@ impl<'a> LogsWidget<'a> {
                         Span::from(*log).style(theme().log_message),
                     ])
                 })
-                .collect()
+                .collect(),
+            fetch_summary: None,
+            page_size: None,
+            level: None,
         }
     }
+
+    pub fn fetch_summary(mut self, summary: HashMap<FetchState, usize>) -> Self {
+        let summary = summary.into_iter()
+            .sorted()
+            .map(|(state, count)| format!("{state}: {count}"))
+            .join(" · ");
+
+        self.fetch_summary = Some(summary);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u16) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn level(mut self, level: LogLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
 }
 
 impl<'a> StatefulWidget for LogsWidget<'a> {
@@ -32,13 +67,26 @@ impl<'a> StatefulWidget for LogsWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         Clear.render(area, buf);
 
-        Block::new()
-            .title(" internal logs ")
+        let title = match self.level {
+            Some(level) => format!(" {} · {level} ", t(Msg::TitleInternalLogs)),
+            None        => format!(" {} ", t(Msg::TitleInternalLogs)),
+        };
+
+        let mut block = Block::new()
+            .title(title)
             .title_style(theme().border_title)
             .borders(Borders::ALL)
             .border_style(theme().table_border)
-            .border_type(BorderType::Plain)
-            .render(area, buf);
+            .border_type(BorderType::Plain);
+
+        let status = self.fetch_summary.into_iter()
+            .chain(self.page_size.map(|size| format!("per_page: {size}")))
+            .join(" · ");
+
+        if !status.is_empty() {
+            block = block.title_bottom(Line::from(format!(" {status} ")).style(theme().border_title));
+        }
+        block.render(area, buf);
 
         let content_area = area.inner(Margin::new(2, 1));
         let logs = List::from_iter(self.logs);
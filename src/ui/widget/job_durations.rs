@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::{Line, Span, Widget};
+
+use crate::domain::Job;
+use crate::id::JobId;
+use crate::theme::theme;
+use crate::ui::format_duration;
+
+/// job durations widget. used inside the project details popup, drawing
+/// the duration of each job of the selected pipeline as a horizontal bar.
+///
+/// Jobs on the critical path, i.e. the longest job of each stage, are
+/// highlighted, as stages run in sequence:
+/// ```
+/// build  compile     ████████████████▏         3m23s
+/// test   unit-tests  ██████▏                   1m02s
+/// ```
+pub struct JobDurationChart {
+    bars: Vec<Bar>,
+    max_stage: u16,
+    max_name: u16,
+}
+
+struct Bar {
+    stage: String,
+    name: String,
+    seconds: i64,
+    critical: bool,
+}
+
+impl JobDurationChart {
+    pub fn new(jobs: &[Job]) -> Self {
+        let critical_path = Self::critical_path(jobs);
+        let bars: Vec<Bar> = jobs.iter()
+            .map(|j| Bar {
+                stage: j.stage.clone(),
+                name: j.name.clone(),
+                seconds: j.duration().num_seconds().max(0),
+                critical: critical_path.contains(&j.id),
+            })
+            .collect();
+
+        let (max_stage, max_name) = bars.iter()
+            .fold((5, 12), |(s, n), b| (
+                s.max(b.stage.chars().count()),
+                n.max(b.name.chars().count()),
+            ));
+
+        Self { bars, max_stage: max_stage as u16, max_name: max_name as u16 }
+    }
+
+    /// Returns the longest running job of each stage.
+    fn critical_path(jobs: &[Job]) -> Vec<JobId> {
+        let mut longest: HashMap<&str, &Job> = HashMap::new();
+        for job in jobs {
+            longest.entry(job.stage.as_str())
+                .and_modify(|j| if job.duration() > j.duration() { *j = job })
+                .or_insert(job);
+        }
+
+        longest.values()
+            .filter(|j| j.started_at.is_some())
+            .map(|j| j.id)
+            .collect()
+    }
+
+    fn bar_line(bar: &Bar, longest: i64, width: u16) -> Line<'static> {
+        const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+        let eighths = match longest {
+            0 => 0,
+            _ => (bar.seconds * width as i64 * 8 / longest) as usize,
+        };
+        let blocks = format!("{}{}", "█".repeat(eighths / 8), EIGHTHS[eighths % 8]);
+
+        let style = match bar.critical {
+            true  => theme().progress_filled,
+            false => theme().progress_unfilled,
+        };
+
+        Line::from(Span::from(blocks).style(style))
+    }
+}
+
+impl Widget for JobDurationChart {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let durations: Vec<String> = self.bars.iter()
+            .map(|b| format_duration(chrono::Duration::seconds(b.seconds)))
+            .collect();
+        let max_duration = durations.iter()
+            .map(|d| d.chars().count())
+            .max()
+            .unwrap_or(4) as u16;
+
+        let longest = self.bars.iter()
+            .map(|b| b.seconds)
+            .max()
+            .unwrap_or(0);
+
+        let rows = (0..area.height)
+            .map(|y| Rect { y: area.y + y, height: 1, ..area });
+
+        for ((bar, duration), row) in self.bars.iter().zip(durations).zip(rows) {
+            let [stage_area, name_area, bar_area, duration_area] = Layout::horizontal([
+                Constraint::Length(self.max_stage),
+                Constraint::Length(self.max_name),
+                Constraint::Percentage(100),
+                Constraint::Length(max_duration),
+            ]).spacing(1).areas(row);
+
+            Span::from(bar.stage.clone()).style(theme().pipeline_source)
+                .render(stage_area, buf);
+            Span::from(bar.name.clone()).style(theme().pipeline_job)
+                .render(name_area, buf);
+            Self::bar_line(bar, longest, bar_area.width)
+                .render(bar_area, buf);
+            Line::from(duration).style(theme().time).right_aligned()
+                .render(duration_area, buf);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::prelude::{Line, Span, StatefulWidget};
+use ratatui::style::Style;
+use ratatui::widgets::{Cell, Row, Table, TableState};
+use crate::domain::{IconRepresentable, Job, PipelineStatus, Stage};
+use crate::theme::theme;
+use crate::ui::format_duration;
+
+/// jobs widget. used inside the project details popup, listing the jobs
+/// of the selected pipeline grouped by stage.
+///
+/// Each stage is headed by a row with the aggregate status and duration of
+/// its jobs, followed by a row per job unless the stage is collapsed:
+/// ```
+/// STAGE   | JOB         | STATUS     | TIME
+/// ▾ build | 1 job       | 🟢 success |  3m23s
+///         | compile     | 🟢 success |  3m23s
+/// ▸ test  | 14 jobs     | 🔴 failed  |  6m40s
+/// ```
+#[derive(Clone)]
+pub struct JobTable {
+    pub constraints: [Constraint; 4],
+    pub rows: Vec<Row<'static>>,
+    focused: bool,
+}
+
+/// row of the [JobTable]
+#[derive(Clone, Debug)]
+pub enum JobRow<'a> {
+    Stage { stage: Stage<'a>, collapsed: bool },
+    Job(&'a Job),
+}
+
+impl<'a> JobRow<'a> {
+    /// Lists the stages, each followed by its jobs unless collapsed.
+    pub fn list(stages: Vec<Stage<'a>>, collapsed: &HashSet<String>) -> Vec<JobRow<'a>> {
+        let mut rows = Vec::new();
+        for stage in stages {
+            let is_collapsed = collapsed.contains(stage.name);
+            let jobs = stage.jobs.clone();
+
+            rows.push(JobRow::Stage { stage, collapsed: is_collapsed });
+            if !is_collapsed {
+                rows.extend(jobs.into_iter().map(JobRow::Job));
+            }
+        }
+
+        rows
+    }
+
+    /// Name of the stage of the row.
+    pub fn stage_name(&self) -> &'a str {
+        match self {
+            JobRow::Stage { stage, .. } => stage.name,
+            JobRow::Job(job)            => &job.stage,
+        }
+    }
+}
+
+impl JobTable {
+    pub fn new(rows: &[JobRow], focused: bool) -> Self {
+        let (max_stage, max_name, max_duration) = rows.iter()
+            .fold((5, 12, 4), |(s, n, d), row| match row {
+                JobRow::Stage { stage, .. } => (
+                    s.max(stage.name.chars().count() + 2),
+                    n.max(Self::job_count(stage).chars().count()),
+                    d.max(format_duration(stage.duration()).chars().count()),
+                ),
+                JobRow::Job(j) => (
+                    s,
+                    n.max(j.name.chars().count()),
+                    d.max(format_duration(j.duration()).chars().count()),
+                ),
+            });
+
+        Self {
+            constraints: [
+                Constraint::Length(max_stage as u16),
+                Constraint::Length(max_name as u16),
+                Constraint::Length(13),
+                Constraint::Length(max_duration as u16),
+            ],
+            rows: rows.iter()
+                .map(|row| match row {
+                    JobRow::Stage { stage, collapsed } => Self::parse_stage_row(stage, *collapsed),
+                    JobRow::Job(job)                   => Self::parse_row(job),
+                })
+                .enumerate()
+                .map(|(idx, r)| r.style(theme().table_row(idx)))
+                .collect(),
+            focused,
+        }
+    }
+
+    fn job_count(stage: &Stage) -> String {
+        match stage.jobs.len() {
+            1 => "1 job".to_string(),
+            n => format!("{n} jobs"),
+        }
+    }
+
+    fn parse_stage_row(stage: &Stage, collapsed: bool) -> Row<'static> {
+        let marker = if collapsed { "▸" } else { "▾" };
+        let status = stage.status();
+
+        Row::new(vec![
+            Cell::from(Span::from(format!("{marker} {}", stage.name)).style(theme().input_label)),
+            Cell::from(Span::from(Self::job_count(stage)).style(theme().pipeline_source)),
+            Cell::from(format!("{} {}", status.icon(), status)),
+            Cell::from(Line::from(format_duration(stage.duration()))
+                .style(theme().time)
+                .alignment(Alignment::Right)),
+        ])
+    }
+
+    fn parse_row(job: &Job) -> Row<'static> {
+        let name_style = if job.status == PipelineStatus::Failed {
+            theme().pipeline_job_failed
+        } else {
+            theme().pipeline_job
+        };
+
+        Row::new(vec![
+            Cell::default(),
+            Cell::from(Span::from(job.name.clone()).style(name_style)),
+            Cell::from(format!("{} {}", job.status.icon(), job.status)),
+            Cell::from(Line::from(format_duration(job.duration()))
+                .style(theme().time)
+                .alignment(Alignment::Right)),
+        ])
+    }
+}
+
+impl StatefulWidget for JobTable {
+    type State = TableState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        // the selection is only highlighted while the jobs pane has focus
+        let highlight = if self.focused { theme().highlight_symbol } else { Style::default() };
+        let table = Table::new(self.rows, self.constraints)
+            .highlight_style(highlight)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, area, buf, state);
+    }
+}
@@ -1,20 +1,54 @@
 mod pipeline_table;
+mod job_table;
+mod job_durations;
+mod duration_history;
+mod pipeline_graph;
 mod projects_table;
+mod project_tree;
 mod internal_logs;
 mod shortcuts;
 mod notification;
+mod followed_pipeline;
+mod unavailable_banner;
+mod offline_banner;
+mod status_bar;
+mod tab_bar;
+mod pipelines_view;
+mod multi_project_view;
+mod failed_jobs_view;
+mod selection_line;
 
 use chrono::{DateTime, Local};
 use ratatui::prelude::{Line, Text};
 pub use pipeline_table::*;
+pub use job_table::*;
+pub use job_durations::*;
+pub use duration_history::*;
+pub use pipeline_graph::*;
 pub use projects_table::*;
+pub use project_tree::*;
 pub use internal_logs::*;
 pub use shortcuts::*;
 pub use notification::*;
+pub use followed_pipeline::*;
+pub use unavailable_banner::*;
+pub use offline_banner::*;
+pub use status_bar::*;
+pub use tab_bar::*;
+pub use pipelines_view::*;
+pub use multi_project_view::*;
+pub use failed_jobs_view::*;
+pub use selection_line::*;
 use crate::theme::theme;
+use crate::ui::{relative_times, timestamp_spans};
 
 
+/// Date and time on two lines, or the age on one line if [relative_times] is set.
 pub fn text_from(date: DateTime<Local>) -> Text<'static> {
+    if relative_times() {
+        return Text::from(Line::from(timestamp_spans(date)));
+    }
+
     Text::from(vec![
         Line::from(date.format("%a, %d %b").to_string())
             .style(theme().date),
@@ -0,0 +1,121 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::{Line, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Row, Table, TableState};
+use crate::domain::IconRepresentable;
+use crate::i18n::{t, Msg};
+use crate::pipeline_tree::PipelineTreeRow;
+use crate::theme::theme;
+use crate::ui::format_duration;
+use crate::ui::widget::Shortcuts;
+use super::projects_table::scroll_to_selected;
+
+/// Multi-project pipelines as trees of upstream and downstream pipelines,
+/// see [pipeline_trees](crate::pipeline_tree::pipeline_trees). Rows are
+/// only built for the pipelines in view.
+pub struct MultiProjectView<'a> {
+    rows: &'a [PipelineTreeRow<'a>],
+}
+
+impl<'a> MultiProjectView<'a> {
+    pub fn new(rows: &'a [PipelineTreeRow<'a>]) -> Self {
+        Self { rows }
+    }
+}
+
+impl StatefulWidget for MultiProjectView<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+
+        let trees = self.rows.iter()
+            .filter(|row| row.depth == 0)
+            .count();
+        let title = t(Msg::TitleMultiProject)
+            .replace("{count}", &trees.to_string());
+
+        let shortcuts = Shortcuts::from(vec![
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("↵",   t(Msg::ShortcutDetails)),
+        ]);
+
+        Block::new()
+            .title(format!(" {title} "))
+            .title_style(theme().border_title)
+            .title_bottom(shortcuts.as_line())
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        if self.rows.is_empty() {
+            Line::from(t(Msg::NoMultiProjectPipelines))
+                .style(theme().log_message)
+                .render(content_area, buf);
+            return;
+        }
+
+        let row_count = content_area.height as usize;
+        scroll_to_selected(state, row_count, self.rows.len());
+
+        let offset = state.offset();
+        let rows: Vec<Row> = self.rows.iter()
+            .enumerate()
+            .skip(offset)
+            .take(row_count)
+            .map(|(idx, row)| tree_row(row).style(theme().table_row(idx)))
+            .collect();
+
+        let mut window_state = TableState::default()
+            .with_selected(state.selected().map(|idx| idx.saturating_sub(offset)));
+
+        let table = Table::new(rows, COLUMN_CONSTRAINTS)
+            .highlight_style(theme().highlight_symbol)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, content_area, buf, &mut window_state);
+    }
+}
+
+fn tree_row<'a>(row: &PipelineTreeRow<'a>) -> Row<'a> {
+    let indent = match row.depth {
+        0 => String::new(),
+        depth => format!("{}└ ", "  ".repeat(depth - 1)),
+    };
+
+    // progress of the whole tree for the root, the starting trigger job otherwise
+    let trigger = match (row.progress, row.trigger) {
+        (Some((completed, total)), _) => format!("{completed}/{total} completed"),
+        (None, Some(job))             => job.to_string(),
+        (None, None)                  => String::new(),
+    };
+
+    match row.pipeline {
+        Some((project, p)) => Row::new([
+            Line::from(format!("{indent}{}", project.title())).style(theme().project_name),
+            Line::from(format!("{} {}", p.status.icon(), p.status)),
+            Line::from(p.branch.as_str()).style(theme().pipeline_branch),
+            Line::from(trigger).style(theme().log_message),
+            Line::from(format_duration(p.duration())).style(theme().time),
+        ]),
+        // e.g. a project not in the projects table
+        None => Row::new([
+            Line::from(format!("{indent}project_id={} pipeline_id={}", row.id.0, row.id.1)).style(theme().log_message),
+            Line::from("?"),
+            Line::default(),
+            Line::from(trigger).style(theme().log_message),
+            Line::default(),
+        ]),
+    }
+}
+
+const COLUMN_CONSTRAINTS: [Constraint; 5] = [
+    Constraint::Min(32),         // project name, indented by depth
+    Constraint::Length(12),      // status
+    Constraint::Percentage(100), // branch
+    Constraint::Length(24),      // trigger job, or progress of the tree
+    Constraint::Length(8),       // duration
+];
@@ -1,6 +1,11 @@
+use crate::capabilities::accessible;
+use crate::i18n::{t, Msg};
+use crate::domain::IconRepresentable;
 use crate::notice_service::{Notice, NoticeMessage};
 use crate::stores::ProjectStore;
 use crate::theme::theme;
+use crate::watch::WatchMatch;
+use crate::ui::fx::{is_enabled, FxId};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Margin, Rect};
 use ratatui::prelude::StatefulWidget;
@@ -34,17 +39,23 @@ impl NotificationState {
         let project_name = match notice.message {
             NoticeMessage::GeneralMessage(_) |
             NoticeMessage::ConfigError(_) |
+            NoticeMessage::RetriesExhausted(_, _) |
             NoticeMessage::JsonDeserializeError(_, _) => None,
             NoticeMessage::JobLogDownloaded(id, _, _) |
             NoticeMessage::GitlabGetJobsError(id, _, _) |
             NoticeMessage::GitlabGetTriggerJobsError(id, _, _) |
-            NoticeMessage::GitlabGetPipelinesError(id, _, _) => project_lookup.find(id).map(|p| p.title()),
+            NoticeMessage::GitlabGetPipelinesError(id, _) |
+            NoticeMessage::WatchMatched(WatchMatch { project_id: id, .. }) => project_lookup.find(id).map(|p| p.title()),
         };
 
         Self {
             notice,
             project_name,
-            effect: effect::notification_effect(),
+            effect: if accessible() {
+                effect::steady_effect()
+            } else {
+                effect::notification_effect(is_enabled(FxId::NotificationBlink))
+            },
         }
     }
 }
@@ -61,43 +72,54 @@ impl StatefulWidget for Notification {
     type State = NotificationState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let project: &str = if let Some(p) = &state.project_name { p } else { "<unknown project>" };
+        let project: &str = if let Some(p) = &state.project_name { p } else { t(Msg::NoticeUnknownProject) };
 
         let text: Line<'_> = match &state.notice.message {
             NoticeMessage::GeneralMessage(s) => Line::from(Span::from(s)),
             NoticeMessage::ConfigError(s) => Line::from(vec![
-                Span::from("Config error: "),
+                Span::from(t(Msg::NoticeConfigError)),
+                Span::from(s),
+            ]),
+            NoticeMessage::RetriesExhausted(retries, s) => Line::from(vec![
+                Span::from(t(Msg::NoticeRetriesExhausted).replace("{retries}", &retries.to_string())),
                 Span::from(s),
             ]),
             NoticeMessage::JsonDeserializeError(cat, s) => Line::from(vec![
-                Span::from("Failed to parse JSON ("),
+                Span::from(t(Msg::NoticeJsonParseFailed)),
+                Span::from(" ("),
                 Span::from(format!("{:?}", cat)),
                 Span::from(")"),
                 Span::from(s),
             ]),
             NoticeMessage::GitlabGetJobsError(_, _, s) => Line::from(vec![
-                Span::from("Failed to get jobs for "),
+                Span::from(t(Msg::NoticeGetJobsFailed)),
                 Span::from(project)
                     .style(theme().notification_project),
                 Span::from(": "),
                 Span::from(s),
             ]),
             NoticeMessage::GitlabGetTriggerJobsError(_, _, s) => Line::from(vec![
-                Span::from("Failed to get trigger jobs for "),
+                Span::from(t(Msg::NoticeGetTriggerJobsFailed)),
                 Span::from(project)
                     .style(theme().notification_project),
                 Span::from(": "),
                 Span::from(s),
             ]),
-            NoticeMessage::GitlabGetPipelinesError(_, _, s) => Line::from(vec![
-                Span::from("Failed to get pipelines for "),
+            NoticeMessage::GitlabGetPipelinesError(_, s) => Line::from(vec![
+                Span::from(t(Msg::NoticeGetPipelinesFailed)),
                 Span::from(project)
                     .style(theme().notification_project),
                 Span::from(": "),
                 Span::from(s),
             ]),
+            NoticeMessage::WatchMatched(m) => Line::from(vec![
+                Span::from(format!("{} ", m.status.icon())),
+                Span::from(project)
+                    .style(theme().notification_project),
+                Span::from(format!(" {}: {}", m.branch, m.outcome())),
+            ]),
             NoticeMessage::JobLogDownloaded(_, _, _) => Line::from(vec![
-                Span::from("Finished downloading job log for "),
+                Span::from(t(Msg::NoticeJobLogDownloadedFor)),
                 Span::from(project)
                     .style(theme().notification_project),
             ]),
@@ -126,7 +148,16 @@ mod effect {
     use tachyonfx::Interpolation::{SineIn, SineOut};
     use tachyonfx::{fx, Duration, Effect};
 
-    pub(super) fn notification_effect() -> Effect {
+    /// Effect of a notification; without `blink`, the text is shown steadily.
+    pub(super) fn notification_effect(blink: bool) -> Effect {
+        let shown = if blink {
+            fx::repeating(fx::ping_pong(
+                fx::hsl_shift_fg([0.0, 0.0, 25.0], (500, SineOut))
+            ))
+        } else {
+            fx::sleep(6000)
+        };
+
         fx::sequence(&[
             // 1. clear the border (border is already cleared, so we first fill it back in)
             fx::parallel(&[
@@ -136,11 +167,7 @@ mod effect {
             // 2. fade in notification text
             fx::fade_from_fg(Dark0Hard, (250, SineOut)),
             // 3. smooth blink while notification is shown
-            fx::with_duration(Duration::from_millis(6000),
-                fx::repeating(fx::ping_pong(
-                    fx::hsl_shift_fg([0.0, 0.0, 25.0], (500, SineOut))
-                )),
-            ),
+            fx::with_duration(Duration::from_millis(6000), shown),
             // 4. fade out notification text and then redraw border
             fx::prolong_end(Duration::from_millis(100),
                 fx::fade_to_fg(Dark0Hard, (250, SineIn))),
@@ -151,6 +178,11 @@ mod effect {
         ])
     }
 
+    /// Effect of a notification in accessibility mode, shown without motion.
+    pub(super) fn steady_effect() -> Effect {
+        fx::sleep(6700)
+    }
+
     fn draw_border(duration: Duration) -> Effect {
         fx::effect_fn((), duration, |_, _, cells| {
             cells.for_each(|(_, cell)| { cell.set_char('─'); });
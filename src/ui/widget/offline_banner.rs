@@ -0,0 +1,34 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::Clear;
+use crate::theme::theme;
+use crate::i18n::{t, Msg};
+
+/// banner shown above the projects table while GitLab is unreachable
+pub struct OfflineBanner {
+    retry_in: std::time::Duration,
+}
+
+impl OfflineBanner {
+    pub const HEIGHT: u16 = 1;
+
+    pub fn new(retry_in: std::time::Duration) -> Self {
+        Self { retry_in }
+    }
+}
+
+impl Widget for OfflineBanner {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        // rounded up, so that the countdown ends at 1s rather than 0s
+        let seconds = self.retry_in.as_millis().div_ceil(1000);
+        let message = t(Msg::BannerOffline)
+            .replace("{seconds}", &seconds.to_string());
+
+        Line::from(Span::from(format!(" ⚠ {message} ")))
+            .style(theme().notification)
+            .render(area, buf);
+    }
+}
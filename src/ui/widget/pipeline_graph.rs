@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::style::Style;
+
+use crate::domain::{IconRepresentable, Job};
+use crate::theme::theme;
+
+/// rows above the first job of each stage: stage name and a spacer
+const HEADER_ROWS: u16 = 2;
+
+const UP: u8    = 0b0001;
+const DOWN: u8  = 0b0010;
+const LEFT: u8  = 0b0100;
+const RIGHT: u8 = 0b1000;
+
+/// stage graph widget. used inside the pipeline graph popup.
+///
+/// Jobs are grouped by stage into columns. `needs` relations between jobs of
+/// adjacent stages are drawn as edges in the gutter between the columns; needs
+/// on jobs of earlier stages are listed after the job name.
+/// ```
+/// build          test                  deploy
+///
+/// 🟢 compile ─╮─▸ 🟢 unit-tests         🔴 deploy-prod ◂ compile
+///             ╰─▸ 🟢 integration ────▸
+/// ```
+pub struct PipelineGraph {
+    columns: Vec<StageColumn>,
+    /// index of the first visible column
+    offset: usize,
+}
+
+struct StageColumn {
+    stage: String,
+    nodes: Vec<GraphNode>,
+    /// edges from the previous column, as (source row, target row)
+    incoming: Vec<(usize, usize)>,
+}
+
+struct GraphNode {
+    label: Line<'static>,
+    /// whether the node is the source of an edge into the next column
+    has_outgoing: bool,
+}
+
+impl PipelineGraph {
+    /// Lays out the most recent run of each job. Without `needs`, only the
+    /// stage columns are shown.
+    pub fn new(
+        jobs: &[Job],
+        needs: Option<&HashMap<String, Vec<String>>>,
+        offset: usize,
+    ) -> Self {
+        // retried jobs share the name of the original job
+        let jobs: Vec<&Job> = jobs.iter()
+            .sorted_by_key(|j| std::cmp::Reverse(j.id))
+            .unique_by(|j| j.name.as_str())
+            .sorted_by_key(|j| j.id)
+            .collect();
+
+        // jobs are created stage by stage, so ids follow the stage order
+        let stages: Vec<&str> = jobs.iter()
+            .map(|j| j.stage.as_str())
+            .unique()
+            .collect();
+
+        let grouped: Vec<Vec<&Job>> = stages.iter()
+            .map(|stage| jobs.iter().filter(|j| j.stage == *stage).copied().collect())
+            .collect();
+
+        let positions: HashMap<&str, (usize, usize)> = grouped.iter()
+            .enumerate()
+            .flat_map(|(col, jobs)| jobs.iter()
+                .enumerate()
+                .map(move |(row, j)| (j.name.as_str(), (col, row))))
+            .collect();
+
+        let mut columns: Vec<StageColumn> = Vec::new();
+        for (col, stage_jobs) in grouped.iter().enumerate() {
+            let mut incoming = Vec::new();
+            let mut distant_needs = Vec::new();
+
+            for (row, job) in stage_jobs.iter().enumerate() {
+                let job_needs = needs
+                    .and_then(|n| n.get(&job.name))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+
+                let mut distant = Vec::new();
+                for need in job_needs {
+                    match positions.get(need.as_str()) {
+                        Some(&(c, r)) if c + 1 == col => {
+                            incoming.push((r, row));
+                            columns[c].nodes[r].has_outgoing = true;
+                        },
+                        Some(_) => distant.push(need.clone()),
+                        None    => (), // optional needs on jobs absent from the pipeline
+                    }
+                }
+                distant_needs.push(distant);
+            }
+
+            let nodes = stage_jobs.iter()
+                .zip(distant_needs)
+                .map(|(job, distant)| GraphNode {
+                    label: node_label(job, &distant),
+                    has_outgoing: false,
+                })
+                .collect();
+
+            columns.push(StageColumn { stage: stages[col].to_string(), nodes, incoming });
+        }
+
+        let offset = offset.min(columns.len().saturating_sub(1));
+        Self { columns, offset }
+    }
+
+    pub fn stage_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Height required to display all jobs.
+    pub fn height(&self) -> u16 {
+        let max_jobs = self.columns.iter()
+            .map(|c| c.nodes.len())
+            .max()
+            .unwrap_or(0);
+
+        HEADER_ROWS + max_jobs as u16
+    }
+}
+
+impl StageColumn {
+    fn width(&self) -> u16 {
+        self.nodes.iter()
+            .map(|n| n.label.width())
+            .chain(std::iter::once(self.stage.chars().count()))
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    /// Source rows of the incoming edges, each assigned its own lane in the gutter.
+    fn lanes(&self) -> Vec<usize> {
+        self.incoming.iter()
+            .map(|(source, _)| *source)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Width of the gutter left of the column.
+    fn gutter_width(&self) -> u16 {
+        match self.lanes().len() {
+            0     => 3,
+            lanes => lanes as u16 + 4,
+        }
+    }
+}
+
+fn node_label(job: &Job, distant_needs: &[String]) -> Line<'static> {
+    let mut spans = vec![
+        Span::from(format!("{} ", job.status.labeled_icon())),
+        Span::from(job.name.clone()).style(theme().job_status(&job.status)),
+    ];
+
+    if !distant_needs.is_empty() {
+        spans.push(Span::from(format!(" ◂ {}", distant_needs.join(", "))).style(theme().graph_edge));
+    }
+
+    Line::from(spans)
+}
+
+/// Box-drawing character for a cell connected in the given directions.
+fn edge_char(bits: u8) -> char {
+    match bits {
+        b if b == LEFT | RIGHT | UP | DOWN => '┼',
+        b if b == LEFT | RIGHT | DOWN      => '┬',
+        b if b == LEFT | RIGHT | UP        => '┴',
+        b if b == UP | DOWN | RIGHT        => '├',
+        b if b == UP | DOWN | LEFT         => '┤',
+        b if b == DOWN | RIGHT             => '╭',
+        b if b == DOWN | LEFT              => '╮',
+        b if b == UP | RIGHT               => '╰',
+        b if b == UP | LEFT                => '╯',
+        b if b & (UP | DOWN) != 0          => '│',
+        _                                  => '─',
+    }
+}
+
+/// Cells of the edges in a gutter, keyed by (x, y) relative to the gutter.
+#[derive(Default)]
+struct EdgeGrid {
+    cells: HashMap<(u16, u16), u8>,
+}
+
+impl EdgeGrid {
+    fn connect(&mut self, x: u16, y: u16, bits: u8) {
+        *self.cells.entry((x, y)).or_default() |= bits;
+    }
+
+    fn horizontal(&mut self, x0: u16, x1: u16, y: u16) {
+        for x in x0..=x1 {
+            if x > x0 { self.connect(x, y, LEFT); }
+            if x < x1 { self.connect(x, y, RIGHT); }
+        }
+    }
+
+    fn vertical(&mut self, x: u16, y0: u16, y1: u16) {
+        let (top, bottom) = (y0.min(y1), y0.max(y1));
+        for y in top..=bottom {
+            if y > top    { self.connect(x, y, UP); }
+            if y < bottom { self.connect(x, y, DOWN); }
+        }
+    }
+}
+
+/// Writes the string, clipped to the area.
+fn put(buf: &mut Buffer, area: Rect, x: u16, y: u16, s: &str, style: Style) {
+    if x < area.right() && y < area.bottom() {
+        buf.set_stringn(x, y, s, (area.right() - x) as usize, style);
+    }
+}
+
+impl Widget for PipelineGraph {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut x = area.x;
+        let mut previous: Option<(&StageColumn, u16)> = None;
+
+        for column in self.columns.iter().skip(self.offset) {
+            if let Some((prev, prev_x)) = previous {
+                let gutter_x = prev_x + prev.width() + 1;
+                render_gutter(buf, area, prev, prev_x, column, gutter_x);
+                x = gutter_x + column.gutter_width();
+            }
+            if x >= area.right() { break; }
+
+            put(buf, area, x, area.y, &column.stage, theme().input_label);
+            for (row, node) in column.nodes.iter().enumerate() {
+                let y = area.y + HEADER_ROWS + row as u16;
+                if y >= area.bottom() { break; }
+
+                let width = area.right().saturating_sub(x);
+                buf.set_line(x, y, &node.label, width);
+            }
+
+            previous = Some((column, x));
+        }
+    }
+}
+
+/// Draws the edges between two adjacent columns. Each source node gets its own
+/// lane, from which edges branch out to the targets.
+fn render_gutter(
+    buf: &mut Buffer,
+    area: Rect,
+    source: &StageColumn,
+    source_x: u16,
+    target: &StageColumn,
+    gutter_x: u16,
+) {
+    let lanes = target.lanes();
+    let lead_in = lanes.len() as u16 + 1;
+
+    let mut grid = EdgeGrid::default();
+    for (lane, source_row) in lanes.iter().enumerate() {
+        let lane_x = 1 + lane as u16;
+        let source_y = *source_row as u16;
+
+        grid.connect(0, source_y, LEFT);
+        grid.horizontal(0, lane_x, source_y);
+        target.incoming.iter()
+            .filter(|(s, _)| s == source_row)
+            .for_each(|(_, target_row)| {
+                let target_y = *target_row as u16;
+                grid.vertical(lane_x, source_y, target_y);
+                grid.horizontal(lane_x, lead_in, target_y);
+                grid.connect(lead_in, target_y, RIGHT);
+            });
+    }
+
+    // extend the edges from the end of each source label to the gutter
+    for (row, node) in source.nodes.iter().enumerate().filter(|(_, n)| n.has_outgoing) {
+        let y = area.y + HEADER_ROWS + row as u16;
+        let start = source_x + node.label.width() as u16 + 1;
+        for x in start..gutter_x {
+            put(buf, area, x, y, "─", theme().graph_edge);
+        }
+    }
+
+    for ((x, y), bits) in grid.cells {
+        put(buf, area, gutter_x + x, area.y + HEADER_ROWS + y, &edge_char(bits).to_string(), theme().graph_edge);
+    }
+
+    target.incoming.iter()
+        .map(|(_, target_row)| *target_row)
+        .unique()
+        .for_each(|row| {
+            let y = area.y + HEADER_ROWS + row as u16;
+            put(buf, area, gutter_x + lead_in + 1, y, "▸", theme().graph_edge);
+        });
+}
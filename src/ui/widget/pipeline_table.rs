@@ -1,35 +1,46 @@
+use std::collections::HashMap;
+
 use chrono::Local;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Rect};
 use ratatui::prelude::{Line, Span, StatefulWidget, Text};
 use ratatui::widgets::{Cell, Row, Table, TableState};
-use crate::domain::{IconRepresentable, Pipeline};
-use crate::id::PipelineId;
+use crate::domain::{Approvals, IconRepresentable, Pipeline};
 use crate::theme::theme;
 use crate::ui::format_duration;
 use crate::ui::widget::text_from;
+use crate::i18n::{t, Msg};
 
 /// pipelines widget. used inside the project details popup.
 ///
 /// Each pipeline is represented as a row in the table, with the following format:
 /// ```
-/// #BRANCH| PIPELNE/JOB | TIME   | %DONE | COMMENT
-/// main   | 🔵🔵🔵🔵🔵 | 14m24s  | ~72%  | Merge branch 'renovate/all-minor-dependencies'
-///        | deploy-prod |  3m23s | ~40%  |  into 'main'
+/// #BRANCH| PIPELNE/JOB | TIME   | APPROVALS | COMMENT
+/// main   | 🔵🔵🔵🔵🔵 | 14m24s  |           | Merge branch 'renovate/all-minor-dependencies'
+///        | deploy-prod |  3m23s |           |  into 'main'
 /// ```
+/// The approvals column is only populated for merge request pipelines.
 #[derive(Clone)]
 pub struct PipelineTable {
-    pub constraints: [Constraint; 5],
+    pub constraints: [Constraint; 6],
     pub rows: Vec<Row<'static>>,
-    pub ids: Vec<PipelineId>
 }
 
 impl PipelineTable {
-    pub fn new(pipelines: &[&Pipeline]) -> Self {
+    pub fn new(pipelines: &[&Pipeline], approvals: &HashMap<u32, Approvals>) -> Self {
+        let approvals_of = |p: &Pipeline| p.merge_request_iid()
+            .and_then(|iid| approvals.get(&iid))
+            .copied();
+        let max_approvals = pipelines.iter()
+            .filter_map(|&p| approvals_of(p))
+            .map(|a| Self::approvals_count(&a).chars().count().max(t(Msg::PipelineApprovals).chars().count()))
+            .max()
+            .unwrap_or(0);
+
         let (max_branch, max_job_name, max_failed_job_name, max_duration) = pipelines.iter()
             .fold((5, 12, 12, 4), |(b, j, f, d), p| (
                 b.max(p.branch.chars().count()),
-                j.max(p.active_job_name().chars().count()).max(p.jobs.clone().map(|j| j.len() * 2).unwrap_or(0)),
+                j.max(p.active_job_name().chars().count()).max(p.stages().len() * 2),
                 f.max(p.failing_job_name().map(|j| j.chars().count()).unwrap_or(0)),
                 d.max(format_duration(p.duration()).chars().count()),
                 // pe.max("NA%".chars().count()),
@@ -41,18 +52,18 @@ impl PipelineTable {
                 Constraint::Length(max_branch as u16),
                 Constraint::Length(max_job_name.max(max_failed_job_name) as u16),
                 Constraint::Length(max_duration as u16),
+                Constraint::Length(max_approvals as u16),
                 Constraint::Percentage(100),
             ],
             rows: pipelines.iter()
-                .map(|p| Self::parse_row(p))
+                .map(|p| Self::parse_row(p, approvals_of(p)))
                 .enumerate()
                 .map(|(idx, r)| r.style(theme().table_row(idx)))
                 .collect(),
-            ids: pipelines.iter().map(|p| p.id).collect(),
         }
     }
 
-    fn parse_row(p: &Pipeline) -> Row<'static> {
+    fn parse_row(p: &Pipeline, approvals: Option<Approvals>) -> Row<'static> {
         let branch = p.branch.clone();
 
         let comment = if let Some(commit) = &p.commit {
@@ -71,6 +82,7 @@ impl PipelineTable {
             branch_cell,
             Self::pipeline_jobs_cell(p),
             Self::pipeline_duration_cell(p),
+            Self::approvals_cell(approvals),
             // Self::pipeline_percentages_cell(p),
             Cell::from(Span::from(comment).style(theme().commit_title)),
         ]).height(2)
@@ -87,14 +99,51 @@ impl PipelineTable {
             Line::from(p.active_job_name()).style(theme().pipeline_job)
         };
 
+        // one icon per stage; large pipelines don't fit one icon per job
+        let stages = p.stages();
+        let icon = match stages.is_empty() {
+            true  => p.status.labeled_icon(),
+            false => stages.labeled_icon(),
+        };
+
+        // deleted pipelines, or trigger jobs that could not be fetched
+        let icons = match (p.gone, p.partial_jobs) {
+            (true, _)     => Line::from(vec![Span::from(icon), Span::from(format!(" {}", t(Msg::PipelineGone))).style(theme().log_message)]),
+            (false, true) => Line::from(vec![Span::from(icon), Span::from(" …").style(theme().log_message)]),
+            _             => Line::from(icon),
+        };
+
         let content = Text::from(vec![
-            Line::from(p.icon()),
+            icons,
             branch_name,
         ]);
 
         Cell::from(content)
     }
 
+    fn approvals_cell(approvals: Option<Approvals>) -> Cell<'static> {
+        let Some(approvals) = approvals else { return Cell::default() };
+
+        let style = if approvals.is_approved() {
+            theme().job_status_success
+        } else {
+            theme().pipeline_job
+        };
+
+        Cell::from(Text::from(vec![
+            Line::from(Self::approvals_count(&approvals)).style(style),
+            Line::from(t(Msg::PipelineApprovals)).style(theme().pipeline_source),
+        ]))
+    }
+
+    /// e.g. "2/3", or "2" when no approvals are required
+    fn approvals_count(approvals: &Approvals) -> String {
+        match approvals.required {
+            0        => approvals.approved.to_string(),
+            required => format!("{}/{required}", approvals.approved),
+        }
+    }
+
     fn pipeline_duration_cell(p: &Pipeline) -> Cell<'static> {
         let active_job_duration = p.active_job()
             .map(|j| j.duration())
@@ -0,0 +1,89 @@
+use chrono::Local;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::{Line, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Row, Table, TableState};
+use crate::domain::{IconRepresentable, Pipeline, Project};
+use crate::i18n::{t, Msg};
+use crate::theme::theme;
+use crate::ui::{format_duration, timestamp_spans};
+use crate::ui::widget::Shortcuts;
+use super::projects_table::scroll_to_selected;
+
+/// The most recent pipelines across all projects, most recently updated
+/// first. Rows are only built for the pipelines in view.
+pub struct PipelinesView<'a> {
+    pipelines: &'a [(&'a Project, &'a Pipeline)],
+}
+
+impl<'a> PipelinesView<'a> {
+    pub fn new(pipelines: &'a [(&'a Project, &'a Pipeline)]) -> Self {
+        Self { pipelines }
+    }
+}
+
+impl StatefulWidget for PipelinesView<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+
+        let active = self.pipelines.iter()
+            .filter(|(_, p)| p.status.is_active())
+            .count();
+        let title = t(Msg::TitleRecentPipelines)
+            .replace("{active}", &active.to_string());
+
+        let shortcuts = Shortcuts::from(vec![
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("↵",   t(Msg::ShortcutDetails)),
+        ]);
+
+        Block::new()
+            .title(format!(" {title} "))
+            .title_style(theme().border_title)
+            .title_bottom(shortcuts.as_line())
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        let row_count = content_area.height as usize;
+        scroll_to_selected(state, row_count, self.pipelines.len());
+
+        let offset = state.offset();
+        let rows: Vec<Row> = self.pipelines.iter()
+            .enumerate()
+            .skip(offset)
+            .take(row_count)
+            .map(|(idx, (project, p))| {
+                Row::new([
+                    Line::from(timestamp_spans(p.updated_at.with_timezone(&Local))),
+                    Line::from(format!("{} {}", p.status.icon(), p.status)),
+                    Line::from(project.title()).style(theme().project_name),
+                    Line::from(p.branch.as_str()).style(theme().pipeline_branch),
+                    Line::from(format_duration(p.duration())).style(theme().time),
+                ]).style(theme().table_row(idx))
+            })
+            .collect();
+
+        let mut window_state = TableState::default()
+            .with_selected(state.selected().map(|idx| idx.saturating_sub(offset)));
+
+        let table = Table::new(rows, COLUMN_CONSTRAINTS)
+            .highlight_style(theme().highlight_symbol)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, content_area, buf, &mut window_state);
+    }
+}
+
+const COLUMN_CONSTRAINTS: [Constraint; 5] = [
+    Constraint::Length(16),      // date and time
+    Constraint::Length(12),      // status
+    Constraint::Min(24),         // project name
+    Constraint::Percentage(100), // branch
+    Constraint::Length(8),       // duration
+];
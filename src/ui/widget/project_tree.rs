@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::prelude::StatefulWidget;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Row, Table, TableState, Widget};
+
+use crate::domain::{pinned_icon, IconRepresentable, Project};
+use crate::i18n::{t, Msg};
+use crate::id::ProjectId;
+use crate::theme::theme;
+use crate::ui::widget::Shortcuts;
+use super::projects_table::scroll_to_selected;
+
+/// Projects table with the projects nested under their namespace, in
+/// collapsible groups. Each project takes a single line.
+pub struct ProjectTree<'a> {
+    projects: &'a [Project],
+    is_pinned: Box<dyn Fn(ProjectId) -> bool + 'a>,
+}
+
+/// Collapsed groups and selection of the [ProjectTree].
+#[derive(Debug, Default)]
+pub struct ProjectTreeState {
+    /// namespaces of the collapsed groups
+    collapsed: HashSet<String>,
+    selected: Option<TreeKey>,
+    table_state: TableState,
+    /// project indices in view, as of the last render
+    viewport: Range<usize>,
+    /// areas of the project rows in view, as of the last render
+    row_areas: Vec<(ProjectId, Rect)>,
+}
+
+/// Identifies a row of the tree, which stays the same as projects are re-sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TreeKey {
+    Group(String),
+    Project(ProjectId),
+}
+
+enum TreeRow<'a> {
+    Group {
+        namespace: &'a str,
+        name: &'a str,
+        depth: usize,
+        /// number of projects in the group and its subgroups
+        count: usize,
+        collapsed: bool,
+    },
+    Project {
+        /// index of the project in the projects table
+        idx: usize,
+        project: &'a Project,
+        depth: usize,
+    },
+}
+
+/// A namespace of the tree, built from the paths of the projects.
+#[derive(Default)]
+struct GroupNode<'a> {
+    namespace: &'a str,
+    groups: BTreeMap<&'a str, GroupNode<'a>>,
+    projects: Vec<usize>,
+    count: usize,
+}
+
+impl<'a> ProjectTree<'a> {
+    pub fn new(
+        projects: &'a [Project],
+        is_pinned: impl Fn(ProjectId) -> bool + 'a,
+    ) -> Self {
+        Self { projects, is_pinned: Box::new(is_pinned) }
+    }
+}
+
+impl ProjectTreeState {
+    /// Creates the tree state, with the project selected.
+    pub fn new(selected: Option<ProjectId>) -> Self {
+        Self {
+            selected: selected.map(TreeKey::Project),
+            ..Self::default()
+        }
+    }
+
+    /// Moves the selection up or down, returning the selected project, if a
+    /// project row was selected.
+    pub fn select(&mut self, direction: i32, projects: &[Project]) -> Option<ProjectId> {
+        let rows = self.rows(projects);
+        if rows.is_empty() { return None; }
+
+        let current = self.selected_idx(&rows) as i32;
+        let row = &rows[(current + direction).clamp(0, rows.len() as i32 - 1) as usize];
+        self.selected = Some(row.key());
+
+        match row {
+            TreeRow::Project { project, .. } => Some(project.id),
+            TreeRow::Group { .. }            => None,
+        }
+    }
+
+    /// Selects the project, expanding the groups it is nested in.
+    pub fn reveal(&mut self, id: ProjectId, projects: &[Project]) {
+        if let Some(project) = projects.iter().find(|p| p.id == id) {
+            self.collapsed.retain(|namespace| !project.path.starts_with(&format!("{namespace}/")));
+            self.selected = Some(TreeKey::Project(id));
+        }
+    }
+
+    /// Collapses the selected group; on a project or a collapsed group, the
+    /// enclosing group is selected instead.
+    pub fn collapse(&mut self, projects: &[Project]) {
+        match self.selected.clone() {
+            Some(TreeKey::Group(namespace)) if !self.collapsed.contains(&namespace) => {
+                self.collapsed.insert(namespace);
+            },
+            Some(TreeKey::Group(namespace)) => {
+                if let Some(i) = namespace.rfind('/') {
+                    self.selected = Some(TreeKey::Group(namespace[..i].to_string()));
+                }
+            },
+            Some(TreeKey::Project(id)) => {
+                let namespace = projects.iter()
+                    .find(|p| p.id == id)
+                    .and_then(|p| p.path.rfind('/').map(|i| &p.path[..i]));
+
+                if let Some(namespace) = namespace {
+                    self.selected = Some(TreeKey::Group(namespace.to_string()));
+                }
+            },
+            None => (),
+        }
+    }
+
+    /// Expands the selected group.
+    pub fn expand(&mut self) {
+        if let Some(TreeKey::Group(namespace)) = self.selected.as_ref() {
+            self.collapsed.remove(namespace);
+        }
+    }
+
+    /// Project indices in view, for fetching their pipelines ahead of the rest.
+    pub fn viewport(&self) -> Range<usize> {
+        self.viewport.clone()
+    }
+
+    /// Returns the area of the project row, if in view.
+    pub fn row_area(&self, id: ProjectId) -> Option<Rect> {
+        self.row_areas.iter()
+            .find(|(project_id, _)| *project_id == id)
+            .map(|(_, area)| *area)
+    }
+
+    fn rows<'a>(&self, projects: &'a [Project]) -> Vec<TreeRow<'a>> {
+        let mut root = GroupNode::default();
+        projects.iter()
+            .enumerate()
+            .for_each(|(idx, p)| root.insert(idx, &p.path));
+
+        let mut rows = Vec::new();
+        root.flatten(0, &self.collapsed, projects, &mut rows);
+        rows
+    }
+
+    fn selected_idx(&self, rows: &[TreeRow]) -> usize {
+        self.selected.as_ref()
+            .and_then(|key| rows.iter().position(|row| row.key() == *key))
+            .unwrap_or(0)
+    }
+}
+
+impl<'a> GroupNode<'a> {
+    fn insert(&mut self, idx: usize, path: &'a str) {
+        let mut node = self;
+        let mut start = 0;
+        for (end, _) in path.match_indices('/') {
+            node = node.groups.entry(&path[start..end])
+                .or_insert_with(|| GroupNode { namespace: &path[..end], ..GroupNode::default() });
+            node.count += 1;
+            start = end + 1;
+        }
+
+        node.projects.push(idx);
+    }
+
+    /// Appends the rows of the subgroups, followed by the projects of the group.
+    fn flatten(
+        &self,
+        depth: usize,
+        collapsed: &HashSet<String>,
+        projects: &'a [Project],
+        rows: &mut Vec<TreeRow<'a>>,
+    ) {
+        for (name, group) in &self.groups {
+            let is_collapsed = collapsed.contains(group.namespace);
+            rows.push(TreeRow::Group {
+                namespace: group.namespace,
+                name,
+                depth,
+                count: group.count,
+                collapsed: is_collapsed,
+            });
+
+            if !is_collapsed {
+                group.flatten(depth + 1, collapsed, projects, rows);
+            }
+        }
+
+        rows.extend(self.projects.iter()
+            .map(|&idx| TreeRow::Project { idx, project: &projects[idx], depth }));
+    }
+}
+
+impl TreeRow<'_> {
+    fn key(&self) -> TreeKey {
+        match self {
+            TreeRow::Group { namespace, .. } => TreeKey::Group(namespace.to_string()),
+            TreeRow::Project { project, .. } => TreeKey::Project(project.id),
+        }
+    }
+}
+
+impl StatefulWidget for ProjectTree<'_> {
+    type State = ProjectTreeState;
+
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State
+    ) {
+        Clear.render(area, buf);
+
+        let shortcuts = Shortcuts::from(vec![
+            ("g",   t(Msg::ShortcutFlatView)),
+            ("← →", t(Msg::ShortcutCollapseExpand)),
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("↵",   t(Msg::ShortcutDetails)),
+        ]);
+
+        Block::new()
+            .title(format!(" {} ", t(Msg::TitleGitlabPipelines)))
+            .title_style(theme().border_title)
+            .title_bottom(shortcuts.as_line())
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let content_area = area.inner(Margin::new(2, 1));
+        let row_count = content_area.height as usize;
+
+        let tree_rows = state.rows(self.projects);
+        let selected = state.selected_idx(&tree_rows);
+        state.table_state.select(Some(selected));
+        scroll_to_selected(&mut state.table_state, row_count, tree_rows.len());
+
+        let offset = state.table_state.offset();
+        let in_view = &tree_rows[offset.min(tree_rows.len())..(offset + row_count).min(tree_rows.len())];
+
+        let project_indices = in_view.iter().filter_map(|row| match row {
+            TreeRow::Project { idx, .. } => Some(*idx),
+            TreeRow::Group { .. }        => None,
+        });
+        state.viewport = match (project_indices.clone().min(), project_indices.max()) {
+            (Some(first), Some(last)) => first..last + 1,
+            _                         => 0..0,
+        };
+        state.row_areas = in_view.iter()
+            .enumerate()
+            .filter_map(|(row, tree_row)| match tree_row {
+                TreeRow::Project { project, .. } => Some((project.id, Rect::new(
+                    content_area.x, content_area.y + row as u16, content_area.width, 1
+                ))),
+                TreeRow::Group { .. } => None,
+            })
+            .collect();
+
+        let rows: Vec<Row> = in_view.iter()
+            .enumerate()
+            .map(|(row, tree_row)| self.row(tree_row).style(theme().table_row(offset + row)))
+            .collect();
+
+        let mut window_state = TableState::default()
+            .with_selected(Some(selected.saturating_sub(offset)));
+
+        let table = Table::new(rows, COLUMN_CONSTRAINTS)
+            .highlight_style(theme().highlight_symbol)
+            .column_spacing(1);
+
+        StatefulWidget::render(table, content_area, buf, &mut window_state);
+    }
+}
+
+impl<'a> ProjectTree<'a> {
+    fn row(&self, tree_row: &TreeRow<'a>) -> Row<'a> {
+        match *tree_row {
+            TreeRow::Group { name, depth, count, collapsed, .. } => {
+                let icon = if collapsed { "▸" } else { "▾" };
+                Row::new([Line::from(vec![
+                    Span::from(format!("{}{icon} ", indent(depth))),
+                    Span::from(format!("{name}/")).style(theme().project_parents),
+                    Span::from(format!(" ({count})")).style(theme().log_message),
+                ])])
+            },
+            TreeRow::Project { project, depth, .. } => {
+                let pin_icon = if (self.is_pinned)(project.id) { format!("{} ", pinned_icon()) } else { String::new() };
+                let name = Line::from(vec![
+                    Span::from(format!("{}{pin_icon}", indent(depth))),
+                    Span::from(project.title()).style(theme().project_name),
+                ]);
+
+                let pipelines = project.branch_pipelines()
+                    .into_iter()
+                    .flat_map(|p| [
+                        Span::from(p.status.labeled_icon()),
+                        Span::from(" "),
+                        Span::from(p.branch.as_str()).style(theme().pipeline_branch),
+                        Span::from("  "),
+                    ])
+                    .collect::<Vec<_>>();
+
+                Row::new([name, Line::from(pipelines)])
+            },
+        }
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+const COLUMN_CONSTRAINTS: [Constraint; 2] = [
+    Constraint::Min(40),         // groups and project names
+    Constraint::Percentage(100), // pipelines
+];
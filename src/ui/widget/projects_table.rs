@@ -1,28 +1,84 @@
+use std::ops::Range;
+
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Margin, Rect};
+use ratatui::layout::{Constraint, Margin, Position, Rect};
 use ratatui::prelude::StatefulWidget;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, BorderType, Clear, Row, Table, TableState, Widget};
-use crate::domain::{parse_row, Project};
+use crate::domain::{parse_row, Project, ProjectRowState};
+use crate::id::ProjectId;
+use crate::stores::{ProjectSort, QuickFilter};
 use crate::theme::theme;
+use crate::ui::branch_lines;
 use crate::ui::widget::Shortcuts;
+use crate::i18n::{t, Msg};
+
+/// minimum height of each project row, fitting the project name and namespace
+const MIN_PROJECT_ROW_HEIGHT: u16 = 3;
 
-/// gitlab pipelines widget
+/// Height of each project row, fitting a line per branch, see [branch_lines].
+pub fn project_row_height() -> u16 {
+    (branch_lines() as u16).max(MIN_PROJECT_ROW_HEIGHT)
+}
+
+/// gitlab pipelines widget. Rows are only built for the projects in view,
+/// as [parse_row] is too costly to run for every project each frame.
 pub struct ProjectsTable<'a> {
-    rows: Vec<Row<'a>>,
+    projects: &'a [Project],
+    row: Box<dyn Fn(&'a Project) -> Row<'a> + 'a>,
+    /// query of the project filter, shown in the title
+    filter: Option<&'a str>,
+    sort: ProjectSort,
+    quick_filter: Option<QuickFilter>,
+    show_instances: bool,
 }
 
 impl<'a> ProjectsTable<'a> {
     pub fn new(
-        projects: &'a [Project]
+        projects: &'a [Project],
+        filter: Option<&'a str>,
+        sort: ProjectSort,
+        quick_filter: Option<QuickFilter>,
+        row_state: impl Fn(ProjectId) -> ProjectRowState<'a> + 'a,
     ) -> Self {
+        let show_instances = projects.first()
+            .is_some_and(|p| row_state(p.id).instance.is_some());
+
         Self {
-            rows: projects.iter()
-                .map(|proj| parse_row(proj))
-                .enumerate()
-                .map(|(idx, r)| r.style(theme().table_row(idx)))
-                .collect()
+            projects,
+            row: Box::new(move |proj| parse_row(proj, row_state(proj.id))),
+            filter,
+            sort,
+            quick_filter,
+            show_instances,
         }
     }
+
+    /// Returns the position of the cursor in the filter query, shown in the
+    /// title of a table rendered to `area`.
+    pub fn filter_cursor_position(area: Rect, cursor: usize) -> Position {
+        let title = Line::from(title()).width() + Line::from(FILTER_PREFIX).width();
+        Position::new(area.x + 1 + (title + cursor) as u16, area.y)
+    }
+
+    /// Returns the indices of the rows in view of a table rendered to `area`.
+    pub fn visible_rows(area: Rect, state: &TableState) -> Range<usize> {
+        let content_area = area.inner(Margin::new(2, 1));
+        let rows = (content_area.height / project_row_height()) as usize;
+
+        state.offset()..state.offset() + rows
+    }
+
+    /// Returns the area of the row at `idx`, if visible in a table rendered to `area`.
+    pub fn row_area(area: Rect, state: &TableState, idx: usize) -> Option<Rect> {
+        let content_area = area.inner(Margin::new(2, 1));
+        let row = idx.checked_sub(state.offset())? as u16;
+        let height = project_row_height();
+        let y = content_area.y + row * height;
+
+        (y + height <= content_area.bottom())
+            .then(|| Rect::new(content_area.x, y, content_area.width, height))
+    }
 }
 
 impl StatefulWidget for ProjectsTable<'_> {
@@ -37,19 +93,42 @@ impl StatefulWidget for ProjectsTable<'_> {
         Clear.render(area, buf);
 
         let shortcuts = Shortcuts::from(vec![
-            ("q",   "quit"),
-            ("w",   "open web"),
-            ("c",   "config"),
-            ("a",   "last notification"),
-            ("l",   "logs"),
-            ("r",   "refresh"),
-            ("p",   "pipeline refresh"),
-            ("↑ ↓", "selection"),
-            ("↵",   "details"),
+            ("q",   t(Msg::ShortcutQuit)),
+            ("w",   t(Msg::ShortcutOpenWeb)),
+            ("b",   t(Msg::ShortcutBranches)),
+            ("s",   t(Msg::ShortcutSchedules)),
+            ("u",   t(Msg::ShortcutRunners)),
+            ("c",   t(Msg::ShortcutConfig)),
+            ("f",   t(Msg::ShortcutPin)),
+            ("n",   t(Msg::ShortcutWatch)),
+            ("x",   t(Msg::ShortcutHide)),
+            ("X",   t(Msg::ShortcutShowHidden)),
+            ("^f",  t(Msg::ShortcutSearch)),
+            ("/",   t(Msg::ShortcutFilter)),
+            ("o",   t(Msg::ShortcutSort)),
+            ("g",   t(Msg::ShortcutGroupedView)),
+            (":",   t(Msg::ShortcutCommands)),
+            ("?",   t(Msg::ShortcutHelp)),
+            ("a",   t(Msg::ShortcutLastNotification)),
+            ("h",   t(Msg::ShortcutHistory)),
+            ("l",   t(Msg::ShortcutLogs)),
+            ("r",   t(Msg::ShortcutRefresh)),
+            ("p",   t(Msg::ShortcutPipelineRefresh)),
+            ("↑ ↓", t(Msg::ShortcutSelection)),
+            ("↵",   t(Msg::ShortcutDetails)),
         ]);
 
+        let mut title = Line::from(title());
+        if let Some(filter) = self.filter {
+            title.push_span(Span::from(format!("{FILTER_PREFIX}{filter} ")).style(theme().input_selected));
+        }
+        if let Some(quick_filter) = self.quick_filter {
+            title.push_span(Span::from(format!("[{}] ", quick_filter.label())).style(theme().input_selected));
+        }
+        title.push_span(Span::from(format!("⇅ {} ", self.sort.label())).style(theme().project_parents));
+
         Block::new()
-            .title(" gitlab pipelines ")
+            .title(title)
             .title_style(theme().border_title)
             .title_bottom(shortcuts.as_line())
             .borders(Borders::ALL)
@@ -59,16 +138,69 @@ impl StatefulWidget for ProjectsTable<'_> {
             .render(area, buf);
 
         let content_area = area.inner(Margin::new(2, 1));
-        let table = Table::new(self.rows, PROJECT_COLUMN_CONSTRAINTS)
+        let constraints = if self.show_instances {
+            MULTI_INSTANCE_COLUMN_CONSTRAINTS.to_vec()
+        } else {
+            PROJECT_COLUMN_CONSTRAINTS.to_vec()
+        };
+
+        // only the rows in view are built, and rendered as a table of their own
+        let row_count = (content_area.height / project_row_height()) as usize;
+        scroll_to_selected(state, row_count, self.projects.len());
+
+        let offset = state.offset();
+        let rows: Vec<Row> = self.projects.iter()
+            .enumerate()
+            .skip(offset)
+            .take(row_count)
+            .map(|(idx, proj)| (self.row)(proj).style(theme().table_row(idx)))
+            .collect();
+
+        let mut window_state = TableState::default()
+            .with_selected(state.selected().map(|idx| idx.saturating_sub(offset)));
+
+        let table = Table::new(rows, constraints)
             .highlight_style(theme().highlight_symbol)
             .column_spacing(1);
 
-        StatefulWidget::render(table, content_area, buf, state);
+        StatefulWidget::render(table, content_area, buf, &mut window_state);
+    }
+}
+
+fn title() -> String {
+    format!(" {} ", t(Msg::TitleGitlabPipelines))
+}
+
+/// Precedes the query of the project filter in the title.
+const FILTER_PREFIX: &str = "/ ";
+
+/// Scrolls the table offset to keep the selected row in view, as [Table]
+/// does when rendering all rows.
+pub(super) fn scroll_to_selected(state: &mut TableState, row_count: usize, len: usize) {
+    let last_idx = len.saturating_sub(1);
+    let mut offset = state.offset().min(last_idx);
+
+    if let Some(selected) = state.selected().map(|idx| idx.min(last_idx)) {
+        if selected >= offset + row_count.max(1) {
+            offset = selected + 1 - row_count.max(1);
+        }
+        offset = offset.min(selected);
     }
+
+    *state.offset_mut() = offset;
 }
 
-const PROJECT_COLUMN_CONSTRAINTS: [Constraint; 3] = [
+const PROJECT_COLUMN_CONSTRAINTS: [Constraint; 4] = [
+    Constraint::Length(16),      // date and time
+    Constraint::Min(40),         // project name
+    Constraint::Length(6),       // health
+    Constraint::Percentage(100), // pipelines
+];
+
+const MULTI_INSTANCE_COLUMN_CONSTRAINTS: [Constraint; 5] = [
     Constraint::Length(16),      // date and time
+    Constraint::Length(14),      // gitlab instance
     Constraint::Min(40),         // project name
+    Constraint::Length(6),       // health
     Constraint::Percentage(100), // pipelines
 ];
@@ -0,0 +1,33 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::{Line, Widget};
+use ratatui::widgets::Clear;
+use crate::theme::theme;
+use crate::i18n::{t, Msg};
+
+/// plain-text line describing the selected row, shown in accessibility mode
+/// for screen readers; free of icons and styling beyond the theme colors
+pub struct SelectionLine {
+    item: String,
+}
+
+impl SelectionLine {
+    pub const HEIGHT: u16 = 1;
+
+    pub fn new(item: String) -> Self {
+        Self { item }
+    }
+}
+
+impl Widget for SelectionLine {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let message = t(Msg::SelectionAnnouncement)
+            .replace("{item}", &self.item);
+
+        Line::from(message)
+            .style(theme().log_message)
+            .render(area, buf);
+    }
+}
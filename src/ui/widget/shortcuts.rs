@@ -27,7 +27,7 @@ impl Shortcuts<'_> {
         }
     }
 
-    pub fn as_line(&self) -> Line {
+    pub fn as_line(&self) -> Line<'_> {
         let shortcuts = self.values.iter()
             .flat_map(|(key, label)| {
                 if label.contains(key) {
@@ -41,7 +41,7 @@ impl Shortcuts<'_> {
             .alignment(self.alignment)
     }
 
-    fn spans_from_shortcut<'a>(&'a self, key: &'a str, label: &'a str) -> Vec<Span> {
+    fn spans_from_shortcut<'a>(&'a self, key: &'a str, label: &'a str) -> Vec<Span<'a>> {
         vec![
             Span::from(" "),
             Span::from(key).style(self.shortcut_key_style),
@@ -0,0 +1,99 @@
+use chrono::Local;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::Clear;
+use crate::i18n::{t, Msg};
+use crate::stores::{QuickFilter, StatusStore};
+use crate::theme::theme;
+use crate::ui::format_age;
+
+/// one-line status bar at the bottom of the screen, with the GitLab host,
+/// time since the last poll, active pipelines, token expiry and scopes,
+/// current filter and last error
+pub struct StatusBar<'a> {
+    status: &'a StatusStore,
+    active_pipelines: usize,
+    filter: &'a str,
+    quick_filter: Option<QuickFilter>,
+    /// whether the session is being recorded
+    recording: bool,
+}
+
+impl<'a> StatusBar<'a> {
+    pub const HEIGHT: u16 = 1;
+
+    pub fn new(
+        status: &'a StatusStore,
+        active_pipelines: usize,
+        filter: &'a str,
+        quick_filter: Option<QuickFilter>,
+        recording: bool,
+    ) -> Self {
+        Self { status, active_pipelines, filter, quick_filter, recording }
+    }
+}
+
+impl Widget for StatusBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let last_poll = match self.status.last_poll() {
+            Some(at) => t(Msg::StatusPolledAgo).replace("{time}", &format_age(Local::now() - at)),
+            None     => t(Msg::StatusNotPolled).to_string(),
+        };
+        let active = t(Msg::StatusActivePipelines)
+            .replace("{count}", &self.active_pipelines.to_string());
+
+        let separator = || Span::from(" │ ").style(theme().table_border);
+        let mut spans = Vec::new();
+        if self.recording {
+            spans.extend([
+                Span::from(format!(" {}", t(Msg::StatusRecording))).style(theme().notification),
+                separator(),
+            ]);
+        }
+        spans.extend([
+            Span::from(format!(" {}", self.status.host())).style(theme().project_name),
+            separator(),
+            Span::from(last_poll).style(theme().time),
+            separator(),
+            Span::from(active).style(theme().pipeline_branch),
+        ]);
+
+        if let Some(days) = self.status.token_days_left() {
+            let style = if self.status.is_token_expiring() { theme().notification } else { theme().time };
+            spans.extend([
+                separator(),
+                Span::from(t(Msg::StatusTokenExpires).replace("{days}", &days.to_string())).style(style),
+            ]);
+        }
+
+        if !self.status.token_scope_warnings().is_empty() {
+            spans.extend([
+                separator(),
+                Span::from(t(Msg::StatusTokenScopes)).style(theme().notification),
+            ]);
+        }
+
+        let filter = [self.quick_filter.map(|f| f.label()), Some(self.filter).filter(|f| !f.is_empty())];
+        let filter = filter.into_iter().flatten().collect::<Vec<_>>();
+        if !filter.is_empty() {
+            spans.extend([
+                separator(),
+                Span::from(format!("{}: {}", t(Msg::StatusFilter), filter.join(", "))).style(theme().input_selected),
+            ]);
+        }
+
+        if let Some(error) = self.status.last_error() {
+            spans.extend([
+                separator(),
+                Span::from(format!("⚠ {error}")).style(theme().notification),
+            ]);
+        }
+
+        Line::from(spans)
+            .style(theme().background)
+            .render(area, buf);
+    }
+}
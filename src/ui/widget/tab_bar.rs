@@ -0,0 +1,117 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Tabs, Wrap};
+use crate::i18n::{t, Msg};
+use crate::theme::theme;
+
+/// Views of the main area, selected from the [TabBar].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tab {
+    #[default]
+    Projects,
+    Pipelines,
+    FailedJobs,
+    MultiProject,
+    MergeRequests,
+    Runners,
+    Logs,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 7] = [
+        Tab::Projects,
+        Tab::Pipelines,
+        Tab::FailedJobs,
+        Tab::MultiProject,
+        Tab::MergeRequests,
+        Tab::Runners,
+        Tab::Logs,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Projects      => t(Msg::TabProjects),
+            Tab::Pipelines     => t(Msg::TabPipelines),
+            Tab::FailedJobs    => t(Msg::TabFailedJobs),
+            Tab::MultiProject  => t(Msg::TabMultiProject),
+            Tab::MergeRequests => t(Msg::TabMergeRequests),
+            Tab::Runners       => t(Msg::TabRunners),
+            Tab::Logs          => t(Msg::TabLogs),
+        }
+    }
+
+    /// The digit key selecting the tab.
+    pub fn key(self) -> char {
+        let idx = Tab::ALL.iter().position(|tab| *tab == self).unwrap_or_default();
+        char::from_digit(idx as u32 + 1, 10).unwrap_or('1')
+    }
+}
+
+/// one-line tab bar above the main area
+pub struct TabBar {
+    selected: Tab,
+}
+
+impl TabBar {
+    pub const HEIGHT: u16 = 1;
+
+    pub fn new(selected: Tab) -> Self {
+        Self { selected }
+    }
+}
+
+impl Widget for TabBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let titles = Tab::ALL.iter()
+            .map(|tab| Line::from(vec![
+                Span::from(format!("{} ", tab.key())).style(theme().input_label),
+                Span::from(tab.title()),
+            ]));
+
+        let selected = Tab::ALL.iter().position(|tab| *tab == self.selected);
+        Tabs::new(titles)
+            .select(selected.unwrap_or_default())
+            .style(theme().log_message)
+            .highlight_style(theme().highlight_symbol)
+            .divider(Span::from("│").style(theme().table_border))
+            .render(area, buf);
+    }
+}
+
+/// Placeholder of tabs without a view of their own yet.
+pub struct EmptyTab {
+    tab: Tab,
+}
+
+impl EmptyTab {
+    pub fn new(tab: Tab) -> Self {
+        Self { tab }
+    }
+}
+
+impl Widget for EmptyTab {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        Block::new()
+            .title(format!(" {} ", self.tab.title()))
+            .title_style(theme().border_title)
+            .borders(Borders::ALL)
+            .border_style(theme().table_border)
+            .style(theme().background)
+            .border_type(BorderType::Plain)
+            .render(area, buf);
+
+        let message = match self.tab {
+            Tab::Runners => t(Msg::TabRunnersHint),
+            _            => t(Msg::TabNotAvailable),
+        };
+
+        Paragraph::new(Line::from(message).style(theme().log_message))
+            .wrap(Wrap { trim: true })
+            .render(area.inner(Margin::new(2, 1)), buf);
+    }
+}
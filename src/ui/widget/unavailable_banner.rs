@@ -0,0 +1,33 @@
+use chrono::{DateTime, Local};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::{Line, Span, Widget};
+use ratatui::widgets::Clear;
+use crate::theme::theme;
+use crate::i18n::{t, Msg};
+
+/// banner shown above the projects table while GitLab responds with 502/503
+pub struct UnavailableBanner {
+    since: DateTime<Local>,
+}
+
+impl UnavailableBanner {
+    pub const HEIGHT: u16 = 1;
+
+    pub fn new(since: DateTime<Local>) -> Self {
+        Self { since }
+    }
+}
+
+impl Widget for UnavailableBanner {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let message = t(Msg::BannerGitlabUnavailable)
+            .replace("{time}", &self.since.format("%H:%M").to_string());
+
+        Line::from(Span::from(format!(" ⚠ {message} ")))
+            .style(theme().notification)
+            .render(area, buf);
+    }
+}
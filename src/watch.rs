@@ -0,0 +1,149 @@
+use std::sync::mpsc::Sender;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dispatcher::Dispatcher;
+use crate::domain::{PipelineStatus, Project};
+use crate::event::GlimEvent;
+use crate::glim_app::GlimConfig;
+use crate::i18n::{t, Msg};
+use crate::id::{PipelineId, ProjectId};
+use crate::stores::ProjectStore;
+
+/// A project, or a single branch of a project, with notifications for
+/// completed pipelines.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Watch {
+    pub project: ProjectId,
+    /// Watched branch; all branches if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub rule: WatchRule,
+}
+
+/// Completed pipelines which trigger a notification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchRule {
+    /// The pipeline failed
+    #[default]
+    Failure,
+    /// The pipeline failed or succeeded
+    Completion,
+    /// The pipeline succeeded after a failed pipeline on the same branch
+    Recovery,
+}
+
+/// A completed pipeline matching a [Watch].
+#[derive(Debug, Clone)]
+pub struct WatchMatch {
+    pub project_id: ProjectId,
+    pub pipeline_id: PipelineId,
+    pub branch: String,
+    pub status: PipelineStatus,
+    pub rule: WatchRule,
+}
+
+/// Matches completed pipelines against the watched projects and branches,
+/// dispatching [GlimEvent::WatchMatched] for each match.
+pub struct WatchList {
+    sender: Sender<GlimEvent>,
+    watches: Vec<Watch>,
+}
+
+impl Watch {
+    fn matches(&self, project: &Project, pipeline_id: PipelineId, status: &PipelineStatus) -> bool {
+        let Some(pipeline) = project.pipeline(pipeline_id) else { return false };
+        if self.project != project.id || self.branch.as_ref().is_some_and(|b| *b != pipeline.branch) {
+            return false;
+        }
+
+        match self.rule {
+            WatchRule::Failure    => *status == PipelineStatus::Failed,
+            WatchRule::Completion => matches!(status, PipelineStatus::Failed | PipelineStatus::Success),
+            WatchRule::Recovery   => *status == PipelineStatus::Success
+                && previous_completed(project, pipeline_id) == Some(&PipelineStatus::Failed),
+        }
+    }
+}
+
+/// Status of the completed pipeline preceding `pipeline_id` on the same branch.
+fn previous_completed(project: &Project, pipeline_id: PipelineId) -> Option<&PipelineStatus> {
+    let pipelines = project.pipelines.as_ref()?;
+    let idx = pipelines.iter().position(|p| p.id == pipeline_id)?;
+    let branch = &pipelines[idx].branch;
+
+    // pipelines are ordered by recency
+    pipelines[idx + 1..].iter()
+        .find(|p| p.branch == *branch && !p.status.is_active())
+        .map(|p| &p.status)
+}
+
+impl WatchMatch {
+    /// Describes the completed pipeline, e.g. "pipeline failed".
+    pub fn outcome(&self) -> &'static str {
+        match (self.rule, &self.status) {
+            (WatchRule::Recovery, _)        => t(Msg::NoticeWatchRecovered),
+            (_, PipelineStatus::Failed)     => t(Msg::NoticeWatchFailed),
+            _                               => t(Msg::NoticeWatchSucceeded),
+        }
+    }
+}
+
+impl WatchList {
+    pub fn new(sender: Sender<GlimEvent>, config: &GlimConfig) -> Self {
+        Self {
+            sender,
+            watches: config.watches.clone(),
+        }
+    }
+
+    pub fn apply(&mut self, event: &GlimEvent, projects: &ProjectStore) {
+        match event {
+            GlimEvent::PipelineStatusChanged(project_id, pipeline_id, _, status @ (PipelineStatus::Failed | PipelineStatus::Success)) => {
+                let Some(project) = projects.find(*project_id) else { return };
+                let Some(watch) = self.watches.iter().find(|w| w.matches(project, *pipeline_id, status)) else { return };
+                let Some(pipeline) = project.pipeline(*pipeline_id) else { return };
+
+                self.dispatch(GlimEvent::WatchMatched(WatchMatch {
+                    project_id: *project_id,
+                    pipeline_id: *pipeline_id,
+                    branch: pipeline.branch.clone(),
+                    status: status.clone(),
+                    rule: watch.rule,
+                }));
+            },
+            GlimEvent::ToggleWatch(project_id, branch) => {
+                let idx = self.watches.iter()
+                    .position(|w| w.project == *project_id && w.branch == *branch);
+
+                match idx {
+                    Some(idx) => { self.watches.remove(idx); },
+                    None      => self.watches.push(Watch {
+                        project: *project_id,
+                        branch: branch.clone(),
+                        rule: WatchRule::default(),
+                    }),
+                }
+            },
+            GlimEvent::UpdateConfig(config) => self.watches.clone_from(&config.watches),
+            _ => (),
+        }
+    }
+
+    pub fn watches(&self) -> &[Watch] {
+        &self.watches
+    }
+
+    /// Returns true if the project, or any of its branches, is watched.
+    pub fn is_watched(&self, id: ProjectId) -> bool {
+        self.watches.iter().any(|w| w.project == id)
+    }
+}
+
+impl Dispatcher for WatchList {
+    fn dispatch(&self, event: GlimEvent) {
+        self.sender.dispatch(event)
+    }
+}